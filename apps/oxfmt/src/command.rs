@@ -27,6 +27,11 @@ pub struct FormatCommand {
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
+    /// Format the code read from stdin and print it to stdout,
+    /// PATH is used to infer the file type and to find the config
+    #[bpaf(argument("PATH"))]
+    pub stdin_filepath: Option<PathBuf>,
+
     /// Single file, single path or list of paths.
     /// If not provided, current working directory is used.
     // `bpaf(fallback)` seems to have issues with `many` or `positional`,
@@ -47,6 +52,10 @@ pub enum OutputOptions {
     /// List mode - list files that would be changed
     #[bpaf(long)]
     ListDifferent,
+    /// Verify mode - check that formatting keeps the AST unchanged and is idempotent,
+    /// without writing files
+    #[bpaf(long)]
+    Verify,
 }
 
 /// Miscellaneous
@@ -1,21 +1,29 @@
-use std::{env, io::Write, path::PathBuf, sync::mpsc, time::Instant};
+use std::{
+    env,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Instant,
+};
 
 use ignore::overrides::OverrideBuilder;
 
 use oxc_diagnostics::DiagnosticService;
+use oxc_formatter::ConfigResolver;
 
 use crate::{
     cli::{CliRunResult, FormatCommand},
     command::OutputOptions,
     reporter::DefaultReporter,
-    service::FormatService,
-    walk::Walk,
+    service::{FormatService, format_source},
+    walk::{FileKind, Walk, is_ignored, load_ignore_file},
 };
 
 #[derive(Debug)]
 pub struct FormatRunner {
     options: FormatCommand,
     cwd: PathBuf,
+    stdin: Option<String>,
 }
 
 impl FormatRunner {
@@ -24,7 +32,11 @@ impl FormatRunner {
     /// # Panics
     /// Panics if the current working directory cannot be determined.
     pub fn new(options: FormatCommand) -> Self {
-        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+        }
     }
 
     #[must_use]
@@ -33,11 +45,29 @@ impl FormatRunner {
         self
     }
 
+    /// Uses `stdin` as the input of `--stdin-filepath` instead of reading it from stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, stdin: String) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+
     pub fn run(self, stdout: &mut dyn Write) -> CliRunResult {
         let start_time = Instant::now();
 
         let cwd = self.cwd;
-        let FormatCommand { paths, output_options, misc_options } = self.options;
+        let FormatCommand { paths, output_options, misc_options, stdin_filepath } = self.options;
+
+        if let Some(stdin_filepath) = stdin_filepath {
+            if !paths.is_empty() || !matches!(output_options, OutputOptions::DefaultWrite) {
+                print_and_flush_stdout(
+                    &mut io::stderr(),
+                    "`--stdin-filepath` cannot be used with paths, `--check` or `--list-different`\n",
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+            return run_stdin(&cwd, &stdin_filepath, self.stdin, stdout);
+        }
 
         // Default to current working directory if no paths are provided
         let paths = if paths.is_empty() { vec![cwd.clone()] } else { paths };
@@ -63,8 +93,15 @@ impl FormatRunner {
             })
             .flatten();
 
-        // TODO: Support ignoring files
-        let walker = Walk::new(&target_paths, override_builder);
+        let ignore_file = match load_ignore_file(&cwd) {
+            Ok(ignore_file) => ignore_file,
+            Err(error) => {
+                print_and_flush_stdout(stdout, &format!("{error}\n"));
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        let walker = Walk::new(&target_paths, override_builder, ignore_file);
 
         // Get the receiver for streaming entries
         let rx_entry = walker.stream_entries();
@@ -146,6 +183,19 @@ impl FormatRunner {
                 print_stats(stdout);
                 CliRunResult::FormatMismatch
             }
+            (OutputOptions::Verify, 0) => {
+                print_and_flush_stdout(stdout, "All matched files passed verification.\n");
+                print_stats(stdout);
+                CliRunResult::FormatSucceeded
+            }
+            (OutputOptions::Verify, failed_count) => {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("Verification failed for {failed_count} scripts in above files.\n"),
+                );
+                print_stats(stdout);
+                CliRunResult::VerifyFailed
+            }
             // Default (write) also outputs friendly summary
             (OutputOptions::DefaultWrite, formatted_count) => {
                 print_and_flush_stdout(stdout, &format!("Formatted {formatted_count} files.\n"));
@@ -156,6 +206,71 @@ impl FormatRunner {
     }
 }
 
+/// Formats the code read from stdin as the file at `path`, and prints it to `stdout`.
+/// Code of files matched by the ignore file is printed unchanged, as Prettier does.
+/// Errors are printed to stderr.
+fn run_stdin(
+    cwd: &Path,
+    path: &Path,
+    stdin: Option<String>,
+    stdout: &mut dyn Write,
+) -> CliRunResult {
+    let path = if path.is_relative() { cwd.join(path) } else { path.to_path_buf() };
+    let mut stderr = io::stderr();
+
+    let ignore_file = match load_ignore_file(cwd) {
+        Ok(ignore_file) => ignore_file,
+        Err(error) => {
+            print_and_flush_stdout(&mut stderr, &format!("{error}\n"));
+            return CliRunResult::InvalidOptionConfig;
+        }
+    };
+
+    let source_text = if let Some(stdin) = stdin {
+        stdin
+    } else {
+        let mut source_text = String::new();
+        if let Err(error) = io::stdin().read_to_string(&mut source_text) {
+            print_and_flush_stdout(&mut stderr, &format!("Failed to read stdin: {error}\n"));
+            return CliRunResult::FormatFailed;
+        }
+        source_text
+    };
+
+    if ignore_file.is_some_and(|ignore_file| is_ignored(&ignore_file, &path, false)) {
+        print_and_flush_stdout(stdout, &source_text);
+        return CliRunResult::FormatSucceeded;
+    }
+
+    let Some(kind) = FileKind::from_path(&path) else {
+        print_and_flush_stdout(
+            &mut stderr,
+            &format!("Unsupported file type: {}\n", path.to_string_lossy()),
+        );
+        return CliRunResult::FormatFailed;
+    };
+
+    let result = ConfigResolver::default()
+        .resolve(&path)
+        .map_err(|diagnostic| vec![diagnostic])
+        .and_then(|options| format_source(kind, &source_text, options, false));
+    match result {
+        Ok(code) => {
+            print_and_flush_stdout(stdout, &code);
+            CliRunResult::FormatSucceeded
+        }
+        Err(errors) => {
+            let (mut diagnostic_service, tx_error) =
+                DiagnosticService::new(Box::new(DefaultReporter::default()));
+            let diagnostics = DiagnosticService::wrap_diagnostics(cwd, &path, &source_text, errors);
+            tx_error.send((path, diagnostics)).unwrap();
+            drop(tx_error);
+            diagnostic_service.run(&mut stderr);
+            CliRunResult::FormatFailed
+        }
+    }
+}
+
 fn print_and_flush_stdout(stdout: &mut dyn Write, message: &str) {
     use std::io::{Error, ErrorKind};
     fn check_for_writer_error(error: Error) -> Result<(), Error> {
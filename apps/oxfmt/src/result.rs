@@ -8,6 +8,7 @@ pub enum CliRunResult {
     // Warning error
     InvalidOptionConfig,
     FormatMismatch,
+    VerifyFailed,
     // Fatal error
     NoFilesFound,
     FormatFailed,
@@ -17,7 +18,9 @@ impl Termination for CliRunResult {
     fn report(self) -> ExitCode {
         match self {
             Self::None | Self::FormatSucceeded => ExitCode::from(0),
-            Self::InvalidOptionConfig | Self::FormatMismatch => ExitCode::from(1),
+            Self::InvalidOptionConfig | Self::FormatMismatch | Self::VerifyFailed => {
+                ExitCode::from(1)
+            }
             Self::NoFilesFound | Self::FormatFailed => ExitCode::from(2),
         }
     }
@@ -1,18 +1,25 @@
 use std::{fs, path::Path, sync::mpsc, time::Instant};
 
 use cow_utils::CowUtils;
+use miette::SourceSpan;
 use rayon::prelude::*;
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, OxcDiagnostic};
-use oxc_formatter::{FormatOptions, Formatter};
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, LabeledSpan, OxcDiagnostic};
+use oxc_formatter::{ConfigResolver, FormatOptions, Formatter, VerifyError};
 use oxc_parser::{ParseOptions, Parser};
+use oxc_partial_loader::PartialLoader;
+use oxc_span::{SourceType, Span};
 
-use crate::{command::OutputOptions, walk::WalkEntry};
+use crate::{
+    command::OutputOptions,
+    walk::{FileKind, WalkEntry},
+};
 
 pub struct FormatService {
     cwd: Box<Path>,
     output_options: OutputOptions,
+    config_resolver: ConfigResolver,
 }
 
 impl FormatService {
@@ -20,7 +27,7 @@ impl FormatService {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), output_options }
+        Self { cwd: cwd.into(), output_options, config_resolver: ConfigResolver::default() }
     }
 
     /// Process entries as they are received from the channel
@@ -44,37 +51,32 @@ impl FormatService {
         let start_time = Instant::now();
 
         let path = Path::new(&entry.path);
-        let source_type = entry.source_type;
 
         // TODO: Use `read_to_arena_str()` like `oxlint`?
         let source_text = fs::read_to_string(path).expect("Failed to read file");
-        // TODO: Use `AllocatorPool.get()` like `oxlint`?
-        let allocator = Allocator::new();
-
-        let ret = Parser::new(&allocator, &source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: false,
-                // Enable all syntax features
-                allow_v8_intrinsics: true,
-                allow_return_outside_function: true,
-                // `oxc_formatter` expects this to be false
-                preserve_parens: false,
-            })
-            .parse();
-        if !ret.errors.is_empty() {
-            let diagnostics = DiagnosticService::wrap_diagnostics(
-                self.cwd.clone(),
-                path,
-                &source_text,
-                ret.errors,
-            );
-            tx_error.send((path.to_path_buf(), diagnostics)).unwrap();
-            return;
-        }
 
-        // TODO: Read and apply config
-        let options = FormatOptions::default();
-        let code = Formatter::new(&allocator, options).build(&ret.program);
+        let options = match self.config_resolver.resolve(path) {
+            Ok(options) => options,
+            Err(diagnostic) => {
+                tx_error.send((path.to_path_buf(), vec![diagnostic.into()])).unwrap();
+                return;
+            }
+        };
+
+        let verify = matches!(self.output_options, OutputOptions::Verify);
+        let code = match format_source(entry.kind, &source_text, options, verify) {
+            Ok(code) => code,
+            Err(errors) => {
+                let diagnostics = DiagnosticService::wrap_diagnostics(
+                    self.cwd.clone(),
+                    path,
+                    &source_text,
+                    errors,
+                );
+                tx_error.send((path.to_path_buf(), diagnostics)).unwrap();
+                return;
+            }
+        };
 
         let elapsed = start_time.elapsed();
         let is_changed = source_text != code;
@@ -111,3 +113,133 @@ impl FormatService {
         }
     }
 }
+
+/// Formats the source text of a file of the given kind.
+///
+/// With `verify`, every script is verified with [`Formatter::verify`],
+/// and a failed verification is returned as a warning.
+pub fn format_source(
+    kind: FileKind,
+    source_text: &str,
+    options: FormatOptions,
+    verify: bool,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    match kind {
+        FileKind::Script(source_type) => {
+            format_script(source_text, source_type, options, "", verify)
+        }
+        FileKind::Partial(extension) => format_partial(extension, source_text, &options, verify),
+    }
+}
+
+/// Formats a JavaScript or TypeScript source, starting every line with `indentation`.
+fn format_script(
+    source_text: &str,
+    source_type: SourceType,
+    options: FormatOptions,
+    indentation: &str,
+    verify: bool,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    // TODO: Use `AllocatorPool.get()` like `oxlint`?
+    let allocator = Allocator::new();
+
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions {
+            parse_regular_expression: false,
+            // Enable all syntax features
+            allow_v8_intrinsics: true,
+            allow_return_outside_function: true,
+            // `oxc_formatter` expects this to be false
+            preserve_parens: false,
+            tokens: false,
+            error_recovery: false,
+        })
+        .parse();
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+
+    let formatter = Formatter::new(&allocator, options);
+    if !verify {
+        return Ok(formatter.build_with_prefix(&ret.program, indentation));
+    }
+    // Files are not written in verify mode, so the code is left unindented
+    formatter.verify(&ret.program).map_err(|error| {
+        let span = match &error {
+            VerifyError::AstMismatch { span, .. } => *span,
+            _ => Span::empty(0),
+        };
+        let mut diagnostic = OxcDiagnostic::warn(error.to_string()).with_label(span);
+        if let Some(diff) = error.diff() {
+            diagnostic = diagnostic.with_help(diff.trim_end().to_string());
+        }
+        vec![diagnostic]
+    })
+}
+
+/// Formats the scripts embedded in a Vue, Svelte or Astro file, and leaves the rest of the file as is.
+///
+/// Each script keeps the indentation of its first line, and the indentation of its closing tag.
+fn format_partial(
+    extension: &str,
+    source_text: &str,
+    options: &FormatOptions,
+    verify: bool,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    let sources = PartialLoader::parse(extension, source_text).unwrap_or_default();
+
+    let mut code = String::with_capacity(source_text.len());
+    let mut errors = vec![];
+    let mut last_end = 0;
+    for source in sources {
+        let script = source.source_text;
+        if script.trim().is_empty() {
+            continue;
+        }
+
+        let leading_whitespace = &script[..script.len() - script.trim_start().len()];
+        let base_indentation =
+            leading_whitespace.rfind('\n').map_or("", |i| &leading_whitespace[i + 1..]);
+        let trailing_whitespace = &script[script.trim_end().len()..];
+        let closing_indentation =
+            trailing_whitespace.rfind('\n').map_or("", |i| &trailing_whitespace[i + 1..]);
+
+        let start = source.start as usize;
+        match format_script(script, source.source_type, options.clone(), base_indentation, verify) {
+            Ok(formatted) => {
+                code.push_str(&source_text[last_end..start]);
+                code.push('\n');
+                code.push_str(&formatted);
+                code.push_str(closing_indentation);
+                last_end = start + script.len();
+            }
+            Err(diagnostics) => {
+                errors.extend(
+                    diagnostics.into_iter().map(|diagnostic| with_offset(diagnostic, start)),
+                );
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    code.push_str(&source_text[last_end..]);
+    Ok(code)
+}
+
+/// Moves the labels of a diagnostic of an embedded script to its position in the whole file.
+fn with_offset(mut diagnostic: OxcDiagnostic, offset: usize) -> OxcDiagnostic {
+    if let Some(labels) = &mut diagnostic.labels {
+        for label in labels {
+            let text = label.label().map(ToString::to_string);
+            let span = SourceSpan::new((label.offset() + offset).into(), label.len());
+            *label = if label.primary() {
+                LabeledSpan::new_primary_with_span(text, span)
+            } else {
+                LabeledSpan::new_with_span(text, span)
+            };
+        }
+    }
+    diagnostic
+}
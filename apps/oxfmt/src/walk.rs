@@ -1,44 +1,94 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+};
 
-use ignore::overrides::Override;
+use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
+    overrides::Override,
+};
 
 use oxc_formatter::get_supported_source_type;
+use oxc_partial_loader::PARTIAL_LOADER_EXTENSIONS;
 use oxc_span::SourceType;
 
 pub struct Walk {
     inner: ignore::WalkParallel,
+    /// The patterns of `.oxfmtignore` or `.prettierignore`.
+    ignore_file: Option<Arc<Gitignore>>,
 }
 
 pub struct WalkEntry {
     pub path: Arc<OsStr>,
-    pub source_type: SourceType,
+    pub kind: FileKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FileKind {
+    /// A JavaScript or TypeScript file.
+    Script(SourceType),
+    /// A Vue, Svelte or Astro file, where only the embedded scripts are formatted.
+    /// Holds the file extension to pass to [`oxc_partial_loader::PartialLoader::parse`].
+    Partial(&'static str),
+}
+
+impl FileKind {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        if let Some(source_type) = get_supported_source_type(path) {
+            return Some(Self::Script(source_type));
+        }
+        let extension = path.extension()?.to_str()?;
+        PARTIAL_LOADER_EXTENSIONS
+            .iter()
+            .find(|ext| **ext == extension)
+            .map(|ext| Self::Partial(ext))
+    }
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<WalkEntry>,
+    ignore_file: Option<Arc<Gitignore>>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
-        Box::new(WalkVisitor { sender: self.sender.clone() })
+        Box::new(WalkVisitor { sender: self.sender.clone(), ignore_file: self.ignore_file.clone() })
     }
 }
 
 struct WalkVisitor {
     sender: mpsc::Sender<WalkEntry>,
+    ignore_file: Option<Arc<Gitignore>>,
+}
+
+impl WalkVisitor {
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.ignore_file.as_ref().is_some_and(|ignore_file| is_ignored(ignore_file, path, is_dir))
+    }
+}
+
+/// Whether `path` is matched by the patterns of the ignore file.
+pub fn is_ignored(ignore_file: &Gitignore, path: &Path, is_dir: bool) -> bool {
+    // Paths outside of the directory of the ignore file can't match it
+    path.starts_with(ignore_file.path())
+        && ignore_file.matched_path_or_any_parents(path, is_dir).is_ignore()
 }
 
 impl ignore::ParallelVisitor for WalkVisitor {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
+                let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+                if self.is_ignored(entry.path(), is_dir) {
+                    return ignore::WalkState::Skip;
+                }
                 // Skip if we can't get file type or if it's a directory
                 if let Some(file_type) = entry.file_type()
                     && !file_type.is_dir()
-                    && let Some(source_type) = get_supported_source_type(entry.path())
+                    && let Some(kind) = FileKind::from_path(entry.path())
                 {
-                    let walk_entry =
-                        WalkEntry { path: entry.path().as_os_str().into(), source_type };
+                    let walk_entry = WalkEntry { path: entry.path().as_os_str().into(), kind };
                     // Send each entry immediately through the channel
                     // If send fails, the receiver has been dropped, so stop walking
                     if self.sender.send(walk_entry).is_err() {
@@ -55,7 +105,11 @@ impl ignore::ParallelVisitor for WalkVisitor {
 impl Walk {
     /// Will not canonicalize paths.
     /// # Panics
-    pub fn new(paths: &[PathBuf], override_builder: Option<Override>) -> Self {
+    pub fn new(
+        paths: &[PathBuf],
+        override_builder: Option<Override>,
+        ignore_file: Option<Gitignore>,
+    ) -> Self {
         assert!(!paths.is_empty(), "At least one path must be provided to Walk::new");
 
         let mut inner = ignore::WalkBuilder::new(
@@ -78,7 +132,7 @@ impl Walk {
         // Do not follow symlinks like Prettier does.
         // See https://github.com/prettier/prettier/pull/14627
         let inner = inner.hidden(false).ignore(false).git_global(false).build_parallel();
-        Self { inner }
+        Self { inner, ignore_file: ignore_file.map(Arc::new) }
     }
 
     /// Stream entries through a channel as they are discovered
@@ -87,7 +141,7 @@ impl Walk {
 
         // Spawn the walk operation in a separate thread
         rayon::spawn(move || {
-            let mut builder = WalkBuilder { sender };
+            let mut builder = WalkBuilder { sender, ignore_file: self.ignore_file };
             self.inner.visit(&mut builder);
             // Channel will be closed when builder is dropped
         });
@@ -95,3 +149,26 @@ impl Walk {
         receiver
     }
 }
+
+/// Loads the ignore file of `cwd`, `.oxfmtignore` or else `.prettierignore`,
+/// whose patterns are matched like `.gitignore` ones relative to `cwd`.
+///
+/// # Errors
+/// Returns an error message if the ignore file can't be read or parsed.
+pub fn load_ignore_file(cwd: &Path) -> Result<Option<Gitignore>, String> {
+    let Some(path) = [".oxfmtignore", ".prettierignore"]
+        .into_iter()
+        .map(|file_name| cwd.join(file_name))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    let mut builder = GitignoreBuilder::new(cwd);
+    if let Some(error) = builder.add(&path) {
+        return Err(format!("Failed to parse '{}': {error}", path.to_string_lossy()));
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|error| format!("Failed to parse '{}': {error}", path.to_string_lossy()))
+}
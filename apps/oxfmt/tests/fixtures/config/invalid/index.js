@@ -0,0 +1,2 @@
+const greeting = "hello";
+function greet(name) { return [greeting, name, "and welcome to the configured formatter"].map((part) => part.toUpperCase()); }
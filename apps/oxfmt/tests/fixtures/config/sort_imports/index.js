@@ -0,0 +1,7 @@
+import { readFile } from "node:fs";
+import { b, a } from "react";
+
+import { Button } from "@/components";
+
+import util from "../util";
+import { x } from "./x";
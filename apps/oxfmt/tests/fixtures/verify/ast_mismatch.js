@@ -0,0 +1,7 @@
+foo();
+
+function* g() {
+  yield ( // comment
+    a
+  );
+}
@@ -0,0 +1,4 @@
+const a = <any>(
+  // c
+  b
+);
@@ -0,0 +1,4 @@
+const   foo    =    "bar"
+function   test  (  )   {
+return   true
+    }
@@ -43,6 +43,114 @@ fn supported_extensions() {
         .test_and_snapshot_multiple(&[&["--check"]]);
 }
 
+#[test]
+fn config_file() {
+    // Each directory is formatted with a different kind of config
+    Tester::new().with_cwd(PathBuf::from("tests/fixtures/config")).test_and_snapshot_multiple(&[
+        &[
+            "--check",
+            "oxfmtrc",
+            "prettierrc",
+            "prettierrc_yaml",
+            "prettierrc_yml",
+            "package_json",
+            "editorconfig",
+            "sort_imports",
+        ],
+        &["--check", "invalid"],
+        &["--check", "unsupported"],
+    ]);
+}
+
+#[test]
+fn stdin_filepath() {
+    let tester = Tester::new().with_cwd(PathBuf::from("tests/fixtures/config"));
+    // The file type and the config are picked from the path, which doesn't need to exist
+    tester.test_stdin(
+        &["--stdin-filepath", "oxfmtrc/stdin.js"],
+        "const a = \"a\";\nif (a) {\n  foo()\n}\n",
+        "const a = 'a'\nif (a) {\n    foo()\n}\n",
+        "FormatSucceeded",
+    );
+    tester.test_stdin(
+        &["--stdin-filepath", "oxfmtrc/stdin.ts"],
+        "let a: string = \"a\"",
+        "let a: string = 'a';\n",
+        "FormatSucceeded",
+    );
+    // Errors are printed to stderr
+    tester.test_stdin(&["--stdin-filepath", "stdin.js"], "let a = ;", "", "FormatFailed");
+    tester.test_stdin(&["--stdin-filepath", "stdin.txt"], "a", "", "FormatFailed");
+    tester.test_stdin(
+        &["--stdin-filepath", "stdin.js", "--check"],
+        "let a",
+        "",
+        "InvalidOptionConfig",
+    );
+    tester.test_stdin(
+        &["--stdin-filepath", "stdin.js", "a.js"],
+        "let a",
+        "",
+        "InvalidOptionConfig",
+    );
+}
+
+#[test]
+fn ignore_file() {
+    // Only unformatted files which are not ignored are reported
+    Tester::new()
+        .with_cwd(PathBuf::from("tests/fixtures/ignore_file/oxfmtignore"))
+        .test_and_snapshot_multiple(&[&["--check"], &["--check", "ignored/a.js", "c.js"]]);
+    Tester::new()
+        .with_cwd(PathBuf::from("tests/fixtures/ignore_file/prettierignore"))
+        .test_and_snapshot_multiple(&[&["--check"]]);
+
+    // Ignored code from stdin is printed unchanged
+    let tester = Tester::new().with_cwd(PathBuf::from("tests/fixtures/ignore_file/oxfmtignore"));
+    tester.test_stdin(
+        &["--stdin-filepath", "ignored/stdin.js"],
+        "let   a",
+        "let   a",
+        "FormatSucceeded",
+    );
+    tester.test_stdin(
+        &["--stdin-filepath", "stdin.min.js"],
+        "let   a",
+        "let   a",
+        "FormatSucceeded",
+    );
+    tester.test_stdin(&["--stdin-filepath", "stdin.js"], "let   a", "let a;\n", "FormatSucceeded");
+}
+
+#[test]
+fn verify() {
+    // Files are reported when formatting changes their AST, or when it is not idempotent
+    Tester::new()
+        .with_cwd(PathBuf::from("tests/fixtures/verify"))
+        .test_and_snapshot_multiple(&[&["--verify", "valid.js"], &["--verify"]]);
+}
+
+#[test]
+fn embedded_scripts() {
+    // Only the scripts of Vue, Svelte and Astro files are formatted
+    Tester::new()
+        .with_cwd(PathBuf::from("tests/fixtures/embedded"))
+        .test_and_snapshot_multiple(&[&["--check"]]);
+}
+
+#[test]
+fn write_mode_embedded_scripts() {
+    let before = "<template>\n  <div   />\n</template>\n<script lang=\"ts\">\n  const   a: number = 1\n</script>\n";
+    let after = "<template>\n  <div   />\n</template>\n<script lang=\"ts\">\n  const a: number = 1;\n</script>\n";
+    Tester::test_write("tests/fixtures/temp.vue", before, after);
+
+    // The indentation is kept as is, even when it is not a multiple of the indent width,
+    // and lines of template literals are not indented
+    let before = "<script>\n   if (a) {   b()\n   }\n   const t = `x\ny`;\n</script>\n";
+    let after = "<script>\n   if (a) {\n     b();\n   }\n   const t = `x\ny`;\n</script>\n";
+    Tester::test_write("tests/fixtures/temp.vue", before, after);
+}
+
 #[test]
 fn write_mode() {
     let before = "  class                 Foo {}";
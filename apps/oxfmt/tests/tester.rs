@@ -102,6 +102,28 @@ impl Tester {
         });
     }
 
+    /// Runs a test with `stdin` as the input of `--stdin-filepath`,
+    /// and checks the output printed to stdout and the result.
+    ///
+    /// # Panics
+    /// Panics if command parsing fails or if the output doesn't match expected values.
+    pub fn test_stdin(
+        &self,
+        args: &[&str],
+        stdin: &str,
+        expected_output: &str,
+        expected_result: &str,
+    ) {
+        let command = format_command().run_inner(args).unwrap();
+        let mut output = Vec::new();
+        let result = FormatRunner::new(command)
+            .with_cwd(self.cwd.clone())
+            .with_stdin(stdin.to_string())
+            .run(&mut output);
+        assert_eq!(String::from_utf8(output).unwrap(), expected_output);
+        assert_eq!(format!("{result:?}"), expected_result);
+    }
+
     /// Tests the write mode where files are actually modified.
     /// Similar to oxlint's test_fix method.
     ///
@@ -0,0 +1,5 @@
+import { logger } from "./logger";
+
+logger.warn("starting", { verbose: true });
+console.log("unchanged");
+logger.warn(`${count} items`);
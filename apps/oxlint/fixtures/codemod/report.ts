@@ -0,0 +1,7 @@
+import { logger } from "./logger";
+
+export function report(errors: Error[]): void {
+  for (const error of errors) {
+    logger.warn(error.message, error as any);
+  }
+}
@@ -0,0 +1 @@
+console.warn("not a logger");
@@ -0,0 +1 @@
+logger.warn("write");
@@ -0,0 +1,8 @@
+// @flow
+import { type Node } from "react";
+
+function render(node: ?Node): void {
+  debugger;
+}
+
+export default render;
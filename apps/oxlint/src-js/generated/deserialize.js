@@ -151,6 +151,8 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -276,6 +278,20 @@ function deserializeThisExpression(pos) {
   };
 }
 
+function deserializeErrorExpression(pos) {
+  let start = deserializeU32(pos), end = deserializeU32(pos + 4);
+  return {
+    type: 'ErrorExpression',
+    start,
+    end,
+    range: [start, end],
+    get loc() {
+      return getLoc(this);
+    },
+    parent,
+  };
+}
+
 function deserializeArrayExpression(pos) {
   let start = deserializeU32(pos),
     end = deserializeU32(pos + 4),
@@ -378,6 +394,8 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -538,6 +556,8 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -888,6 +908,8 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1489,6 +1511,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorStatement(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1677,6 +1701,20 @@ function deserializeEmptyStatement(pos) {
   };
 }
 
+function deserializeErrorStatement(pos) {
+  let start = deserializeU32(pos), end = deserializeU32(pos + 4);
+  return {
+    type: 'ErrorStatement',
+    start,
+    end,
+    range: [start, end],
+    get loc() {
+      return getLoc(this);
+    },
+    parent,
+  };
+}
+
 function deserializeExpressionStatement(pos) {
   let start = deserializeU32(pos),
     end = deserializeU32(pos + 4),
@@ -1875,6 +1913,8 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3194,6 +3234,8 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -3752,6 +3794,8 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -6653,6 +6697,10 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxErrorExpression(pos) {
+  return deserializeErrorExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [], pos32 = pos >> 2;
   pos = uint32[pos32];
@@ -6887,6 +6935,10 @@ function deserializeBoxWithStatement(pos) {
   return deserializeWithStatement(uint32[pos >> 2]);
 }
 
+function deserializeBoxErrorStatement(pos) {
+  return deserializeErrorStatement(uint32[pos >> 2]);
+}
+
 function deserializeBoxVariableDeclaration(pos) {
   return deserializeVariableDeclaration(uint32[pos >> 2]);
 }
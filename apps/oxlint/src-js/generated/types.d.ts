@@ -53,6 +53,7 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | ErrorExpression
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -96,6 +97,11 @@ export interface ThisExpression extends Span {
   parent?: Node;
 }
 
+export interface ErrorExpression extends Span {
+  type: 'ErrorExpression';
+  parent?: Node;
+}
+
 export interface ArrayExpression extends Span {
   type: 'ArrayExpression';
   elements: Array<ArrayExpressionElement>;
@@ -400,6 +406,7 @@ export type Statement =
   | TryStatement
   | WhileStatement
   | WithStatement
+  | ErrorStatement
   | Declaration
   | ModuleDeclaration;
 
@@ -455,6 +462,11 @@ export interface EmptyStatement extends Span {
   parent?: Node;
 }
 
+export interface ErrorStatement extends Span {
+  type: 'ErrorStatement';
+  parent?: Node;
+}
+
 export interface ExpressionStatement extends Span {
   type: 'ExpressionStatement';
   expression: Expression;
@@ -1713,6 +1725,7 @@ export type Node =
   | BindingIdentifier
   | LabelIdentifier
   | ThisExpression
+  | ErrorExpression
   | ArrayExpression
   | ObjectExpression
   | ObjectProperty
@@ -1750,6 +1763,7 @@ export type Node =
   | VariableDeclaration
   | VariableDeclarator
   | EmptyStatement
+  | ErrorStatement
   | ExpressionStatement
   | IfStatement
   | DoWhileStatement
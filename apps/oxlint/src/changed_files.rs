@@ -0,0 +1,208 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashMap;
+
+/// The revision to compare against, to find changed files.
+#[derive(Debug, Clone)]
+pub enum ChangeBase {
+    /// Changes since a git revision, including uncommitted and untracked files.
+    Revision(String),
+    /// Changes in the git index.
+    Staged,
+}
+
+/// Lines changed in a file, 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangedLines {
+    /// The whole file is new.
+    All,
+    Ranges(Vec<RangeInclusive<usize>>),
+}
+
+/// Files changed in the git repository containing the working directory.
+///
+/// Only files which are changed are linted, but unchanged files are still loaded through the module
+/// graph when import plugin rules follow their imports.
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    cwd: PathBuf,
+    files: FxHashMap<PathBuf, ChangedLines>,
+}
+
+impl ChangedFiles {
+    /// Reads the changed files with `git diff`.
+    ///
+    /// Changed lines are only read when `with_lines` is set, otherwise every changed file is
+    /// considered changed as a whole.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `git` could not be run, e.g. `cwd` is not in a git repository or the
+    /// revision does not exist.
+    pub fn from_git(cwd: &Path, base: &ChangeBase, with_lines: bool) -> Result<Self, String> {
+        let mut diff = vec!["diff", "--relative", "--diff-filter=d", "--no-ext-diff", "--no-color"];
+        if with_lines {
+            diff.push("--unified=0");
+        } else {
+            diff.extend(["--name-only", "-z"]);
+        }
+        match base {
+            ChangeBase::Revision(revision) => diff.extend(["--end-of-options", revision]),
+            ChangeBase::Staged => diff.push("--cached"),
+        }
+
+        let output = git(cwd, &diff)?;
+        let mut changed_files = if with_lines {
+            Self::from_unified_diff(cwd, &output)
+        } else {
+            Self {
+                cwd: cwd.to_path_buf(),
+                files: output
+                    .split('\0')
+                    .filter(|path| !path.is_empty())
+                    .map(|path| (join_git_path(cwd, path), ChangedLines::All))
+                    .collect(),
+            }
+        };
+
+        // Untracked files are new files, they are not staged yet.
+        if matches!(base, ChangeBase::Revision(_)) {
+            let output = git(cwd, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+            for path in output.split('\0').filter(|path| !path.is_empty()) {
+                changed_files.files.insert(join_git_path(cwd, path), ChangedLines::All);
+            }
+        }
+
+        Ok(changed_files)
+    }
+
+    /// Reads the changed lines from the output of `git diff --unified=0`.
+    fn from_unified_diff(cwd: &Path, diff: &str) -> Self {
+        let mut files = FxHashMap::default();
+        let mut current: Option<&mut ChangedLines> = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current = path.strip_prefix("b/").map(|path| {
+                    &mut *files
+                        .entry(join_git_path(cwd, path))
+                        .or_insert(ChangedLines::Ranges(vec![]))
+                });
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                // `@@ -start[,count] +start[,count] @@`
+                let Some(added) = hunk.split(' ').find_map(|range| range.strip_prefix('+')) else {
+                    continue;
+                };
+                let (start, count) = added.split_once(',').unwrap_or((added, "1"));
+                let (Ok(start), Ok(count)) = (start.parse::<usize>(), count.parse::<usize>())
+                else {
+                    continue;
+                };
+                // Hunks which only remove lines have a count of 0.
+                if count == 0 {
+                    continue;
+                }
+                if let Some(ChangedLines::Ranges(ranges)) = current.as_deref_mut() {
+                    ranges.push(start..=start + count - 1);
+                }
+            }
+        }
+        Self { cwd: cwd.to_path_buf(), files }
+    }
+
+    /// Returns `true` if `path` has been changed.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains_key(&self.cwd.join(path))
+    }
+
+    /// Returns `true` if `line` (1-based) of `path` has been changed.
+    pub fn contains_line(&self, path: &Path, line: usize) -> bool {
+        match self.files.get(&self.cwd.join(path)) {
+            Some(ChangedLines::All) => true,
+            Some(ChangedLines::Ranges(ranges)) => ranges.iter().any(|range| range.contains(&line)),
+            None => false,
+        }
+    }
+}
+
+/// Joins a path printed by git, which always uses `/` as separator, onto `cwd`.
+fn join_git_path(cwd: &Path, path: &str) -> PathBuf {
+    let mut joined = cwd.to_path_buf();
+    joined.extend(path.split('/'));
+    joined
+}
+
+/// Runs `git` in `cwd`, returning its standard output.
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{ChangedFiles, ChangedLines};
+
+    #[test]
+    fn test_changed_files_from_unified_diff() {
+        let cwd = Path::new("/repo");
+        let diff = "\
+diff --git a/src/foo.js b/src/foo.js
+index 1111111..2222222 100644
+--- a/src/foo.js
++++ b/src/foo.js
+@@ -1,0 +2,3 @@ function foo() {
++  a;
++  b;
++  c;
+@@ -10 +12 @@ function bar() {
+-  old;
++  new;
+@@ -20,2 +23,0 @@
+-  removed;
+-  removed;
+diff --git a/new.js b/new.js
+new file mode 100644
+--- /dev/null
++++ b/new.js
+@@ -0,0 +1,2 @@
++let a;
++let b;
+";
+        let changed_files = ChangedFiles::from_unified_diff(cwd, diff);
+
+        assert_eq!(
+            changed_files.files[&cwd.join("src").join("foo.js")],
+            ChangedLines::Ranges(vec![2..=4, 12..=12])
+        );
+        assert_eq!(changed_files.files[&cwd.join("new.js")], ChangedLines::Ranges(vec![1..=2]));
+
+        let foo = Path::new("src/foo.js");
+        assert!(changed_files.contains(foo));
+        assert!(changed_files.contains(&cwd.join("new.js")));
+        assert!(!changed_files.contains(Path::new("src/bar.js")));
+        assert!(!changed_files.contains_line(foo, 1));
+        assert!(changed_files.contains_line(foo, 2));
+        assert!(changed_files.contains_line(foo, 4));
+        assert!(!changed_files.contains_line(foo, 5));
+        assert!(changed_files.contains_line(foo, 12));
+        assert!(!changed_files.contains_line(foo, 23));
+        assert!(!changed_files.contains_line(Path::new("src/bar.js"), 1));
+    }
+}
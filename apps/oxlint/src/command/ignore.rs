@@ -1,4 +1,4 @@
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 
 use bpaf::{Bpaf, doc::Style};
 
@@ -28,6 +28,13 @@ pub struct IgnoreOptions {
     pub no_ignore: bool,
 }
 
+impl IgnoreOptions {
+    /// The ignore file to read, or `None` with `--no-ignore`.
+    pub fn ignore_file(&self) -> Option<&OsStr> {
+        (!self.no_ignore).then_some(self.ignore_path.as_os_str())
+    }
+}
+
 #[cfg(test)]
 mod ignore_options {
     use std::{ffi::OsString, path::PathBuf};
@@ -26,9 +26,15 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub fix_options: FixOptions,
 
+    #[bpaf(external)]
+    pub codemod_options: CodemodOptions,
+
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    #[bpaf(external)]
+    pub changed_files_options: ChangedFilesOptions,
+
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
@@ -63,6 +69,37 @@ impl LintCommand {
         Self::init_rayon_thread_pool(self.misc_options.threads);
     }
 
+    /// The first option given which only applies to linting, and so cannot be used with `--codemod`.
+    pub fn lint_only_option(&self) -> Option<&'static str> {
+        let options = [
+            (!self.filter.is_empty(), "`-A`, `-W` or `-D`"),
+            (self.basic_options.config.is_some(), "`--config`"),
+            (self.basic_options.tsconfig.is_some(), "`--tsconfig`"),
+            (self.enable_plugins.is_any_set(), "plugin options"),
+            (self.fix_options.is_enabled(), "`--fix`"),
+            (self.changed_files_options.changed_since.is_some(), "`--changed-since`"),
+            (self.changed_files_options.staged, "`--staged`"),
+            (self.changed_files_options.changed_lines, "`--changed-lines`"),
+            (self.warning_options.quiet, "`--quiet`"),
+            (self.warning_options.deny_warnings, "`--deny-warnings`"),
+            (self.warning_options.max_warnings.is_some(), "`--max-warnings`"),
+            (self.output_options.format != OutputFormat::Default, "`--format`"),
+            (self.misc_options.print_config, "`--print-config`"),
+            (self.misc_options.timing, "`--timing`"),
+            (self.disable_nested_config, "`--disable-nested-config`"),
+            (self.type_aware, "`--type-aware`"),
+            (
+                !matches!(
+                    self.inline_config_options.report_unused_directives,
+                    ReportUnusedDirectives::WithoutSeverity(false)
+                        | ReportUnusedDirectives::WithSeverity(None)
+                ),
+                "`--report-unused-disable-directives`",
+            ),
+        ];
+        options.into_iter().find_map(|(is_set, option)| is_set.then_some(option))
+    }
+
     /// Initialize Rayon global thread pool with specified number of threads.
     ///
     /// If `--threads` option is not used, or `--threads 0` is given,
@@ -121,6 +158,14 @@ pub struct BasicOptions {
     /// Initialize oxlint configuration with default values
     #[bpaf(switch, hide_usage)]
     pub init: bool,
+
+    /// Migrate an ESLint configuration file to `.oxlintrc.json`
+    ///  * legacy `.eslintrc` configs can be JSON or YAML, or the `eslintConfig` field in `package.json`
+    ///  * flat configs (`eslint.config.js`) must be evaluated to JSON first
+    ///
+    /// Rules which are not implemented by oxlint are reported and left out.
+    #[bpaf(argument("./.eslintrc.json"), hide_usage)]
+    pub migrate: Option<PathBuf>,
 }
 
 // This is formatted according to
@@ -211,6 +256,43 @@ impl FixOptions {
     }
 }
 
+/// Codemods
+#[derive(Debug, Clone, Bpaf)]
+pub struct CodemodOptions {
+    /// Rewrite expressions matching PATTERN instead of linting. Options which only apply to linting,
+    /// such as `--fix`, cannot be used with it.
+    ///  * `$NAME` matches any expression, e.g. `$obj.foo($arg)`
+    ///  * `$$$NAME` matches any number of arguments, e.g. `foo($$$args)`
+    #[bpaf(argument("PATTERN"), hide_usage)]
+    pub codemod: Option<String>,
+
+    /// Replacement for expressions matching `--codemod`, which can use the pattern's metavariables
+    #[bpaf(argument("TEMPLATE"), hide_usage)]
+    pub rewrite: Option<String>,
+
+    /// Print the changes `--codemod` would make as a diff, without writing them
+    #[bpaf(switch, hide_usage)]
+    pub dry_run: bool,
+}
+
+/// Lint Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedFilesOptions {
+    /// Only lint files changed since the given git revision,
+    /// including uncommitted and untracked files
+    #[bpaf(argument("REV"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint files with changes staged in git
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+
+    /// Only report diagnostics on lines changed according to
+    /// `--changed-since` or `--staged`
+    #[bpaf(switch, hide_usage)]
+    pub changed_lines: bool,
+}
+
 /// Handle Warnings
 #[derive(Debug, Clone, Bpaf)]
 pub struct WarningOptions {
@@ -373,6 +455,29 @@ impl OverrideToggle {
 }
 
 impl EnablePlugins {
+    /// Returns `true` if any plugin is enabled or disabled on the command line.
+    pub fn is_any_set(&self) -> bool {
+        [
+            self.unicorn_plugin,
+            self.oxc_plugin,
+            self.typescript_plugin,
+            self.import_plugin,
+            self.react_plugin,
+            self.jsdoc_plugin,
+            self.jest_plugin,
+            self.vitest_plugin,
+            self.jsx_a11y_plugin,
+            self.nextjs_plugin,
+            self.react_perf_plugin,
+            self.promise_plugin,
+            self.node_plugin,
+            self.regex_plugin,
+            self.vue_plugin,
+        ]
+        .iter()
+        .any(|toggle| !toggle.is_not_set())
+    }
+
     pub fn apply_overrides(&self, plugins: &mut LintPlugins) {
         self.react_plugin.inspect(|yes| plugins.set(LintPlugins::REACT, yes));
         self.unicorn_plugin.inspect(|yes| plugins.set(LintPlugins::UNICORN, yes));
@@ -611,6 +716,33 @@ mod lint_options {
         let options = get_lint_options(".");
         assert!(!options.type_aware);
     }
+
+    #[test]
+    fn codemod() {
+        let options = lint_command()
+            .run_inner(&[
+                "--codemod",
+                "$a.foo($$$b)",
+                "--rewrite",
+                "$a.bar($$$b)",
+                "--dry-run",
+                "src",
+            ])
+            .unwrap();
+        assert_eq!(options.codemod_options.codemod.as_deref(), Some("$a.foo($$$b)"));
+        assert_eq!(options.codemod_options.rewrite.as_deref(), Some("$a.bar($$$b)"));
+        assert!(options.codemod_options.dry_run);
+        assert_eq!(options.paths, [PathBuf::from("src")]);
+        assert_eq!(options.lint_only_option(), None);
+        let options = get_lint_options(".");
+        assert!(options.codemod_options.codemod.is_none());
+        assert!(!options.codemod_options.dry_run);
+
+        let options = get_lint_options("--codemod foo($a) --rewrite bar($a) --fix .");
+        assert_eq!(options.lint_only_option(), Some("`--fix`"));
+        let options = get_lint_options("--codemod foo($a) --rewrite bar($a) --react-plugin .");
+        assert_eq!(options.lint_only_option(), Some("plugin options"));
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,10 @@ use bpaf::Bpaf;
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    lint::{
+        ChangedFilesOptions, CodemodOptions, LintCommand, OutputOptions, ReportUnusedDirectives,
+        WarningOptions, lint_command,
+    },
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -30,6 +33,14 @@ pub struct MiscOptions {
     /// When present, no linting is performed and only config-related options are valid.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Measure the time spent in each rule, and print the slowest rules
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Number of rules to print with `--timing`
+    #[bpaf(argument("INT"), fallback(10), hide_usage)]
+    pub timing_limit: usize,
 }
 
 #[expect(clippy::ptr_arg)]
@@ -1,11 +1,12 @@
 // Ignore dead code warnings when building `tasks/website`, which disables `napi` Cargo feature
 #![cfg_attr(not(feature = "napi"), allow(dead_code))]
 
+mod changed_files;
 mod command;
 mod lint;
+mod migrate;
 mod output_formatter;
 mod result;
-mod walk;
 
 #[cfg(test)]
 mod tester;
@@ -1,6 +1,7 @@
 use std::{
     env,
     ffi::OsStr,
+    fmt::Write as _,
     fs,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
@@ -10,19 +11,30 @@ use std::{
 
 use cow_utils::CowUtils;
 use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
+use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
+use similar::TextDiff;
 
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{
+    DiagnosticFilter, DiagnosticSender, DiagnosticService, Error, GraphicalReportHandler,
+    OxcDiagnostic,
+};
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
-    InvalidFilterKind, LintFilter, LintOptions, LintRunner, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, Codemod, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter,
+    ExternalPluginStore, InvalidFilterKind, LintFilter, LintOptions, LintRunner,
+    LintServiceOptions, Linter, Oxlintrc, RuleTimings, Walk, read_to_string,
 };
+use oxc_span::SourceType;
 
 use crate::{
-    cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormatter},
-    walk::Walk,
+    changed_files::{ChangeBase, ChangedFiles},
+    cli::{
+        ChangedFilesOptions, CliRunResult, CodemodOptions, LintCommand, MiscOptions,
+        ReportUnusedDirectives, WarningOptions,
+    },
+    migrate::migrate_file,
+    output_formatter::{LintCommandInfo, LintTimings, OutputFormatter},
 };
 use oxc_linter::LintIgnoreMatcher;
 
@@ -53,12 +65,21 @@ impl CliRunner {
             return CliRunResult::None;
         }
 
+        if let Some(eslint_config) = &self.options.basic_options.migrate {
+            return self.migrate_eslint_config(stdout, eslint_config);
+        }
+
+        if let Some(pattern) = &self.options.codemod_options.codemod {
+            return self.run_codemod(stdout, pattern);
+        }
+
         let LintCommand {
             paths,
             filter,
             basic_options,
             warning_options,
             ignore_options,
+            changed_files_options,
             fix_options,
             enable_plugins,
             misc_options,
@@ -158,6 +179,7 @@ impl CliRunner {
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    timings: None,
                 }) {
                     print_and_flush_stdout(stdout, &end);
                 }
@@ -168,7 +190,18 @@ impl CliRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
+        let changed_files = match Self::get_changed_files(&self.cwd, &changed_files_options) {
+            Ok(changed_files) => changed_files.map(Arc::new),
+            Err(message) => {
+                print_and_flush_stdout(stdout, &message);
+                return CliRunResult::InvalidOptionChangedFiles;
+            }
+        };
+
+        let mut walker = Walk::new(&paths, ignore_options.ignore_file(), override_builder);
+        if let Some(changed_files) = changed_files.clone() {
+            walker = walker.with_filter(move |path| changed_files.contains(path));
+        }
         let paths = walker.paths();
 
         let mut external_plugin_store = ExternalPluginStore::default();
@@ -300,8 +333,16 @@ impl CliRunner {
             ReportUnusedDirectives::WithSeverity(Some(severity)) => Some(severity),
             _ => None,
         };
+        let diagnostic_filter = changed_files.filter(|_| changed_files_options.changed_lines).map(
+            |changed_files| -> DiagnosticFilter {
+                Box::new(move |path, diagnostic| {
+                    Self::is_on_changed_line(&changed_files, path, diagnostic)
+                })
+            },
+        );
         let (mut diagnostic_service, tx_error) =
             Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+        diagnostic_service = diagnostic_service.with_filter(diagnostic_filter);
 
         let config_store = ConfigStore::new(lint_config, nested_configs, external_plugin_store);
 
@@ -311,9 +352,13 @@ impl CliRunner {
             .collect::<Vec<Arc<OsStr>>>();
 
         let has_external_linter = external_linter.is_some();
-        let linter = Linter::new(LintOptions::default(), config_store.clone(), external_linter)
+        let mut linter = Linter::new(LintOptions::default(), config_store.clone(), external_linter)
             .with_fix(fix_options.fix_kind())
             .with_report_unused_directives(report_unused_directives);
+        let rule_timings = misc_options.timing.then(|| Arc::new(RuleTimings::default()));
+        if let Some(rule_timings) = &rule_timings {
+            linter = linter.with_rule_timings(Arc::clone(rule_timings));
+        }
 
         let number_of_files = files_to_lint.len();
 
@@ -392,6 +437,11 @@ impl CliRunner {
             number_of_rules,
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
+            timings: rule_timings.map(|rule_timings| {
+                let mut slowest = rule_timings.slowest();
+                slowest.truncate(misc_options.timing_limit);
+                LintTimings { slowest, total: rule_timings.total() }
+            }),
         }) {
             print_and_flush_stdout(stdout, &end);
         }
@@ -411,6 +461,161 @@ impl CliRunner {
 impl CliRunner {
     const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
 
+    fn migrate_eslint_config(&self, stdout: &mut dyn Write, eslint_config: &Path) -> CliRunResult {
+        let migration = match migrate_file(&self.cwd.join(eslint_config)) {
+            Ok(migration) => migration,
+            Err(err) => {
+                let handler = if cfg!(any(test, feature = "force_test_reporter")) {
+                    GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
+                } else {
+                    GraphicalReportHandler::new()
+                };
+                print_and_flush_stdout(stdout, &render_report(&handler, &err));
+                return CliRunResult::ConfigFileMigrationFailed;
+            }
+        };
+
+        let configuration = serde_json::to_string_pretty(&migration.config).unwrap();
+        if fs::write(self.cwd.join(Self::DEFAULT_OXLINTRC), configuration).is_err() {
+            print_and_flush_stdout(stdout, "Failed to create configuration file\n");
+            return CliRunResult::ConfigFileMigrationFailed;
+        }
+
+        let mut output = String::from("Configuration file created\n");
+        if !migration.unsupported_rules.is_empty() {
+            output.push_str(
+                "\nThe following rules are not supported by oxlint and were not migrated:\n",
+            );
+            for rule in &migration.unsupported_rules {
+                let _ = writeln!(output, "  - {rule}");
+            }
+        }
+        if !migration.skipped.is_empty() {
+            output
+                .push_str("\nThe following parts of the ESLint configuration were not migrated:\n");
+            for skipped in &migration.skipped {
+                let _ = writeln!(output, "  - {skipped}");
+            }
+        }
+        print_and_flush_stdout(stdout, &output);
+        CliRunResult::ConfigFileMigrationSucceeded
+    }
+
+    fn run_codemod(&self, stdout: &mut dyn Write, pattern: &str) -> CliRunResult {
+        let handler = if cfg!(any(test, feature = "force_test_reporter")) {
+            GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
+        } else {
+            GraphicalReportHandler::new()
+        };
+
+        let CodemodOptions { rewrite, dry_run, .. } = &self.options.codemod_options;
+        let Some(rewrite) = rewrite else {
+            print_and_flush_stdout(stdout, "The `--codemod` option requires `--rewrite`.\n");
+            return CliRunResult::InvalidOptionConfig;
+        };
+        if let Some(option) = self.options.lint_only_option() {
+            print_and_flush_stdout(
+                stdout,
+                &format!("The `--codemod` option cannot be used with {option}.\n"),
+            );
+            return CliRunResult::InvalidOptionConfig;
+        }
+        let codemod = match Codemod::new(pattern, rewrite) {
+            Ok(codemod) => codemod,
+            Err(err) => {
+                print_and_flush_stdout(stdout, &render_report(&handler, &err));
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        let ignore_options = &self.options.ignore_options;
+        let override_builder = (!ignore_options.no_ignore).then(|| {
+            let mut builder = OverrideBuilder::new(&self.cwd);
+            for pattern in &ignore_options.ignore_pattern {
+                // Meaning of ignore pattern is reversed
+                builder.add(&format!("!{pattern}")).unwrap();
+            }
+            builder.build().unwrap()
+        });
+        let paths = if self.options.paths.is_empty() {
+            vec![self.cwd.clone()]
+        } else {
+            self.options.paths.iter().map(|path| self.cwd.join(path)).collect()
+        };
+
+        let mut results = Walk::new(&paths, ignore_options.ignore_file(), override_builder)
+            .paths()
+            .into_par_iter()
+            .filter_map(|path| {
+                // Skip files which need a partial loader, such as `.vue`
+                let source_type = SourceType::from_path(Path::new(&path)).ok()?;
+                let (source_text, result) = match read_to_string(Path::new(&path)) {
+                    Ok(source_text) => {
+                        let result = codemod.apply(&source_text, source_type);
+                        (source_text, result)
+                    }
+                    Err(err) => (
+                        String::new(),
+                        Err(vec![OxcDiagnostic::error(format!("Failed to read file: {err}"))]),
+                    ),
+                };
+                match result {
+                    Ok(None) => None,
+                    result => Some((path, source_text, result)),
+                }
+            })
+            .collect::<Vec<_>>();
+        results.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut output = String::new();
+        let mut matches = 0;
+        let mut files = 0;
+        let mut failed = false;
+        for (path, source_text, result) in results {
+            let path = Path::new(&path);
+            match result {
+                Ok(Some(result)) => {
+                    if *dry_run {
+                        let display_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                        let display_path = display_path.to_string_lossy();
+                        let display_path = display_path.cow_replace('\\', "/");
+                        let diff = TextDiff::from_lines(source_text.as_str(), result.code.as_str())
+                            .unified_diff()
+                            .header(&display_path, &display_path)
+                            .to_string();
+                        output.push_str(&diff);
+                    } else if let Err(err) = fs::write(path, &result.code) {
+                        let _ = writeln!(output, "Failed to write {}: {err}", path.display());
+                        failed = true;
+                        continue;
+                    }
+                    matches += result.matches;
+                    files += 1;
+                }
+                Ok(None) => {}
+                Err(errors) => {
+                    failed = true;
+                    let errors =
+                        DiagnosticService::wrap_diagnostics(&self.cwd, path, &source_text, errors);
+                    for error in errors {
+                        handler.render_report(&mut output, error.as_ref()).unwrap();
+                    }
+                }
+            }
+        }
+
+        let _ = writeln!(
+            output,
+            "{} {matches} match{} in {files} file{}.",
+            if *dry_run { "Found" } else { "Rewrote" },
+            if matches == 1 { "" } else { "es" },
+            if files == 1 { "" } else { "s" },
+        );
+        print_and_flush_stdout(stdout, &output);
+
+        if failed { CliRunResult::CodemodFailed } else { CliRunResult::CodemodSucceeded }
+    }
+
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
         self.cwd = cwd;
@@ -432,6 +637,43 @@ impl CliRunner {
         )
     }
 
+    fn get_changed_files(
+        cwd: &Path,
+        options: &ChangedFilesOptions,
+    ) -> Result<Option<ChangedFiles>, String> {
+        let base = match (&options.changed_since, options.staged) {
+            (Some(_), true) => {
+                return Err("`--changed-since` and `--staged` cannot be used together.\n".into());
+            }
+            (Some(revision), false) => ChangeBase::Revision(revision.clone()),
+            (None, true) => ChangeBase::Staged,
+            (None, false) if options.changed_lines => {
+                return Err("`--changed-lines` requires `--changed-since` or `--staged`.\n".into());
+            }
+            (None, false) => return Ok(None),
+        };
+
+        ChangedFiles::from_git(cwd, &base, options.changed_lines)
+            .map(Some)
+            .map_err(|err| format!("Failed to read changed files.\n{err}\n"))
+    }
+
+    /// Returns `true` if the diagnostic starts on a changed line. Diagnostics without a location
+    /// are always reported.
+    fn is_on_changed_line(changed_files: &ChangedFiles, path: &Path, diagnostic: &Error) -> bool {
+        let (Some(mut labels), Some(source_code)) = (diagnostic.labels(), diagnostic.source_code())
+        else {
+            return true;
+        };
+        let Some(label) = labels.next() else {
+            return true;
+        };
+        let Ok(contents) = source_code.read_span(label.inner(), 0, 0) else {
+            return true;
+        };
+        changed_files.contains_line(path, contents.line() + 1)
+    }
+
     // moved into a separate function for readability, but it's only ever used
     // in one place.
     fn get_filters(
@@ -689,6 +931,12 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn lint_flow_pragma() {
+        let args = &["fixtures/flow_pragma/index.js"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     // https://github.com/oxc-project/oxc/issues/7406
     fn ignore_flow_import_plugin_directory() {
@@ -983,6 +1231,86 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_changed_files_invalid_options() {
+        let args = &["--changed-since", "HEAD", "--staged"];
+        Tester::new().test_and_snapshot(args);
+        let args = &["--changed-lines"];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_codemod_dry_run() {
+        let args = &[
+            "--codemod",
+            "logger.warn($$$args)",
+            "--rewrite",
+            "logger.log('warn', $$$args)",
+            "--dry-run",
+            "app.js",
+            "report.ts",
+            "unchanged.js",
+        ];
+        Tester::new().with_cwd("fixtures/codemod".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_codemod_invalid_options() {
+        Tester::new().with_cwd("fixtures/codemod".into()).test_and_snapshot_multiple(&[
+            &["--codemod", "foo($a)", "app.js"],
+            &["--codemod", "foo(", "--rewrite", "bar()", "app.js"],
+            &["--codemod", "foo($a)", "--rewrite", "bar($b)", "app.js"],
+            &["--codemod", "foo($a)", "--rewrite", "bar($a)", "--fix", "app.js"],
+            &["--codemod", "foo($a)", "--rewrite", "bar($a)", "-D", "correctness", "app.js"],
+        ]);
+    }
+
+    #[test]
+    fn test_codemod_write() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("write.js");
+        fs::copy("fixtures/codemod/write.js", &file).unwrap();
+        Tester::new().test(&[
+            "--codemod",
+            "logger.warn($$$args)",
+            "--rewrite",
+            "logger.log('warn', $$$args)",
+            file.to_str().unwrap(),
+        ]);
+        #[expect(clippy::disallowed_methods)]
+        let content = fs::read_to_string(&file).unwrap().replace("\r\n", "\n");
+        assert_eq!(content, "logger.log('warn', \"write\");\n");
+    }
+
+    #[test]
+    fn test_migrate_config() {
+        let cwd = PathBuf::from("fixtures/migrate");
+        let oxlintrc = cwd.join(CliRunner::DEFAULT_OXLINTRC);
+        assert!(!fs::exists(&oxlintrc).unwrap());
+
+        Tester::new().with_cwd(cwd.clone()).test_and_snapshot(&["--migrate", ".eslintrc.yml"]);
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&oxlintrc).unwrap()).unwrap();
+        fs::remove_file(&oxlintrc).unwrap();
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "plugins": ["react", "typescript"],
+                "categories": { "correctness": "off" },
+                "env": { "browser": true },
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "no-console": "warn",
+                    "typescript/no-explicit-any": "error"
+                },
+                "overrides": [{ "files": ["*.test.js"], "rules": { "no-console": "off" } }]
+            })
+        );
+
+        Tester::new().with_cwd(cwd).test_and_snapshot(&["--migrate", "eslint.config.js"]);
+    }
+
     #[test]
     fn test_init_config() {
         assert!(!fs::exists(CliRunner::DEFAULT_OXLINTRC).unwrap());
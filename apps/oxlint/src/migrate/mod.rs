@@ -0,0 +1,483 @@
+//! Migration of ESLint configuration files to `.oxlintrc.json`.
+//!
+//! Both legacy (`.eslintrc.*`) and flat (`eslint.config.*`) configurations are supported.
+//! JavaScript configuration files can not be evaluated here, so flat configs must be dumped to
+//! JSON first.
+
+use std::{collections::BTreeSet, ffi::OsStr, fs, path::Path};
+
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_linter::{LintPlugins, OxlintRules, Oxlintrc};
+
+/// Settings keys understood by oxlint. See `OxlintSettings`.
+const SUPPORTED_SETTINGS: [&str; 4] = ["jsx-a11y", "next", "react", "jsdoc"];
+
+/// The result of migrating an ESLint configuration.
+#[derive(Debug)]
+pub struct Migration {
+    /// The `.oxlintrc.json` configuration.
+    pub config: Value,
+    /// Rules which are not implemented by oxlint, as named in the ESLint configuration.
+    pub unsupported_rules: BTreeSet<String>,
+    /// Parts of the ESLint configuration which could not be migrated.
+    pub skipped: Vec<String>,
+}
+
+/// Read an ESLint configuration file and migrate it.
+///
+/// # Errors
+/// Returns an error if the file can not be read or parsed, or is a JavaScript file.
+pub fn migrate_file(path: &Path) -> Result<Migration, OxcDiagnostic> {
+    let extension = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+    if matches!(extension, "js" | "cjs" | "mjs" | "ts" | "cts" | "mts") {
+        return Err(OxcDiagnostic::error(format!(
+            "Failed to read ESLint config {}: JavaScript configuration files can not be read directly.",
+            path.display()
+        ))
+        .with_help(
+            "Evaluate the configuration to JSON first, e.g. `node -e \"import('./eslint.config.js').then((config) => console.log(JSON.stringify(config.default)))\" > eslint.config.json`",
+        ));
+    }
+
+    let source = fs::read_to_string(path).map_err(|err| {
+        OxcDiagnostic::error(format!("Failed to read ESLint config {}: {err}", path.display()))
+    })?;
+    let parse_error = |err: String| {
+        OxcDiagnostic::error(format!("Failed to parse ESLint config {}.\n{err}", path.display()))
+    };
+
+    let config = match extension {
+        "yaml" | "yml" => oxc_yaml::parse(&source).map_err(parse_error)?,
+        "json" => parse_json(source).map_err(parse_error)?,
+        // `.eslintrc` may contain either JSON or YAML.
+        _ => {
+            parse_json(source.clone()).or_else(|_| oxc_yaml::parse(&source)).map_err(parse_error)?
+        }
+    };
+
+    let config = if path.file_name() == Some(OsStr::new("package.json")) {
+        config.get("eslintConfig").cloned().ok_or_else(|| {
+            parse_error("`package.json` does not contain an `eslintConfig` field".to_string())
+        })?
+    } else {
+        config
+    };
+
+    migrate(&config)
+}
+
+fn parse_json(mut source: String) -> Result<Value, String> {
+    json_strip_comments::strip(&mut source).map_err(|err| err.to_string())?;
+    serde_json::from_str(&source).map_err(|err| err.to_string())
+}
+
+/// Migrate a legacy ESLint configuration object, or a flat configuration array.
+///
+/// # Errors
+/// Returns an error if the configuration has an unexpected shape, or the migrated configuration
+/// is not a valid oxlint configuration.
+pub fn migrate(eslint_config: &Value) -> Result<Migration, OxcDiagnostic> {
+    let mut migrator = Migrator::default();
+    match eslint_config {
+        Value::Object(config) => migrator.migrate_legacy(config),
+        Value::Array(configs) => migrator.migrate_flat(configs),
+        _ => {
+            return Err(OxcDiagnostic::error(
+                "Expected an ESLint configuration object or a flat configuration array",
+            ));
+        }
+    }
+    let config = migrator.finish();
+
+    Oxlintrc::deserialize(&config)
+        .map_err(|err| OxcDiagnostic::error(format!("Failed to migrate ESLint config: {err}")))?;
+
+    Ok(Migration {
+        config,
+        unsupported_rules: migrator.unsupported_rules,
+        skipped: migrator.skipped,
+    })
+}
+
+#[derive(Default)]
+struct Migrator {
+    plugins: BTreeSet<&'static str>,
+    env: Map<String, Value>,
+    globals: Map<String, Value>,
+    settings: Map<String, Value>,
+    rules: Map<String, Value>,
+    overrides: Vec<Value>,
+    ignore_patterns: Vec<Value>,
+    unsupported_rules: BTreeSet<String>,
+    skipped: Vec<String>,
+}
+
+impl Migrator {
+    /// Build the `.oxlintrc.json` configuration.
+    ///
+    /// Only the migrated rules are enabled, so the default `correctness` category is turned off.
+    fn finish(&mut self) -> Value {
+        let mut config = Map::new();
+        config.insert("plugins".to_string(), json!(self.plugins));
+        config.insert("categories".to_string(), json!({ "correctness": "off" }));
+        let sections = [
+            ("env", Value::Object(std::mem::take(&mut self.env))),
+            ("globals", Value::Object(std::mem::take(&mut self.globals))),
+            ("settings", Value::Object(std::mem::take(&mut self.settings))),
+            ("rules", Value::Object(std::mem::take(&mut self.rules))),
+            ("overrides", Value::Array(std::mem::take(&mut self.overrides))),
+            ("ignorePatterns", Value::Array(std::mem::take(&mut self.ignore_patterns))),
+        ];
+        for (key, value) in sections {
+            let is_empty = match &value {
+                Value::Object(map) => map.is_empty(),
+                Value::Array(array) => array.is_empty(),
+                _ => false,
+            };
+            if !is_empty || key == "rules" {
+                config.insert(key.to_string(), value);
+            }
+        }
+        Value::Object(config)
+    }
+
+    fn skip(&mut self, what: impl Into<String>) {
+        self.skipped.push(what.into());
+    }
+
+    /// Migrate a `.eslintrc.*` configuration.
+    fn migrate_legacy(&mut self, config: &Map<String, Value>) {
+        for (key, value) in config {
+            match key.as_str() {
+                "root" | "$schema" => {}
+                "env" => extend_object(&mut self.env, value),
+                "globals" => extend_object(&mut self.globals, value),
+                "settings" => self.migrate_settings(value, "settings"),
+                "plugins" => self.migrate_plugin_names(value),
+                "rules" => {
+                    let rules = self.migrate_rules(value);
+                    self.rules.extend(rules);
+                }
+                "ignorePatterns" => self.ignore_patterns.extend(string_or_array(value)),
+                "overrides" => {
+                    for (index, config) in value.as_array().into_iter().flatten().enumerate() {
+                        let Some(config) = config.as_object() else {
+                            self.skip(format!("`overrides[{index}]`"));
+                            continue;
+                        };
+                        self.migrate_legacy_override(config, index);
+                    }
+                }
+                _ => self.skip(format!("`{key}`")),
+            }
+        }
+    }
+
+    fn migrate_legacy_override(&mut self, config: &Map<String, Value>, index: usize) {
+        let mut migrated = Map::new();
+        for (key, value) in config {
+            match key.as_str() {
+                "files" => {
+                    migrated.insert("files".to_string(), Value::Array(string_or_array(value)));
+                }
+                "env" | "globals" => {
+                    migrated.insert(key.clone(), value.clone());
+                }
+                "plugins" => self.migrate_plugin_names(value),
+                "rules" => {
+                    let rules = self.migrate_rules(value);
+                    migrated.insert("rules".to_string(), Value::Object(rules));
+                }
+                _ => self.skip(format!("`overrides[{index}].{key}`")),
+            }
+        }
+        if migrated.contains_key("files") {
+            self.overrides.push(Value::Object(migrated));
+        } else {
+            self.skip(format!("`overrides[{index}]`"));
+        }
+    }
+
+    /// Migrate an `eslint.config.*` configuration, evaluated to JSON.
+    fn migrate_flat(&mut self, configs: &[Value]) {
+        for (index, config) in configs.iter().enumerate() {
+            let Some(config) = config.as_object() else {
+                self.skip(format!("`[{index}]`"));
+                continue;
+            };
+
+            // A config object with only `ignores` specifies global ignores.
+            if let Some(ignores) = config.get("ignores")
+                && config.keys().all(|key| key == "ignores" || key == "name")
+            {
+                self.ignore_patterns.extend(string_or_array(ignores));
+                continue;
+            }
+
+            let files = config.get("files").map(|files| {
+                files.as_array().map_or_else(Vec::new, |files| {
+                    files.iter().filter(|file| file.is_string()).cloned().collect::<Vec<_>>()
+                })
+            });
+            if let Some(files) = &files {
+                // Nested arrays, which require all patterns to match, are not supported.
+                if files.is_empty()
+                    || config["files"].as_array().is_some_and(|all| all.len() != files.len())
+                {
+                    self.skip(format!("`[{index}]`"));
+                    continue;
+                }
+            }
+
+            let mut globals = Map::new();
+            let mut rules = Map::new();
+            for (key, value) in config {
+                match key.as_str() {
+                    "name" | "files" => {}
+                    "languageOptions" => {
+                        for (option, value) in value.as_object().into_iter().flatten() {
+                            match option.as_str() {
+                                "globals" => extend_object(&mut globals, value),
+                                "ecmaVersion" | "sourceType" => {}
+                                _ => self.skip(format!("`[{index}].languageOptions.{option}`")),
+                            }
+                        }
+                    }
+                    "plugins" => {
+                        let names = value.as_object().map(|plugins| {
+                            plugins.keys().map(|key| Value::String(key.clone())).collect()
+                        });
+                        self.migrate_plugin_names(&Value::Array(names.unwrap_or_default()));
+                    }
+                    "rules" => rules = self.migrate_rules(value),
+                    "settings" if files.is_none() => {
+                        self.migrate_settings(value, &format!("[{index}].settings"));
+                    }
+                    _ => self.skip(format!("`[{index}].{key}`")),
+                }
+            }
+
+            if let Some(files) = files {
+                let mut migrated = Map::new();
+                migrated.insert("files".to_string(), Value::Array(files));
+                if !globals.is_empty() {
+                    migrated.insert("globals".to_string(), Value::Object(globals));
+                }
+                migrated.insert("rules".to_string(), Value::Object(rules));
+                self.overrides.push(Value::Object(migrated));
+            } else {
+                self.globals.extend(globals);
+                self.rules.extend(rules);
+            }
+        }
+    }
+
+    fn migrate_rules(&mut self, rules: &Value) -> Map<String, Value> {
+        let mut migrated = Map::new();
+        for (name, value) in rules.as_object().into_iter().flatten() {
+            let Ok(parsed) = OxlintRules::deserialize(&json!({ name: value })) else {
+                self.skip(format!("rule `{name}`"));
+                continue;
+            };
+            let Some(rule) = parsed.iter().next() else {
+                continue;
+            };
+            if !rule.is_builtin() {
+                self.unsupported_rules.insert(name.clone());
+                continue;
+            }
+            let plugin = <&'static str>::from(LintPlugins::from(rule.plugin_name.as_str()));
+            if !plugin.is_empty() {
+                self.plugins.insert(plugin);
+            }
+            migrated.insert(rule.full_name().into_owned(), value.clone());
+        }
+        migrated
+    }
+
+    /// Enable the oxlint plugins corresponding to ESLint plugin names or flat config prefixes.
+    fn migrate_plugin_names(&mut self, names: &Value) {
+        for name in names.as_array().into_iter().flatten().filter_map(Value::as_str) {
+            let plugin = <&'static str>::from(LintPlugins::from(normalize_plugin_name(name)));
+            if plugin.is_empty() {
+                self.skip(format!("plugin `{name}`"));
+            } else {
+                self.plugins.insert(plugin);
+            }
+        }
+    }
+
+    fn migrate_settings(&mut self, settings: &Value, path: &str) {
+        for (key, value) in settings.as_object().into_iter().flatten() {
+            if SUPPORTED_SETTINGS.contains(&key.as_str()) {
+                self.settings.insert(key.clone(), value.clone());
+            } else {
+                self.skip(format!("`{path}.{key}`"));
+            }
+        }
+    }
+}
+
+/// `eslint-plugin-foo` → `foo`, `@foo/eslint-plugin` → `@foo`, `@foo/eslint-plugin-bar` → `@foo/bar`.
+fn normalize_plugin_name(name: &str) -> &str {
+    let name = name.strip_prefix("eslint-plugin-").unwrap_or(name);
+    let name = name.strip_suffix("/eslint-plugin").unwrap_or(name);
+    match name {
+        "@next/next" | "@next" | "next" => "nextjs",
+        "@vitest" => "vitest",
+        "n" => "node",
+        "import-x" => "import",
+        _ => name,
+    }
+}
+
+fn extend_object(target: &mut Map<String, Value>, value: &Value) {
+    if let Some(object) = value.as_object() {
+        target.extend(object.iter().map(|(key, value)| (key.clone(), value.clone())));
+    }
+}
+
+fn string_or_array(value: &Value) -> Vec<Value> {
+    match value {
+        Value::String(_) => vec![value.clone()],
+        Value::Array(values) => values.iter().filter(|value| value.is_string()).cloned().collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::migrate;
+
+    #[test]
+    fn legacy_config() {
+        let migration = migrate(&json!({
+            "root": true,
+            "extends": ["eslint:recommended"],
+            "plugins": ["react", "@typescript-eslint", "eslint-plugin-unknown"],
+            "env": { "browser": true },
+            "globals": { "myGlobal": "readonly" },
+            "settings": { "react": { "version": "18.0" }, "import/resolver": {} },
+            "rules": {
+                "eqeqeq": ["error", "always"],
+                "no-console": 1,
+                "@typescript-eslint/no-explicit-any": "warn",
+                "react-hooks/rules-of-hooks": "error",
+                "unknown/some-rule": "error",
+                "not-a-real-rule": "off"
+            },
+            "ignorePatterns": "dist",
+            "overrides": [{
+                "files": "*.test.js",
+                "excludedFiles": "*.snap.js",
+                "env": { "jest": true },
+                "rules": { "no-console": "off", "jest/no-focused-tests": "error" }
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            migration.config,
+            json!({
+                "plugins": ["jest", "react", "typescript"],
+                "categories": { "correctness": "off" },
+                "env": { "browser": true },
+                "globals": { "myGlobal": "readonly" },
+                "settings": { "react": { "version": "18.0" } },
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "no-console": 1,
+                    "typescript/no-explicit-any": "warn",
+                    "react/rules-of-hooks": "error"
+                },
+                "overrides": [{
+                    "files": ["*.test.js"],
+                    "env": { "jest": true },
+                    "rules": { "no-console": "off", "jest/no-focused-tests": "error" }
+                }],
+                "ignorePatterns": ["dist"]
+            })
+        );
+        assert_eq!(
+            migration.unsupported_rules.into_iter().collect::<Vec<_>>(),
+            ["not-a-real-rule", "unknown/some-rule"]
+        );
+        assert_eq!(
+            migration.skipped,
+            [
+                "`extends`",
+                "plugin `eslint-plugin-unknown`",
+                "`settings.import/resolver`",
+                "`overrides[0].excludedFiles`"
+            ]
+        );
+    }
+
+    #[test]
+    fn flat_config() {
+        let migration = migrate(&json!([
+            { "ignores": ["dist/**", "coverage/**"] },
+            {
+                "name": "base",
+                "languageOptions": {
+                    "ecmaVersion": 2024,
+                    "globals": { "window": "readonly" },
+                    "parser": {}
+                },
+                "plugins": { "unicorn": {} },
+                "rules": { "no-debugger": "error", "unicorn/no-null": "warn" }
+            },
+            { "rules": { "no-debugger": "warn" } },
+            {
+                "files": ["**/*.ts"],
+                "languageOptions": { "globals": { "Bun": "readonly" } },
+                "rules": { "@typescript-eslint/no-unused-vars": "error" }
+            },
+            { "files": [["**/*.js", "!**/*.min.js"]], "rules": { "no-var": "error" } },
+            { "linterOptions": { "reportUnusedDisableDirectives": true } }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            migration.config,
+            json!({
+                "plugins": ["typescript", "unicorn"],
+                "categories": { "correctness": "off" },
+                "globals": { "window": "readonly" },
+                "rules": { "no-debugger": "warn", "unicorn/no-null": "warn" },
+                "overrides": [{
+                    "files": ["**/*.ts"],
+                    "globals": { "Bun": "readonly" },
+                    "rules": { "typescript/no-unused-vars": "error" }
+                }],
+                "ignorePatterns": ["dist/**", "coverage/**"]
+            })
+        );
+        assert!(migration.unsupported_rules.is_empty());
+        assert_eq!(
+            migration.skipped,
+            ["`[1].languageOptions.parser`", "`[4]`", "`[5].linterOptions`"]
+        );
+    }
+
+    #[test]
+    fn flat_config_without_ignores() {
+        let migration = migrate(&json!([{}, { "name": "empty" }])).unwrap();
+        assert_eq!(
+            migration.config,
+            json!({ "plugins": [], "categories": { "correctness": "off" }, "rules": {} })
+        );
+        assert!(migration.skipped.is_empty());
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(migrate(&json!("eslint:recommended")).is_err());
+        assert!(migrate(&json!({ "rules": { "eqeqeq": "bad-severity" } })).is_ok());
+    }
+}
@@ -1,10 +1,11 @@
-use std::time::Duration;
+use std::{fmt::Write, time::Duration};
 
-use crate::output_formatter::InternalFormatter;
+use crate::output_formatter::{InternalFormatter, LintTimings};
 use oxc_diagnostics::{
     Error, GraphicalReportHandler,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
+use oxc_linter::RuleTiming;
 use oxc_linter::table::RuleTable;
 
 #[derive(Debug)]
@@ -27,17 +28,26 @@ impl InternalFormatter for DefaultOutputFormatter {
         let time = Self::get_execution_time(&lint_command_info.start_time);
         let s = if lint_command_info.number_of_files == 1 { "" } else { "s" };
 
+        let mut output =
+            lint_command_info.timings.as_ref().map(Self::render_timings).unwrap_or_default();
+
         if let Some(number_of_rules) = lint_command_info.number_of_rules {
-            Some(format!(
-                "Finished in {time} on {} file{s} with {} rules using {} threads.\n",
+            writeln!(
+                output,
+                "Finished in {time} on {} file{s} with {} rules using {} threads.",
                 lint_command_info.number_of_files, number_of_rules, lint_command_info.threads_count
-            ))
+            )
+            .unwrap();
         } else {
-            Some(format!(
-                "Finished in {time} on {} file{s} using {} threads.\n",
+            writeln!(
+                output,
+                "Finished in {time} on {} file{s} using {} threads.",
                 lint_command_info.number_of_files, lint_command_info.threads_count
-            ))
+            )
+            .unwrap();
         }
+
+        Some(output)
     }
 
     #[cfg(not(any(test, feature = "force_test_reporter")))]
@@ -58,6 +68,31 @@ impl DefaultOutputFormatter {
         let ms = duration.as_millis();
         if ms < 1000 { format!("{ms}ms") } else { format!("{:.1}s", duration.as_secs_f64()) }
     }
+
+    /// Renders the slowest rules as a table, with the share of the total time spent in rules.
+    fn render_timings(timings: &LintTimings) -> String {
+        let names = timings.slowest.iter().map(RuleTiming::name).collect::<Vec<_>>();
+        let width = names.iter().map(|name| name.len()).max().unwrap_or(0).max("Rule".len());
+        let total = timings.total.as_secs_f64();
+
+        let mut output = String::new();
+        writeln!(output, "{:<width$} | Time (ms) | Invocations | Relative", "Rule").unwrap();
+        writeln!(output, "{:-<width$}-+-----------+-------------+---------", "").unwrap();
+        for (timing, name) in timings.slowest.iter().zip(&names) {
+            let relative =
+                if total > 0.0 { timing.duration.as_secs_f64() / total * 100.0 } else { 0.0 };
+            writeln!(
+                output,
+                "{name:<width$} | {:>9.3} | {:>11} | {:>7.1}%",
+                timing.duration.as_secs_f64() * 1000.0,
+                timing.invocations,
+                relative,
+            )
+            .unwrap();
+        }
+        output.push('\n');
+        output
+    }
 }
 
 /// Pretty-prints diagnostics. Primarily meant for human-readable output in a terminal.
@@ -159,10 +194,11 @@ mod test {
     use std::time::Duration;
 
     use crate::output_formatter::{
-        InternalFormatter, LintCommandInfo,
+        InternalFormatter, LintCommandInfo, LintTimings,
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
     use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
+    use oxc_linter::{RuleTiming, TSGOLINT_TIMING};
 
     #[test]
     fn all_rules() {
@@ -180,6 +216,7 @@ mod test {
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            timings: None,
         });
 
         assert!(result.is_some());
@@ -189,6 +226,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn lint_command_info_timings() {
+        let formatter = DefaultOutputFormatter;
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 5,
+            number_of_rules: Some(10),
+            threads_count: 12,
+            start_time: Duration::new(1, 0),
+            timings: Some(LintTimings {
+                slowest: vec![
+                    RuleTiming {
+                        plugin_name: TSGOLINT_TIMING,
+                        rule_name: "",
+                        duration: Duration::from_millis(30),
+                        invocations: 1,
+                    },
+                    RuleTiming {
+                        plugin_name: "eslint",
+                        rule_name: "no-unused-vars",
+                        duration: Duration::from_micros(12_500),
+                        invocations: 5,
+                    },
+                ],
+                total: Duration::from_millis(50),
+            }),
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "\
+Rule                  | Time (ms) | Invocations | Relative
+----------------------+-----------+-------------+---------
+tsgolint              |    30.000 |           1 |    60.0%
+eslint/no-unused-vars |    12.500 |           5 |    25.0%
+
+Finished in 1.0s on 5 files with 10 rules using 12 threads.
+"
+        );
+    }
+
     #[test]
     fn lint_command_info_unknown_rules() {
         let formatter = DefaultOutputFormatter;
@@ -197,6 +274,7 @@ mod test {
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            timings: None,
         });
 
         assert!(result.is_some());
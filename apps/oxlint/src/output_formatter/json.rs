@@ -2,6 +2,7 @@ use std::{cell::RefCell, rc::Rc};
 
 use miette::JSONReportHandler;
 use serde::Serialize;
+use serde_json::json;
 
 use oxc_diagnostics::{
     Error,
@@ -42,13 +43,31 @@ impl InternalFormatter for JsonOutputFormatter {
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let timings = lint_command_info.timings.as_ref().map_or(String::new(), |timings| {
+            let total = timings.total.as_secs_f64();
+            let slowest = timings
+                .slowest
+                .iter()
+                .map(|timing| {
+                    let relative =
+                        if total > 0.0 { timing.duration.as_secs_f64() / total } else { 0.0 };
+                    json!({
+                        "rule": timing.name(),
+                        "time": timing.duration.as_secs_f64(),
+                        "invocations": timing.invocations,
+                        "relative": relative,
+                    })
+                })
+                .collect::<Vec<_>>();
+            format!(",\n              \"timings\": {}", json!({ "total": total, "rules": slowest }))
+        });
 
         Some(format!(
             r#"{{ "diagnostics": {},
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
-              "start_time": {}
+              "start_time": {}{}
             }}
             "#,
             diagnostics,
@@ -56,6 +75,7 @@ impl InternalFormatter for JsonOutputFormatter {
             number_of_rules,
             lint_command_info.threads_count,
             start_time,
+            timings,
         ))
     }
 
@@ -151,6 +171,7 @@ mod test {
                 number_of_rules: Some(0),
                 start_time: Duration::new(0, 0),
                 threads_count: 1,
+                timings: None,
             })
             .unwrap();
         assert_eq!(
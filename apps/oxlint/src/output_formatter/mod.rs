@@ -19,6 +19,7 @@ use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
 use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_linter::RuleTiming;
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
@@ -66,6 +67,16 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// Time spent in the slowest rules, when `--timing` is passed.
+    pub timings: Option<LintTimings>,
+}
+
+/// Time spent in the slowest rules, collected with `--timing`.
+pub struct LintTimings {
+    /// The slowest rules, slowest first.
+    pub slowest: Vec<RuleTiming>,
+    /// Total time spent in all rules, including the ones not in `slowest`.
+    pub total: Duration,
 }
 
 /// An Interface for the different output formats.
@@ -8,6 +8,7 @@ pub enum CliRunResult {
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
+    InvalidOptionChangedFiles,
     LintSucceeded,
     LintFoundErrors,
     LintMaxWarningsExceeded,
@@ -16,6 +17,10 @@ pub enum CliRunResult {
     PrintConfigResult,
     ConfigFileInitFailed,
     ConfigFileInitSucceeded,
+    ConfigFileMigrationFailed,
+    ConfigFileMigrationSucceeded,
+    CodemodSucceeded,
+    CodemodFailed,
     TsGoLintError,
 }
 
@@ -25,10 +30,14 @@ impl Termination for CliRunResult {
             Self::None
             | Self::PrintConfigResult
             | Self::ConfigFileInitSucceeded
+            | Self::ConfigFileMigrationSucceeded
+            | Self::CodemodSucceeded
             | Self::LintSucceeded
             // ToDo: when oxc_linter (config) validates the configuration, we can use exit_code = 1 to fail
             | Self::LintNoFilesFound => ExitCode::SUCCESS,
             Self::ConfigFileInitFailed
+            | Self::ConfigFileMigrationFailed
+            | Self::CodemodFailed
             | Self::LintFoundErrors
             | Self::LintNoWarningsAllowed
             | Self::LintMaxWarningsExceeded
@@ -37,6 +46,7 @@ impl Termination for CliRunResult {
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
             | Self::InvalidOptionSeverityWithoutRuleName
+            | Self::InvalidOptionChangedFiles
             | Self::TsGoLintError => ExitCode::FAILURE,
         }
     }
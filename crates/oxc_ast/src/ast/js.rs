@@ -160,6 +160,8 @@ pub enum Expression<'a> {
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>) = 38,
     /// See [`V8IntrinsicExpression`] for AST node details.
     V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
+    /// See [`ErrorExpression`] for AST node details.
+    ErrorExpression(Box<'a, ErrorExpression>) = 40,
 
     // `MemberExpression` variants added here by `inherit_variants!` macro
     @inherit MemberExpression
@@ -214,6 +216,7 @@ macro_rules! match_expression {
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
             | $ty::V8IntrinsicExpression(_)
+            | $ty::ErrorExpression(_)
     };
 }
 pub use match_expression;
@@ -322,6 +325,16 @@ pub struct ThisExpression {
     pub span: Span,
 }
 
+/// Placeholder for a missing or invalid expression, e.g. the initializer in `let x = ;`.
+///
+/// Only produced by the parser when error recovery is enabled.
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+pub struct ErrorExpression {
+    pub span: Span,
+}
+
 /// `[1, 2, ...[3, 4], null]` in `const array = [1, 2, ...[3, 4], null];`
 ///
 /// Represents an array literal, which can include elements, spread elements, or null values.
@@ -1107,6 +1120,7 @@ pub enum Statement<'a> {
     TryStatement(Box<'a, TryStatement<'a>>) = 15,
     WhileStatement(Box<'a, WhileStatement<'a>>) = 16,
     WithStatement(Box<'a, WithStatement<'a>>) = 17,
+    ErrorStatement(Box<'a, ErrorStatement>) = 18,
     // `Declaration` variants added here by `inherit_variants!` macro
     @inherit Declaration
     // `ModuleDeclaration` variants added here by `inherit_variants!` macro
@@ -1241,6 +1255,17 @@ pub struct EmptyStatement {
     pub span: Span,
 }
 
+/// Placeholder for a statement which could not be parsed.
+///
+/// Only produced by the parser when error recovery is enabled.
+/// `span` covers the source text which was skipped.
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+pub struct ErrorStatement {
+    pub span: Span,
+}
+
 /// Expression Statement
 #[ast(visit)]
 #[derive(Debug)]
@@ -1,4 +1,7 @@
 mod js;
 mod jsx;
 mod literal;
+mod styled_components;
 mod ts;
+
+pub use styled_components::STYLED_COMPONENTS_CSS_HELPERS;
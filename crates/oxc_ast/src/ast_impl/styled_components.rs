@@ -0,0 +1,56 @@
+//! Detection of [styled-components](https://styled-components.com) tags, shared by the
+//! transformer's `styled_components` plugin and the formatter's embedded CSS formatting.
+
+use crate::ast::{Expression, IdentifierReference};
+
+/// Helpers of styled-components which take a CSS template, e.g. ``css`color: red;` ``.
+pub const STYLED_COMPONENTS_CSS_HELPERS: [&str; 4] =
+    ["createGlobalStyle", "css", "injectGlobal", "keyframes"];
+
+impl<'a> Expression<'a> {
+    /// Returns `true` if this expression creates a styled component from a template,
+    /// e.g. `styled.div`, `styled(Component)`, `styled.div.attrs(...)` or
+    /// `styled(Component).withConfig(...)`.
+    ///
+    /// `is_styled` decides if an identifier is the default export of styled-components, and
+    /// `is_namespace` if it is a namespace import of it, which is used as `styled.default.div`.
+    pub fn is_styled_components_tag(
+        &self,
+        is_styled: &impl Fn(&IdentifierReference<'a>) -> bool,
+        is_namespace: &impl Fn(&IdentifierReference<'a>) -> bool,
+    ) -> bool {
+        match self.without_parentheses() {
+            Expression::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(ident) => {
+                    let name = member.property.name.as_str();
+                    !(STYLED_COMPONENTS_CSS_HELPERS.contains(&name)
+                        || matches!(name, "useTheme" | "withTheme"))
+                        && is_styled(ident)
+                }
+                Expression::StaticMemberExpression(object) => {
+                    object.property.name == "default"
+                        && matches!(&object.object, Expression::Identifier(ident) if is_namespace(ident))
+                }
+                _ => false,
+            },
+            Expression::CallExpression(call) => {
+                // `(0, styled)(Component)`
+                let callee = match &call.callee {
+                    Expression::SequenceExpression(sequence) => {
+                        let Some(last) = sequence.expressions.last() else { return false };
+                        last
+                    }
+                    callee => callee,
+                };
+                match callee {
+                    Expression::Identifier(ident) => is_styled(ident),
+                    Expression::StaticMemberExpression(member) => {
+                        member.object.is_styled_components_tag(is_styled, is_namespace)
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
@@ -20,7 +20,7 @@ impl<'a> AstKind<'a> {
                     | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
                     | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
                     | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
-                    | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::ExportDefaultDeclaration(_))
+                    | Self::ErrorStatement(_) | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::ExportDefaultDeclaration(_))
     }
 
     /// Check if this AST node is a declaration
@@ -235,6 +235,7 @@ impl<'a> AstKind<'a> {
             Expression::TSNonNullExpression(e) => Self::TSNonNullExpression(e),
             Expression::TSInstantiationExpression(e) => Self::TSInstantiationExpression(e),
             Expression::V8IntrinsicExpression(e) => Self::V8IntrinsicExpression(e),
+            Expression::ErrorExpression(e) => Self::ErrorExpression(e),
         }
     }
 
@@ -338,6 +339,7 @@ impl AstKind<'_> {
             Self::TryStatement(_) => "TryStatement".into(),
             Self::WhileStatement(_) => "WhileStatement".into(),
             Self::WithStatement(_) => "WithStatement".into(),
+            Self::ErrorStatement(_) => "ErrorStatement".into(),
 
             Self::SwitchCase(_) => "SwitchCase".into(),
             Self::CatchClause(_) => "CatchClause".into(),
@@ -546,6 +548,7 @@ impl AstKind<'_> {
             Self::TSInterfaceBody(_) => "TSInterfaceBody".into(),
             Self::TSIndexSignature(_) => "TSIndexSignature".into(),
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression".into(),
+            Self::ErrorExpression(_) => "ErrorExpression".into(),
 
             Self::JSDocNullableType(_) => "JSDocNullableType".into(),
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType".into(),
@@ -0,0 +1,1328 @@
+use oxc_allocator::{Box, Vec};
+use oxc_span::{SPAN, SourceType};
+
+use crate::ast::*;
+
+use super::{Deserializer, Node, Result, ts::ts_accessibility};
+
+const ASSIGNMENT_OPERATORS: &[AssignmentOperator] = &[
+    AssignmentOperator::Assign,
+    AssignmentOperator::Addition,
+    AssignmentOperator::Subtraction,
+    AssignmentOperator::Multiplication,
+    AssignmentOperator::Division,
+    AssignmentOperator::Remainder,
+    AssignmentOperator::Exponential,
+    AssignmentOperator::ShiftLeft,
+    AssignmentOperator::ShiftRight,
+    AssignmentOperator::ShiftRightZeroFill,
+    AssignmentOperator::BitwiseOR,
+    AssignmentOperator::BitwiseXOR,
+    AssignmentOperator::BitwiseAnd,
+    AssignmentOperator::LogicalOr,
+    AssignmentOperator::LogicalAnd,
+    AssignmentOperator::LogicalNullish,
+];
+
+const BINARY_OPERATORS: &[BinaryOperator] = &[
+    BinaryOperator::Equality,
+    BinaryOperator::Inequality,
+    BinaryOperator::StrictEquality,
+    BinaryOperator::StrictInequality,
+    BinaryOperator::LessThan,
+    BinaryOperator::LessEqualThan,
+    BinaryOperator::GreaterThan,
+    BinaryOperator::GreaterEqualThan,
+    BinaryOperator::Addition,
+    BinaryOperator::Subtraction,
+    BinaryOperator::Multiplication,
+    BinaryOperator::Division,
+    BinaryOperator::Remainder,
+    BinaryOperator::Exponential,
+    BinaryOperator::ShiftLeft,
+    BinaryOperator::ShiftRight,
+    BinaryOperator::ShiftRightZeroFill,
+    BinaryOperator::BitwiseOR,
+    BinaryOperator::BitwiseXOR,
+    BinaryOperator::BitwiseAnd,
+    BinaryOperator::In,
+    BinaryOperator::Instanceof,
+];
+
+const LOGICAL_OPERATORS: &[LogicalOperator] =
+    &[LogicalOperator::Or, LogicalOperator::And, LogicalOperator::Coalesce];
+
+pub(super) const UNARY_OPERATORS: &[UnaryOperator] = &[
+    UnaryOperator::UnaryPlus,
+    UnaryOperator::UnaryNegation,
+    UnaryOperator::LogicalNot,
+    UnaryOperator::BitwiseNot,
+    UnaryOperator::Typeof,
+    UnaryOperator::Void,
+    UnaryOperator::Delete,
+];
+
+const UPDATE_OPERATORS: &[UpdateOperator] = &[UpdateOperator::Increment, UpdateOperator::Decrement];
+
+const VARIABLE_DECLARATION_KINDS: &[(&str, VariableDeclarationKind)] = &[
+    ("var", VariableDeclarationKind::Var),
+    ("let", VariableDeclarationKind::Let),
+    ("const", VariableDeclarationKind::Const),
+    ("using", VariableDeclarationKind::Using),
+    ("await using", VariableDeclarationKind::AwaitUsing),
+];
+
+const PROPERTY_KINDS: &[(&str, PropertyKind)] =
+    &[("init", PropertyKind::Init), ("get", PropertyKind::Get), ("set", PropertyKind::Set)];
+
+const METHOD_DEFINITION_KINDS: &[(&str, MethodDefinitionKind)] = &[
+    ("constructor", MethodDefinitionKind::Constructor),
+    ("method", MethodDefinitionKind::Method),
+    ("get", MethodDefinitionKind::Get),
+    ("set", MethodDefinitionKind::Set),
+];
+
+const IMPORT_PHASES: &[(&str, ImportPhase)] =
+    &[("source", ImportPhase::Source), ("defer", ImportPhase::Defer)];
+
+const IMPORT_OR_EXPORT_KINDS: &[(&str, ImportOrExportKind)] =
+    &[("value", ImportOrExportKind::Value), ("type", ImportOrExportKind::Type)];
+
+impl<'a> Deserializer<'a> {
+    // ----------------------------------------
+    // Program and statements
+    // ----------------------------------------
+
+    pub(super) fn program(
+        self,
+        node: Node<'_>,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Program<'a>> {
+        node.expect("Program")?;
+        let source_type = match node.opt_str("sourceType")? {
+            None => source_type,
+            Some("module") => source_type.with_module(true),
+            Some("script") => source_type.with_script(true),
+            Some(_) => return Err(node.invalid("sourceType", "`module` or `script`")),
+        };
+        let hashbang =
+            node.opt_child("hashbang")?.map(|hashbang| self.hashbang(hashbang)).transpose()?;
+        let (directives, body) = self.directives_and_statements(node, "body")?;
+        Ok(self.ast.program(
+            node.span(),
+            source_type,
+            source_text,
+            self.ast.vec(),
+            hashbang,
+            directives,
+            body,
+        ))
+    }
+
+    fn hashbang(self, node: Node<'_>) -> Result<Hashbang<'a>> {
+        node.expect("Hashbang")?;
+        Ok(self.ast.hashbang(node.span(), self.atom(node.str("value")?)))
+    }
+
+    /// Split the statements in `field` into directive prologue and statements.
+    ///
+    /// ESTree represents directives as `ExpressionStatement`s with a `directive` field.
+    pub(super) fn directives_and_statements(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
+        let mut directives = self.ast.vec();
+        let mut statements = self.ast.vec();
+        for statement in node.nodes(field)? {
+            let statement = statement?;
+            if statements.is_empty()
+                && statement.ty == "ExpressionStatement"
+                && let Some(directive) = statement.opt_str("directive")?
+            {
+                let expression = self.string_literal(statement.child("expression")?)?;
+                directives.push(self.ast.directive(
+                    statement.span(),
+                    expression,
+                    self.atom(directive),
+                ));
+            } else {
+                statements.push(self.statement(statement)?);
+            }
+        }
+        Ok((directives, statements))
+    }
+
+    pub(super) fn statement(self, node: Node<'_>) -> Result<Statement<'a>> {
+        let span = node.span();
+        let statement = match node.ty {
+            "BlockStatement" => {
+                Statement::BlockStatement(self.ast.alloc(self.block_statement(node)?))
+            }
+            "BreakStatement" => self.ast.statement_break(span, self.opt_label(node)?),
+            "ContinueStatement" => self.ast.statement_continue(span, self.opt_label(node)?),
+            "DebuggerStatement" => self.ast.statement_debugger(span),
+            "DoWhileStatement" => self.ast.statement_do_while(
+                span,
+                self.statement(node.child("body")?)?,
+                self.child_expression(node, "test")?,
+            ),
+            "EmptyStatement" => self.ast.statement_empty(span),
+            "ErrorStatement" => self.ast.statement_error(span),
+            "ExpressionStatement" => {
+                self.ast.statement_expression(span, self.child_expression(node, "expression")?)
+            }
+            "ForInStatement" => self.ast.statement_for_in(
+                span,
+                self.for_statement_left(node.child("left")?)?,
+                self.child_expression(node, "right")?,
+                self.statement(node.child("body")?)?,
+            ),
+            "ForOfStatement" => self.ast.statement_for_of(
+                span,
+                node.bool("await")?,
+                self.for_statement_left(node.child("left")?)?,
+                self.child_expression(node, "right")?,
+                self.statement(node.child("body")?)?,
+            ),
+            "ForStatement" => self.ast.statement_for(
+                span,
+                node.opt_child("init")?.map(|init| self.for_statement_init(init)).transpose()?,
+                self.opt_expression(node, "test")?,
+                self.opt_expression(node, "update")?,
+                self.statement(node.child("body")?)?,
+            ),
+            "IfStatement" => self.ast.statement_if(
+                span,
+                self.child_expression(node, "test")?,
+                self.statement(node.child("consequent")?)?,
+                node.opt_child("alternate")?
+                    .map(|alternate| self.statement(alternate))
+                    .transpose()?,
+            ),
+            "LabeledStatement" => self.ast.statement_labeled(
+                span,
+                self.label_identifier(node.child("label")?)?,
+                self.statement(node.child("body")?)?,
+            ),
+            "ReturnStatement" => {
+                self.ast.statement_return(span, self.opt_expression(node, "argument")?)
+            }
+            "SwitchStatement" => self.ast.statement_switch(
+                span,
+                self.child_expression(node, "discriminant")?,
+                self.list(node, "cases", |case| self.switch_case(case))?,
+            ),
+            "ThrowStatement" => {
+                self.ast.statement_throw(span, self.child_expression(node, "argument")?)
+            }
+            "TryStatement" => self.ast.statement_try(
+                span,
+                self.block_statement(node.child("block")?)?,
+                node.opt_child("handler")?.map(|handler| self.catch_clause(handler)).transpose()?,
+                node.opt_child("finalizer")?
+                    .map(|finalizer| self.block_statement(finalizer))
+                    .transpose()?,
+            ),
+            "WhileStatement" => self.ast.statement_while(
+                span,
+                self.child_expression(node, "test")?,
+                self.statement(node.child("body")?)?,
+            ),
+            "WithStatement" => self.ast.statement_with(
+                span,
+                self.child_expression(node, "object")?,
+                self.statement(node.child("body")?)?,
+            ),
+            "VariableDeclaration"
+            | "FunctionDeclaration"
+            | "TSDeclareFunction"
+            | "ClassDeclaration"
+            | "TSTypeAliasDeclaration"
+            | "TSInterfaceDeclaration"
+            | "TSEnumDeclaration"
+            | "TSModuleDeclaration"
+            | "TSImportEqualsDeclaration" => Statement::from(self.declaration(node)?),
+            "ImportDeclaration"
+            | "ExportAllDeclaration"
+            | "ExportDefaultDeclaration"
+            | "ExportNamedDeclaration"
+            | "TSExportAssignment"
+            | "TSNamespaceExportDeclaration" => Statement::from(self.module_declaration(node)?),
+            _ => return Err(node.unknown("Statement")),
+        };
+        Ok(statement)
+    }
+
+    fn block_statement(self, node: Node<'_>) -> Result<BlockStatement<'a>> {
+        node.expect("BlockStatement")?;
+        let body = self.list(node, "body", |statement| self.statement(statement))?;
+        Ok(self.ast.block_statement(node.span(), body))
+    }
+
+    fn opt_label(self, node: Node<'_>) -> Result<Option<LabelIdentifier<'a>>> {
+        node.opt_child("label")?.map(|label| self.label_identifier(label)).transpose()
+    }
+
+    fn for_statement_init(self, node: Node<'_>) -> Result<ForStatementInit<'a>> {
+        if node.ty == "VariableDeclaration" {
+            let declaration = self.variable_declaration(node)?;
+            return Ok(ForStatementInit::VariableDeclaration(self.ast.alloc(declaration)));
+        }
+        Ok(ForStatementInit::from(self.expression(node)?))
+    }
+
+    fn for_statement_left(self, node: Node<'_>) -> Result<ForStatementLeft<'a>> {
+        if node.ty == "VariableDeclaration" {
+            let declaration = self.variable_declaration(node)?;
+            return Ok(ForStatementLeft::VariableDeclaration(self.ast.alloc(declaration)));
+        }
+        Ok(ForStatementLeft::from(self.assignment_target(node)?))
+    }
+
+    fn switch_case(self, node: Node<'_>) -> Result<SwitchCase<'a>> {
+        node.expect("SwitchCase")?;
+        Ok(self.ast.switch_case(
+            node.span(),
+            self.opt_expression(node, "test")?,
+            self.list(node, "consequent", |statement| self.statement(statement))?,
+        ))
+    }
+
+    fn catch_clause(self, node: Node<'_>) -> Result<CatchClause<'a>> {
+        node.expect("CatchClause")?;
+        let param = node
+            .opt_child("param")?
+            .map(|param| {
+                let pattern = self.binding_pattern(param)?;
+                Ok(self.ast.catch_parameter(param.span(), pattern))
+            })
+            .transpose()?;
+        Ok(self.ast.catch_clause(node.span(), param, self.block_statement(node.child("body")?)?))
+    }
+
+    // ----------------------------------------
+    // Declarations
+    // ----------------------------------------
+
+    pub(super) fn declaration(self, node: Node<'_>) -> Result<Declaration<'a>> {
+        let declaration = match node.ty {
+            "VariableDeclaration" => {
+                Declaration::VariableDeclaration(self.ast.alloc(self.variable_declaration(node)?))
+            }
+            "FunctionDeclaration" | "TSDeclareFunction" => Declaration::FunctionDeclaration(
+                self.ast.alloc(self.function(node, FormalParameterKind::FormalParameter)?),
+            ),
+            "ClassDeclaration" => Declaration::ClassDeclaration(self.ast.alloc(self.class(node)?)),
+            "TSTypeAliasDeclaration" => Declaration::TSTypeAliasDeclaration(
+                self.ast.alloc(self.ts_type_alias_declaration(node)?),
+            ),
+            "TSInterfaceDeclaration" => Declaration::TSInterfaceDeclaration(
+                self.ast.alloc(self.ts_interface_declaration(node)?),
+            ),
+            "TSEnumDeclaration" => {
+                Declaration::TSEnumDeclaration(self.ast.alloc(self.ts_enum_declaration(node)?))
+            }
+            "TSModuleDeclaration" => {
+                Declaration::TSModuleDeclaration(self.ast.alloc(self.ts_module_declaration(node)?))
+            }
+            "TSImportEqualsDeclaration" => Declaration::TSImportEqualsDeclaration(
+                self.ast.alloc(self.ts_import_equals_declaration(node)?),
+            ),
+            _ => return Err(node.unknown("Declaration")),
+        };
+        Ok(declaration)
+    }
+
+    fn variable_declaration(self, node: Node<'_>) -> Result<VariableDeclaration<'a>> {
+        node.expect("VariableDeclaration")?;
+        let kind =
+            node.keyword("kind", "a variable declaration kind", VARIABLE_DECLARATION_KINDS)?;
+        let declarations = self
+            .list(node, "declarations", |declarator| self.variable_declarator(declarator, kind))?;
+        Ok(self.ast.variable_declaration(node.span(), kind, declarations, node.bool("declare")?))
+    }
+
+    fn variable_declarator(
+        self,
+        node: Node<'_>,
+        kind: VariableDeclarationKind,
+    ) -> Result<VariableDeclarator<'a>> {
+        node.expect("VariableDeclarator")?;
+        Ok(self.ast.variable_declarator(
+            node.span(),
+            kind,
+            self.binding_pattern(node.child("id")?)?,
+            self.opt_expression(node, "init")?,
+            node.bool("definite")?,
+        ))
+    }
+
+    // ----------------------------------------
+    // Identifiers
+    // ----------------------------------------
+
+    pub(super) fn identifier_name(self, node: Node<'_>) -> Result<IdentifierName<'a>> {
+        node.expect("Identifier")?;
+        Ok(self.ast.identifier_name(node.span(), self.atom(node.str("name")?)))
+    }
+
+    pub(super) fn identifier_reference(self, node: Node<'_>) -> Result<IdentifierReference<'a>> {
+        node.expect("Identifier")?;
+        Ok(self.ast.identifier_reference(node.span(), self.atom(node.str("name")?)))
+    }
+
+    pub(super) fn binding_identifier(self, node: Node<'_>) -> Result<BindingIdentifier<'a>> {
+        node.expect("Identifier")?;
+        Ok(self.ast.binding_identifier(node.span(), self.atom(node.str("name")?)))
+    }
+
+    pub(super) fn opt_binding_identifier(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Option<BindingIdentifier<'a>>> {
+        node.opt_child(field)?.map(|id| self.binding_identifier(id)).transpose()
+    }
+
+    fn label_identifier(self, node: Node<'_>) -> Result<LabelIdentifier<'a>> {
+        node.expect("Identifier")?;
+        Ok(self.ast.label_identifier(node.span(), self.atom(node.str("name")?)))
+    }
+
+    fn private_identifier(self, node: Node<'_>) -> Result<PrivateIdentifier<'a>> {
+        node.expect("PrivateIdentifier")?;
+        Ok(self.ast.private_identifier(node.span(), self.atom(node.str("name")?)))
+    }
+
+    // ----------------------------------------
+    // Expressions
+    // ----------------------------------------
+
+    pub(super) fn expression(self, node: Node<'_>) -> Result<Expression<'a>> {
+        let span = node.span();
+        let expression = match node.ty {
+            "Identifier" => self.ast.expression_identifier(span, self.atom(node.str("name")?)),
+            "Literal" => self.literal(node)?,
+            "TemplateLiteral" => {
+                Expression::TemplateLiteral(self.ast.alloc(self.template_literal(node)?))
+            }
+            "TaggedTemplateExpression" => self.ast.expression_tagged_template(
+                span,
+                self.child_expression(node, "tag")?,
+                self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+                self.template_literal(node.child("quasi")?)?,
+            ),
+            "MetaProperty" => self.ast.expression_meta_property(
+                span,
+                self.identifier_name(node.child("meta")?)?,
+                self.identifier_name(node.child("property")?)?,
+            ),
+            "Super" => self.ast.expression_super(span),
+            "ThisExpression" => self.ast.expression_this(span),
+            "ArrayExpression" => {
+                self.ast.expression_array(span, self.array_expression_elements(node)?)
+            }
+            "ObjectExpression" => self.ast.expression_object(
+                span,
+                self.list(node, "properties", |property| self.object_property_kind(property))?,
+            ),
+            "FunctionExpression" => Expression::FunctionExpression(
+                self.ast.alloc(self.function(node, FormalParameterKind::FormalParameter)?),
+            ),
+            "ArrowFunctionExpression" => {
+                Expression::ArrowFunctionExpression(self.ast.alloc(self.arrow_function(node)?))
+            }
+            "ClassExpression" => Expression::ClassExpression(self.ast.alloc(self.class(node)?)),
+            "AssignmentExpression" => self.ast.expression_assignment(
+                span,
+                node.operator(ASSIGNMENT_OPERATORS, AssignmentOperator::as_str)?,
+                self.assignment_target(node.child("left")?)?,
+                self.child_expression(node, "right")?,
+            ),
+            "AwaitExpression" => {
+                self.ast.expression_await(span, self.child_expression(node, "argument")?)
+            }
+            "YieldExpression" => self.ast.expression_yield(
+                span,
+                node.bool("delegate")?,
+                self.opt_expression(node, "argument")?,
+            ),
+            "BinaryExpression" => {
+                let left = node.child("left")?;
+                if left.ty == "PrivateIdentifier" && node.str("operator")? == "in" {
+                    // `#field in object`
+                    self.ast.expression_private_in(
+                        span,
+                        self.private_identifier(left)?,
+                        self.child_expression(node, "right")?,
+                    )
+                } else {
+                    self.ast.expression_binary(
+                        span,
+                        self.expression(left)?,
+                        node.operator(BINARY_OPERATORS, BinaryOperator::as_str)?,
+                        self.child_expression(node, "right")?,
+                    )
+                }
+            }
+            "LogicalExpression" => self.ast.expression_logical(
+                span,
+                self.child_expression(node, "left")?,
+                node.operator(LOGICAL_OPERATORS, LogicalOperator::as_str)?,
+                self.child_expression(node, "right")?,
+            ),
+            "ConditionalExpression" => self.ast.expression_conditional(
+                span,
+                self.child_expression(node, "test")?,
+                self.child_expression(node, "consequent")?,
+                self.child_expression(node, "alternate")?,
+            ),
+            "CallExpression" => {
+                Expression::CallExpression(self.ast.alloc(self.call_expression(node)?))
+            }
+            "NewExpression" => self.ast.expression_new(
+                span,
+                self.child_expression(node, "callee")?,
+                self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+                self.arguments(node)?,
+            ),
+            "MemberExpression" => Expression::from(self.member_expression(node)?),
+            "ChainExpression" => {
+                self.ast.expression_chain(span, self.chain_element(node.child("expression")?)?)
+            }
+            "ImportExpression" => self.ast.expression_import(
+                span,
+                self.child_expression(node, "source")?,
+                self.opt_expression(node, "options")?,
+                node.opt_keyword("phase", "an import phase", IMPORT_PHASES)?,
+            ),
+            "ParenthesizedExpression" => {
+                self.ast.expression_parenthesized(span, self.child_expression(node, "expression")?)
+            }
+            "SequenceExpression" => self.ast.expression_sequence(
+                span,
+                self.list(node, "expressions", |expression| self.expression(expression))?,
+            ),
+            "UnaryExpression" => self.ast.expression_unary(
+                span,
+                node.operator(UNARY_OPERATORS, UnaryOperator::as_str)?,
+                self.child_expression(node, "argument")?,
+            ),
+            "UpdateExpression" => self.ast.expression_update(
+                span,
+                node.operator(UPDATE_OPERATORS, UpdateOperator::as_str)?,
+                node.bool("prefix")?,
+                self.simple_assignment_target(node.child("argument")?)?,
+            ),
+            "V8IntrinsicExpression" => self.ast.expression_v_8_intrinsic(
+                span,
+                self.identifier_name(node.child("name")?)?,
+                self.arguments(node)?,
+            ),
+            "ErrorExpression" => self.ast.expression_error(span),
+            "JSXElement" => Expression::JSXElement(self.ast.alloc(self.jsx_element(node)?)),
+            "JSXFragment" => Expression::JSXFragment(self.ast.alloc(self.jsx_fragment(node)?)),
+            "TSAsExpression" => self.ast.expression_ts_as(
+                span,
+                self.child_expression(node, "expression")?,
+                self.ts_type(node.child("typeAnnotation")?)?,
+            ),
+            "TSSatisfiesExpression" => self.ast.expression_ts_satisfies(
+                span,
+                self.child_expression(node, "expression")?,
+                self.ts_type(node.child("typeAnnotation")?)?,
+            ),
+            "TSTypeAssertion" => self.ast.expression_ts_type_assertion(
+                span,
+                self.ts_type(node.child("typeAnnotation")?)?,
+                self.child_expression(node, "expression")?,
+            ),
+            "TSNonNullExpression" => {
+                self.ast.expression_ts_non_null(span, self.child_expression(node, "expression")?)
+            }
+            "TSInstantiationExpression" => self.ast.expression_ts_instantiation(
+                span,
+                self.child_expression(node, "expression")?,
+                self.ts_type_parameter_instantiation(node.child("typeArguments")?)?,
+            ),
+            _ => return Err(node.unknown("Expression")),
+        };
+        Ok(expression)
+    }
+
+    pub(super) fn child_expression(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Expression<'a>> {
+        self.expression(node.child(field)?)
+    }
+
+    pub(super) fn opt_expression(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Option<Expression<'a>>> {
+        node.opt_child(field)?.map(|expression| self.expression(expression)).transpose()
+    }
+
+    fn array_expression_elements(
+        self,
+        node: Node<'_>,
+    ) -> Result<Vec<'a, ArrayExpressionElement<'a>>> {
+        let mut elements = self.ast.vec();
+        for element in node.opt_nodes("elements")? {
+            let element = match element? {
+                None => self.ast.array_expression_element_elision(SPAN),
+                Some(element) if element.ty == "SpreadElement" => {
+                    self.ast.array_expression_element_spread_element(
+                        element.span(),
+                        self.child_expression(element, "argument")?,
+                    )
+                }
+                Some(element) => ArrayExpressionElement::from(self.expression(element)?),
+            };
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+
+    fn object_property_kind(self, node: Node<'_>) -> Result<ObjectPropertyKind<'a>> {
+        let span = node.span();
+        match node.ty {
+            "SpreadElement" => Ok(self.ast.object_property_kind_spread_property(
+                span,
+                self.child_expression(node, "argument")?,
+            )),
+            "Property" => Ok(self.ast.object_property_kind_object_property(
+                span,
+                node.keyword("kind", "a property kind", PROPERTY_KINDS)?,
+                self.property_key(node)?,
+                self.child_expression(node, "value")?,
+                node.bool("method")?,
+                node.bool("shorthand")?,
+                node.bool("computed")?,
+            )),
+            _ => Err(node.unknown("ObjectProperty")),
+        }
+    }
+
+    /// Get the `key` of a `Property`, `MethodDefinition` etc, respecting its `computed` field.
+    pub(super) fn property_key(self, node: Node<'_>) -> Result<PropertyKey<'a>> {
+        let key = node.child("key")?;
+        if node.bool("computed")? {
+            return Ok(PropertyKey::from(self.expression(key)?));
+        }
+        let span = key.span();
+        match key.ty {
+            "Identifier" => {
+                Ok(self.ast.property_key_static_identifier(span, self.atom(key.str("name")?)))
+            }
+            "PrivateIdentifier" => {
+                Ok(self.ast.property_key_private_identifier(span, self.atom(key.str("name")?)))
+            }
+            "Literal" => Ok(PropertyKey::from(self.literal(key)?)),
+            _ => Err(key.unknown("PropertyKey")),
+        }
+    }
+
+    fn call_expression(self, node: Node<'_>) -> Result<CallExpression<'a>> {
+        node.expect("CallExpression")?;
+        Ok(self.ast.call_expression(
+            node.span(),
+            self.child_expression(node, "callee")?,
+            self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+            self.arguments(node)?,
+            node.bool("optional")?,
+        ))
+    }
+
+    fn arguments(self, node: Node<'_>) -> Result<Vec<'a, Argument<'a>>> {
+        self.list(node, "arguments", |argument| {
+            if argument.ty == "SpreadElement" {
+                Ok(self.ast.argument_spread_element(
+                    argument.span(),
+                    self.child_expression(argument, "argument")?,
+                ))
+            } else {
+                Ok(Argument::from(self.expression(argument)?))
+            }
+        })
+    }
+
+    fn member_expression(self, node: Node<'_>) -> Result<MemberExpression<'a>> {
+        node.expect("MemberExpression")?;
+        let span = node.span();
+        let object = self.child_expression(node, "object")?;
+        let property = node.child("property")?;
+        let optional = node.bool("optional")?;
+        let member = if node.bool("computed")? {
+            self.ast.member_expression_computed(span, object, self.expression(property)?, optional)
+        } else if property.ty == "PrivateIdentifier" {
+            self.ast.member_expression_private_field_expression(
+                span,
+                object,
+                self.private_identifier(property)?,
+                optional,
+            )
+        } else {
+            self.ast.member_expression_static(
+                span,
+                object,
+                self.identifier_name(property)?,
+                optional,
+            )
+        };
+        Ok(member)
+    }
+
+    fn chain_element(self, node: Node<'_>) -> Result<ChainElement<'a>> {
+        match node.ty {
+            "CallExpression" => {
+                Ok(ChainElement::CallExpression(self.ast.alloc(self.call_expression(node)?)))
+            }
+            "MemberExpression" => Ok(ChainElement::from(self.member_expression(node)?)),
+            "TSNonNullExpression" => Ok(self.ast.chain_element_ts_non_null_expression(
+                node.span(),
+                self.child_expression(node, "expression")?,
+            )),
+            _ => Err(node.unknown("ChainElement")),
+        }
+    }
+
+    // ----------------------------------------
+    // Assignment targets
+    // ----------------------------------------
+
+    fn assignment_target(self, node: Node<'_>) -> Result<AssignmentTarget<'a>> {
+        let span = node.span();
+        match node.ty {
+            "ArrayPattern" => {
+                let mut elements = self.ast.vec();
+                let mut rest = None;
+                for element in node.opt_nodes("elements")? {
+                    if rest.is_some() {
+                        return Err(node.invalid("elements", "rest element to be last"));
+                    }
+                    match element? {
+                        Some(element) if element.ty == "RestElement" => {
+                            rest = Some(self.assignment_target_rest(element)?);
+                        }
+                        element => elements.push(
+                            element
+                                .map(|element| self.assignment_target_maybe_default(element))
+                                .transpose()?,
+                        ),
+                    }
+                }
+                Ok(self
+                    .ast
+                    .assignment_target_pattern_array_assignment_target(span, elements, rest)
+                    .into())
+            }
+            "ObjectPattern" => {
+                let mut properties = self.ast.vec();
+                let mut rest = None;
+                for property in node.nodes("properties")? {
+                    let property = property?;
+                    if rest.is_some() {
+                        return Err(node.invalid("properties", "rest element to be last"));
+                    }
+                    if property.ty == "RestElement" {
+                        rest = Some(self.assignment_target_rest(property)?);
+                    } else {
+                        properties.push(self.assignment_target_property(property)?);
+                    }
+                }
+                Ok(self
+                    .ast
+                    .assignment_target_pattern_object_assignment_target(span, properties, rest)
+                    .into())
+            }
+            _ => Ok(AssignmentTarget::from(self.simple_assignment_target(node)?)),
+        }
+    }
+
+    fn simple_assignment_target(self, node: Node<'_>) -> Result<SimpleAssignmentTarget<'a>> {
+        let target = match node.ty {
+            "Identifier" => self.ast.simple_assignment_target_assignment_target_identifier(
+                node.span(),
+                self.atom(node.str("name")?),
+            ),
+            "MemberExpression" => SimpleAssignmentTarget::from(self.member_expression(node)?),
+            // Parentheses around an assignment target are not represented in the AST
+            "ParenthesizedExpression" => {
+                return self.simple_assignment_target(node.child("expression")?);
+            }
+            "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSNonNullExpression"
+            | "TSTypeAssertion" => match self.expression(node)? {
+                Expression::TSAsExpression(expr) => SimpleAssignmentTarget::TSAsExpression(expr),
+                Expression::TSSatisfiesExpression(expr) => {
+                    SimpleAssignmentTarget::TSSatisfiesExpression(expr)
+                }
+                Expression::TSNonNullExpression(expr) => {
+                    SimpleAssignmentTarget::TSNonNullExpression(expr)
+                }
+                Expression::TSTypeAssertion(expr) => SimpleAssignmentTarget::TSTypeAssertion(expr),
+                _ => unreachable!(),
+            },
+            _ => return Err(node.unknown("AssignmentTarget")),
+        };
+        Ok(target)
+    }
+
+    fn assignment_target_maybe_default(
+        self,
+        node: Node<'_>,
+    ) -> Result<AssignmentTargetMaybeDefault<'a>> {
+        if node.ty == "AssignmentPattern" {
+            return Ok(self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                node.span(),
+                self.assignment_target(node.child("left")?)?,
+                self.child_expression(node, "right")?,
+            ));
+        }
+        Ok(AssignmentTargetMaybeDefault::from(self.assignment_target(node)?))
+    }
+
+    fn assignment_target_rest(self, node: Node<'_>) -> Result<AssignmentTargetRest<'a>> {
+        node.expect("RestElement")?;
+        Ok(self
+            .ast
+            .assignment_target_rest(node.span(), self.assignment_target(node.child("argument")?)?))
+    }
+
+    fn assignment_target_property(self, node: Node<'_>) -> Result<AssignmentTargetProperty<'a>> {
+        node.expect("Property")?;
+        let span = node.span();
+        let value = node.child("value")?;
+        if node.bool("shorthand")? {
+            let binding = self.identifier_reference(node.child("key")?)?;
+            let init = if value.ty == "AssignmentPattern" {
+                Some(self.child_expression(value, "right")?)
+            } else {
+                None
+            };
+            return Ok(self.ast.assignment_target_property_assignment_target_property_identifier(
+                span, binding, init,
+            ));
+        }
+        Ok(self.ast.assignment_target_property_assignment_target_property_property(
+            span,
+            self.property_key(node)?,
+            self.assignment_target_maybe_default(value)?,
+            node.bool("computed")?,
+        ))
+    }
+
+    // ----------------------------------------
+    // Binding patterns and function params
+    // ----------------------------------------
+
+    pub(super) fn binding_pattern(self, node: Node<'_>) -> Result<BindingPattern<'a>> {
+        let span = node.span();
+        let kind = match node.ty {
+            "Identifier" => {
+                self.ast.binding_pattern_kind_binding_identifier(span, self.atom(node.str("name")?))
+            }
+            "ObjectPattern" => {
+                let mut properties = self.ast.vec();
+                let mut rest = None;
+                for property in node.nodes("properties")? {
+                    let property = property?;
+                    if rest.is_some() {
+                        return Err(node.invalid("properties", "rest element to be last"));
+                    }
+                    if property.ty == "RestElement" {
+                        rest = Some(self.binding_rest_element(property)?);
+                    } else {
+                        properties.push(self.binding_property(property)?);
+                    }
+                }
+                self.ast.binding_pattern_kind_object_pattern(span, properties, rest)
+            }
+            "ArrayPattern" => {
+                let mut elements = self.ast.vec();
+                let mut rest = None;
+                for element in node.opt_nodes("elements")? {
+                    if rest.is_some() {
+                        return Err(node.invalid("elements", "rest element to be last"));
+                    }
+                    match element? {
+                        Some(element) if element.ty == "RestElement" => {
+                            rest = Some(self.binding_rest_element(element)?);
+                        }
+                        element => elements.push(
+                            element.map(|element| self.binding_pattern(element)).transpose()?,
+                        ),
+                    }
+                }
+                self.ast.binding_pattern_kind_array_pattern(span, elements, rest)
+            }
+            "AssignmentPattern" => self.ast.binding_pattern_kind_assignment_pattern(
+                span,
+                self.binding_pattern(node.child("left")?)?,
+                self.child_expression(node, "right")?,
+            ),
+            _ => return Err(node.unknown("BindingPattern")),
+        };
+        Ok(self.ast.binding_pattern(
+            kind,
+            self.opt_ts_type_annotation(node, "typeAnnotation")?,
+            node.bool("optional")?,
+        ))
+    }
+
+    fn binding_property(self, node: Node<'_>) -> Result<BindingProperty<'a>> {
+        node.expect("Property")?;
+        Ok(self.ast.binding_property(
+            node.span(),
+            self.property_key(node)?,
+            self.binding_pattern(node.child("value")?)?,
+            node.bool("shorthand")?,
+            node.bool("computed")?,
+        ))
+    }
+
+    fn binding_rest_element(self, node: Node<'_>) -> Result<BindingRestElement<'a>> {
+        node.expect("RestElement")?;
+        let mut argument = self.binding_pattern(node.child("argument")?)?;
+        // For function params, TS-ESTree puts the type annotation on the `RestElement`
+        if argument.type_annotation.is_none()
+            && let Some(type_annotation) = self.opt_ts_type_annotation(node, "typeAnnotation")?
+        {
+            argument.type_annotation = Some(self.ast.alloc(type_annotation));
+        }
+        argument.optional |= node.bool("optional")?;
+        Ok(self.ast.binding_rest_element(node.span(), argument))
+    }
+
+    /// Get the `params` of a function or signature.
+    ///
+    /// In TS-ESTree, a `this` parameter is the first element of `params`.
+    pub(super) fn formal_parameters(
+        self,
+        node: Node<'_>,
+        kind: FormalParameterKind,
+    ) -> Result<(Option<Box<'a, TSThisParameter<'a>>>, Box<'a, FormalParameters<'a>>)> {
+        let mut this_param = None;
+        let mut items = self.ast.vec();
+        let mut rest = None;
+        for (index, param) in node.nodes("params")?.enumerate() {
+            let param = param?;
+            if rest.is_some() {
+                return Err(node.invalid("params", "rest element to be last"));
+            }
+            match param.ty {
+                "RestElement" => rest = Some(self.binding_rest_element(param)?),
+                "Identifier" if index == 0 && param.str("name")? == "this" => {
+                    this_param = Some(self.ast.alloc(self.ts_this_parameter(param)?));
+                }
+                _ => items.push(self.formal_parameter(param)?),
+            }
+        }
+        let params = self.ast.alloc_formal_parameters(SPAN, kind, items, rest);
+        Ok((this_param, params))
+    }
+
+    fn formal_parameter(self, node: Node<'_>) -> Result<FormalParameter<'a>> {
+        let span = node.span();
+        let decorators = self.decorators(node)?;
+        if node.ty == "TSParameterProperty" {
+            return Ok(self.ast.formal_parameter(
+                span,
+                decorators,
+                self.binding_pattern(node.child("parameter")?)?,
+                ts_accessibility(node)?,
+                node.bool("readonly")?,
+                node.bool("override")?,
+            ));
+        }
+        Ok(self.ast.formal_parameter(
+            span,
+            decorators,
+            self.binding_pattern(node)?,
+            None,
+            false,
+            false,
+        ))
+    }
+
+    // ----------------------------------------
+    // Functions
+    // ----------------------------------------
+
+    fn function(self, node: Node<'_>, params_kind: FormalParameterKind) -> Result<Function<'a>> {
+        let r#type = match node.ty {
+            "FunctionDeclaration" => FunctionType::FunctionDeclaration,
+            "FunctionExpression" => FunctionType::FunctionExpression,
+            "TSDeclareFunction" => FunctionType::TSDeclareFunction,
+            "TSEmptyBodyFunctionExpression" => FunctionType::TSEmptyBodyFunctionExpression,
+            _ => return Err(node.unknown("Function")),
+        };
+        let id = self.opt_binding_identifier(node, "id")?;
+        let type_parameters = self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+        let (this_param, params) = self.formal_parameters(node, params_kind)?;
+        let return_type = self.opt_ts_type_annotation(node, "returnType")?;
+        let body = node.opt_child("body")?.map(|body| self.function_body(body)).transpose()?;
+        Ok(self.ast.function(
+            node.span(),
+            r#type,
+            id,
+            node.bool("generator")?,
+            node.bool("async")?,
+            node.bool("declare")?,
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            body,
+        ))
+    }
+
+    fn function_body(self, node: Node<'_>) -> Result<FunctionBody<'a>> {
+        node.expect("BlockStatement")?;
+        let (directives, statements) = self.directives_and_statements(node, "body")?;
+        Ok(self.ast.function_body(node.span(), directives, statements))
+    }
+
+    fn arrow_function(self, node: Node<'_>) -> Result<ArrowFunctionExpression<'a>> {
+        let expression = node.bool("expression")?;
+        let type_parameters = self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+        let (_, params) =
+            self.formal_parameters(node, FormalParameterKind::ArrowFormalParameters)?;
+        let return_type = self.opt_ts_type_annotation(node, "returnType")?;
+        let body = node.child("body")?;
+        let body = if expression {
+            // Expression body is represented as a `FunctionBody` containing a single `ExpressionStatement`
+            let span = body.span();
+            let statement = self.ast.statement_expression(span, self.expression(body)?);
+            self.ast.function_body(span, self.ast.vec(), self.ast.vec1(statement))
+        } else {
+            self.function_body(body)?
+        };
+        Ok(self.ast.arrow_function_expression(
+            node.span(),
+            expression,
+            node.bool("async")?,
+            type_parameters,
+            params,
+            return_type,
+            body,
+        ))
+    }
+
+    // ----------------------------------------
+    // Classes
+    // ----------------------------------------
+
+    fn class(self, node: Node<'_>) -> Result<Class<'a>> {
+        let r#type = match node.ty {
+            "ClassDeclaration" => ClassType::ClassDeclaration,
+            "ClassExpression" => ClassType::ClassExpression,
+            _ => return Err(node.unknown("Class")),
+        };
+        Ok(self.ast.class(
+            node.span(),
+            r#type,
+            self.decorators(node)?,
+            self.opt_binding_identifier(node, "id")?,
+            self.opt_ts_type_parameter_declaration(node, "typeParameters")?,
+            self.opt_expression(node, "superClass")?,
+            self.opt_ts_type_parameter_instantiation(node, "superTypeArguments")?,
+            self.list_or_empty(node, "implements", |implements| {
+                self.ts_class_implements(implements)
+            })?,
+            self.class_body(node.child("body")?)?,
+            node.bool("abstract")?,
+            node.bool("declare")?,
+        ))
+    }
+
+    fn class_body(self, node: Node<'_>) -> Result<ClassBody<'a>> {
+        node.expect("ClassBody")?;
+        let body = self.list(node, "body", |element| self.class_element(element))?;
+        Ok(self.ast.class_body(node.span(), body))
+    }
+
+    fn class_element(self, node: Node<'_>) -> Result<ClassElement<'a>> {
+        let span = node.span();
+        let element = match node.ty {
+            "StaticBlock" => self.ast.class_element_static_block(
+                span,
+                self.list(node, "body", |statement| self.statement(statement))?,
+            ),
+            "MethodDefinition" | "TSAbstractMethodDefinition" => {
+                let r#type = if node.ty == "MethodDefinition" {
+                    MethodDefinitionType::MethodDefinition
+                } else {
+                    MethodDefinitionType::TSAbstractMethodDefinition
+                };
+                self.ast.class_element_method_definition(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.property_key(node)?,
+                    self.function(
+                        node.child("value")?,
+                        FormalParameterKind::UniqueFormalParameters,
+                    )?,
+                    node.keyword("kind", "a method kind", METHOD_DEFINITION_KINDS)?,
+                    node.bool("computed")?,
+                    node.bool("static")?,
+                    node.bool("override")?,
+                    node.bool("optional")?,
+                    ts_accessibility(node)?,
+                )
+            }
+            "PropertyDefinition" | "TSAbstractPropertyDefinition" => {
+                let r#type = if node.ty == "PropertyDefinition" {
+                    PropertyDefinitionType::PropertyDefinition
+                } else {
+                    PropertyDefinitionType::TSAbstractPropertyDefinition
+                };
+                self.ast.class_element_property_definition(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.property_key(node)?,
+                    self.opt_ts_type_annotation(node, "typeAnnotation")?,
+                    self.opt_expression(node, "value")?,
+                    node.bool("computed")?,
+                    node.bool("static")?,
+                    node.bool("declare")?,
+                    node.bool("override")?,
+                    node.bool("optional")?,
+                    node.bool("definite")?,
+                    node.bool("readonly")?,
+                    ts_accessibility(node)?,
+                )
+            }
+            "AccessorProperty" | "TSAbstractAccessorProperty" => {
+                let r#type = if node.ty == "AccessorProperty" {
+                    AccessorPropertyType::AccessorProperty
+                } else {
+                    AccessorPropertyType::TSAbstractAccessorProperty
+                };
+                self.ast.class_element_accessor_property(
+                    span,
+                    r#type,
+                    self.decorators(node)?,
+                    self.property_key(node)?,
+                    self.opt_ts_type_annotation(node, "typeAnnotation")?,
+                    self.opt_expression(node, "value")?,
+                    node.bool("computed")?,
+                    node.bool("static")?,
+                    node.bool("override")?,
+                    node.bool("definite")?,
+                    ts_accessibility(node)?,
+                )
+            }
+            "TSIndexSignature" => {
+                ClassElement::TSIndexSignature(self.ast.alloc(self.ts_index_signature(node)?))
+            }
+            _ => return Err(node.unknown("ClassElement")),
+        };
+        Ok(element)
+    }
+
+    fn decorators(self, node: Node<'_>) -> Result<Vec<'a, Decorator<'a>>> {
+        self.list_or_empty(node, "decorators", |decorator| {
+            decorator.expect("Decorator")?;
+            Ok(self
+                .ast
+                .decorator(decorator.span(), self.child_expression(decorator, "expression")?))
+        })
+    }
+
+    // ----------------------------------------
+    // Import / export
+    // ----------------------------------------
+
+    fn module_declaration(self, node: Node<'_>) -> Result<ModuleDeclaration<'a>> {
+        let span = node.span();
+        let declaration = match node.ty {
+            "ImportDeclaration" => {
+                let specifiers =
+                    self.list(node, "specifiers", |specifier| self.import_specifier(specifier))?;
+                // ESTree does not distinguish `import {} from 'x'` from `import 'x'`
+                let specifiers = if specifiers.is_empty() { None } else { Some(specifiers) };
+                self.ast.module_declaration_import_declaration(
+                    span,
+                    specifiers,
+                    self.string_literal(node.child("source")?)?,
+                    node.opt_keyword("phase", "an import phase", IMPORT_PHASES)?,
+                    self.with_clause(node)?,
+                    import_or_export_kind(node, "importKind")?,
+                )
+            }
+            "ExportAllDeclaration" => self.ast.module_declaration_export_all_declaration(
+                span,
+                node.opt_child("exported")?
+                    .map(|exported| self.module_export_name(exported, false))
+                    .transpose()?,
+                self.string_literal(node.child("source")?)?,
+                self.with_clause(node)?,
+                import_or_export_kind(node, "exportKind")?,
+            ),
+            "ExportDefaultDeclaration" => self.ast.module_declaration_export_default_declaration(
+                span,
+                self.export_default_declaration_kind(node.child("declaration")?)?,
+            ),
+            "ExportNamedDeclaration" => {
+                let declaration = node
+                    .opt_child("declaration")?
+                    .map(|declaration| self.declaration(declaration))
+                    .transpose()?;
+                let source = node
+                    .opt_child("source")?
+                    .map(|source| self.string_literal(source))
+                    .transpose()?;
+                // Without a source, `local` refers to a binding in this module
+                let is_reference = source.is_none();
+                let specifiers = self.list(node, "specifiers", |specifier| {
+                    self.export_specifier(specifier, is_reference)
+                })?;
+                self.ast.module_declaration_export_named_declaration(
+                    span,
+                    declaration,
+                    specifiers,
+                    source,
+                    import_or_export_kind(node, "exportKind")?,
+                    self.with_clause(node)?,
+                )
+            }
+            "TSExportAssignment" => self.ast.module_declaration_ts_export_assignment(
+                span,
+                self.child_expression(node, "expression")?,
+            ),
+            "TSNamespaceExportDeclaration" => {
+                self.ast.module_declaration_ts_namespace_export_declaration(
+                    span,
+                    self.identifier_name(node.child("id")?)?,
+                )
+            }
+            _ => return Err(node.unknown("ModuleDeclaration")),
+        };
+        Ok(declaration)
+    }
+
+    fn import_specifier(self, node: Node<'_>) -> Result<ImportDeclarationSpecifier<'a>> {
+        let span = node.span();
+        let specifier = match node.ty {
+            "ImportSpecifier" => self.ast.import_declaration_specifier_import_specifier(
+                span,
+                self.module_export_name(node.child("imported")?, false)?,
+                self.binding_identifier(node.child("local")?)?,
+                import_or_export_kind(node, "importKind")?,
+            ),
+            "ImportDefaultSpecifier" => {
+                self.ast.import_declaration_specifier_import_default_specifier(
+                    span,
+                    self.binding_identifier(node.child("local")?)?,
+                )
+            }
+            "ImportNamespaceSpecifier" => {
+                self.ast.import_declaration_specifier_import_namespace_specifier(
+                    span,
+                    self.binding_identifier(node.child("local")?)?,
+                )
+            }
+            _ => return Err(node.unknown("ImportDeclarationSpecifier")),
+        };
+        Ok(specifier)
+    }
+
+    fn export_specifier(self, node: Node<'_>, is_reference: bool) -> Result<ExportSpecifier<'a>> {
+        node.expect("ExportSpecifier")?;
+        Ok(self.ast.export_specifier(
+            node.span(),
+            self.module_export_name(node.child("local")?, is_reference)?,
+            self.module_export_name(node.child("exported")?, false)?,
+            import_or_export_kind(node, "exportKind")?,
+        ))
+    }
+
+    fn module_export_name(
+        self,
+        node: Node<'_>,
+        is_reference: bool,
+    ) -> Result<ModuleExportName<'a>> {
+        let span = node.span();
+        match node.ty {
+            "Identifier" if is_reference => Ok(self
+                .ast
+                .module_export_name_identifier_reference(span, self.atom(node.str("name")?))),
+            "Identifier" => {
+                Ok(self.ast.module_export_name_identifier_name(span, self.atom(node.str("name")?)))
+            }
+            "Literal" => {
+                let literal = self.string_literal(node)?;
+                Ok(self.ast.module_export_name_string_literal(span, literal.value, literal.raw))
+            }
+            _ => Err(node.unknown("ModuleExportName")),
+        }
+    }
+
+    fn export_default_declaration_kind(
+        self,
+        node: Node<'_>,
+    ) -> Result<ExportDefaultDeclarationKind<'a>> {
+        let kind = match node.ty {
+            "FunctionDeclaration" | "TSDeclareFunction" => {
+                ExportDefaultDeclarationKind::FunctionDeclaration(
+                    self.ast.alloc(self.function(node, FormalParameterKind::FormalParameter)?),
+                )
+            }
+            "ClassDeclaration" => {
+                ExportDefaultDeclarationKind::ClassDeclaration(self.ast.alloc(self.class(node)?))
+            }
+            "TSInterfaceDeclaration" => ExportDefaultDeclarationKind::TSInterfaceDeclaration(
+                self.ast.alloc(self.ts_interface_declaration(node)?),
+            ),
+            _ => ExportDefaultDeclarationKind::from(self.expression(node)?),
+        };
+        Ok(kind)
+    }
+
+    /// Get the `attributes` of an import / export declaration.
+    fn with_clause(self, node: Node<'_>) -> Result<Option<WithClause<'a>>> {
+        let attributes = self.list_or_empty(node, "attributes", |attribute| {
+            attribute.expect("ImportAttribute")?;
+            let key = attribute.child("key")?;
+            let key = match key.ty {
+                "Identifier" => self
+                    .ast
+                    .import_attribute_key_identifier(key.span(), self.atom(key.str("name")?)),
+                "Literal" => {
+                    let literal = self.string_literal(key)?;
+                    self.ast.import_attribute_key_string_literal(
+                        key.span(),
+                        literal.value,
+                        literal.raw,
+                    )
+                }
+                _ => return Err(key.unknown("ImportAttributeKey")),
+            };
+            let value = self.string_literal(attribute.child("value")?)?;
+            Ok(self.ast.import_attribute(attribute.span(), key, value))
+        })?;
+        if attributes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.ast.with_clause(SPAN, WithClauseKeyword::With, attributes)))
+    }
+}
+
+pub(super) fn import_or_export_kind(
+    node: Node<'_>,
+    field: &'static str,
+) -> Result<ImportOrExportKind> {
+    Ok(node
+        .opt_keyword(field, "`value` or `type`", IMPORT_OR_EXPORT_KINDS)?
+        .unwrap_or(ImportOrExportKind::Value))
+}
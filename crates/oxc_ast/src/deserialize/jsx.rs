@@ -0,0 +1,188 @@
+use oxc_allocator::Vec;
+
+use crate::ast::*;
+
+use super::{Deserializer, Node, Result};
+
+impl<'a> Deserializer<'a> {
+    pub(super) fn jsx_element(self, node: Node<'_>) -> Result<JSXElement<'a>> {
+        node.expect("JSXElement")?;
+        let opening = node.child("openingElement")?;
+        opening.expect("JSXOpeningElement")?;
+        let opening_element = self.ast.jsx_opening_element(
+            opening.span(),
+            self.jsx_element_name(opening.child("name")?)?,
+            self.opt_ts_type_parameter_instantiation(opening, "typeArguments")?,
+            self.list(opening, "attributes", |attribute| self.jsx_attribute_item(attribute))?,
+        );
+        let closing_element = node
+            .opt_child("closingElement")?
+            .map(|closing| {
+                closing.expect("JSXClosingElement")?;
+                let name = self.jsx_element_name(closing.child("name")?)?;
+                Ok(self.ast.jsx_closing_element(closing.span(), name))
+            })
+            .transpose()?;
+        Ok(self.ast.jsx_element(
+            node.span(),
+            opening_element,
+            self.jsx_children(node)?,
+            closing_element,
+        ))
+    }
+
+    pub(super) fn jsx_fragment(self, node: Node<'_>) -> Result<JSXFragment<'a>> {
+        node.expect("JSXFragment")?;
+        let opening = node.child("openingFragment")?;
+        opening.expect("JSXOpeningFragment")?;
+        let closing = node.child("closingFragment")?;
+        closing.expect("JSXClosingFragment")?;
+        Ok(self.ast.jsx_fragment(
+            node.span(),
+            self.ast.jsx_opening_fragment(opening.span()),
+            self.jsx_children(node)?,
+            self.ast.jsx_closing_fragment(closing.span()),
+        ))
+    }
+
+    fn jsx_children(self, node: Node<'_>) -> Result<Vec<'a, JSXChild<'a>>> {
+        self.list(node, "children", |child| {
+            let span = child.span();
+            let child = match child.ty {
+                "JSXText" => {
+                    let raw = child.opt_str("raw")?.map(|raw| self.atom(raw));
+                    self.ast.jsx_child_text(span, self.atom(child.str("value")?), raw)
+                }
+                "JSXElement" => JSXChild::Element(self.ast.alloc(self.jsx_element(child)?)),
+                "JSXFragment" => JSXChild::Fragment(self.ast.alloc(self.jsx_fragment(child)?)),
+                "JSXExpressionContainer" => self.ast.jsx_child_expression_container(
+                    span,
+                    self.jsx_expression(child.child("expression")?)?,
+                ),
+                "JSXSpreadChild" => {
+                    self.ast.jsx_child_spread(span, self.child_expression(child, "expression")?)
+                }
+                _ => return Err(child.unknown("JSXChild")),
+            };
+            Ok(child)
+        })
+    }
+
+    fn jsx_expression(self, node: Node<'_>) -> Result<JSXExpression<'a>> {
+        if node.ty == "JSXEmptyExpression" {
+            return Ok(self.ast.jsx_expression_empty_expression(node.span()));
+        }
+        Ok(JSXExpression::from(self.expression(node)?))
+    }
+
+    fn jsx_element_name(self, node: Node<'_>) -> Result<JSXElementName<'a>> {
+        let span = node.span();
+        let name = match node.ty {
+            "JSXIdentifier" => {
+                let name = node.str("name")?;
+                // Same classification as the parser: `<div>` and `<foo-bar>` are intrinsic elements,
+                // `<Foo>` is a reference to a binding
+                if name == "this" {
+                    self.ast.jsx_element_name_this_expression(span)
+                } else if name.starts_with(|c: char| c.is_ascii_lowercase()) || name.contains('-') {
+                    self.ast.jsx_element_name_identifier(span, self.atom(name))
+                } else {
+                    self.ast.jsx_element_name_identifier_reference(span, self.atom(name))
+                }
+            }
+            "JSXNamespacedName" => {
+                let name = self.jsx_namespaced_name(node)?;
+                JSXElementName::NamespacedName(self.ast.alloc(name))
+            }
+            "JSXMemberExpression" => {
+                let expression = self.jsx_member_expression(node)?;
+                JSXElementName::MemberExpression(self.ast.alloc(expression))
+            }
+            _ => return Err(node.unknown("JSXElementName")),
+        };
+        Ok(name)
+    }
+
+    fn jsx_member_expression(self, node: Node<'_>) -> Result<JSXMemberExpression<'a>> {
+        node.expect("JSXMemberExpression")?;
+        let object = node.child("object")?;
+        let object_span = object.span();
+        let object = match object.ty {
+            "JSXIdentifier" => match object.str("name")? {
+                "this" => self.ast.jsx_member_expression_object_this_expression(object_span),
+                name => self.ast.jsx_member_expression_object_identifier_reference(
+                    object_span,
+                    self.atom(name),
+                ),
+            },
+            "JSXMemberExpression" => {
+                let expression = self.jsx_member_expression(object)?;
+                JSXMemberExpressionObject::MemberExpression(self.ast.alloc(expression))
+            }
+            _ => return Err(object.unknown("JSXMemberExpressionObject")),
+        };
+        Ok(self.ast.jsx_member_expression(
+            node.span(),
+            object,
+            self.jsx_identifier(node.child("property")?)?,
+        ))
+    }
+
+    fn jsx_namespaced_name(self, node: Node<'_>) -> Result<JSXNamespacedName<'a>> {
+        node.expect("JSXNamespacedName")?;
+        Ok(self.ast.jsx_namespaced_name(
+            node.span(),
+            self.jsx_identifier(node.child("namespace")?)?,
+            self.jsx_identifier(node.child("name")?)?,
+        ))
+    }
+
+    fn jsx_identifier(self, node: Node<'_>) -> Result<JSXIdentifier<'a>> {
+        node.expect("JSXIdentifier")?;
+        Ok(self.ast.jsx_identifier(node.span(), self.atom(node.str("name")?)))
+    }
+
+    fn jsx_attribute_item(self, node: Node<'_>) -> Result<JSXAttributeItem<'a>> {
+        let span = node.span();
+        match node.ty {
+            "JSXAttribute" => {
+                let name = node.child("name")?;
+                let name = match name.ty {
+                    "JSXIdentifier" => {
+                        JSXAttributeName::Identifier(self.ast.alloc(self.jsx_identifier(name)?))
+                    }
+                    "JSXNamespacedName" => JSXAttributeName::NamespacedName(
+                        self.ast.alloc(self.jsx_namespaced_name(name)?),
+                    ),
+                    _ => return Err(name.unknown("JSXAttributeName")),
+                };
+                let value = node
+                    .opt_child("value")?
+                    .map(|value| self.jsx_attribute_value(value))
+                    .transpose()?;
+                Ok(self.ast.jsx_attribute_item_attribute(span, name, value))
+            }
+            "JSXSpreadAttribute" => Ok(self.ast.jsx_attribute_item_spread_attribute(
+                span,
+                self.child_expression(node, "argument")?,
+            )),
+            _ => Err(node.unknown("JSXAttributeItem")),
+        }
+    }
+
+    fn jsx_attribute_value(self, node: Node<'_>) -> Result<JSXAttributeValue<'a>> {
+        let value = match node.ty {
+            "Literal" => {
+                JSXAttributeValue::StringLiteral(self.ast.alloc(self.string_literal(node)?))
+            }
+            "JSXExpressionContainer" => self.ast.jsx_attribute_value_expression_container(
+                node.span(),
+                self.jsx_expression(node.child("expression")?)?,
+            ),
+            "JSXElement" => JSXAttributeValue::Element(self.ast.alloc(self.jsx_element(node)?)),
+            "JSXFragment" => JSXAttributeValue::Fragment(self.ast.alloc(self.jsx_fragment(node)?)),
+            _ => return Err(node.unknown("JSXAttributeValue")),
+        };
+        Ok(value)
+    }
+}
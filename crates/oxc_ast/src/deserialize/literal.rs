@@ -0,0 +1,113 @@
+use serde_json::Value;
+
+use crate::ast::*;
+
+use super::{Deserializer, Node, Result};
+
+impl<'a> Deserializer<'a> {
+    /// Deserialize a `Literal` node.
+    ///
+    /// ESTree uses the same node type for all literals, distinguished by `regex`, `bigint`
+    /// and the JSON type of `value`.
+    pub(super) fn literal(self, node: Node<'_>) -> Result<Expression<'a>> {
+        node.expect("Literal")?;
+        let span = node.span();
+        let raw_str = node.opt_str("raw")?;
+        let raw = raw_str.map(|raw| self.atom(raw));
+        if let Some(regex) = node.get("regex") {
+            return Ok(self.ast.expression_reg_exp_literal(span, self.regexp(node, regex)?, raw));
+        }
+        if let Some(bigint) = node.opt_str("bigint")? {
+            return Ok(self.ast.expression_big_int_literal(
+                span,
+                self.atom(bigint),
+                raw,
+                bigint_base(raw_str),
+            ));
+        }
+        let literal = match node.object.get("value") {
+            None => return Err(node.missing("value")),
+            Some(Value::Null) => self.ast.expression_null_literal(span),
+            Some(Value::Bool(value)) => self.ast.expression_boolean_literal(span, *value),
+            Some(Value::Number(value)) => self.ast.expression_numeric_literal(
+                span,
+                value.as_f64().ok_or_else(|| node.invalid("value", "a finite number"))?,
+                raw,
+                number_base(raw_str),
+            ),
+            Some(Value::String(value)) => {
+                self.ast.expression_string_literal(span, self.atom(value), raw)
+            }
+            Some(_) => return Err(node.invalid("value", "a literal value")),
+        };
+        Ok(literal)
+    }
+
+    fn regexp(self, node: Node<'_>, regex: &Value) -> Result<RegExp<'a>> {
+        let expected = "an object with `pattern` and `flags` strings";
+        let pattern = regex.get("pattern").and_then(Value::as_str);
+        let flags = regex.get("flags").and_then(Value::as_str);
+        let (Some(pattern), Some(flags)) = (pattern, flags) else {
+            return Err(node.invalid("regex", expected));
+        };
+        let flags = flags.chars().try_fold(RegExpFlags::empty(), |flags, flag| {
+            RegExpFlags::try_from(flag).map(|flag| flags | flag)
+        });
+        let flags = flags.map_err(|_| node.invalid("regex", "valid regular expression flags"))?;
+        Ok(RegExp { pattern: RegExpPattern { text: self.atom(pattern), pattern: None }, flags })
+    }
+
+    /// Deserialize a `Literal` node whose value must be a string.
+    pub(super) fn string_literal(self, node: Node<'_>) -> Result<StringLiteral<'a>> {
+        node.expect("Literal")?;
+        let value = node.object.get("value").and_then(Value::as_str);
+        let value = value.ok_or_else(|| node.invalid("value", "a string"))?;
+        let raw = node.opt_str("raw")?.map(|raw| self.atom(raw));
+        Ok(self.ast.string_literal(node.span(), self.atom(value), raw))
+    }
+
+    pub(super) fn template_literal(self, node: Node<'_>) -> Result<TemplateLiteral<'a>> {
+        node.expect("TemplateLiteral")?;
+        Ok(self.ast.template_literal(
+            node.span(),
+            self.list(node, "quasis", |quasi| self.template_element(quasi))?,
+            self.list(node, "expressions", |expression| self.expression(expression))?,
+        ))
+    }
+
+    pub(super) fn template_element(self, node: Node<'_>) -> Result<TemplateElement<'a>> {
+        node.expect("TemplateElement")?;
+        let expected = "an object with `raw` and `cooked` strings";
+        let value = node.get("value").ok_or_else(|| node.missing("value"))?;
+        let raw = value.get("raw").and_then(Value::as_str);
+        let raw = raw.ok_or_else(|| node.invalid("value", expected))?;
+        // `cooked` is `null` when the template contains an invalid escape sequence
+        let cooked = match value.get("cooked") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(cooked)) => Some(self.atom(cooked)),
+            Some(_) => return Err(node.invalid("value", expected)),
+        };
+        let value = TemplateElementValue { raw: self.atom(raw), cooked };
+        Ok(self.ast.template_element(node.span(), value, node.bool("tail")?))
+    }
+}
+
+pub(super) fn number_base(raw: Option<&str>) -> NumberBase {
+    let Some(raw) = raw else { return NumberBase::Decimal };
+    match raw.as_bytes() {
+        [b'0', b'x' | b'X', ..] => NumberBase::Hex,
+        [b'0', b'o' | b'O', ..] => NumberBase::Octal,
+        [b'0', b'b' | b'B', ..] => NumberBase::Binary,
+        bytes if bytes.iter().any(|b| matches!(b, b'.' | b'e' | b'E')) => NumberBase::Float,
+        _ => NumberBase::Decimal,
+    }
+}
+
+pub(super) fn bigint_base(raw: Option<&str>) -> BigintBase {
+    match raw.map(str::as_bytes) {
+        Some([b'0', b'x' | b'X', ..]) => BigintBase::Hex,
+        Some([b'0', b'o' | b'O', ..]) => BigintBase::Octal,
+        Some([b'0', b'b' | b'B', ..]) => BigintBase::Binary,
+        _ => BigintBase::Decimal,
+    }
+}
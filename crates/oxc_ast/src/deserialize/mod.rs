@@ -0,0 +1,352 @@
+//! Deserialization of ESTree / TS-ESTree JSON into an Oxc AST.
+//!
+//! This is the inverse of the `serialize` feature: it accepts the JSON that `Program::to_estree_ts_json`
+//! and `Program::to_estree_js_json` produce, as well as ASTs produced or modified by JS tools
+//! (codemods, ESLint fixers, etc.), and builds arena-allocated nodes via [`AstBuilder`].
+//!
+//! TS-only fields (`typeAnnotation`, `optional`, `declare`, ...) are optional, so plain ESTree JSON
+//! is accepted too. Spans are read from `start` / `end`, falling back to `range`, and are otherwise
+//! left empty. Fields which ESTree does not carry (e.g. comments, `FormalParameters`'s span)
+//! are filled with defaults.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_span::{SPAN, SourceType, Span};
+
+use crate::{AstBuilder, ast::*};
+
+mod js;
+mod jsx;
+mod literal;
+mod ts;
+
+type Result<T> = std::result::Result<T, DeserializeError>;
+
+/// Error produced when ESTree JSON cannot be converted into an AST.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializeError {
+    /// The input is not valid JSON.
+    InvalidJson(String),
+    /// A node's `type` is not valid at this position in the AST.
+    UnknownNodeType {
+        /// The `type` of the node that was found.
+        node_type: String,
+        /// The kind of node that was expected, e.g. `Expression`.
+        expected: &'static str,
+        /// Span of the node.
+        span: Span,
+    },
+    /// A required field is absent or `null`.
+    MissingField {
+        /// The `type` of the node which is missing the field.
+        node_type: String,
+        /// The name of the missing field.
+        field: &'static str,
+        /// Span of the node.
+        span: Span,
+    },
+    /// A field is present but holds an unexpected value.
+    InvalidField {
+        /// The `type` of the node which holds the field.
+        node_type: String,
+        /// The name of the field.
+        field: &'static str,
+        /// Description of what the field should contain.
+        expected: &'static str,
+        /// Span of the node.
+        span: Span,
+    },
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidJson(message) => write!(f, "Invalid JSON: {message}"),
+            Self::UnknownNodeType { node_type, expected, span } => write!(
+                f,
+                "Unknown node type `{node_type}` at {}..{}: expected {expected}",
+                span.start, span.end
+            ),
+            Self::MissingField { node_type, field, span } => write!(
+                f,
+                "`{node_type}` node at {}..{} is missing required field `{field}`",
+                span.start, span.end
+            ),
+            Self::InvalidField { node_type, field, expected, span } => write!(
+                f,
+                "Field `{field}` of `{node_type}` node at {}..{} is invalid: expected {expected}",
+                span.start, span.end
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+/// Converts ESTree / TS-ESTree JSON into an Oxc AST.
+///
+/// ```
+/// use oxc_allocator::Allocator;
+/// use oxc_ast::deserialize::Deserializer;
+/// use oxc_span::SourceType;
+///
+/// let allocator = Allocator::default();
+/// let json = r#"{"type":"Program","sourceType":"module","body":[
+///     {"type":"ExpressionStatement","expression":{"type":"Identifier","name":"foo"}}
+/// ]}"#;
+/// let program = Deserializer::new(&allocator)
+///     .deserialize_program(json, "", SourceType::mjs())
+///     .unwrap();
+/// assert_eq!(program.body.len(), 1);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Deserializer<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Create a [`Deserializer`] which allocates nodes in `allocator`.
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    /// Deserialize a `Program` node.
+    ///
+    /// `source_text` is the text which the node spans refer to, or an empty string if there is none.
+    /// The module kind of `source_type` is overridden by the `sourceType` field, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeError`] if `json` is not valid JSON, or does not describe a valid `Program`.
+    pub fn deserialize_program(
+        self,
+        json: &str,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Program<'a>> {
+        let value = parse_json(json)?;
+        let node = Node::root(&value, "Program")?;
+        self.program(node, source_text, source_type)
+    }
+
+    /// Deserialize a single statement or declaration node.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeError`] if `json` is not valid JSON, or does not describe a valid `Statement`.
+    pub fn deserialize_statement(self, json: &str) -> Result<Statement<'a>> {
+        let value = parse_json(json)?;
+        self.statement(Node::root(&value, "Statement")?)
+    }
+
+    /// Deserialize a single expression node.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DeserializeError`] if `json` is not valid JSON, or does not describe a valid `Expression`.
+    pub fn deserialize_expression(self, json: &str) -> Result<Expression<'a>> {
+        let value = parse_json(json)?;
+        self.expression(Node::root(&value, "Expression")?)
+    }
+
+    /// Convert every element of array field `field` of `node` with `f`.
+    fn list<T>(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+        mut f: impl FnMut(Node<'_>) -> Result<T>,
+    ) -> Result<Vec<'a, T>> {
+        let elements = node.array(field)?;
+        let mut list = self.ast.vec_with_capacity(elements.len());
+        for element in elements {
+            list.push(f(node.element(field, element)?)?);
+        }
+        Ok(list)
+    }
+
+    /// As [`Deserializer::list`], but an absent field is treated as an empty array.
+    ///
+    /// Used for fields which only exist in TS-ESTree, such as `decorators`.
+    fn list_or_empty<T>(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+        f: impl FnMut(Node<'_>) -> Result<T>,
+    ) -> Result<Vec<'a, T>> {
+        if node.get(field).is_none() {
+            return Ok(self.ast.vec());
+        }
+        self.list(node, field, f)
+    }
+
+    fn atom(self, value: &str) -> Atom<'a> {
+        self.ast.atom(value)
+    }
+}
+
+fn parse_json(json: &str) -> Result<Value> {
+    serde_json::from_str(json).map_err(|err| DeserializeError::InvalidJson(err.to_string()))
+}
+
+/// A JSON object with a `type` field, representing an ESTree node.
+#[derive(Clone, Copy)]
+struct Node<'v> {
+    object: &'v Map<String, Value>,
+    ty: &'v str,
+}
+
+impl<'v> Node<'v> {
+    fn new(value: &'v Value) -> Option<Self> {
+        let object = value.as_object()?;
+        let ty = object.get("type")?.as_str()?;
+        Some(Self { object, ty })
+    }
+
+    /// Get the node at the top level of the input.
+    fn root(value: &'v Value, expected: &'static str) -> Result<Self> {
+        Self::new(value).ok_or_else(|| DeserializeError::MissingField {
+            node_type: expected.to_string(),
+            field: "type",
+            span: SPAN,
+        })
+    }
+
+    fn span(self) -> Span {
+        let start = self.object.get("start").and_then(Value::as_u64);
+        let end = self.object.get("end").and_then(Value::as_u64);
+        if let (Some(start), Some(end)) = (start, end) {
+            return span_from(start, end);
+        }
+        if let Some([start, end]) =
+            self.object.get("range").and_then(Value::as_array).map(|r| &r[..])
+            && let (Some(start), Some(end)) = (start.as_u64(), end.as_u64())
+        {
+            return span_from(start, end);
+        }
+        SPAN
+    }
+
+    /// Get a field's value. `null` is treated the same as an absent field.
+    fn get(self, field: &str) -> Option<&'v Value> {
+        self.object.get(field).filter(|value| !value.is_null())
+    }
+
+    fn child(self, field: &'static str) -> Result<Node<'v>> {
+        self.opt_child(field)?.ok_or_else(|| self.missing(field))
+    }
+
+    fn opt_child(self, field: &'static str) -> Result<Option<Node<'v>>> {
+        self.get(field).map(|value| self.element(field, value)).transpose()
+    }
+
+    /// Interpret `value`, found in field `field` of this node, as a node.
+    fn element(self, field: &'static str, value: &'v Value) -> Result<Node<'v>> {
+        Self::new(value).ok_or_else(|| self.invalid(field, "a node"))
+    }
+
+    fn array(self, field: &'static str) -> Result<&'v [Value]> {
+        let value = self.get(field).ok_or_else(|| self.missing(field))?;
+        value.as_array().map(std::vec::Vec::as_slice).ok_or_else(|| self.invalid(field, "an array"))
+    }
+
+    fn str(self, field: &'static str) -> Result<&'v str> {
+        self.opt_str(field)?.ok_or_else(|| self.missing(field))
+    }
+
+    fn opt_str(self, field: &'static str) -> Result<Option<&'v str>> {
+        self.get(field)
+            .map(|value| value.as_str().ok_or_else(|| self.invalid(field, "a string")))
+            .transpose()
+    }
+
+    /// Get a boolean field. An absent field is `false`, as TS-only flags are omitted from ESTree.
+    fn bool(self, field: &'static str) -> Result<bool> {
+        self.get(field).map_or(Ok(false), |value| {
+            value.as_bool().ok_or_else(|| self.invalid(field, "a boolean"))
+        })
+    }
+
+    /// Iterate over the nodes in array field `field`.
+    fn nodes(self, field: &'static str) -> Result<impl Iterator<Item = Result<Node<'v>>>> {
+        Ok(self.array(field)?.iter().map(move |value| self.element(field, value)))
+    }
+
+    /// As [`Node::nodes`], but `null` elements (array holes) are yielded as `None`.
+    fn opt_nodes(
+        self,
+        field: &'static str,
+    ) -> Result<impl Iterator<Item = Result<Option<Node<'v>>>>> {
+        Ok(self.array(field)?.iter().map(move |value| {
+            if value.is_null() { Ok(None) } else { self.element(field, value).map(Some) }
+        }))
+    }
+
+    /// Get a string field which must be one of `variants`.
+    fn keyword<T: Copy>(
+        self,
+        field: &'static str,
+        expected: &'static str,
+        variants: &[(&str, T)],
+    ) -> Result<T> {
+        self.opt_keyword(field, expected, variants)?.ok_or_else(|| self.missing(field))
+    }
+
+    fn opt_keyword<T: Copy>(
+        self,
+        field: &'static str,
+        expected: &'static str,
+        variants: &[(&str, T)],
+    ) -> Result<Option<T>> {
+        let Some(value) = self.get(field) else { return Ok(None) };
+        let value = value.as_str().ok_or_else(|| self.invalid(field, expected))?;
+        variants
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|&(_, variant)| Some(variant))
+            .ok_or_else(|| self.invalid(field, expected))
+    }
+
+    /// Get the `operator` field, which must match `as_str` of one of `operators`.
+    fn operator<T: Copy>(self, operators: &[T], as_str: fn(T) -> &'static str) -> Result<T> {
+        let value = self.str("operator")?;
+        operators
+            .iter()
+            .copied()
+            .find(|&operator| as_str(operator) == value)
+            .ok_or_else(|| self.invalid("operator", "a valid operator"))
+    }
+
+    /// Check this node has type `ty`.
+    fn expect(self, ty: &'static str) -> Result<()> {
+        if self.ty == ty { Ok(()) } else { Err(self.unknown(ty)) }
+    }
+
+    fn unknown(self, expected: &'static str) -> DeserializeError {
+        DeserializeError::UnknownNodeType {
+            node_type: self.ty.to_string(),
+            expected,
+            span: self.span(),
+        }
+    }
+
+    fn missing(self, field: &'static str) -> DeserializeError {
+        DeserializeError::MissingField { node_type: self.ty.to_string(), field, span: self.span() }
+    }
+
+    fn invalid(self, field: &'static str, expected: &'static str) -> DeserializeError {
+        DeserializeError::InvalidField {
+            node_type: self.ty.to_string(),
+            field,
+            expected,
+            span: self.span(),
+        }
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn span_from(start: u64, end: u64) -> Span {
+    Span::new(start as u32, end as u32)
+}
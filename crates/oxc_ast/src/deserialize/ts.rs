@@ -0,0 +1,680 @@
+use serde_json::Value;
+
+use oxc_allocator::Vec;
+use oxc_span::Span;
+
+use crate::ast::*;
+
+use super::{Deserializer, Node, Result, js::import_or_export_kind};
+
+const TS_ACCESSIBILITIES: &[(&str, TSAccessibility)] = &[
+    ("private", TSAccessibility::Private),
+    ("protected", TSAccessibility::Protected),
+    ("public", TSAccessibility::Public),
+];
+
+const TS_TYPE_OPERATORS: &[(&str, TSTypeOperatorOperator)] = &[
+    ("keyof", TSTypeOperatorOperator::Keyof),
+    ("unique", TSTypeOperatorOperator::Unique),
+    ("readonly", TSTypeOperatorOperator::Readonly),
+];
+
+const TS_METHOD_SIGNATURE_KINDS: &[(&str, TSMethodSignatureKind)] = &[
+    ("method", TSMethodSignatureKind::Method),
+    ("get", TSMethodSignatureKind::Get),
+    ("set", TSMethodSignatureKind::Set),
+];
+
+const TS_MODULE_DECLARATION_KINDS: &[(&str, TSModuleDeclarationKind)] = &[
+    ("global", TSModuleDeclarationKind::Global),
+    ("module", TSModuleDeclarationKind::Module),
+    ("namespace", TSModuleDeclarationKind::Namespace),
+];
+
+impl<'a> Deserializer<'a> {
+    // ----------------------------------------
+    // Types
+    // ----------------------------------------
+
+    pub(super) fn ts_type(self, node: Node<'_>) -> Result<TSType<'a>> {
+        let span = node.span();
+        let ts_type = match node.ty {
+            "TSAnyKeyword" => self.ast.ts_type_any_keyword(span),
+            "TSBigIntKeyword" => self.ast.ts_type_big_int_keyword(span),
+            "TSBooleanKeyword" => self.ast.ts_type_boolean_keyword(span),
+            "TSIntrinsicKeyword" => self.ast.ts_type_intrinsic_keyword(span),
+            "TSNeverKeyword" => self.ast.ts_type_never_keyword(span),
+            "TSNullKeyword" => self.ast.ts_type_null_keyword(span),
+            "TSNumberKeyword" => self.ast.ts_type_number_keyword(span),
+            "TSObjectKeyword" => self.ast.ts_type_object_keyword(span),
+            "TSStringKeyword" => self.ast.ts_type_string_keyword(span),
+            "TSSymbolKeyword" => self.ast.ts_type_symbol_keyword(span),
+            "TSUndefinedKeyword" => self.ast.ts_type_undefined_keyword(span),
+            "TSUnknownKeyword" => self.ast.ts_type_unknown_keyword(span),
+            "TSVoidKeyword" => self.ast.ts_type_void_keyword(span),
+            "TSThisType" => self.ast.ts_type_this_type(span),
+            "TSArrayType" => {
+                self.ast.ts_type_array_type(span, self.child_ts_type(node, "elementType")?)
+            }
+            "TSConditionalType" => self.ast.ts_type_conditional_type(
+                span,
+                self.child_ts_type(node, "checkType")?,
+                self.child_ts_type(node, "extendsType")?,
+                self.child_ts_type(node, "trueType")?,
+                self.child_ts_type(node, "falseType")?,
+            ),
+            "TSConstructorType" => {
+                let type_parameters =
+                    self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+                let (_, params) = self.formal_parameters(node, FormalParameterKind::Signature)?;
+                self.ast.ts_type_constructor_type(
+                    span,
+                    node.bool("abstract")?,
+                    type_parameters,
+                    params,
+                    self.ts_type_annotation(node.child("returnType")?)?,
+                )
+            }
+            "TSFunctionType" => {
+                let type_parameters =
+                    self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+                let (this_param, params) =
+                    self.formal_parameters(node, FormalParameterKind::Signature)?;
+                self.ast.ts_type_function_type(
+                    span,
+                    type_parameters,
+                    this_param,
+                    params,
+                    self.ts_type_annotation(node.child("returnType")?)?,
+                )
+            }
+            "TSImportType" => TSType::TSImportType(self.ast.alloc(self.ts_import_type(node)?)),
+            "TSIndexedAccessType" => self.ast.ts_type_indexed_access_type(
+                span,
+                self.child_ts_type(node, "objectType")?,
+                self.child_ts_type(node, "indexType")?,
+            ),
+            "TSInferType" => self
+                .ast
+                .ts_type_infer_type(span, self.ts_type_parameter(node.child("typeParameter")?)?),
+            "TSIntersectionType" => {
+                self.ast.ts_type_intersection_type(span, self.ts_types(node, "types")?)
+            }
+            "TSUnionType" => self.ast.ts_type_union_type(span, self.ts_types(node, "types")?),
+            "TSLiteralType" => {
+                self.ast.ts_type_literal_type(span, self.ts_literal(node.child("literal")?)?)
+            }
+            "TSMappedType" => self.ts_mapped_type(node)?,
+            "TSNamedTupleMember" => self.ast.ts_type_named_tuple_member(
+                span,
+                self.identifier_name(node.child("label")?)?,
+                self.ts_tuple_element(node.child("elementType")?)?,
+                node.bool("optional")?,
+            ),
+            "TSTemplateLiteralType" => self.ast.ts_type_template_literal_type(
+                span,
+                self.list(node, "quasis", |quasi| self.template_element(quasi))?,
+                self.ts_types(node, "types")?,
+            ),
+            "TSTupleType" => self.ast.ts_type_tuple_type(
+                span,
+                self.list(node, "elementTypes", |element| self.ts_tuple_element(element))?,
+            ),
+            "TSTypeLiteral" => self.ast.ts_type_type_literal(
+                span,
+                self.list(node, "members", |member| self.ts_signature(member))?,
+            ),
+            "TSTypeOperator" => self.ast.ts_type_type_operator_type(
+                span,
+                node.keyword("operator", "a type operator", TS_TYPE_OPERATORS)?,
+                self.child_ts_type(node, "typeAnnotation")?,
+            ),
+            "TSTypePredicate" => {
+                let name = node.child("parameterName")?;
+                let parameter_name = match name.ty {
+                    "Identifier" => self.ast.ts_type_predicate_name_identifier(
+                        name.span(),
+                        self.atom(name.str("name")?),
+                    ),
+                    "TSThisType" => self.ast.ts_type_predicate_name_this(name.span()),
+                    _ => return Err(name.unknown("TSTypePredicateName")),
+                };
+                self.ast.ts_type_type_predicate(
+                    span,
+                    parameter_name,
+                    node.bool("asserts")?,
+                    self.opt_ts_type_annotation(node, "typeAnnotation")?,
+                )
+            }
+            "TSTypeQuery" => {
+                let expr_name = node.child("exprName")?;
+                let expr_name = if expr_name.ty == "TSImportType" {
+                    TSTypeQueryExprName::TSImportType(
+                        self.ast.alloc(self.ts_import_type(expr_name)?),
+                    )
+                } else {
+                    TSTypeQueryExprName::from(self.ts_type_name(expr_name)?)
+                };
+                self.ast.ts_type_type_query(
+                    span,
+                    expr_name,
+                    self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+                )
+            }
+            "TSTypeReference" => self.ast.ts_type_type_reference(
+                span,
+                self.ts_type_name(node.child("typeName")?)?,
+                self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+            ),
+            "TSParenthesizedType" => self
+                .ast
+                .ts_type_parenthesized_type(span, self.child_ts_type(node, "typeAnnotation")?),
+            "TSJSDocNullableType" => self.ast.ts_type_js_doc_nullable_type(
+                span,
+                self.child_ts_type(node, "typeAnnotation")?,
+                node.bool("postfix")?,
+            ),
+            "TSJSDocNonNullableType" => self.ast.ts_type_js_doc_non_nullable_type(
+                span,
+                self.child_ts_type(node, "typeAnnotation")?,
+                node.bool("postfix")?,
+            ),
+            "TSJSDocUnknownType" => self.ast.ts_type_js_doc_unknown_type(span),
+            _ => return Err(node.unknown("TSType")),
+        };
+        Ok(ts_type)
+    }
+
+    fn child_ts_type(self, node: Node<'_>, field: &'static str) -> Result<TSType<'a>> {
+        self.ts_type(node.child(field)?)
+    }
+
+    fn opt_ts_type(self, node: Node<'_>, field: &'static str) -> Result<Option<TSType<'a>>> {
+        node.opt_child(field)?.map(|ts_type| self.ts_type(ts_type)).transpose()
+    }
+
+    fn ts_types(self, node: Node<'_>, field: &'static str) -> Result<Vec<'a, TSType<'a>>> {
+        self.list(node, field, |ts_type| self.ts_type(ts_type))
+    }
+
+    fn ts_tuple_element(self, node: Node<'_>) -> Result<TSTupleElement<'a>> {
+        let span = node.span();
+        match node.ty {
+            "TSOptionalType" => Ok(self
+                .ast
+                .ts_tuple_element_optional_type(span, self.child_ts_type(node, "typeAnnotation")?)),
+            "TSRestType" => Ok(self
+                .ast
+                .ts_tuple_element_rest_type(span, self.child_ts_type(node, "typeAnnotation")?)),
+            _ => Ok(TSTupleElement::from(self.ts_type(node)?)),
+        }
+    }
+
+    fn ts_literal(self, node: Node<'_>) -> Result<TSLiteral<'a>> {
+        let literal = match node.ty {
+            "Literal" => match self.literal(node)? {
+                Expression::BooleanLiteral(literal) => TSLiteral::BooleanLiteral(literal),
+                Expression::NumericLiteral(literal) => TSLiteral::NumericLiteral(literal),
+                Expression::BigIntLiteral(literal) => TSLiteral::BigIntLiteral(literal),
+                Expression::StringLiteral(literal) => TSLiteral::StringLiteral(literal),
+                _ => return Err(node.invalid("value", "a boolean, number, bigint or string")),
+            },
+            "TemplateLiteral" => {
+                TSLiteral::TemplateLiteral(self.ast.alloc(self.template_literal(node)?))
+            }
+            // `-1`
+            "UnaryExpression" => match self.expression(node)? {
+                Expression::UnaryExpression(expression) => TSLiteral::UnaryExpression(expression),
+                _ => unreachable!(),
+            },
+            _ => return Err(node.unknown("TSLiteral")),
+        };
+        Ok(literal)
+    }
+
+    fn ts_mapped_type(self, node: Node<'_>) -> Result<TSType<'a>> {
+        // TS-ESTree flattens the type parameter into `key` and `constraint` fields
+        let key = node.child("key")?;
+        let constraint = self.child_ts_type(node, "constraint")?;
+        let constraint_span = node.child("constraint")?.span();
+        let type_parameter = self.ast.ts_type_parameter(
+            Span::new(key.span().start, constraint_span.end),
+            self.binding_identifier(key)?,
+            Some(constraint),
+            None,
+            false,
+            false,
+            false,
+        );
+        Ok(self.ast.ts_type_mapped_type(
+            node.span(),
+            type_parameter,
+            self.opt_ts_type(node, "nameType")?,
+            self.opt_ts_type(node, "typeAnnotation")?,
+            mapped_type_modifier(node, "optional")?,
+            mapped_type_modifier(node, "readonly")?,
+        ))
+    }
+
+    fn ts_import_type(self, node: Node<'_>) -> Result<TSImportType<'a>> {
+        node.expect("TSImportType")?;
+        let options = match self.opt_expression(node, "options")? {
+            None => None,
+            Some(Expression::ObjectExpression(options)) => Some(options),
+            Some(_) => return Err(node.invalid("options", "an object expression")),
+        };
+        let qualifier = node
+            .opt_child("qualifier")?
+            .map(|qualifier| self.ts_import_type_qualifier(qualifier))
+            .transpose()?;
+        Ok(self.ast.ts_import_type(
+            node.span(),
+            self.child_ts_type(node, "argument")?,
+            options,
+            qualifier,
+            self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+        ))
+    }
+
+    fn ts_import_type_qualifier(self, node: Node<'_>) -> Result<TSImportTypeQualifier<'a>> {
+        let span = node.span();
+        match node.ty {
+            "Identifier" => {
+                Ok(self.ast.ts_import_type_qualifier_identifier(span, self.atom(node.str("name")?)))
+            }
+            "TSQualifiedName" => Ok(self.ast.ts_import_type_qualifier_qualified_name(
+                span,
+                self.ts_import_type_qualifier(node.child("left")?)?,
+                self.identifier_name(node.child("right")?)?,
+            )),
+            _ => Err(node.unknown("TSImportTypeQualifier")),
+        }
+    }
+
+    /// Deserialize a type name.
+    ///
+    /// `MemberExpression`s are also accepted, as TS-ESTree uses them for `implements` clauses.
+    fn ts_type_name(self, node: Node<'_>) -> Result<TSTypeName<'a>> {
+        let span = node.span();
+        match node.ty {
+            "Identifier" => {
+                Ok(self.ast.ts_type_name_identifier_reference(span, self.atom(node.str("name")?)))
+            }
+            "ThisExpression" => Ok(self.ast.ts_type_name_this_expression(span)),
+            "TSQualifiedName" => Ok(self.ast.ts_type_name_qualified_name(
+                span,
+                self.ts_type_name(node.child("left")?)?,
+                self.identifier_name(node.child("right")?)?,
+            )),
+            "MemberExpression" if !node.bool("computed")? => {
+                Ok(self.ast.ts_type_name_qualified_name(
+                    span,
+                    self.ts_type_name(node.child("object")?)?,
+                    self.identifier_name(node.child("property")?)?,
+                ))
+            }
+            _ => Err(node.unknown("TSTypeName")),
+        }
+    }
+
+    // ----------------------------------------
+    // Signatures
+    // ----------------------------------------
+
+    fn ts_signature(self, node: Node<'_>) -> Result<TSSignature<'a>> {
+        let span = node.span();
+        let signature = match node.ty {
+            "TSPropertySignature" => self.ast.ts_signature_property_signature(
+                span,
+                node.bool("computed")?,
+                node.bool("optional")?,
+                node.bool("readonly")?,
+                self.property_key(node)?,
+                self.opt_ts_type_annotation(node, "typeAnnotation")?,
+            ),
+            "TSIndexSignature" => {
+                TSSignature::TSIndexSignature(self.ast.alloc(self.ts_index_signature(node)?))
+            }
+            "TSCallSignatureDeclaration" => {
+                let type_parameters =
+                    self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+                let (this_param, params) =
+                    self.formal_parameters(node, FormalParameterKind::Signature)?;
+                self.ast.ts_signature_call_signature_declaration(
+                    span,
+                    type_parameters,
+                    this_param,
+                    params,
+                    self.opt_ts_type_annotation(node, "returnType")?,
+                )
+            }
+            "TSConstructSignatureDeclaration" => {
+                let type_parameters =
+                    self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+                let (_, params) = self.formal_parameters(node, FormalParameterKind::Signature)?;
+                self.ast.ts_signature_construct_signature_declaration(
+                    span,
+                    type_parameters,
+                    params,
+                    self.opt_ts_type_annotation(node, "returnType")?,
+                )
+            }
+            "TSMethodSignature" => {
+                let key = self.property_key(node)?;
+                let type_parameters =
+                    self.opt_ts_type_parameter_declaration(node, "typeParameters")?;
+                let (this_param, params) =
+                    self.formal_parameters(node, FormalParameterKind::Signature)?;
+                self.ast.ts_signature_method_signature(
+                    span,
+                    key,
+                    node.bool("computed")?,
+                    node.bool("optional")?,
+                    node.keyword("kind", "a method signature kind", TS_METHOD_SIGNATURE_KINDS)?,
+                    type_parameters,
+                    this_param,
+                    params,
+                    self.opt_ts_type_annotation(node, "returnType")?,
+                )
+            }
+            _ => return Err(node.unknown("TSSignature")),
+        };
+        Ok(signature)
+    }
+
+    pub(super) fn ts_index_signature(self, node: Node<'_>) -> Result<TSIndexSignature<'a>> {
+        node.expect("TSIndexSignature")?;
+        let parameters = self.list(node, "parameters", |parameter| {
+            parameter.expect("Identifier")?;
+            Ok(self.ast.ts_index_signature_name(
+                parameter.span(),
+                self.atom(parameter.str("name")?),
+                self.ts_type_annotation(parameter.child("typeAnnotation")?)?,
+            ))
+        })?;
+        Ok(self.ast.ts_index_signature(
+            node.span(),
+            parameters,
+            self.ts_type_annotation(node.child("typeAnnotation")?)?,
+            node.bool("readonly")?,
+            node.bool("static")?,
+        ))
+    }
+
+    pub(super) fn ts_this_parameter(self, node: Node<'_>) -> Result<TSThisParameter<'a>> {
+        let span = node.span();
+        Ok(self.ast.ts_this_parameter(
+            span,
+            Span::sized(span.start, 4),
+            self.opt_ts_type_annotation(node, "typeAnnotation")?,
+        ))
+    }
+
+    pub(super) fn ts_class_implements(self, node: Node<'_>) -> Result<TSClassImplements<'a>> {
+        node.expect("TSClassImplements")?;
+        Ok(self.ast.ts_class_implements(
+            node.span(),
+            self.ts_type_name(node.child("expression")?)?,
+            self.opt_ts_type_parameter_instantiation(node, "typeArguments")?,
+        ))
+    }
+
+    // ----------------------------------------
+    // Type annotations and type parameters
+    // ----------------------------------------
+
+    fn ts_type_annotation(self, node: Node<'_>) -> Result<TSTypeAnnotation<'a>> {
+        node.expect("TSTypeAnnotation")?;
+        Ok(self.ast.ts_type_annotation(node.span(), self.child_ts_type(node, "typeAnnotation")?))
+    }
+
+    pub(super) fn opt_ts_type_annotation(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Option<TSTypeAnnotation<'a>>> {
+        node.opt_child(field)?.map(|annotation| self.ts_type_annotation(annotation)).transpose()
+    }
+
+    fn ts_type_parameter(self, node: Node<'_>) -> Result<TSTypeParameter<'a>> {
+        node.expect("TSTypeParameter")?;
+        Ok(self.ast.ts_type_parameter(
+            node.span(),
+            self.binding_identifier(node.child("name")?)?,
+            self.opt_ts_type(node, "constraint")?,
+            self.opt_ts_type(node, "default")?,
+            node.bool("in")?,
+            node.bool("out")?,
+            node.bool("const")?,
+        ))
+    }
+
+    pub(super) fn opt_ts_type_parameter_declaration(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Option<TSTypeParameterDeclaration<'a>>> {
+        node.opt_child(field)?
+            .map(|declaration| {
+                declaration.expect("TSTypeParameterDeclaration")?;
+                let params =
+                    self.list(declaration, "params", |param| self.ts_type_parameter(param))?;
+                Ok(self.ast.ts_type_parameter_declaration(declaration.span(), params))
+            })
+            .transpose()
+    }
+
+    pub(super) fn ts_type_parameter_instantiation(
+        self,
+        node: Node<'_>,
+    ) -> Result<TSTypeParameterInstantiation<'a>> {
+        node.expect("TSTypeParameterInstantiation")?;
+        Ok(self.ast.ts_type_parameter_instantiation(node.span(), self.ts_types(node, "params")?))
+    }
+
+    pub(super) fn opt_ts_type_parameter_instantiation(
+        self,
+        node: Node<'_>,
+        field: &'static str,
+    ) -> Result<Option<TSTypeParameterInstantiation<'a>>> {
+        node.opt_child(field)?
+            .map(|instantiation| self.ts_type_parameter_instantiation(instantiation))
+            .transpose()
+    }
+
+    // ----------------------------------------
+    // Declarations
+    // ----------------------------------------
+
+    pub(super) fn ts_type_alias_declaration(
+        self,
+        node: Node<'_>,
+    ) -> Result<TSTypeAliasDeclaration<'a>> {
+        node.expect("TSTypeAliasDeclaration")?;
+        Ok(self.ast.ts_type_alias_declaration(
+            node.span(),
+            self.binding_identifier(node.child("id")?)?,
+            self.opt_ts_type_parameter_declaration(node, "typeParameters")?,
+            self.child_ts_type(node, "typeAnnotation")?,
+            node.bool("declare")?,
+        ))
+    }
+
+    pub(super) fn ts_interface_declaration(
+        self,
+        node: Node<'_>,
+    ) -> Result<TSInterfaceDeclaration<'a>> {
+        node.expect("TSInterfaceDeclaration")?;
+        let extends = self.list_or_empty(node, "extends", |heritage| {
+            heritage.expect("TSInterfaceHeritage")?;
+            Ok(self.ast.ts_interface_heritage(
+                heritage.span(),
+                self.child_expression(heritage, "expression")?,
+                self.opt_ts_type_parameter_instantiation(heritage, "typeArguments")?,
+            ))
+        })?;
+        let body = node.child("body")?;
+        body.expect("TSInterfaceBody")?;
+        let body = self.ast.ts_interface_body(
+            body.span(),
+            self.list(body, "body", |signature| self.ts_signature(signature))?,
+        );
+        Ok(self.ast.ts_interface_declaration(
+            node.span(),
+            self.binding_identifier(node.child("id")?)?,
+            self.opt_ts_type_parameter_declaration(node, "typeParameters")?,
+            extends,
+            body,
+            node.bool("declare")?,
+        ))
+    }
+
+    pub(super) fn ts_enum_declaration(self, node: Node<'_>) -> Result<TSEnumDeclaration<'a>> {
+        node.expect("TSEnumDeclaration")?;
+        let body = node.child("body")?;
+        body.expect("TSEnumBody")?;
+        let members = self.list(body, "members", |member| self.ts_enum_member(member))?;
+        Ok(self.ast.ts_enum_declaration(
+            node.span(),
+            self.binding_identifier(node.child("id")?)?,
+            self.ast.ts_enum_body(body.span(), members),
+            node.bool("const")?,
+            node.bool("declare")?,
+        ))
+    }
+
+    fn ts_enum_member(self, node: Node<'_>) -> Result<TSEnumMember<'a>> {
+        node.expect("TSEnumMember")?;
+        let id = node.child("id")?;
+        let span = id.span();
+        let computed = node.bool("computed")?;
+        let name = match id.ty {
+            "Identifier" if !computed => {
+                self.ast.ts_enum_member_name_identifier(span, self.atom(id.str("name")?))
+            }
+            "Literal" => {
+                let literal = self.string_literal(id)?;
+                if computed {
+                    self.ast.ts_enum_member_name_computed_string(span, literal.value, literal.raw)
+                } else {
+                    self.ast.ts_enum_member_name_string(span, literal.value, literal.raw)
+                }
+            }
+            "TemplateLiteral" if computed => {
+                let template = self.template_literal(id)?;
+                self.ast.ts_enum_member_name_computed_template_string(
+                    span,
+                    template.quasis,
+                    template.expressions,
+                )
+            }
+            _ => return Err(id.unknown("TSEnumMemberName")),
+        };
+        Ok(self.ast.ts_enum_member(node.span(), name, self.opt_expression(node, "initializer")?))
+    }
+
+    pub(super) fn ts_module_declaration(self, node: Node<'_>) -> Result<TSModuleDeclaration<'a>> {
+        node.expect("TSModuleDeclaration")?;
+        let span = node.span();
+        let kind =
+            node.keyword("kind", "a module declaration kind", TS_MODULE_DECLARATION_KINDS)?;
+        let declare = node.bool("declare")?;
+        let mut body = node
+            .opt_child("body")?
+            .map(|block| {
+                block.expect("TSModuleBlock")?;
+                let (directives, statements) = self.directives_and_statements(block, "body")?;
+                Ok(self.ast.ts_module_declaration_body_module_block(
+                    block.span(),
+                    directives,
+                    statements,
+                ))
+            })
+            .transpose()?;
+
+        // TS-ESTree represents `module A.B.C {}` as a single node with a `TSQualifiedName` id.
+        // Oxc represents it as nested modules, so rebuild them from the innermost outwards.
+        let mut id = node.child("id")?;
+        while id.ty == "TSQualifiedName" {
+            let right = id.child("right")?;
+            let name = self.module_declaration_name(right)?;
+            body = Some(self.ast.ts_module_declaration_body_module_declaration(
+                Span::new(right.span().start, span.end),
+                name,
+                body,
+                kind,
+                declare,
+            ));
+            id = id.child("left")?;
+        }
+        Ok(self.ast.ts_module_declaration(
+            span,
+            self.module_declaration_name(id)?,
+            body,
+            kind,
+            declare,
+        ))
+    }
+
+    fn module_declaration_name(self, node: Node<'_>) -> Result<TSModuleDeclarationName<'a>> {
+        let span = node.span();
+        match node.ty {
+            "Identifier" => Ok(self
+                .ast
+                .ts_module_declaration_name_identifier(span, self.atom(node.str("name")?))),
+            "Literal" => {
+                let literal = self.string_literal(node)?;
+                Ok(self.ast.ts_module_declaration_name_string_literal(
+                    span,
+                    literal.value,
+                    literal.raw,
+                ))
+            }
+            _ => Err(node.unknown("TSModuleDeclarationName")),
+        }
+    }
+
+    pub(super) fn ts_import_equals_declaration(
+        self,
+        node: Node<'_>,
+    ) -> Result<TSImportEqualsDeclaration<'a>> {
+        node.expect("TSImportEqualsDeclaration")?;
+        let reference = node.child("moduleReference")?;
+        let module_reference = if reference.ty == "TSExternalModuleReference" {
+            self.ast.ts_module_reference_external_module_reference(
+                reference.span(),
+                self.string_literal(reference.child("expression")?)?,
+            )
+        } else {
+            TSModuleReference::from(self.ts_type_name(reference)?)
+        };
+        Ok(self.ast.ts_import_equals_declaration(
+            node.span(),
+            self.binding_identifier(node.child("id")?)?,
+            module_reference,
+            import_or_export_kind(node, "importKind")?,
+        ))
+    }
+}
+
+/// Get the `optional` / `readonly` modifier of a `TSMappedType`, which is `true`, `"+"` or `"-"`.
+fn mapped_type_modifier(
+    node: Node<'_>,
+    field: &'static str,
+) -> Result<Option<TSMappedTypeModifierOperator>> {
+    match node.get(field) {
+        None | Some(Value::Bool(false)) => Ok(None),
+        Some(Value::Bool(true)) => Ok(Some(TSMappedTypeModifierOperator::True)),
+        Some(Value::String(modifier)) if modifier == "+" => {
+            Ok(Some(TSMappedTypeModifierOperator::Plus))
+        }
+        Some(Value::String(modifier)) if modifier == "-" => {
+            Ok(Some(TSMappedTypeModifierOperator::Minus))
+        }
+        Some(_) => Err(node.invalid(field, "`true`, `false`, `\"+\"` or `\"-\"`")),
+    }
+}
+
+pub(super) fn ts_accessibility(node: Node<'_>) -> Result<Option<TSAccessibility>> {
+    node.opt_keyword("accessibility", "an accessibility modifier", TS_ACCESSIBILITIES)
+}
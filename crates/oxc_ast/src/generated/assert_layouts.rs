@@ -55,6 +55,11 @@ const _: () = {
     assert!(align_of::<ThisExpression>() == 8);
     assert!(offset_of!(ThisExpression, span) == 0);
 
+    // Padding: 0 bytes
+    assert!(size_of::<ErrorExpression>() == 8);
+    assert!(align_of::<ErrorExpression>() == 8);
+    assert!(offset_of!(ErrorExpression, span) == 0);
+
     // Padding: 0 bytes
     assert!(size_of::<ArrayExpression>() == 32);
     assert!(align_of::<ArrayExpression>() == 8);
@@ -380,6 +385,11 @@ const _: () = {
     assert!(align_of::<EmptyStatement>() == 8);
     assert!(offset_of!(EmptyStatement, span) == 0);
 
+    // Padding: 0 bytes
+    assert!(size_of::<ErrorStatement>() == 8);
+    assert!(align_of::<ErrorStatement>() == 8);
+    assert!(offset_of!(ErrorStatement, span) == 0);
+
     // Padding: 0 bytes
     assert!(size_of::<ExpressionStatement>() == 24);
     assert!(align_of::<ExpressionStatement>() == 8);
@@ -1659,6 +1669,11 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(align_of::<ThisExpression>() == 4);
     assert!(offset_of!(ThisExpression, span) == 0);
 
+    // Padding: 0 bytes
+    assert!(size_of::<ErrorExpression>() == 8);
+    assert!(align_of::<ErrorExpression>() == 4);
+    assert!(offset_of!(ErrorExpression, span) == 0);
+
     // Padding: 0 bytes
     assert!(size_of::<ArrayExpression>() == 24);
     assert!(align_of::<ArrayExpression>() == 4);
@@ -1984,6 +1999,11 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(align_of::<EmptyStatement>() == 4);
     assert!(offset_of!(EmptyStatement, span) == 0);
 
+    // Padding: 0 bytes
+    assert!(size_of::<ErrorStatement>() == 8);
+    assert!(align_of::<ErrorStatement>() == 4);
+    assert!(offset_of!(ErrorStatement, span) == 0);
+
     // Padding: 0 bytes
     assert!(size_of::<ExpressionStatement>() == 16);
     assert!(align_of::<ExpressionStatement>() == 4);
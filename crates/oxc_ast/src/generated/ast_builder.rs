@@ -1217,6 +1217,17 @@ impl<'a> AstBuilder<'a> {
         )
     }
 
+    /// Build an [`Expression::ErrorExpression`].
+    ///
+    /// This node contains an [`ErrorExpression`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn expression_error(self, span: Span) -> Expression<'a> {
+        Expression::ErrorExpression(self.alloc_error_expression(span))
+    }
+
     /// Build an [`IdentifierName`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -1449,6 +1460,30 @@ impl<'a> AstBuilder<'a> {
         Box::new_in(self.this_expression(span), self.allocator)
     }
 
+    /// Build an [`ErrorExpression`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_error_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn error_expression(self, span: Span) -> ErrorExpression {
+        ErrorExpression { span }
+    }
+
+    /// Build an [`ErrorExpression`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::error_expression`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn alloc_error_expression(self, span: Span) -> Box<'a, ErrorExpression> {
+        Box::new_in(self.error_expression(span), self.allocator)
+    }
+
     /// Build an [`ArrayExpression`].
     ///
     /// If you want the built node to be allocated in the memory arena,
@@ -3742,6 +3777,17 @@ impl<'a> AstBuilder<'a> {
         Statement::WithStatement(self.alloc_with_statement(span, object, body))
     }
 
+    /// Build a [`Statement::ErrorStatement`].
+    ///
+    /// This node contains an [`ErrorStatement`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn statement_error(self, span: Span) -> Statement<'a> {
+        Statement::ErrorStatement(self.alloc_error_statement(span))
+    }
+
     /// Build a [`Directive`].
     ///
     /// ## Parameters
@@ -4438,6 +4484,30 @@ impl<'a> AstBuilder<'a> {
         Box::new_in(self.empty_statement(span), self.allocator)
     }
 
+    /// Build an [`ErrorStatement`].
+    ///
+    /// If you want the built node to be allocated in the memory arena,
+    /// use [`AstBuilder::alloc_error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn error_statement(self, span: Span) -> ErrorStatement {
+        ErrorStatement { span }
+    }
+
+    /// Build an [`ErrorStatement`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node.
+    /// If you want a stack-allocated node, use [`AstBuilder::error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn alloc_error_statement(self, span: Span) -> Box<'a, ErrorStatement> {
+        Box::new_in(self.error_statement(span), self.allocator)
+    }
+
     /// Build an [`ExpressionStatement`].
     ///
     /// If you want the built node to be allocated in the memory arena,
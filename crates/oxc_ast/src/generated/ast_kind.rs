@@ -12,7 +12,7 @@ use oxc_span::{GetSpan, Span};
 use crate::ast::*;
 
 /// The largest integer value that can be mapped to an `AstType`/`AstKind` enum variant.
-pub const AST_TYPE_MAX: u8 = 186;
+pub const AST_TYPE_MAX: u8 = 188;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -23,187 +23,189 @@ pub enum AstType {
     BindingIdentifier = 3,
     LabelIdentifier = 4,
     ThisExpression = 5,
-    ArrayExpression = 6,
-    Elision = 7,
-    ObjectExpression = 8,
-    ObjectProperty = 9,
-    TemplateLiteral = 10,
-    TaggedTemplateExpression = 11,
-    TemplateElement = 12,
-    ComputedMemberExpression = 13,
-    StaticMemberExpression = 14,
-    PrivateFieldExpression = 15,
-    CallExpression = 16,
-    NewExpression = 17,
-    MetaProperty = 18,
-    SpreadElement = 19,
-    Argument = 20,
-    UpdateExpression = 21,
-    UnaryExpression = 22,
-    BinaryExpression = 23,
-    PrivateInExpression = 24,
-    LogicalExpression = 25,
-    ConditionalExpression = 26,
-    AssignmentExpression = 27,
-    ArrayAssignmentTarget = 28,
-    ObjectAssignmentTarget = 29,
-    AssignmentTargetRest = 30,
-    AssignmentTargetWithDefault = 31,
-    AssignmentTargetPropertyIdentifier = 32,
-    AssignmentTargetPropertyProperty = 33,
-    SequenceExpression = 34,
-    Super = 35,
-    AwaitExpression = 36,
-    ChainExpression = 37,
-    ParenthesizedExpression = 38,
-    Directive = 39,
-    Hashbang = 40,
-    BlockStatement = 41,
-    VariableDeclaration = 42,
-    VariableDeclarator = 43,
-    EmptyStatement = 44,
-    ExpressionStatement = 45,
-    IfStatement = 46,
-    DoWhileStatement = 47,
-    WhileStatement = 48,
-    ForStatement = 49,
-    ForInStatement = 50,
-    ForOfStatement = 51,
-    ContinueStatement = 52,
-    BreakStatement = 53,
-    ReturnStatement = 54,
-    WithStatement = 55,
-    SwitchStatement = 56,
-    SwitchCase = 57,
-    LabeledStatement = 58,
-    ThrowStatement = 59,
-    TryStatement = 60,
-    CatchClause = 61,
-    CatchParameter = 62,
-    DebuggerStatement = 63,
-    AssignmentPattern = 64,
-    ObjectPattern = 65,
-    BindingProperty = 66,
-    ArrayPattern = 67,
-    BindingRestElement = 68,
-    Function = 69,
-    FormalParameters = 70,
-    FormalParameter = 71,
-    FunctionBody = 72,
-    ArrowFunctionExpression = 73,
-    YieldExpression = 74,
-    Class = 75,
-    ClassBody = 76,
-    MethodDefinition = 77,
-    PropertyDefinition = 78,
-    PrivateIdentifier = 79,
-    StaticBlock = 80,
-    AccessorProperty = 81,
-    ImportExpression = 82,
-    ImportDeclaration = 83,
-    ImportSpecifier = 84,
-    ImportDefaultSpecifier = 85,
-    ImportNamespaceSpecifier = 86,
-    WithClause = 87,
-    ImportAttribute = 88,
-    ExportNamedDeclaration = 89,
-    ExportDefaultDeclaration = 90,
-    ExportAllDeclaration = 91,
-    ExportSpecifier = 92,
-    V8IntrinsicExpression = 93,
-    BooleanLiteral = 94,
-    NullLiteral = 95,
-    NumericLiteral = 96,
-    StringLiteral = 97,
-    BigIntLiteral = 98,
-    RegExpLiteral = 99,
-    JSXElement = 100,
-    JSXOpeningElement = 101,
-    JSXClosingElement = 102,
-    JSXFragment = 103,
-    JSXOpeningFragment = 104,
-    JSXClosingFragment = 105,
-    JSXNamespacedName = 106,
-    JSXMemberExpression = 107,
-    JSXExpressionContainer = 108,
-    JSXEmptyExpression = 109,
-    JSXAttribute = 110,
-    JSXSpreadAttribute = 111,
-    JSXIdentifier = 112,
-    JSXSpreadChild = 113,
-    JSXText = 114,
-    TSThisParameter = 115,
-    TSEnumDeclaration = 116,
-    TSEnumBody = 117,
-    TSEnumMember = 118,
-    TSTypeAnnotation = 119,
-    TSLiteralType = 120,
-    TSConditionalType = 121,
-    TSUnionType = 122,
-    TSIntersectionType = 123,
-    TSParenthesizedType = 124,
-    TSTypeOperator = 125,
-    TSArrayType = 126,
-    TSIndexedAccessType = 127,
-    TSTupleType = 128,
-    TSNamedTupleMember = 129,
-    TSOptionalType = 130,
-    TSRestType = 131,
-    TSAnyKeyword = 132,
-    TSStringKeyword = 133,
-    TSBooleanKeyword = 134,
-    TSNumberKeyword = 135,
-    TSNeverKeyword = 136,
-    TSIntrinsicKeyword = 137,
-    TSUnknownKeyword = 138,
-    TSNullKeyword = 139,
-    TSUndefinedKeyword = 140,
-    TSVoidKeyword = 141,
-    TSSymbolKeyword = 142,
-    TSThisType = 143,
-    TSObjectKeyword = 144,
-    TSBigIntKeyword = 145,
-    TSTypeReference = 146,
-    TSQualifiedName = 147,
-    TSTypeParameterInstantiation = 148,
-    TSTypeParameter = 149,
-    TSTypeParameterDeclaration = 150,
-    TSTypeAliasDeclaration = 151,
-    TSClassImplements = 152,
-    TSInterfaceDeclaration = 153,
-    TSInterfaceBody = 154,
-    TSPropertySignature = 155,
-    TSIndexSignature = 156,
-    TSCallSignatureDeclaration = 157,
-    TSMethodSignature = 158,
-    TSConstructSignatureDeclaration = 159,
-    TSIndexSignatureName = 160,
-    TSInterfaceHeritage = 161,
-    TSTypePredicate = 162,
-    TSModuleDeclaration = 163,
-    TSModuleBlock = 164,
-    TSTypeLiteral = 165,
-    TSInferType = 166,
-    TSTypeQuery = 167,
-    TSImportType = 168,
-    TSImportTypeQualifiedName = 169,
-    TSFunctionType = 170,
-    TSConstructorType = 171,
-    TSMappedType = 172,
-    TSTemplateLiteralType = 173,
-    TSAsExpression = 174,
-    TSSatisfiesExpression = 175,
-    TSTypeAssertion = 176,
-    TSImportEqualsDeclaration = 177,
-    TSExternalModuleReference = 178,
-    TSNonNullExpression = 179,
-    Decorator = 180,
-    TSExportAssignment = 181,
-    TSNamespaceExportDeclaration = 182,
-    TSInstantiationExpression = 183,
-    JSDocNullableType = 184,
-    JSDocNonNullableType = 185,
-    JSDocUnknownType = 186,
+    ErrorExpression = 6,
+    ArrayExpression = 7,
+    Elision = 8,
+    ObjectExpression = 9,
+    ObjectProperty = 10,
+    TemplateLiteral = 11,
+    TaggedTemplateExpression = 12,
+    TemplateElement = 13,
+    ComputedMemberExpression = 14,
+    StaticMemberExpression = 15,
+    PrivateFieldExpression = 16,
+    CallExpression = 17,
+    NewExpression = 18,
+    MetaProperty = 19,
+    SpreadElement = 20,
+    Argument = 21,
+    UpdateExpression = 22,
+    UnaryExpression = 23,
+    BinaryExpression = 24,
+    PrivateInExpression = 25,
+    LogicalExpression = 26,
+    ConditionalExpression = 27,
+    AssignmentExpression = 28,
+    ArrayAssignmentTarget = 29,
+    ObjectAssignmentTarget = 30,
+    AssignmentTargetRest = 31,
+    AssignmentTargetWithDefault = 32,
+    AssignmentTargetPropertyIdentifier = 33,
+    AssignmentTargetPropertyProperty = 34,
+    SequenceExpression = 35,
+    Super = 36,
+    AwaitExpression = 37,
+    ChainExpression = 38,
+    ParenthesizedExpression = 39,
+    Directive = 40,
+    Hashbang = 41,
+    BlockStatement = 42,
+    VariableDeclaration = 43,
+    VariableDeclarator = 44,
+    EmptyStatement = 45,
+    ErrorStatement = 46,
+    ExpressionStatement = 47,
+    IfStatement = 48,
+    DoWhileStatement = 49,
+    WhileStatement = 50,
+    ForStatement = 51,
+    ForInStatement = 52,
+    ForOfStatement = 53,
+    ContinueStatement = 54,
+    BreakStatement = 55,
+    ReturnStatement = 56,
+    WithStatement = 57,
+    SwitchStatement = 58,
+    SwitchCase = 59,
+    LabeledStatement = 60,
+    ThrowStatement = 61,
+    TryStatement = 62,
+    CatchClause = 63,
+    CatchParameter = 64,
+    DebuggerStatement = 65,
+    AssignmentPattern = 66,
+    ObjectPattern = 67,
+    BindingProperty = 68,
+    ArrayPattern = 69,
+    BindingRestElement = 70,
+    Function = 71,
+    FormalParameters = 72,
+    FormalParameter = 73,
+    FunctionBody = 74,
+    ArrowFunctionExpression = 75,
+    YieldExpression = 76,
+    Class = 77,
+    ClassBody = 78,
+    MethodDefinition = 79,
+    PropertyDefinition = 80,
+    PrivateIdentifier = 81,
+    StaticBlock = 82,
+    AccessorProperty = 83,
+    ImportExpression = 84,
+    ImportDeclaration = 85,
+    ImportSpecifier = 86,
+    ImportDefaultSpecifier = 87,
+    ImportNamespaceSpecifier = 88,
+    WithClause = 89,
+    ImportAttribute = 90,
+    ExportNamedDeclaration = 91,
+    ExportDefaultDeclaration = 92,
+    ExportAllDeclaration = 93,
+    ExportSpecifier = 94,
+    V8IntrinsicExpression = 95,
+    BooleanLiteral = 96,
+    NullLiteral = 97,
+    NumericLiteral = 98,
+    StringLiteral = 99,
+    BigIntLiteral = 100,
+    RegExpLiteral = 101,
+    JSXElement = 102,
+    JSXOpeningElement = 103,
+    JSXClosingElement = 104,
+    JSXFragment = 105,
+    JSXOpeningFragment = 106,
+    JSXClosingFragment = 107,
+    JSXNamespacedName = 108,
+    JSXMemberExpression = 109,
+    JSXExpressionContainer = 110,
+    JSXEmptyExpression = 111,
+    JSXAttribute = 112,
+    JSXSpreadAttribute = 113,
+    JSXIdentifier = 114,
+    JSXSpreadChild = 115,
+    JSXText = 116,
+    TSThisParameter = 117,
+    TSEnumDeclaration = 118,
+    TSEnumBody = 119,
+    TSEnumMember = 120,
+    TSTypeAnnotation = 121,
+    TSLiteralType = 122,
+    TSConditionalType = 123,
+    TSUnionType = 124,
+    TSIntersectionType = 125,
+    TSParenthesizedType = 126,
+    TSTypeOperator = 127,
+    TSArrayType = 128,
+    TSIndexedAccessType = 129,
+    TSTupleType = 130,
+    TSNamedTupleMember = 131,
+    TSOptionalType = 132,
+    TSRestType = 133,
+    TSAnyKeyword = 134,
+    TSStringKeyword = 135,
+    TSBooleanKeyword = 136,
+    TSNumberKeyword = 137,
+    TSNeverKeyword = 138,
+    TSIntrinsicKeyword = 139,
+    TSUnknownKeyword = 140,
+    TSNullKeyword = 141,
+    TSUndefinedKeyword = 142,
+    TSVoidKeyword = 143,
+    TSSymbolKeyword = 144,
+    TSThisType = 145,
+    TSObjectKeyword = 146,
+    TSBigIntKeyword = 147,
+    TSTypeReference = 148,
+    TSQualifiedName = 149,
+    TSTypeParameterInstantiation = 150,
+    TSTypeParameter = 151,
+    TSTypeParameterDeclaration = 152,
+    TSTypeAliasDeclaration = 153,
+    TSClassImplements = 154,
+    TSInterfaceDeclaration = 155,
+    TSInterfaceBody = 156,
+    TSPropertySignature = 157,
+    TSIndexSignature = 158,
+    TSCallSignatureDeclaration = 159,
+    TSMethodSignature = 160,
+    TSConstructSignatureDeclaration = 161,
+    TSIndexSignatureName = 162,
+    TSInterfaceHeritage = 163,
+    TSTypePredicate = 164,
+    TSModuleDeclaration = 165,
+    TSModuleBlock = 166,
+    TSTypeLiteral = 167,
+    TSInferType = 168,
+    TSTypeQuery = 169,
+    TSImportType = 170,
+    TSImportTypeQualifiedName = 171,
+    TSFunctionType = 172,
+    TSConstructorType = 173,
+    TSMappedType = 174,
+    TSTemplateLiteralType = 175,
+    TSAsExpression = 176,
+    TSSatisfiesExpression = 177,
+    TSTypeAssertion = 178,
+    TSImportEqualsDeclaration = 179,
+    TSExternalModuleReference = 180,
+    TSNonNullExpression = 181,
+    Decorator = 182,
+    TSExportAssignment = 183,
+    TSNamespaceExportDeclaration = 184,
+    TSInstantiationExpression = 185,
+    JSDocNullableType = 186,
+    JSDocNonNullableType = 187,
+    JSDocUnknownType = 188,
 }
 
 /// Untyped AST Node Kind
@@ -216,6 +218,7 @@ pub enum AstKind<'a> {
     BindingIdentifier(&'a BindingIdentifier<'a>) = AstType::BindingIdentifier as u8,
     LabelIdentifier(&'a LabelIdentifier<'a>) = AstType::LabelIdentifier as u8,
     ThisExpression(&'a ThisExpression) = AstType::ThisExpression as u8,
+    ErrorExpression(&'a ErrorExpression) = AstType::ErrorExpression as u8,
     ArrayExpression(&'a ArrayExpression<'a>) = AstType::ArrayExpression as u8,
     Elision(&'a Elision) = AstType::Elision as u8,
     ObjectExpression(&'a ObjectExpression<'a>) = AstType::ObjectExpression as u8,
@@ -261,6 +264,7 @@ pub enum AstKind<'a> {
     VariableDeclaration(&'a VariableDeclaration<'a>) = AstType::VariableDeclaration as u8,
     VariableDeclarator(&'a VariableDeclarator<'a>) = AstType::VariableDeclarator as u8,
     EmptyStatement(&'a EmptyStatement) = AstType::EmptyStatement as u8,
+    ErrorStatement(&'a ErrorStatement) = AstType::ErrorStatement as u8,
     ExpressionStatement(&'a ExpressionStatement<'a>) = AstType::ExpressionStatement as u8,
     IfStatement(&'a IfStatement<'a>) = AstType::IfStatement as u8,
     DoWhileStatement(&'a DoWhileStatement<'a>) = AstType::DoWhileStatement as u8,
@@ -438,6 +442,7 @@ impl GetSpan for AstKind<'_> {
             Self::BindingIdentifier(it) => it.span(),
             Self::LabelIdentifier(it) => it.span(),
             Self::ThisExpression(it) => it.span(),
+            Self::ErrorExpression(it) => it.span(),
             Self::ArrayExpression(it) => it.span(),
             Self::Elision(it) => it.span(),
             Self::ObjectExpression(it) => it.span(),
@@ -477,6 +482,7 @@ impl GetSpan for AstKind<'_> {
             Self::VariableDeclaration(it) => it.span(),
             Self::VariableDeclarator(it) => it.span(),
             Self::EmptyStatement(it) => it.span(),
+            Self::ErrorStatement(it) => it.span(),
             Self::ExpressionStatement(it) => it.span(),
             Self::IfStatement(it) => it.span(),
             Self::DoWhileStatement(it) => it.span(),
@@ -632,6 +638,7 @@ impl GetAddress for AstKind<'_> {
             Self::BindingIdentifier(it) => Address::from_ptr(it),
             Self::LabelIdentifier(it) => Address::from_ptr(it),
             Self::ThisExpression(it) => Address::from_ptr(it),
+            Self::ErrorExpression(it) => Address::from_ptr(it),
             Self::ArrayExpression(it) => Address::from_ptr(it),
             Self::Elision(it) => Address::from_ptr(it),
             Self::ObjectExpression(it) => Address::from_ptr(it),
@@ -671,6 +678,7 @@ impl GetAddress for AstKind<'_> {
             Self::VariableDeclaration(it) => Address::from_ptr(it),
             Self::VariableDeclarator(it) => Address::from_ptr(it),
             Self::EmptyStatement(it) => Address::from_ptr(it),
+            Self::ErrorStatement(it) => Address::from_ptr(it),
             Self::ExpressionStatement(it) => Address::from_ptr(it),
             Self::IfStatement(it) => Address::from_ptr(it),
             Self::DoWhileStatement(it) => Address::from_ptr(it),
@@ -848,6 +856,11 @@ impl<'a> AstKind<'a> {
         if let Self::ThisExpression(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_error_expression(self) -> Option<&'a ErrorExpression> {
+        if let Self::ErrorExpression(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_array_expression(self) -> Option<&'a ArrayExpression<'a>> {
         if let Self::ArrayExpression(v) = self { Some(v) } else { None }
@@ -1047,6 +1060,11 @@ impl<'a> AstKind<'a> {
         if let Self::EmptyStatement(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_error_statement(self) -> Option<&'a ErrorStatement> {
+        if let Self::ErrorStatement(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_expression_statement(self) -> Option<&'a ExpressionStatement<'a>> {
         if let Self::ExpressionStatement(v) = self { Some(v) } else { None }
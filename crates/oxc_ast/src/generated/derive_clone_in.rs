@@ -146,6 +146,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Expression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                Expression::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Expression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -280,6 +283,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => Expression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => {
+                Expression::ErrorExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Expression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -381,6 +387,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ThisExpression {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for ErrorExpression {
+    type Cloned = ErrorExpression;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorExpression { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorExpression { span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator) }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpression<'_> {
     type Cloned = ArrayExpression<'new_alloc>;
 
@@ -526,6 +544,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ArrayExpressionElement::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                ArrayExpressionElement::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ArrayExpressionElement::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -668,6 +689,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => ArrayExpressionElement::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => ArrayExpressionElement::ErrorExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ArrayExpressionElement::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -876,6 +900,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => {
                 PropertyKey::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                PropertyKey::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 PropertyKey::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1016,6 +1043,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => PropertyKey::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => {
+                PropertyKey::ErrorExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => PropertyKey::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -1407,6 +1437,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                Argument::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Argument::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1542,6 +1575,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                Argument::ErrorExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Argument::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -2292,6 +2328,7 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::TryStatement(it) => Statement::TryStatement(CloneIn::clone_in(it, allocator)),
             Self::WhileStatement(it) => Statement::WhileStatement(CloneIn::clone_in(it, allocator)),
             Self::WithStatement(it) => Statement::WithStatement(CloneIn::clone_in(it, allocator)),
+            Self::ErrorStatement(it) => Statement::ErrorStatement(CloneIn::clone_in(it, allocator)),
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in(it, allocator))
             }
@@ -2393,6 +2430,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::WithStatement(it) => {
                 Statement::WithStatement(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::ErrorStatement(it) => {
+                Statement::ErrorStatement(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
@@ -2631,6 +2671,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for EmptyStatement {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for ErrorStatement {
+    type Cloned = ErrorStatement;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorStatement { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ErrorStatement { span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator) }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for ExpressionStatement<'_> {
     type Cloned = ExpressionStatement<'new_alloc>;
 
@@ -2859,6 +2911,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ForStatementInit::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                ForStatementInit::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ForStatementInit::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -2996,6 +3051,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => ForStatementInit::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => ForStatementInit::ErrorExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ForStatementInit::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -4564,6 +4622,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in(it, allocator),
             ),
+            Self::ErrorExpression(it) => {
+                ExportDefaultDeclarationKind::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(CloneIn::clone_in(
                     it, allocator,
@@ -4723,6 +4784,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => ExportDefaultDeclarationKind::ErrorExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(
                     CloneIn::clone_in_with_semantic_ids(it, allocator),
@@ -5308,6 +5372,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 JSXExpression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::ErrorExpression(it) => {
+                JSXExpression::ErrorExpression(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 JSXExpression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -5445,6 +5512,9 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => JSXExpression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::ErrorExpression(it) => {
+                JSXExpression::ErrorExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => JSXExpression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
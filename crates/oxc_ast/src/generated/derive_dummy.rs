@@ -90,6 +90,15 @@ impl<'a> Dummy<'a> for ThisExpression {
     }
 }
 
+impl<'a> Dummy<'a> for ErrorExpression {
+    /// Create a dummy [`ErrorExpression`].
+    ///
+    /// Does not allocate any data into arena.
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self { span: Dummy::dummy(allocator) }
+    }
+}
+
 impl<'a> Dummy<'a> for ArrayExpression<'a> {
     /// Create a dummy [`ArrayExpression`].
     ///
@@ -705,6 +714,15 @@ impl<'a> Dummy<'a> for EmptyStatement {
     }
 }
 
+impl<'a> Dummy<'a> for ErrorStatement {
+    /// Create a dummy [`ErrorStatement`].
+    ///
+    /// Does not allocate any data into arena.
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self { span: Dummy::dummy(allocator) }
+    }
+}
+
 impl<'a> Dummy<'a> for ExpressionStatement<'a> {
     /// Create a dummy [`ExpressionStatement`].
     ///
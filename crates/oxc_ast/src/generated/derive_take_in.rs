@@ -24,6 +24,8 @@ impl<'a> TakeIn<'a> for LabelIdentifier<'a> {}
 
 impl<'a> TakeIn<'a> for ThisExpression {}
 
+impl<'a> TakeIn<'a> for ErrorExpression {}
+
 impl<'a> TakeIn<'a> for ArrayExpression<'a> {}
 
 impl<'a> TakeIn<'a> for ArrayExpressionElement<'a> {}
@@ -128,6 +130,8 @@ impl<'a> TakeIn<'a> for VariableDeclarator<'a> {}
 
 impl<'a> TakeIn<'a> for EmptyStatement {}
 
+impl<'a> TakeIn<'a> for ErrorStatement {}
+
 impl<'a> TakeIn<'a> for ExpressionStatement<'a> {}
 
 impl<'a> TakeIn<'a> for IfStatement<'a> {}
@@ -23,6 +23,7 @@
 //!
 //! ## Cargo Features
 //! * `"serialize"` enables support for serialization to ESTree JSON
+//! * `"deserialize"` enables support for deserialization from ESTree JSON, via [`deserialize::Deserializer`]
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -50,6 +51,8 @@ pub mod ast;
 mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
+#[cfg(feature = "deserialize")]
+pub mod deserialize;
 pub mod precedence;
 mod trivia;
 
@@ -76,6 +79,7 @@ pub use generated::ast_kind;
 pub use crate::{
     ast::comment::{Comment, CommentContent, CommentKind, CommentPosition},
     ast_builder_impl::{AstBuilder, NONE},
+    ast_impl::STYLED_COMPONENTS_CSS_HELPERS,
     ast_kind::{AstKind, AstType},
     ast_kind_impl::{MemberExpressionKind, ModuleDeclarationKind},
     trivia::{CommentsRange, comments_range, has_comments_between},
@@ -9,301 +9,303 @@ pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
     key: 16287231350648472473,
     disps: &[
         (0, 0),
-        (0, 16),
-        (0, 95),
-        (0, 6),
-        (1, 214),
-        (1, 83),
-        (0, 36),
-        (2, 182),
-        (1, 62),
-        (0, 15),
-        (1, 172),
-        (0, 12),
-        (1, 22),
-        (1, 52),
-        (0, 130),
-        (0, 3),
-        (0, 30),
-        (2, 79),
-        (2, 67),
-        (0, 105),
-        (2, 230),
-        (0, 7),
-        (0, 52),
-        (0, 113),
+        (0, 70),
+        (0, 48),
         (0, 2),
-        (1, 199),
+        (1, 112),
+        (0, 220),
+        (13, 54),
+        (0, 105),
+        (15, 58),
+        (1, 205),
         (0, 0),
-        (1, 3),
-        (2, 140),
-        (7, 34),
-        (1, 156),
-        (0, 13),
-        (0, 1),
-        (0, 1),
-        (7, 216),
-        (11, 204),
-        (2, 66),
-        (83, 63),
+        (0, 5),
+        (0, 55),
+        (0, 145),
+        (0, 25),
+        (0, 2),
+        (0, 45),
+        (9, 162),
+        (0, 98),
+        (0, 223),
+        (5, 159),
         (0, 0),
-        (11, 100),
-        (0, 171),
+        (1, 32),
+        (0, 83),
         (0, 10),
-        (4, 163),
+        (0, 52),
+        (0, 36),
+        (0, 2),
+        (2, 92),
+        (1, 150),
+        (1, 158),
+        (0, 9),
+        (1, 88),
+        (0, 1),
+        (0, 109),
+        (15, 59),
         (0, 22),
-        (0, 16),
-        (8, 193),
-        (0, 25),
+        (89, 92),
+        (0, 0),
+        (13, 234),
+        (0, 14),
+        (0, 26),
+        (2, 212),
+        (0, 42),
+        (2, 34),
+        (162, 69),
+        (0, 3),
     ],
     entries: &[
-        ("TSFunctionType", StructDetails { field_order: None }),
-        ("TSTypeOperator", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("YieldExpression", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("TSLiteralType", StructDetails { field_order: None }),
-        ("Class", StructDetails { field_order: Some(&[0, 9, 1, 2, 3, 4, 5, 6, 7, 10, 11, 8]) }),
-        ("IfStatement", StructDetails { field_order: None }),
-        ("CharacterClassRange", StructDetails { field_order: None }),
+        ("TryStatement", StructDetails { field_order: None }),
+        ("TSClassImplements", StructDetails { field_order: None }),
+        ("PrivateInExpression", StructDetails { field_order: None }),
+        ("IdentifierReference", StructDetails { field_order: None }),
+        ("SwitchStatement", StructDetails { field_order: None }),
+        ("ReferenceId", StructDetails { field_order: None }),
         ("TSObjectKeyword", StructDetails { field_order: None }),
-        ("TSNamedTupleMember", StructDetails { field_order: None }),
-        ("WhileStatement", StructDetails { field_order: None }),
-        ("UpdateExpression", StructDetails { field_order: Some(&[0, 2, 3, 1]) }),
-        ("Alternative", StructDetails { field_order: None }),
+        ("ExportAllDeclaration", StructDetails { field_order: None }),
+        ("JSXOpeningElement", StructDetails { field_order: None }),
+        ("ObjectExpression", StructDetails { field_order: None }),
+        ("TSTypeAliasDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 4]) }),
+        ("StaticBlock", StructDetails { field_order: None }),
+        (
+            "PropertyDefinition",
+            StructDetails { field_order: Some(&[0, 5, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13]) },
+        ),
+        ("JSXSpreadAttribute", StructDetails { field_order: None }),
+        ("NonMaxU32", StructDetails { field_order: None }),
+        ("TemplateElementValue", StructDetails { field_order: None }),
+        ("BindingRestElement", StructDetails { field_order: None }),
+        ("ExportDefaultDeclaration", StructDetails { field_order: None }),
+        ("CommentNodeId", StructDetails { field_order: None }),
+        (
+            "Function",
+            StructDetails { field_order: Some(&[0, 8, 1, 9, 10, 11, 2, 3, 4, 5, 6, 7, 12, 13]) },
+        ),
         ("TSIntersectionType", StructDetails { field_order: None }),
-        ("TSUnionType", StructDetails { field_order: None }),
-        ("ArrayPattern", StructDetails { field_order: None }),
+        ("AssignmentPattern", StructDetails { field_order: None }),
+        ("JSXMemberExpression", StructDetails { field_order: None }),
+        ("Character", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("ExpressionStatement", StructDetails { field_order: None }),
+        ("TSNamedTupleMember", StructDetails { field_order: None }),
+        ("CapturingGroup", StructDetails { field_order: None }),
+        ("TSTupleType", StructDetails { field_order: None }),
+        ("TSBooleanKeyword", StructDetails { field_order: None }),
+        ("DebuggerStatement", StructDetails { field_order: None }),
         ("AssignmentTargetRest", StructDetails { field_order: None }),
+        ("ObjectPattern", StructDetails { field_order: None }),
+        ("ImportSpecifier", StructDetails { field_order: None }),
         ("Error", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
-        ("JSXOpeningElement", StructDetails { field_order: None }),
+        ("Quantifier", StructDetails { field_order: Some(&[0, 1, 2, 4, 3]) }),
+        ("DynamicImport", StructDetails { field_order: None }),
+        ("IgnoreGroup", StructDetails { field_order: None }),
+        ("TSNamespaceExportDeclaration", StructDetails { field_order: None }),
+        ("ArrayPattern", StructDetails { field_order: None }),
+        ("TSUndefinedKeyword", StructDetails { field_order: None }),
+        ("FormalParameter", StructDetails { field_order: None }),
+        ("JSDocNullableType", StructDetails { field_order: None }),
+        ("ConditionalExpression", StructDetails { field_order: None }),
+        ("JSXNamespacedName", StructDetails { field_order: None }),
+        ("FunctionBody", StructDetails { field_order: None }),
         ("SequenceExpression", StructDetails { field_order: None }),
-        ("JSXSpreadAttribute", StructDetails { field_order: None }),
-        ("TemplateLiteral", StructDetails { field_order: None }),
-        ("LabelIdentifier", StructDetails { field_order: None }),
-        ("BindingPattern", StructDetails { field_order: None }),
-        ("TSTypeAssertion", StructDetails { field_order: None }),
+        ("Comment", StructDetails { field_order: None }),
+        ("TSInstantiationExpression", StructDetails { field_order: None }),
+        ("TSSymbolKeyword", StructDetails { field_order: None }),
+        ("TSNonNullExpression", StructDetails { field_order: None }),
+        ("ObjectAssignmentTarget", StructDetails { field_order: None }),
+        ("BigIntLiteral", StructDetails { field_order: None }),
+        ("Modifiers", StructDetails { field_order: None }),
+        ("TSRestType", StructDetails { field_order: None }),
+        ("ErrorLabel", StructDetails { field_order: Some(&[1, 0]) }),
+        ("ImportExpression", StructDetails { field_order: None }),
+        ("CatchClause", StructDetails { field_order: None }),
+        ("TSImportType", StructDetails { field_order: None }),
+        ("TSTypeOperator", StructDetails { field_order: Some(&[0, 2, 1]) }),
         ("NamedReference", StructDetails { field_order: None }),
-        ("UnaryExpression", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("EmptyStatement", StructDetails { field_order: None }),
-        ("ExportDefaultDeclaration", StructDetails { field_order: None }),
-        ("ArrayAssignmentTarget", StructDetails { field_order: None }),
-        ("NewExpression", StructDetails { field_order: None }),
+        ("TSImportTypeQualifiedName", StructDetails { field_order: None }),
+        ("TSInterfaceDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 4, 6, 5]) }),
+        ("NumericLiteral", StructDetails { field_order: None }),
         ("TSIndexSignatureName", StructDetails { field_order: None }),
-        ("TSNumberKeyword", StructDetails { field_order: None }),
-        ("PrivateInExpression", StructDetails { field_order: None }),
-        ("TSMethodSignature", StructDetails { field_order: Some(&[0, 1, 7, 8, 9, 2, 3, 4, 5, 6]) }),
-        ("BoundaryAssertion", StructDetails { field_order: None }),
-        ("TSEnumMember", StructDetails { field_order: None }),
-        ("PrivateFieldExpression", StructDetails { field_order: None }),
-        ("BlockStatement", StructDetails { field_order: None }),
-        ("ComputedMemberExpression", StructDetails { field_order: None }),
-        ("ReferenceId", StructDetails { field_order: None }),
-        ("TSOptionalType", StructDetails { field_order: None }),
-        ("TSTupleType", StructDetails { field_order: None }),
+        ("LookAroundAssertion", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("BindingProperty", StructDetails { field_order: None }),
+        ("RawTransferMetadata2", StructDetails { field_order: Some(&[1, 2, 0]) }),
+        ("JSXSpreadChild", StructDetails { field_order: None }),
+        ("Super", StructDetails { field_order: None }),
+        ("TSTemplateLiteralType", StructDetails { field_order: None }),
+        ("LabelIdentifier", StructDetails { field_order: None }),
+        ("ClassStringDisjunction", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("ImportNamespaceSpecifier", StructDetails { field_order: None }),
+        ("BindingIdentifier", StructDetails { field_order: None }),
         ("TSAnyKeyword", StructDetails { field_order: None }),
-        ("JSDocNonNullableType", StructDetails { field_order: None }),
-        ("Modifiers", StructDetails { field_order: None }),
-        ("TSIndexedAccessType", StructDetails { field_order: None }),
-        ("JSXIdentifier", StructDetails { field_order: None }),
-        ("RegExpFlags", StructDetails { field_order: None }),
-        ("V8IntrinsicExpression", StructDetails { field_order: None }),
-        ("Hashbang", StructDetails { field_order: None }),
         ("ClassBody", StructDetails { field_order: None }),
+        ("StaticExport", StructDetails { field_order: None }),
+        ("FormalParameters", StructDetails { field_order: Some(&[0, 3, 1, 2]) }),
+        ("ReturnStatement", StructDetails { field_order: None }),
+        ("BlockStatement", StructDetails { field_order: None }),
+        ("TSSatisfiesExpression", StructDetails { field_order: None }),
+        ("LogicalExpression", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
+        ("CallExpression", StructDetails { field_order: None }),
+        ("FixedSizeAllocatorMetadata", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("AssignmentTargetPropertyIdentifier", StructDetails { field_order: None }),
+        ("TSLiteralType", StructDetails { field_order: None }),
+        ("Program", StructDetails { field_order: Some(&[0, 7, 1, 2, 3, 4, 5, 6]) }),
+        ("TaggedTemplateExpression", StructDetails { field_order: None }),
+        ("TSConditionalType", StructDetails { field_order: None }),
+        ("TSExternalModuleReference", StructDetails { field_order: None }),
+        ("TSMappedType", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 6, 4]) }),
+        ("PrivateFieldExpression", StructDetails { field_order: None }),
+        ("ExportNamedDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 4]) }),
+        ("TSStringKeyword", StructDetails { field_order: None }),
+        ("RawTransferData", StructDetails { field_order: None }),
+        ("CharacterClassRange", StructDetails { field_order: None }),
+        ("TSTypeReference", StructDetails { field_order: None }),
+        ("JSXOpeningFragment", StructDetails { field_order: None }),
+        ("TSNullKeyword", StructDetails { field_order: None }),
+        ("ComputedMemberExpression", StructDetails { field_order: None }),
+        ("TSEnumMember", StructDetails { field_order: None }),
+        ("UnicodePropertyEscape", StructDetails { field_order: Some(&[0, 3, 4, 1, 2]) }),
+        ("CharacterClassEscape", StructDetails { field_order: None }),
+        ("ParenthesizedExpression", StructDetails { field_order: None }),
+        ("ThisExpression", StructDetails { field_order: None }),
+        ("TSThisParameter", StructDetails { field_order: None }),
+        ("TSTypeParameterDeclaration", StructDetails { field_order: None }),
+        ("TSEnumBody", StructDetails { field_order: None }),
+        ("RegExpPattern", StructDetails { field_order: None }),
+        ("JSXClosingElement", StructDetails { field_order: None }),
+        ("Disjunction", StructDetails { field_order: None }),
         ("IdentifierName", StructDetails { field_order: None }),
-        ("FunctionBody", StructDetails { field_order: None }),
-        ("Directive", StructDetails { field_order: None }),
-        ("BindingRestElement", StructDetails { field_order: None }),
-        ("TSBooleanKeyword", StructDetails { field_order: None }),
+        ("TSConstructSignatureDeclaration", StructDetails { field_order: None }),
+        ("Elision", StructDetails { field_order: None }),
+        ("BinaryExpression", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
+        ("NewExpression", StructDetails { field_order: None }),
+        ("TSVoidKeyword", StructDetails { field_order: None }),
+        ("JSXClosingFragment", StructDetails { field_order: None }),
+        ("JSDocNonNullableType", StructDetails { field_order: None }),
+        ("ErrorStatement", StructDetails { field_order: None }),
+        ("AssignmentTargetWithDefault", StructDetails { field_order: None }),
+        ("Decorator", StructDetails { field_order: None }),
+        ("TSNeverKeyword", StructDetails { field_order: None }),
+        ("TSIntrinsicKeyword", StructDetails { field_order: None }),
+        ("TSQualifiedName", StructDetails { field_order: None }),
+        ("Alternative", StructDetails { field_order: None }),
+        ("WhileStatement", StructDetails { field_order: None }),
+        ("ImportAttribute", StructDetails { field_order: None }),
+        ("RegExpLiteral", StructDetails { field_order: None }),
         ("TSInterfaceBody", StructDetails { field_order: None }),
-        ("Quantifier", StructDetails { field_order: Some(&[0, 1, 2, 4, 3]) }),
-        ("TSNonNullExpression", StructDetails { field_order: None }),
-        ("DynamicImport", StructDetails { field_order: None }),
-        ("RawTransferMetadata", StructDetails { field_order: Some(&[1, 2, 0]) }),
-        (
-            "MethodDefinition",
-            StructDetails { field_order: Some(&[0, 4, 1, 2, 3, 5, 6, 7, 8, 9, 10]) },
-        ),
-        ("LookAroundAssertion", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("JSXEmptyExpression", StructDetails { field_order: None }),
+        ("WithStatement", StructDetails { field_order: None }),
+        ("BreakStatement", StructDetails { field_order: None }),
+        ("JSXElement", StructDetails { field_order: None }),
+        ("ErrorExpression", StructDetails { field_order: None }),
+        ("V8IntrinsicExpression", StructDetails { field_order: None }),
+        ("ExportEntry", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
+        ("MetaProperty", StructDetails { field_order: None }),
+        ("TSUnionType", StructDetails { field_order: None }),
+        ("TSModuleDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 5, 3]) }),
+        ("AwaitExpression", StructDetails { field_order: None }),
         ("ForStatement", StructDetails { field_order: None }),
-        ("CommentNewlines", StructDetails { field_order: None }),
-        ("TemplateElement", StructDetails { field_order: None }),
-        ("SwitchStatement", StructDetails { field_order: None }),
-        ("Modifier", StructDetails { field_order: None }),
-        ("ScopeId", StructDetails { field_order: None }),
-        ("ExportNamedDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 4]) }),
         ("DoWhileStatement", StructDetails { field_order: None }),
-        ("ReturnStatement", StructDetails { field_order: None }),
-        ("TSImportType", StructDetails { field_order: None }),
-        ("TSConstructorType", StructDetails { field_order: Some(&[0, 4, 1, 2, 3]) }),
-        ("TSImportEqualsDeclaration", StructDetails { field_order: None }),
-        ("RegExpLiteral", StructDetails { field_order: None }),
-        ("LogicalExpression", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
-        ("TSPropertySignature", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
-        ("TSTemplateLiteralType", StructDetails { field_order: None }),
-        ("TSEnumBody", StructDetails { field_order: None }),
-        ("TSInterfaceDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 4, 6, 5]) }),
-        ("BindingIdentifier", StructDetails { field_order: None }),
         ("TSTypeQuery", StructDetails { field_order: None }),
-        ("TSTypeLiteral", StructDetails { field_order: None }),
-        ("LabeledStatement", StructDetails { field_order: None }),
-        ("CommentNodeId", StructDetails { field_order: None }),
-        ("Comment", StructDetails { field_order: None }),
-        ("ObjectPattern", StructDetails { field_order: None }),
-        ("TSImportTypeQualifiedName", StructDetails { field_order: None }),
-        ("FormalParameter", StructDetails { field_order: None }),
-        ("TSTypePredicate", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
-        ("IdentifierReference", StructDetails { field_order: None }),
-        ("TSQualifiedName", StructDetails { field_order: None }),
-        ("TSExternalModuleReference", StructDetails { field_order: None }),
-        ("ObjectProperty", StructDetails { field_order: Some(&[0, 3, 1, 2, 4, 5, 6]) }),
-        ("IndexedReference", StructDetails { field_order: None }),
-        ("TSAsExpression", StructDetails { field_order: None }),
-        ("JSXAttribute", StructDetails { field_order: None }),
+        ("ThrowStatement", StructDetails { field_order: None }),
         ("TSParenthesizedType", StructDetails { field_order: None }),
-        ("TSClassImplements", StructDetails { field_order: None }),
-        ("TSSatisfiesExpression", StructDetails { field_order: None }),
-        ("StaticExport", StructDetails { field_order: None }),
+        ("SymbolId", StructDetails { field_order: None }),
+        ("YieldExpression", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("TSExportAssignment", StructDetails { field_order: None }),
+        ("Hashbang", StructDetails { field_order: None }),
+        ("IndexedReference", StructDetails { field_order: None }),
+        ("ArrayAssignmentTarget", StructDetails { field_order: None }),
+        ("SourceType", StructDetails { field_order: None }),
+        ("ChainExpression", StructDetails { field_order: None }),
+        ("WithClause", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("Class", StructDetails { field_order: Some(&[0, 9, 1, 2, 3, 4, 5, 6, 7, 10, 11, 8]) }),
+        ("TSMethodSignature", StructDetails { field_order: Some(&[0, 1, 7, 8, 9, 2, 3, 4, 5, 6]) }),
+        ("Directive", StructDetails { field_order: None }),
+        ("TSUnknownKeyword", StructDetails { field_order: None }),
         (
             "ArrowFunctionExpression",
             StructDetails { field_order: Some(&[0, 6, 7, 1, 2, 3, 4, 5, 8, 9]) },
         ),
-        ("PrivateIdentifier", StructDetails { field_order: None }),
+        ("RegExpFlags", StructDetails { field_order: None }),
+        ("ArrayExpression", StructDetails { field_order: None }),
+        ("ExportSpecifier", StructDetails { field_order: None }),
+        ("TSTypeLiteral", StructDetails { field_order: None }),
+        ("ContinueStatement", StructDetails { field_order: None }),
+        ("TemplateElement", StructDetails { field_order: None }),
+        ("TSThisType", StructDetails { field_order: None }),
+        ("LabeledStatement", StructDetails { field_order: None }),
+        ("NullLiteral", StructDetails { field_order: None }),
+        ("SwitchCase", StructDetails { field_order: None }),
+        ("RegExp", StructDetails { field_order: None }),
+        ("TSModuleBlock", StructDetails { field_order: None }),
+        ("StaticMemberExpression", StructDetails { field_order: None }),
+        ("CatchParameter", StructDetails { field_order: None }),
+        ("BooleanLiteral", StructDetails { field_order: None }),
+        ("TSInterfaceHeritage", StructDetails { field_order: None }),
+        ("JSXExpressionContainer", StructDetails { field_order: None }),
+        ("TSOptionalType", StructDetails { field_order: None }),
+        ("TSTypeAssertion", StructDetails { field_order: None }),
+        ("BoundaryAssertion", StructDetails { field_order: None }),
+        ("TSInferType", StructDetails { field_order: None }),
+        ("NameSpan", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSEnumDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 5, 3]) }),
+        ("AssignmentExpression", StructDetails { field_order: Some(&[0, 3, 1, 2]) }),
+        ("ImportDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 3, 5]) }),
+        ("StaticImport", StructDetails { field_order: None }),
+        ("TSIndexedAccessType", StructDetails { field_order: None }),
         (
             "AccessorProperty",
             StructDetails { field_order: Some(&[0, 5, 1, 2, 3, 4, 6, 7, 8, 9, 10]) },
         ),
-        ("Span", StructDetails { field_order: None }),
-        ("TSStringKeyword", StructDetails { field_order: None }),
-        ("CharacterClass", StructDetails { field_order: Some(&[0, 2, 3, 4, 1]) }),
-        ("WithClause", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("ThrowStatement", StructDetails { field_order: None }),
+        ("TSAsExpression", StructDetails { field_order: None }),
+        ("JSDocUnknownType", StructDetails { field_order: None }),
+        ("IfStatement", StructDetails { field_order: None }),
         ("TSArrayType", StructDetails { field_order: None }),
-        ("ChainExpression", StructDetails { field_order: None }),
-        ("ParenthesizedExpression", StructDetails { field_order: None }),
-        ("TSRestType", StructDetails { field_order: None }),
-        ("ThisExpression", StructDetails { field_order: None }),
-        ("ExportAllDeclaration", StructDetails { field_order: None }),
-        ("CharacterClassEscape", StructDetails { field_order: None }),
-        ("TSUnknownKeyword", StructDetails { field_order: None }),
-        ("ObjectExpression", StructDetails { field_order: None }),
-        (
-            "Function",
-            StructDetails { field_order: Some(&[0, 8, 1, 9, 10, 11, 2, 3, 4, 5, 6, 7, 12, 13]) },
-        ),
+        ("EcmaScriptModule", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
+        ("ImportDefaultSpecifier", StructDetails { field_order: None }),
+        ("PrivateIdentifier", StructDetails { field_order: None }),
+        ("TSNumberKeyword", StructDetails { field_order: None }),
+        ("VariableDeclaration", StructDetails { field_order: Some(&[0, 2, 1, 3]) }),
+        ("TemplateLiteral", StructDetails { field_order: None }),
+        ("TSFunctionType", StructDetails { field_order: None }),
+        ("JSXAttribute", StructDetails { field_order: None }),
         ("TSIndexSignature", StructDetails { field_order: None }),
-        ("JSXMemberExpression", StructDetails { field_order: None }),
+        ("Modifier", StructDetails { field_order: None }),
+        ("ObjectProperty", StructDetails { field_order: Some(&[0, 3, 1, 2, 4, 5, 6]) }),
+        ("TSPropertySignature", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
+        ("CharacterClass", StructDetails { field_order: Some(&[0, 2, 3, 4, 1]) }),
+        ("JSXIdentifier", StructDetails { field_order: None }),
+        ("TSTypeParameterInstantiation", StructDetails { field_order: None }),
+        ("RawTransferMetadata", StructDetails { field_order: Some(&[1, 2, 0]) }),
+        ("StringLiteral", StructDetails { field_order: None }),
+        ("UpdateExpression", StructDetails { field_order: Some(&[0, 2, 3, 1]) }),
+        ("Dot", StructDetails { field_order: None }),
         ("TSTypeParameter", StructDetails { field_order: None }),
-        ("ExpressionStatement", StructDetails { field_order: None }),
-        ("RegExpPattern", StructDetails { field_order: None }),
-        ("TSConditionalType", StructDetails { field_order: None }),
-        ("SourceType", StructDetails { field_order: None }),
-        ("TSTypeParameterDeclaration", StructDetails { field_order: None }),
-        ("ClassString", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("TSVoidKeyword", StructDetails { field_order: None }),
-        ("TSCallSignatureDeclaration", StructDetails { field_order: None }),
-        ("TSNeverKeyword", StructDetails { field_order: None }),
-        ("ImportExpression", StructDetails { field_order: None }),
-        ("BreakStatement", StructDetails { field_order: None }),
-        ("MetaProperty", StructDetails { field_order: None }),
-        ("TSInferType", StructDetails { field_order: None }),
-        ("ConditionalExpression", StructDetails { field_order: None }),
-        ("AssignmentTargetWithDefault", StructDetails { field_order: None }),
-        ("Elision", StructDetails { field_order: None }),
-        ("TryStatement", StructDetails { field_order: None }),
+        ("BindingPattern", StructDetails { field_order: None }),
+        ("ScopeId", StructDetails { field_order: None }),
         ("TSBigIntKeyword", StructDetails { field_order: None }),
+        ("Span", StructDetails { field_order: None }),
+        ("ForInStatement", StructDetails { field_order: None }),
+        ("JSXText", StructDetails { field_order: None }),
         ("ForOfStatement", StructDetails { field_order: Some(&[0, 5, 1, 2, 3, 4]) }),
-        ("Decorator", StructDetails { field_order: None }),
-        ("SwitchCase", StructDetails { field_order: None }),
-        ("ImportDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 3, 5]) }),
-        ("ImportNamespaceSpecifier", StructDetails { field_order: None }),
-        ("FixedSizeAllocatorMetadata", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ImportDefaultSpecifier", StructDetails { field_order: None }),
-        ("StaticMemberExpression", StructDetails { field_order: None }),
-        ("Pattern", StructDetails { field_order: None }),
-        ("ImportEntry", StructDetails { field_order: None }),
-        ("ClassStringDisjunction", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("TSInterfaceHeritage", StructDetails { field_order: None }),
-        ("StaticImport", StructDetails { field_order: None }),
-        ("SpreadElement", StructDetails { field_order: None }),
-        ("TSTypeParameterInstantiation", StructDetails { field_order: None }),
-        ("AssignmentExpression", StructDetails { field_order: Some(&[0, 3, 1, 2]) }),
-        ("RegExp", StructDetails { field_order: None }),
-        ("AssignmentPattern", StructDetails { field_order: None }),
-        ("ErrorLabel", StructDetails { field_order: Some(&[1, 0]) }),
-        ("JSXElement", StructDetails { field_order: None }),
-        ("TSTypeAliasDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 4]) }),
-        ("NumericLiteral", StructDetails { field_order: None }),
-        ("BooleanLiteral", StructDetails { field_order: None }),
-        ("JSXNamespacedName", StructDetails { field_order: None }),
-        ("TSThisType", StructDetails { field_order: None }),
-        ("JSXEmptyExpression", StructDetails { field_order: None }),
-        ("BinaryExpression", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
-        ("RawTransferMetadata2", StructDetails { field_order: Some(&[1, 2, 0]) }),
-        ("ImportSpecifier", StructDetails { field_order: None }),
+        ("UnaryExpression", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("ClassString", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("JSXFragment", StructDetails { field_order: None }),
         (
-            "PropertyDefinition",
-            StructDetails { field_order: Some(&[0, 5, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13]) },
+            "MethodDefinition",
+            StructDetails { field_order: Some(&[0, 4, 1, 2, 3, 5, 6, 7, 8, 9, 10]) },
         ),
-        ("JSXClosingFragment", StructDetails { field_order: None }),
-        ("JSXText", StructDetails { field_order: None }),
-        ("JSXClosingElement", StructDetails { field_order: None }),
-        ("TSInstantiationExpression", StructDetails { field_order: None }),
-        ("JSXFragment", StructDetails { field_order: None }),
-        ("StringLiteral", StructDetails { field_order: None }),
-        ("TSThisParameter", StructDetails { field_order: None }),
-        ("ExportEntry", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
-        ("TSConstructSignatureDeclaration", StructDetails { field_order: None }),
-        ("Disjunction", StructDetails { field_order: None }),
-        ("TSSymbolKeyword", StructDetails { field_order: None }),
-        ("JSDocUnknownType", StructDetails { field_order: None }),
-        ("NameSpan", StructDetails { field_order: Some(&[1, 0]) }),
-        ("NonMaxU32", StructDetails { field_order: None }),
-        ("SymbolId", StructDetails { field_order: None }),
-        ("TSIntrinsicKeyword", StructDetails { field_order: None }),
-        ("WithStatement", StructDetails { field_order: None }),
-        ("VariableDeclaration", StructDetails { field_order: Some(&[0, 2, 1, 3]) }),
-        ("BigIntLiteral", StructDetails { field_order: None }),
-        ("Character", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("SpreadElement", StructDetails { field_order: None }),
+        ("TSConstructorType", StructDetails { field_order: Some(&[0, 4, 1, 2, 3]) }),
         ("AssignmentTargetPropertyProperty", StructDetails { field_order: None }),
-        ("TSTypeReference", StructDetails { field_order: None }),
-        ("TSUndefinedKeyword", StructDetails { field_order: None }),
-        ("CatchParameter", StructDetails { field_order: None }),
-        ("ForInStatement", StructDetails { field_order: None }),
-        ("ContinueStatement", StructDetails { field_order: None }),
-        ("Dot", StructDetails { field_order: None }),
-        ("TemplateElementValue", StructDetails { field_order: None }),
-        ("NullLiteral", StructDetails { field_order: None }),
-        ("IgnoreGroup", StructDetails { field_order: None }),
-        ("Program", StructDetails { field_order: Some(&[0, 7, 1, 2, 3, 4, 5, 6]) }),
-        ("AwaitExpression", StructDetails { field_order: None }),
-        ("BindingProperty", StructDetails { field_order: None }),
-        ("FormalParameters", StructDetails { field_order: Some(&[0, 3, 1, 2]) }),
+        ("TSCallSignatureDeclaration", StructDetails { field_order: None }),
         ("TSTypeAnnotation", StructDetails { field_order: None }),
-        ("StaticBlock", StructDetails { field_order: None }),
-        ("TSNullKeyword", StructDetails { field_order: None }),
-        ("JSDocNullableType", StructDetails { field_order: None }),
-        ("TaggedTemplateExpression", StructDetails { field_order: None }),
-        ("TSNamespaceExportDeclaration", StructDetails { field_order: None }),
-        ("ImportAttribute", StructDetails { field_order: None }),
-        ("EcmaScriptModule", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
-        ("ObjectAssignmentTarget", StructDetails { field_order: None }),
-        ("TSExportAssignment", StructDetails { field_order: None }),
-        ("JSXOpeningFragment", StructDetails { field_order: None }),
-        ("TSModuleDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 5, 3]) }),
-        ("RawTransferData", StructDetails { field_order: None }),
-        ("JSXExpressionContainer", StructDetails { field_order: None }),
-        ("CatchClause", StructDetails { field_order: None }),
-        ("AssignmentTargetPropertyIdentifier", StructDetails { field_order: None }),
-        ("UnicodePropertyEscape", StructDetails { field_order: Some(&[0, 3, 4, 1, 2]) }),
-        ("ArrayExpression", StructDetails { field_order: None }),
-        ("TSEnumDeclaration", StructDetails { field_order: Some(&[0, 1, 2, 4, 5, 3]) }),
-        ("TSMappedType", StructDetails { field_order: Some(&[0, 1, 2, 3, 5, 6, 4]) }),
-        ("Super", StructDetails { field_order: None }),
-        ("CapturingGroup", StructDetails { field_order: None }),
-        ("TSModuleBlock", StructDetails { field_order: None }),
-        ("DebuggerStatement", StructDetails { field_order: None }),
-        ("JSXSpreadChild", StructDetails { field_order: None }),
+        ("TSImportEqualsDeclaration", StructDetails { field_order: None }),
+        ("TSTypePredicate", StructDetails { field_order: Some(&[0, 1, 3, 2]) }),
+        ("ImportEntry", StructDetails { field_order: None }),
+        ("CommentNewlines", StructDetails { field_order: None }),
+        ("EmptyStatement", StructDetails { field_order: None }),
+        ("Pattern", StructDetails { field_order: None }),
         ("VariableDeclarator", StructDetails { field_order: Some(&[0, 3, 1, 2, 4]) }),
-        ("CallExpression", StructDetails { field_order: None }),
-        ("ExportSpecifier", StructDetails { field_order: None }),
     ],
 };
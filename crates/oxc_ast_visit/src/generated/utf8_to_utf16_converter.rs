@@ -43,6 +43,12 @@ impl<'a> VisitMut<'a> for Utf8ToUtf16Converter<'_> {
         self.convert_offset(&mut it.span.end);
     }
 
+    fn visit_error_expression(&mut self, it: &mut ErrorExpression) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_error_expression(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
     fn visit_array_expression(&mut self, it: &mut ArrayExpression<'a>) {
         self.convert_offset(&mut it.span.start);
         walk_mut::walk_array_expression(self, it);
@@ -275,6 +281,12 @@ impl<'a> VisitMut<'a> for Utf8ToUtf16Converter<'_> {
         self.convert_offset(&mut it.span.end);
     }
 
+    fn visit_error_statement(&mut self, it: &mut ErrorStatement) {
+        self.convert_offset(&mut it.span.start);
+        walk_mut::walk_error_statement(self, it);
+        self.convert_offset(&mut it.span.end);
+    }
+
     fn visit_expression_statement(&mut self, it: &mut ExpressionStatement<'a>) {
         self.convert_offset(&mut it.span.start);
         walk_mut::walk_expression_statement(self, it);
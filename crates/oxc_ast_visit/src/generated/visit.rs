@@ -75,6 +75,11 @@ pub trait Visit<'a>: Sized {
         walk_this_expression(self, it);
     }
 
+    #[inline]
+    fn visit_error_expression(&mut self, it: &ErrorExpression) {
+        walk_error_expression(self, it);
+    }
+
     #[inline]
     fn visit_array_expression(&mut self, it: &ArrayExpression<'a>) {
         walk_array_expression(self, it);
@@ -336,6 +341,11 @@ pub trait Visit<'a>: Sized {
         walk_empty_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_expression_statement(&mut self, it: &ExpressionStatement<'a>) {
         walk_expression_statement(self, it);
@@ -1420,6 +1430,7 @@ pub mod walk {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v_8_intrinsic_expression(it),
+            Expression::ErrorExpression(it) => visitor.visit_error_expression(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression())
             }
@@ -1469,6 +1480,14 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_expression<'a, V: Visit<'a>>(visitor: &mut V, it: &ErrorExpression) {
+        let kind = AstKind::ErrorExpression(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_array_expression<'a, V: Visit<'a>>(visitor: &mut V, it: &ArrayExpression<'a>) {
         let kind = AstKind::ArrayExpression(visitor.alloc(it));
@@ -2017,6 +2036,7 @@ pub mod walk {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration())
@@ -2106,6 +2126,14 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: Visit<'a>>(visitor: &mut V, it: &ErrorStatement) {
+        let kind = AstKind::ErrorStatement(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_expression_statement<'a, V: Visit<'a>>(
         visitor: &mut V,
@@ -67,6 +67,11 @@ pub trait VisitMut<'a>: Sized {
         walk_this_expression(self, it);
     }
 
+    #[inline]
+    fn visit_error_expression(&mut self, it: &mut ErrorExpression) {
+        walk_error_expression(self, it);
+    }
+
     #[inline]
     fn visit_array_expression(&mut self, it: &mut ArrayExpression<'a>) {
         walk_array_expression(self, it);
@@ -328,6 +333,11 @@ pub trait VisitMut<'a>: Sized {
         walk_empty_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &mut ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_expression_statement(&mut self, it: &mut ExpressionStatement<'a>) {
         walk_expression_statement(self, it);
@@ -1415,6 +1425,7 @@ pub mod walk_mut {
                 visitor.visit_ts_instantiation_expression(it)
             }
             Expression::V8IntrinsicExpression(it) => visitor.visit_v_8_intrinsic_expression(it),
+            Expression::ErrorExpression(it) => visitor.visit_error_expression(it),
             match_member_expression!(Expression) => {
                 visitor.visit_member_expression(it.to_member_expression_mut())
             }
@@ -1470,6 +1481,14 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_expression<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ErrorExpression) {
+        let kind = AstType::ErrorExpression;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_array_expression<'a, V: VisitMut<'a>>(
         visitor: &mut V,
@@ -2054,6 +2073,7 @@ pub mod walk_mut {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration_mut()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration_mut())
@@ -2143,6 +2163,14 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ErrorStatement) {
+        let kind = AstType::ErrorStatement;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_expression_statement<'a, V: VisitMut<'a>>(
         visitor: &mut V,
@@ -125,6 +125,7 @@ impl Gen for Statement<'_> {
             Self::TryStatement(stmt) => stmt.print(p, ctx),
             Self::WhileStatement(stmt) => stmt.print(p, ctx),
             Self::WithStatement(stmt) => stmt.print(p, ctx),
+            Self::ErrorStatement(stmt) => stmt.print(p, ctx),
             Self::ImportDeclaration(decl) => decl.print(p, ctx),
             Self::ExportAllDeclaration(decl) => decl.print(p, ctx),
             Self::ExportDefaultDeclaration(decl) => decl.print(p, ctx),
@@ -640,6 +641,19 @@ impl Gen for DebuggerStatement {
     }
 }
 
+impl Gen for ErrorStatement {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.print_comments_at(self.span.start);
+        p.add_source_mapping(self.span);
+        p.print_indent();
+        // Reproduce the unparsable source verbatim so that the user's text is preserved.
+        if let Some(source_text) = p.source_text {
+            p.print_str(self.span.source_text(source_text).trim_end());
+        }
+        p.print_soft_newline();
+    }
+}
+
 impl Gen for VariableDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span);
@@ -1183,6 +1197,7 @@ impl GenExpr for Expression<'_> {
             Self::TSNonNullExpression(e) => e.print_expr(p, precedence, ctx),
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::ErrorExpression(e) => e.print(p, ctx),
         }
     }
 }
@@ -1337,6 +1352,15 @@ impl Gen for ThisExpression {
     }
 }
 
+impl Gen for ErrorExpression {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        if let Some(source_text) = p.source_text {
+            p.print_str(self.span.source_text(source_text));
+        }
+    }
+}
+
 impl GenExpr for MemberExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         match self {
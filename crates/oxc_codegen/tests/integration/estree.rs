@@ -0,0 +1,143 @@
+use oxc_allocator::Allocator;
+use oxc_ast::deserialize::{DeserializeError, Deserializer};
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_span::{SPAN, SourceType, Span};
+
+/// Parse `source_text`, serialize it to ESTree JSON, deserialize it, and check that printing
+/// the deserialized AST gives the same output as printing the original.
+#[track_caller]
+fn test_round_trip(source_text: &str, source_type: SourceType) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "Parse errors for source: {source_text}");
+    let expected = Codegen::new().build(&ret.program).code;
+
+    let json = if source_type.is_typescript() {
+        ret.program.to_estree_ts_json(false)
+    } else {
+        ret.program.to_estree_js_json(false)
+    };
+    let program = Deserializer::new(&allocator)
+        .deserialize_program(&json, source_text, source_type)
+        .unwrap_or_else(|err| panic!("{err}\nfor source: {source_text}"));
+    let result = Codegen::new().build(&program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text}");
+}
+
+#[test]
+fn js() {
+    let cases = [
+        "#!/usr/bin/env node\n'use strict';\nfoo;",
+        "var a = 1, b; let [c, , ...d] = e; const { f, g: [h] = 1, ...i } = j;",
+        "if (a) b; else if (c) { d } else e;",
+        "for (let i = 0; i < 10; i++) continue; for (;;) break; for (x in y); for await (const x of y);",
+        "outer: while (a) { do { break outer; } while (b); }",
+        "switch (a) { case 1: b; break; default: c; }",
+        "try { a } catch ({ message }) { b } finally { c } try {} catch {}",
+        "with (a) b; debugger; throw new Error('x');",
+        "function* f(a, b = 1, { c }, ...d) { 'use strict'; yield* a; return yield; }",
+        "async function f() { await a; } const g = async (a) => a; const h = () => { return; };",
+        "class A extends B { #x = 1; static y; static { z(); } constructor() { super(); }\n get a() {} set a(v) {} static async *m() {} [k]() {} has() { return #x in this; } }",
+        "a = b; a += b; a ||= b; a ??= b; [a, b = 1, ...c] = d; ({ a, b: c, d = 1, ...e } = f);",
+        "a + b * c ** d; (a && b || c) ?? d; !a; -a; typeof a; void 0; delete a.b; ++a; a--;",
+        "a ? b : c; (a, b); a?.b?.[c]?.(d); new A(...b); a`b${c}d`; `x${y}z`;",
+        "x = [1, , 2, ...a]; y = { a, b: 1, [c]: 2, ...d, get e() {}, set e(v) {}, f() {} };",
+        "x = 1.5 + 0x1F + 0o17 + 0b11 + 1e3 + 10n + 0xFFn; y = /ab+c/gi; z = null + true + 'str' + \"str\";",
+        "x = import('a', { with: { type: 'json' } }); y = import.meta; function f() { new.target; }",
+        "import a, { b, c as d, 'e' as f } from 'g'; import * as h from 'i'; import 'j';",
+        "import k from 'l' with { type: 'json' };",
+        "export { a, b as c }; export * from 'd'; export * as e from 'f'; export { g as 'h' } from 'i';",
+        "export default function () {} export const x = 1; export class Y {}",
+        "export default a + b;",
+        "label: for (;;) { continue label; }",
+    ];
+    for case in cases {
+        test_round_trip(case, SourceType::mjs());
+    }
+    test_round_trip("'use strict'; with (a) {}", SourceType::cjs().with_script(true));
+}
+
+#[test]
+fn jsx() {
+    let cases = [
+        "<div className=\"a\" {...props}>text {value} <Foo.Bar /> <a:b c:d='e' /></div>;",
+        "<><span>{}</span><this.Foo x={<y />} /><my-element /></>;",
+        "<A b={1}>{...children}</A>;",
+    ];
+    for case in cases {
+        test_round_trip(case, SourceType::jsx());
+    }
+}
+
+#[test]
+fn ts() {
+    let cases = [
+        "let a: string | number = 1; let b: Array<string>[] = []; let c: [x: number, y?: string, ...z: boolean[]];",
+        "type A<T extends object = {}> = { readonly [K in keyof T]?: T[K] }; type B = { -readonly [K in X as `a${K}`]-?: K };",
+        "type C = T extends (infer U)[] ? U : never; type D = typeof import('x').y<Z>; type E = typeof a.b;",
+        "type F = (this: Window, a: string, ...b: number[]) => void; type G = abstract new () => object;",
+        "type H = { a: string; b?(): void; readonly c: 1; new (x: T): Y; (y: U): V; [key: string]: unknown; get d(): 1 };",
+        "type I = 'a' | 1 | -1 | true | 1n | `x` | unique symbol | asserts | this;",
+        "function f(this: Foo, x?: number): x is string { return true; } function g(x: unknown): asserts x {}",
+        "declare function h<const T>(x: T): T; declare const x: any;",
+        "interface J<in out T> extends K<T>, L.M { a: T; } abstract class N<T> extends O<T> implements P, Q.R<T> { abstract a: string; private readonly b?: number; protected static c!: boolean; public abstract m(): void; declare d: number; override accessor e = 1; }",
+        "class S { constructor(private readonly a: string, public b?: number) {} [key: string]: any; }",
+        "enum T { A, B = 1, 'C' = 'c' } const enum U { V } declare enum W {}",
+        "namespace X.Y.Z { export const a = 1; } declare module 'm' { export type A = 1; } declare global { var b: number; }",
+        "import type { A } from 'a'; import { type B, C } from 'b'; export type { D }; export { type E } from 'e';",
+        "import F = G.H; import I = require('i'); export import J = K; export = L; export as namespace M;",
+        "a as B; a satisfies C; <D>a; a!; f<E>; a!.b; new F<G>(); h<I>(); j`k`;",
+        "let fn = <T,>(a: T): T => a; for (const x of y as Z[]) {}",
+        "@dec class Dec { @dec() m(@param p) {} }",
+    ];
+    for case in cases {
+        test_round_trip(case, SourceType::ts());
+    }
+    test_round_trip("const a = <div<string> b={c as D} />;", SourceType::tsx());
+}
+
+#[test]
+fn errors() {
+    let allocator = Allocator::default();
+    let deserializer = Deserializer::new(&allocator);
+
+    let err = deserializer
+        .deserialize_statement(
+            r#"{"type":"ExpressionStatement","expression":{"type":"Foo","start":3,"end":6}}"#,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        DeserializeError::UnknownNodeType {
+            node_type: "Foo".to_string(),
+            expected: "Expression",
+            span: Span::new(3, 6),
+        }
+    );
+    assert_eq!(err.to_string(), "Unknown node type `Foo` at 3..6: expected Expression");
+
+    let err = deserializer
+        .deserialize_expression(
+            r#"{"type":"BinaryExpression","operator":"+","left":{"type":"Identifier","name":"a"}}"#,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        DeserializeError::MissingField {
+            node_type: "BinaryExpression".to_string(),
+            field: "right",
+            span: SPAN,
+        }
+    );
+
+    let err = deserializer
+        .deserialize_statement(
+            r#"{"type":"VariableDeclaration","kind":"variable","declarations":[]}"#,
+        )
+        .unwrap_err();
+    assert!(matches!(err, DeserializeError::InvalidField { field: "kind", .. }));
+
+    let err = deserializer.deserialize_program("{", "", SourceType::mjs()).unwrap_err();
+    assert!(matches!(err, DeserializeError::InvalidJson(_)));
+}
@@ -18,6 +18,7 @@ use crate::{
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<DiagnosticTuple>;
 pub type DiagnosticReceiver = mpsc::Receiver<DiagnosticTuple>;
+pub type DiagnosticFilter = Box<dyn Fn(&Path, &Error) -> bool>;
 
 /// Listens for diagnostics sent over a [channel](DiagnosticSender) by some job, and
 /// formats/reports them to the user.
@@ -61,6 +62,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Only report diagnostics for which the filter returns `true`
+    filter: Option<DiagnosticFilter>,
+
     receiver: DiagnosticReceiver,
 }
 
@@ -69,7 +73,17 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> (Self, DiagnosticSender) {
         let (sender, receiver) = mpsc::channel();
-        (Self { reporter, quiet: false, silent: false, max_warnings: None, receiver }, sender)
+        (
+            Self {
+                reporter,
+                quiet: false,
+                silent: false,
+                max_warnings: None,
+                filter: None,
+                receiver,
+            },
+            sender,
+        )
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -108,6 +122,16 @@ impl DiagnosticService {
         self
     }
 
+    /// Only report diagnostics for which `filter` returns `true`. Diagnostics which are filtered
+    /// out do not count towards the number of warnings and errors.
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_filter(mut self, filter: Option<DiagnosticFilter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Check if the max warning threshold, as set by
     /// [`with_max_warnings`](DiagnosticService::with_max_warnings), has been exceeded.
     fn max_warnings_exceeded(&self, warnings_count: usize) -> bool {
@@ -159,6 +183,10 @@ impl DiagnosticService {
         while let Ok((path, diagnostics)) = self.receiver.recv() {
             let mut is_minified = false;
             for diagnostic in diagnostics {
+                if self.filter.as_ref().is_some_and(|filter| !filter(&path, &diagnostic)) {
+                    continue;
+                }
+
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
@@ -27,7 +27,8 @@ impl<'a> MayHaveSideEffects<'a> for Statement<'a> {
             | Statement::ForStatement(_)
             | Statement::ThrowStatement(_)
             | Statement::WithStatement(_)
-            | Statement::DebuggerStatement(_) => true,
+            | Statement::DebuggerStatement(_)
+            | Statement::ErrorStatement(_) => true,
             #[expect(clippy::match_same_arms)]
             match_module_declaration!(Statement) => true,
         }
@@ -41,6 +41,8 @@ fn main() -> Result<(), String> {
             allow_return_outside_function: true,
             // `oxc_formatter` expects this to be false
             preserve_parens: false,
+            tokens: false,
+            error_recovery: false,
         })
         .parse();
 
@@ -0,0 +1,390 @@
+//! A pretty-printer for CSS, covering the nested rules used by styled-components.
+//!
+//! Statements are printed one per line and rules are indented, the text of selectors and declarations
+//! is only normalized in its whitespace.
+
+use super::{PLACEHOLDER_PREFIX, is_placeholders};
+
+/// Formats `code`, returns `None` if it is not valid CSS.
+pub fn format(code: &str, indent: &str) -> Option<String> {
+    let entries = Parser { text: code, pos: 0 }.parse_block(false)?;
+    let mut printer = Printer { out: String::new(), indent };
+    printer.print_block(&entries, 0);
+    Some(printer.out.trim_end().to_string())
+}
+
+enum Node<'a> {
+    /// A declaration like `color: red`, or an at-rule without a block like `@import "a.css"`.
+    Declaration {
+        text: &'a str,
+        semicolon: bool,
+    },
+    /// A rule or an at-rule with a block.
+    Rule {
+        prelude: &'a str,
+        children: Vec<Entry<'a>>,
+    },
+    Comment(&'a str),
+}
+
+struct Entry<'a> {
+    node: Node<'a>,
+    /// Whether the entry is preceded by an empty line.
+    empty_line_before: bool,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Parses the entries of a block, up to and including its `}` if `nested`.
+    fn parse_block(&mut self, nested: bool) -> Option<Vec<Entry<'a>>> {
+        let mut entries = Vec::new();
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            let empty_line_before = !entries.is_empty()
+                && rest[..rest.len() - trimmed.len()].bytes().filter(|&b| b == b'\n').count() > 1;
+            self.pos += rest.len() - trimmed.len();
+
+            let node = if trimmed.is_empty() {
+                return (!nested).then_some(entries);
+            } else if trimmed.starts_with("/*") {
+                let end = trimmed.find("*/")? + 2;
+                self.pos += end;
+                Node::Comment(&trimmed[..end])
+            } else if trimmed.starts_with("//") {
+                let end = trimmed.find('\n').unwrap_or(trimmed.len());
+                self.pos += end;
+                Node::Comment(trimmed[..end].trim_end())
+            } else if trimmed.starts_with('}') {
+                self.pos += 1;
+                return nested.then_some(entries);
+            } else {
+                self.parse_statement(nested)?
+            };
+            entries.push(Entry { node, empty_line_before });
+        }
+    }
+
+    fn parse_statement(&mut self, nested: bool) -> Option<Node<'a>> {
+        let start = self.pos;
+        let mut depth = 0u32;
+        let mut chars = self.rest().char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '"' | '\'' => {
+                    // Skip the string, escaped quotes included
+                    let mut escaped = false;
+                    loop {
+                        let (_, next) = chars.next()?;
+                        if next == '\n' {
+                            return None;
+                        }
+                        if escaped {
+                            escaped = false;
+                        } else if next == '\\' {
+                            escaped = true;
+                        } else if next == c {
+                            break;
+                        }
+                    }
+                }
+                '/' if chars.peek().is_some_and(|(_, c)| *c == '*') => {
+                    let end = self.rest()[offset..].find("*/")?;
+                    while chars.peek().is_some_and(|(next, _)| *next < offset + end + 2) {
+                        chars.next();
+                    }
+                }
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth = depth.checked_sub(1)?,
+                _ if depth > 0 => {}
+                ';' => {
+                    self.pos += offset + 1;
+                    let text = &self.text[start..start + offset];
+                    return Some(Node::Declaration { text, semicolon: true });
+                }
+                '{' => {
+                    self.pos += offset + 1;
+                    let prelude = &self.text[start..start + offset];
+                    let children = self.parse_block(true)?;
+                    return Some(Node::Rule { prelude, children });
+                }
+                '}' => {
+                    // The last declaration of a block can omit its semicolon
+                    self.pos += offset;
+                    let text = &self.text[start..start + offset];
+                    return Some(Node::Declaration { text, semicolon: false });
+                }
+                // Interpolations like `${mixin}` are usually not followed by a semicolon
+                '\n' if is_placeholders(&self.text[start..start + offset]) => {
+                    self.pos += offset;
+                    let text = &self.text[start..start + offset];
+                    return Some(Node::Declaration { text, semicolon: false });
+                }
+                _ => {}
+            }
+        }
+        if depth > 0 || nested {
+            return None;
+        }
+        let text = self.rest();
+        self.pos = self.text.len();
+        Some(Node::Declaration { text, semicolon: false })
+    }
+}
+
+struct Printer<'a> {
+    out: String,
+    indent: &'a str,
+}
+
+impl Printer<'_> {
+    fn print_block(&mut self, entries: &[Entry], level: usize) {
+        for entry in entries {
+            if entry.empty_line_before {
+                self.out.push('\n');
+            }
+            self.print_indent(level);
+            match &entry.node {
+                Node::Comment(comment) => self.out.push_str(comment),
+                Node::Declaration { text, semicolon } => {
+                    self.out.push_str(&format_declaration(text));
+                    // Semicolons are only optional after interpolations
+                    if *semicolon || !is_placeholders(text) {
+                        self.out.push(';');
+                    }
+                }
+                Node::Rule { prelude, children } => {
+                    if is_at_rule(prelude) {
+                        self.out.push_str(&collapse_whitespace(prelude));
+                    } else {
+                        // Every selector of a list is printed on its own line
+                        for (index, selector) in split_top_level(prelude, ',').enumerate() {
+                            if index > 0 {
+                                self.out.push_str(",\n");
+                                self.print_indent(level);
+                            }
+                            self.out.push_str(&format_selector(selector));
+                        }
+                    }
+                    if children.is_empty() {
+                        self.out.push_str(" {}");
+                    } else {
+                        self.out.push_str(" {\n");
+                        self.print_block(children, level + 1);
+                        self.print_indent(level);
+                        self.out.push('}');
+                    }
+                }
+            }
+            self.out.push('\n');
+        }
+    }
+
+    fn print_indent(&mut self, level: usize) {
+        for _ in 0..level {
+            self.out.push_str(self.indent);
+        }
+    }
+}
+
+fn is_at_rule(text: &str) -> bool {
+    text.starts_with('@') && !text.starts_with(PLACEHOLDER_PREFIX)
+}
+
+/// `color:red` -> `color: red`
+fn format_declaration(text: &str) -> String {
+    let text = text.trim();
+    if is_at_rule(text) {
+        return collapse_whitespace(text);
+    }
+    let mut parts = split_top_level(text, ':');
+    match (parts.next(), parts.next()) {
+        (Some(property), Some(_)) => {
+            let value = &text[property.len() + 1..];
+            format!("{}: {}", property.trim(), format_value(value))
+        }
+        _ => collapse_whitespace(text),
+    }
+}
+
+/// Collapses whitespace and adds a space after every comma, e.g. `rgba(0,0,0, .5)` -> `rgba(0, 0, 0, .5)`
+fn format_value(value: &str) -> String {
+    let collapsed = collapse_whitespace(value);
+    let mut out = String::with_capacity(collapsed.len());
+    let mut quote = None;
+    for c in collapsed.chars() {
+        match quote {
+            Some(q) => {
+                if c == q && !out.ends_with('\\') {
+                    quote = None;
+                }
+                out.push(c);
+            }
+            None if c == ',' => {
+                if out.ends_with(' ') {
+                    out.pop();
+                }
+                out.push_str(", ");
+            }
+            None if c == ' ' && out.ends_with(' ') => {}
+            None => {
+                if matches!(c, '"' | '\'') {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// Adds spaces around combinators, e.g. `a>b` -> `a > b`
+fn format_selector(selector: &str) -> String {
+    let collapsed = collapse_whitespace(selector);
+    let mut out = String::with_capacity(collapsed.len());
+    let mut depth = 0u32;
+    let mut chars = collapsed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            '>' | '+' | '~' if depth == 0 => {
+                if out.ends_with(' ') {
+                    out.pop();
+                }
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push(c);
+                out.push(' ');
+                while chars.next_if_eq(&' ').is_some() {}
+                continue;
+            }
+            _ => {}
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Replaces every sequence of whitespace outside of strings by a single space, and trims the text.
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut quote = None;
+    let mut escaped = false;
+    for c in text.trim().chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+                out.push(c);
+            }
+            None if c.is_whitespace() => {
+                if !out.ends_with(' ') {
+                    out.push(' ');
+                }
+            }
+            None => {
+                if matches!(c, '"' | '\'') {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+/// Splits `text` by `separator`, outside of strings, parentheses and brackets.
+fn split_top_level(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    let mut depth = 0u32;
+    let mut quote = None;
+    let mut escaped = false;
+    text.split(move |c: char| {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth = depth.saturating_sub(1),
+                _ => return c == separator && depth == 0,
+            },
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::format;
+
+    #[test]
+    fn rules() {
+        assert_eq!(
+            format("a,b>c{color:red;margin:0 auto}\n\n\n@media (max-width:100px){a{b:c}}", "  ")
+                .unwrap(),
+            "a,\nb > c {\n  color: red;\n  margin: 0 auto;\n}\n\n@media (max-width:100px) {\n  a {\n    b: c;\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn declarations() {
+        assert_eq!(
+            format(
+                "color : rgba(0,0,0 ,.5) ;\nfont-family:\"a,  b\",serif;\n@import url(x.css)\n",
+                "\t"
+            )
+            .unwrap(),
+            "color: rgba(0, 0, 0, .5);\nfont-family: \"a,  b\", serif;\n@import url(x.css);"
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            format(
+                "@prettier-placeholder-0\n  @prettier-placeholder-1;\n@prettier-placeholder-2:hover{color:@prettier-placeholder-3}",
+                "  "
+            )
+            .unwrap(),
+            "@prettier-placeholder-0\n@prettier-placeholder-1;\n@prettier-placeholder-2:hover {\n  color: @prettier-placeholder-3;\n}"
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            format("/* a */\na{ // b\ncolor: red; /* c */}", "  ").unwrap(),
+            "/* a */\na {\n  // b\n  color: red;\n  /* c */\n}"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(format("a { color: red;", "  "), None);
+        assert_eq!(format("color: red; }", "  "), None);
+        assert_eq!(format("content: \"a", "  "), None);
+        assert_eq!(format("a { b: c(d; }", "  "), None);
+    }
+}
@@ -0,0 +1,341 @@
+//! A pretty-printer for GraphQL documents.
+//!
+//! Works on tokens: selections and fields of type definitions are printed one per line,
+//! arguments, lists and input objects stay on the line of their field.
+
+use super::{PLACEHOLDER_PREFIX, placeholder_len};
+
+/// Formats `code`, returns `None` if it is not valid GraphQL.
+pub fn format(code: &str, indent: &str) -> Option<String> {
+    let tokens = lex(code)?;
+    let mut printer = Printer { out: String::new(), indent, stack: Vec::new() };
+    for (index, token) in tokens.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| &tokens[index]);
+        let before_previous = index.checked_sub(2).map(|index| &tokens[index]);
+        printer.print(token, previous, before_previous)?;
+    }
+    printer.stack.is_empty().then_some(printer.out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Punctuator,
+    /// Names, numbers and placeholders
+    Name,
+    String,
+    Comment,
+}
+
+#[derive(Debug)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    /// The number of line breaks between the previous token and this one.
+    lines_before: usize,
+}
+
+impl Token<'_> {
+    fn is(&self, text: &str) -> bool {
+        self.kind != Kind::String && self.kind != Kind::Comment && self.text == text
+    }
+
+    fn is_placeholder(&self) -> bool {
+        self.kind == Kind::Name && self.text.starts_with(PLACEHOLDER_PREFIX)
+    }
+
+    /// Whether the token can start a selection, a field or an argument.
+    fn starts_item(&self) -> bool {
+        matches!(self.kind, Kind::Name | Kind::String) || self.is("...") || self.is("$")
+    }
+
+    /// Whether the token can end a field, an argument or a value.
+    fn ends_item(&self) -> bool {
+        matches!(self.kind, Kind::Name | Kind::String)
+            || matches!(self.text, "]" | "}" | ")" | "!") && self.kind == Kind::Punctuator
+    }
+}
+
+fn lex(code: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut lines_before = 0;
+    let mut pos = 0;
+    let bytes = code.as_bytes();
+    while pos < bytes.len() {
+        let rest = &code[pos..];
+        let (kind, len) = match bytes[pos] {
+            b'\n' => {
+                lines_before += 1;
+                pos += 1;
+                continue;
+            }
+            // Commas are insignificant, like whitespace
+            b' ' | b'\t' | b'\r' | b',' => {
+                pos += 1;
+                continue;
+            }
+            b'#' => (Kind::Comment, rest.find('\n').unwrap_or(rest.len())),
+            b'"' if rest.starts_with("\"\"\"") => {
+                let mut end = 3;
+                loop {
+                    end += rest[end..].find("\"\"\"")?;
+                    if rest.as_bytes()[end - 1] != b'\\' {
+                        break (Kind::String, end + 3);
+                    }
+                    end += 3;
+                }
+            }
+            b'"' => {
+                let mut escaped = false;
+                let len = rest[1..].find(|c| {
+                    let end = !escaped && c == '"';
+                    escaped = !escaped && c == '\\';
+                    end || c == '\n'
+                })?;
+                if rest.as_bytes()[len + 1] == b'\n' {
+                    return None;
+                }
+                (Kind::String, len + 2)
+            }
+            b'.' if rest.starts_with("...") => (Kind::Punctuator, 3),
+            b'@' if placeholder_len(rest).is_some() => (Kind::Name, placeholder_len(rest)?),
+            b'!' | b'$' | b'&' | b'(' | b')' | b'[' | b']' | b'{' | b'}' | b':' | b'=' | b'@'
+            | b'|' => (Kind::Punctuator, 1),
+            b'-' | b'0'..=b'9' => {
+                let len = rest[1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')))
+                    .map_or(rest.len(), |len| len + 1);
+                (Kind::Name, len)
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                (Kind::Name, len)
+            }
+            _ => return None,
+        };
+        tokens.push(Token { kind, text: &rest[..len], lines_before });
+        lines_before = 0;
+        pos += len;
+    }
+    Some(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    /// A selection set, or the fields of a type definition, one per line.
+    Selection,
+    /// An input object value, on a single line.
+    Object,
+    /// Arguments or variable definitions.
+    Parentheses,
+    List,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    None,
+    Space,
+    Comma,
+    Line,
+    EmptyLine,
+}
+
+/// Keywords starting a definition at the top level of a document.
+const DEFINITION_KEYWORDS: [&str; 13] = [
+    "query",
+    "mutation",
+    "subscription",
+    "fragment",
+    "schema",
+    "scalar",
+    "type",
+    "interface",
+    "union",
+    "enum",
+    "input",
+    "directive",
+    "extend",
+];
+
+struct Printer<'a> {
+    out: String,
+    indent: &'a str,
+    stack: Vec<Context>,
+}
+
+impl Printer<'_> {
+    fn print(
+        &mut self,
+        token: &Token,
+        previous: Option<&Token>,
+        before_previous: Option<&Token>,
+    ) -> Option<()> {
+        let separator = previous
+            .map_or(Separator::None, |previous| self.separator(token, previous, before_previous));
+
+        if token.kind == Kind::Punctuator {
+            match token.text {
+                "{" => {
+                    let context = match self.stack.last() {
+                        None | Some(Context::Selection)
+                            if !previous
+                                .is_some_and(|previous| previous.is(":") || previous.is("=")) =>
+                        {
+                            Context::Selection
+                        }
+                        _ => Context::Object,
+                    };
+                    self.stack.push(context);
+                }
+                "(" => self.stack.push(Context::Parentheses),
+                "[" => self.stack.push(Context::List),
+                "}" => {
+                    self.stack.pop().filter(|context| {
+                        matches!(context, Context::Selection | Context::Object)
+                    })?;
+                }
+                ")" => {
+                    self.stack.pop().filter(|context| *context == Context::Parentheses)?;
+                }
+                "]" => {
+                    self.stack.pop().filter(|context| *context == Context::List)?;
+                }
+                _ => {}
+            }
+        }
+
+        match separator {
+            Separator::None => {}
+            Separator::Space => self.out.push(' '),
+            Separator::Comma => self.out.push_str(", "),
+            Separator::Line | Separator::EmptyLine => {
+                if separator == Separator::EmptyLine {
+                    self.out.push('\n');
+                }
+                self.out.push('\n');
+                // The selection set of `{` is already pushed, the one of `}` already popped
+                let level = self.stack.iter().filter(|c| **c == Context::Selection).count()
+                    - usize::from(token.is("{"));
+                for _ in 0..level {
+                    self.out.push_str(self.indent);
+                }
+            }
+        }
+        self.out.push_str(token.text);
+        Some(())
+    }
+
+    fn separator(
+        &self,
+        token: &Token,
+        previous: &Token,
+        before_previous: Option<&Token>,
+    ) -> Separator {
+        let line = if token.lines_before > 1 { Separator::EmptyLine } else { Separator::Line };
+        if previous.kind == Kind::Comment {
+            return line;
+        }
+        if token.kind == Kind::Comment {
+            return if token.lines_before == 0 { Separator::Space } else { line };
+        }
+        if token.kind == Kind::Punctuator {
+            match token.text {
+                "}" if self.stack.last() == Some(&Context::Selection) => return Separator::Line,
+                "(" | ")" | "]" | "!" | ":" => return Separator::None,
+                _ => {}
+            }
+        }
+        if previous.kind == Kind::Punctuator {
+            match previous.text {
+                "(" | "[" | "$" | "@" => return Separator::None,
+                "..." => {
+                    return if token.is("on") || token.is("@") || token.is("{") {
+                        Separator::Space
+                    } else {
+                        Separator::None
+                    };
+                }
+                "{" if self.stack.last() == Some(&Context::Object) => return Separator::Space,
+                _ => {}
+            }
+        }
+
+        match self.stack.last() {
+            None => {
+                // Definitions are separated by an empty line
+                let starts_definition = (token.kind == Kind::Name
+                    && DEFINITION_KEYWORDS.contains(&token.text)
+                    && !previous.is("extend"))
+                    || token.is_placeholder()
+                    || previous.is_placeholder()
+                    || (token.is("{") && previous.is("}"));
+                if starts_definition { Separator::EmptyLine } else { Separator::Space }
+            }
+            Some(Context::Selection) => {
+                // `alias: field`, `field: Type = default`, `... on Type`
+                let continues_item = (previous.kind == Kind::Punctuator
+                    && matches!(previous.text, ":" | "=" | "|" | "&"))
+                    || (previous.is("on") && before_previous.is_some_and(|token| token.is("...")));
+                if token.starts_item() && !continues_item {
+                    if previous.is("{") { Separator::Line } else { line }
+                } else {
+                    Separator::Space
+                }
+            }
+            Some(Context::Object | Context::Parentheses | Context::List) => {
+                if (token.starts_item() || token.is("[") || token.is("{")) && previous.ends_item() {
+                    Separator::Comma
+                } else {
+                    Separator::Space
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::format;
+
+    #[test]
+    fn operations() {
+        assert_eq!(
+            format(
+                "query Q($a: [Int!]! = [1,2], $b: In = {x: 1}) { a: b(c: $a, d: {e: \"f\"}) @include(if: $b) { ...F ... on T { g } } h }",
+                "  "
+            )
+            .unwrap(),
+            "query Q($a: [Int!]! = [1, 2], $b: In = { x: 1 }) {\n  a: b(c: $a, d: { e: \"f\" }) @include(if: $b) {\n    ...F\n    ... on T {\n      g\n    }\n  }\n  h\n}"
+        );
+    }
+
+    #[test]
+    fn definitions() {
+        assert_eq!(
+            format(
+                "fragment F on User { id }\n# comment\nmutation { a }\n{ b }\ntype T implements A & B {\n  a(b: Int = 1): [String!]!\n\n  c: U }\nunion U = A | B\nextend type T { d: Int }",
+                "\t"
+            )
+            .unwrap(),
+            "fragment F on User {\n\tid\n}\n# comment\nmutation {\n\ta\n}\n\n{\n\tb\n}\n\ntype T implements A & B {\n\ta(b: Int = 1): [String!]!\n\n\tc: U\n}\n\nunion U = A | B\n\nextend type T {\n\td: Int\n}"
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            format("query { ...F }\n@prettier-placeholder-0\n@prettier-placeholder-1", "  ")
+                .unwrap(),
+            "query {\n  ...F\n}\n\n@prettier-placeholder-0\n\n@prettier-placeholder-1"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(format("query { a", "  "), None);
+        assert_eq!(format("query { a ) }", "  "), None);
+        assert_eq!(format("query { a(b: \"c) }", "  "), None);
+        assert_eq!(format("query { a % b }", "  "), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Formatting of code in other languages embedded in tagged templates,
+//! e.g. the CSS of styled-components or GraphQL queries.
+
+mod css;
+mod graphql;
+
+use oxc_ast::{
+    STYLED_COMPONENTS_CSS_HELPERS,
+    ast::{Expression, IdentifierReference},
+};
+
+use crate::FormatOptions;
+
+/// Placeholders replacing the `${}` expressions of a template in the embedded code,
+/// followed by the index of the expression, e.g. `@prettier-placeholder-0`.
+///
+/// Uses the same format as Prettier, so that the embedded code is still valid in most places.
+pub const PLACEHOLDER_PREFIX: &str = "@prettier-placeholder-";
+
+/// A language which can be embedded in a tagged template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddedLanguage {
+    /// ``styled.div`...` ``, ``css`...` ``, ``keyframes`...` `` and ``createGlobalStyle`...` ``
+    Css,
+    /// ``gql`...` `` and ``graphql`...` ``
+    GraphQL,
+    /// ``html`...` ``
+    Html,
+}
+
+impl EmbeddedLanguage {
+    /// Returns the language of a tagged template from its `tag`.
+    ///
+    /// Recognizes the same styled-components tags as the transformer's `styled_components` plugin,
+    /// by name only.
+    pub fn from_tag(tag: &Expression) -> Option<Self> {
+        let is_styled = |ident: &IdentifierReference| ident.name == "styled";
+        match tag.without_parentheses() {
+            Expression::Identifier(ident) => match ident.name.as_str() {
+                name if STYLED_COMPONENTS_CSS_HELPERS.contains(&name) => Some(Self::Css),
+                "gql" | "graphql" => Some(Self::GraphQL),
+                "html" => Some(Self::Html),
+                _ => None,
+            },
+            // `graphql.experimental`
+            Expression::StaticMemberExpression(member)
+                if member.property.name == "experimental"
+                    && matches!(&member.object, Expression::Identifier(ident) if ident.name == "graphql") =>
+            {
+                Some(Self::GraphQL)
+            }
+            tag => tag.is_styled_components_tag(&is_styled, &is_styled).then_some(Self::Css),
+        }
+    }
+}
+
+/// Formats code embedded in tagged templates, see [`crate::Formatter::with_embedded_formatter`].
+pub trait EmbeddedFormatter {
+    /// Formats `code` of `language`, indented with the indent style of `options`.
+    ///
+    /// The `${}` expressions of the template are replaced by placeholders (see [`PLACEHOLDER_PREFIX`]),
+    /// every one of them must be kept in the formatted code.
+    /// Returns `None` when the code can't be formatted, the template is then printed as-is.
+    fn format(
+        &self,
+        language: EmbeddedLanguage,
+        code: &str,
+        options: &FormatOptions,
+    ) -> Option<String>;
+}
+
+/// The built-in formatter of embedded CSS and GraphQL.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BuiltinEmbeddedFormatter;
+
+impl EmbeddedFormatter for BuiltinEmbeddedFormatter {
+    fn format(
+        &self,
+        language: EmbeddedLanguage,
+        code: &str,
+        options: &FormatOptions,
+    ) -> Option<String> {
+        let indent = if options.indent_style.is_tab() {
+            "\t".to_string()
+        } else {
+            " ".repeat(usize::from(options.indent_width.value()))
+        };
+        match language {
+            EmbeddedLanguage::Css => css::format(code, &indent),
+            EmbeddedLanguage::GraphQL => graphql::format(code, &indent),
+            EmbeddedLanguage::Html => None,
+        }
+    }
+}
+
+/// Returns the length of the placeholder at the start of `text`.
+fn placeholder_len(text: &str) -> Option<usize> {
+    let index = text.strip_prefix(PLACEHOLDER_PREFIX)?;
+    let digits = index.bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0).then_some(PLACEHOLDER_PREFIX.len() + digits)
+}
+
+/// Returns `true` if `text` consists of nothing but placeholders separated by whitespace.
+fn is_placeholders(text: &str) -> bool {
+    let mut words = text.split_ascii_whitespace().peekable();
+    words.peek().is_some() && words.all(|word| placeholder_len(word) == Some(word.len()))
+}
+
+/// Splits `line` into text and the indices of placeholders.
+pub fn split_placeholders(line: &str) -> impl Iterator<Item = Result<&str, usize>> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        if let Some(len) = placeholder_len(rest) {
+            let index = rest[PLACEHOLDER_PREFIX.len()..len].parse().ok()?;
+            rest = &rest[len..];
+            return Some(Err(index));
+        }
+        // Skip the first character, which is not the start of a placeholder
+        let end = rest[1..].find(PLACEHOLDER_PREFIX).map_or(rest.len(), |end| end + 1);
+        let (text, after) = rest.split_at(end);
+        rest = after;
+        Some(Ok(text))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::split_placeholders;
+    use crate::{
+        BuiltinEmbeddedFormatter, EmbeddedFormatter, EmbeddedLanguage, EmbeddedLanguageFormatting,
+        FormatOptions, Formatter,
+    };
+
+    /// Formats `source_text` with embedded formatting enabled, and checks that the result is stable.
+    fn format(source_text: &str) -> String {
+        let format_once = |source_text: &str| {
+            let allocator = Allocator::new();
+            let ret = Parser::new(&allocator, source_text, SourceType::tsx()).parse();
+            assert!(ret.errors.is_empty());
+            let options = FormatOptions {
+                embedded_language_formatting: EmbeddedLanguageFormatting::Auto,
+                ..FormatOptions::default()
+            };
+            Formatter::new(&allocator, options).build(&ret.program)
+        };
+        let formatted = format_once(source_text);
+        assert_eq!(format_once(&formatted), formatted);
+        formatted
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            split_placeholders(
+                "a @prettier-placeholder-1@prettier-placeholder-23 b @prettier-placeholder-"
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok("a "), Err(1), Err(23), Ok(" b "), Ok("@prettier-placeholder-")]
+        );
+    }
+
+    #[test]
+    fn styled_components() {
+        assert_eq!(
+            format(
+                "const Button = styled.a`\ndisplay:inline-block;\n  color:   ${(props) => props.color};\n  ${(props) => props.primary && css`\n    background: white;color: black;\n  `}\n&:hover{opacity:.5}\n`;\n"
+            ),
+            "const Button = styled.a`\n  display: inline-block;\n  color: ${(props) => props.color};\n  ${(props) =>\n    props.primary &&\n    css`\n      background: white;\n      color: black;\n    `}\n  &:hover {\n    opacity: .5;\n  }\n`;\n"
+        );
+        assert_eq!(
+            format("const Link = styled(Base).attrs({ href: \"#\" })`color:red`;\n"),
+            "const Link = styled(Base).attrs({ href: \"#\" })`\n  color: red;\n`;\n"
+        );
+    }
+
+    #[test]
+    fn graphql() {
+        assert_eq!(
+            format(
+                "const query = gql`\n  query   User($id: ID!,$full:Boolean = false) { user(id: $id) { id, name ...UserFields } }\n  ${USER_FIELDS}\n`;\n"
+            ),
+            "const query = gql`\n  query User($id: ID!, $full: Boolean = false) {\n    user(id: $id) {\n      id\n      name\n      ...UserFields\n    }\n  }\n\n  ${USER_FIELDS}\n`;\n"
+        );
+    }
+
+    #[test]
+    fn verbatim() {
+        // Parse errors
+        for source_text in [
+            "const a = css`\n  color: red; }\n`;\n",
+            "const a = gql`\n  query { a\n`;\n",
+            // No built-in HTML formatter
+            "const a = html`<div>${a}</div>`;\n",
+            // Not a known tag
+            "const a = sql`select   1`;\n",
+        ] {
+            assert_eq!(format(source_text), source_text);
+        }
+        // Disabled by default
+        let allocator = Allocator::new();
+        let source_text = "const a = css`color:red`;\n";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert_eq!(
+            Formatter::new(&allocator, FormatOptions::default()).build(&ret.program),
+            source_text
+        );
+    }
+
+    #[test]
+    fn custom_formatter() {
+        struct Html;
+        impl EmbeddedFormatter for Html {
+            fn format(
+                &self,
+                language: EmbeddedLanguage,
+                code: &str,
+                options: &FormatOptions,
+            ) -> Option<String> {
+                match language {
+                    EmbeddedLanguage::Html => Some(code.trim().to_string()),
+                    _ => BuiltinEmbeddedFormatter.format(language, code, options),
+                }
+            }
+        }
+
+        let allocator = Allocator::new();
+        let source_text = "const a = html`  <p>${a}</p>  `;\nconst b = css`color:red`;\n";
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        let options = FormatOptions {
+            embedded_language_formatting: EmbeddedLanguageFormatting::Auto,
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            Formatter::new(&allocator, options).with_embedded_formatter(Html).build(&ret.program),
+            "const a = html`\n  <p>${a}</p>\n`;\nconst b = css`\n  color: red;\n`;\n"
+        );
+    }
+}
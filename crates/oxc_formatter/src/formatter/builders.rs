@@ -2449,7 +2449,50 @@ where
                 }
             }
             self.has_elements = true;
-            write!(self.fmt, content)
+
+            let Some(range_entries) = self.fmt.context().range_entries() else {
+                return write!(self.fmt, content);
+            };
+            let is_first = range_entries.first == span;
+            let is_last = range_entries.last == span;
+            if !is_first && !is_last {
+                return write!(self.fmt, content);
+            }
+
+            let printed_count = self.fmt.comments().printed_comments().len();
+            if is_first {
+                self.fmt.write_element(FormatElement::SourcePosition(span.start))?;
+            }
+            write!(self.fmt, content)?;
+            if is_last {
+                self.fmt.write_element(FormatElement::SourcePosition(span.end))?;
+            }
+
+            // The comments printed by this entry are replaced along with it
+            let comments = &self.fmt.comments().printed_comments()[printed_count..];
+            let start =
+                comments.first().map_or(span.start, |comment| comment.span.start.min(span.start));
+            let mut end =
+                comments.last().map_or(span.end, |comment| comment.span.end.max(span.end));
+            // So is the separator, which is printed by the entry as well
+            let source_text = self.fmt.source_text();
+            let after = source_text.slice_from(end);
+            let trimmed = after.trim_ascii_start();
+            if trimmed.starts_with([',', ';']) {
+                #[expect(clippy::cast_possible_truncation)]
+                let separator_end = (after.len() - trimmed.len() + 1) as u32;
+                end += separator_end;
+            }
+
+            if let Some(range_entries) = self.fmt.context_mut().range_entries_mut() {
+                if is_first {
+                    range_entries.source.start = start;
+                }
+                if is_last {
+                    range_entries.source.end = end;
+                }
+            }
+            Ok(())
         });
     }
 
@@ -465,12 +465,16 @@ impl<'a> Comments<'a> {
         &[]
     }
 
-    /// Checks if the node has a suppression comment (prettier-ignore).
+    /// Checks if the node has a suppression comment (`oxfmt-ignore` or `prettier-ignore`).
     pub fn is_suppressed(&self, start: u32) -> bool {
-        self.comments_before(start).iter().any(|comment| {
-            // TODO: Consider using `oxc-formatter-ignore` instead of `prettier-ignore`
-            self.source_text.text_for(&comment.content_span()).trim() == "prettier-ignore"
-        })
+        self.comments_before(start)
+            .iter()
+            .any(|comment| matches!(self.comment_text(comment), "oxfmt-ignore" | "prettier-ignore"))
+    }
+
+    /// Returns the content of a comment, without its delimiters and surrounding whitespace.
+    pub fn comment_text(&self, comment: &Comment) -> &'a str {
+        self.source_text.text_for(&comment.content_span()).trim()
     }
 
     /// Checks if a comment is a type cast comment containing `@type` or `@satisfies`.
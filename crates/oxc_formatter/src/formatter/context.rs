@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, rc::Rc};
 
 use oxc_allocator::Allocator;
 use oxc_ast::{
@@ -8,7 +8,12 @@ use oxc_ast::{
 use oxc_span::{GetSpan, SourceType, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{formatter::FormatElement, generated::ast_nodes::AstNode, options::FormatOptions};
+use crate::{
+    embed::{BuiltinEmbeddedFormatter, EmbeddedFormatter},
+    formatter::FormatElement,
+    generated::ast_nodes::AstNode,
+    options::FormatOptions,
+};
 
 use super::{Comments, SourceText};
 
@@ -25,9 +30,23 @@ pub struct FormatContext<'ast> {
 
     cached_elements: FxHashMap<Span, FormatElement<'ast>>,
 
+    range_entries: Option<RangeEntries>,
+
+    embedded_formatter: Option<Rc<dyn EmbeddedFormatter + 'ast>>,
+
     allocator: &'ast Allocator,
 }
 
+/// The first and the last entry of the innermost list of statements, class members or object properties
+/// enclosing the range to format, see [`crate::Formatter::build_range`].
+#[derive(Debug, Clone, Copy)]
+pub struct RangeEntries {
+    pub first: Span,
+    pub last: Span,
+    /// The source text covered by the formatted entries, including their comments and trailing separator.
+    pub source: Span,
+}
+
 impl std::fmt::Debug for FormatContext<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FormatContext")
@@ -36,6 +55,7 @@ impl std::fmt::Debug for FormatContext<'_> {
             .field("source_type", &self.source_type)
             .field("comments", &self.comments)
             .field("cached_elements", &self.cached_elements)
+            .field("range_entries", &self.range_entries)
             .finish()
     }
 }
@@ -54,9 +74,47 @@ impl<'ast> FormatContext<'ast> {
             comments: Comments::new(source_text, &program.comments),
             allocator,
             cached_elements: FxHashMap::default(),
+            range_entries: None,
+            embedded_formatter: None,
         }
     }
 
+    /// Formats code embedded in tagged templates with `embedded_formatter`,
+    /// if [`FormatOptions::embedded_language_formatting`] is enabled.
+    #[must_use]
+    pub fn with_embedded_formatter(
+        mut self,
+        embedded_formatter: Option<Rc<dyn EmbeddedFormatter + 'ast>>,
+    ) -> Self {
+        self.embedded_formatter = embedded_formatter;
+        self
+    }
+
+    /// Returns the formatter of embedded code, if formatting it is enabled.
+    pub fn embedded_formatter(&self) -> Option<&dyn EmbeddedFormatter> {
+        if !self.options.embedded_language_formatting.is_auto() {
+            return None;
+        }
+        Some(self.embedded_formatter.as_deref().unwrap_or(&BuiltinEmbeddedFormatter))
+    }
+
+    /// Marks the positions of the given list entries in the printed code, used for range formatting.
+    #[must_use]
+    pub fn with_range_entries(mut self, first: Span, last: Span) -> Self {
+        self.range_entries =
+            Some(RangeEntries { first, last, source: Span::new(first.start, last.end) });
+        self
+    }
+
+    /// Returns the list entries to mark in the printed code, if formatting a range.
+    pub fn range_entries(&self) -> Option<RangeEntries> {
+        self.range_entries
+    }
+
+    pub(crate) fn range_entries_mut(&mut self) -> Option<&mut RangeEntries> {
+        self.range_entries.as_mut()
+    }
+
     /// Returns the formatting options
     pub fn options(&self) -> &FormatOptions {
         &self.options
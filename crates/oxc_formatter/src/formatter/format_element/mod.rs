@@ -46,6 +46,10 @@ pub enum FormatElement<'a> {
         slice: TokenText,
     },
 
+    /// Marks the position in the unformatted source code that corresponds to the printed position
+    /// of this element, see [crate::formatter::SourceMarker].
+    SourcePosition(TextSize),
+
     /// Prevents that line suffixes move past this boundary. Forces the printer to print any pending
     /// line suffixes, potentially by inserting a hard line break.
     LineSuffixBoundary,
@@ -77,6 +81,9 @@ impl std::fmt::Debug for FormatElement<'_> {
             FormatElement::LocatedTokenText { slice, .. } => {
                 fmt.debug_tuple("LocatedTokenText").field(slice).finish()
             }
+            FormatElement::SourcePosition(position) => {
+                fmt.debug_tuple("SourcePosition").field(position).finish()
+            }
             FormatElement::LineSuffixBoundary => fmt.write_str("LineSuffixBoundary"),
             FormatElement::BestFitting(best_fitting) => {
                 fmt.debug_tuple("BestFitting").field(&best_fitting).finish()
@@ -250,6 +257,7 @@ impl FormatElements for FormatElement<'_> {
             // the most flat version contains some content that forces a break.
             FormatElement::BestFitting(best_fitting) => best_fitting.most_flat().will_break(),
             FormatElement::LineSuffixBoundary
+            | FormatElement::SourcePosition(_)
             | FormatElement::Space
             | FormatElement::Tag(_)
             | FormatElement::HardSpace => false,
@@ -131,24 +131,55 @@ impl Formatted<'_> {
 
         // Ok(printed)
     }
+
+    /// Prints the document with `prefix` at the start of every line, see [`Printer::print_with_prefix`].
+    pub fn print_with_prefix(&self, prefix: &str) -> PrintResult<Printed> {
+        let print_options = self.context.options().as_print_options();
+        Printer::new(print_options).print_with_prefix(&self.document, prefix)
+    }
 }
 pub type PrintResult<T> = Result<T, PrintError>;
 
+/// Maps a position in the unformatted source code to a position in the printed code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMarker {
+    /// The position in the unformatted source code
+    pub source: TextSize,
+    /// The position in the printed code
+    pub dest: TextSize,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Printed {
     code: String,
     range: Option<TextRange>,
+    sourcemap: Vec<SourceMarker>,
     verbatim_ranges: Vec<TextRange>,
 }
 
 impl Printed {
-    pub fn new(code: String, range: Option<TextRange>, verbatim_source: Vec<TextRange>) -> Self {
-        Self { code, range, verbatim_ranges: verbatim_source }
+    pub fn new(
+        code: String,
+        range: Option<TextRange>,
+        sourcemap: Vec<SourceMarker>,
+        verbatim_source: Vec<TextRange>,
+    ) -> Self {
+        Self { code, range, sourcemap, verbatim_ranges: verbatim_source }
     }
 
     /// Construct an empty formatter result
     pub fn new_empty() -> Self {
-        Self { code: String::new(), range: None, verbatim_ranges: Vec::new() }
+        Self {
+            code: String::new(),
+            range: None,
+            sourcemap: Vec::new(),
+            verbatim_ranges: Vec::new(),
+        }
+    }
+
+    /// The markers of the [FormatElement::SourcePosition] elements in the printed code, in printing order.
+    pub fn sourcemap(&self) -> &[SourceMarker] {
+        &self.sourcemap
     }
 
     /// Range of the input source file covered by this formatted code,
@@ -12,7 +12,7 @@ use unicode_width::UnicodeWidthChar;
 use self::call_stack::PrintIndentStack;
 use super::{
     ActualStart, FormatElement, GroupId, InvalidDocumentError, PrintError, PrintResult, Printed,
-    TextRange, TextSize,
+    SourceMarker, TextRange, TextSize,
     format_element::{BestFittingElement, LineMode, PrintMode, document::Document, tag::Condition},
     prelude::{
         Tag::EndFill,
@@ -48,6 +48,20 @@ impl<'a> Printer<'a> {
         self.print_with_indent(document, 0)
     }
 
+    /// Prints the passed in element as well as all its content, starting every line with `prefix`.
+    ///
+    /// Used for code embedded in other languages, where `prefix` is the indentation of the embedding.
+    /// Lines inside of texts, like the lines of template literals, are not prefixed.
+    pub fn print_with_prefix(
+        mut self,
+        document: &'a Document<'a>,
+        prefix: &'a str,
+    ) -> PrintResult<Printed> {
+        self.state.line_prefix = prefix;
+        self.state.pending_prefix = !prefix.is_empty();
+        self.print(document)
+    }
+
     /// Prints the passed in element as well as all its content,
     /// starting at the specified indentation level
     pub fn print_with_indent(
@@ -67,7 +81,12 @@ impl<'a> Printer<'a> {
             }
         }
 
-        Ok(Printed::new(self.state.buffer, None, self.state.verbatim_markers))
+        Ok(Printed::new(
+            self.state.buffer,
+            None,
+            self.state.source_markers,
+            self.state.verbatim_markers,
+        ))
     }
 
     /// Prints a single element and push the following elements to queue
@@ -100,6 +119,17 @@ impl<'a> Printer<'a> {
             FormatElement::LocatedTokenText { slice, source_position } => {
                 self.print_text(slice);
             }
+            FormatElement::SourcePosition(position) => {
+                if self.state.line_suffixes.has_pending() {
+                    // Line suffixes (e.g. trailing comments) are printed before the next line break,
+                    // defer the marker so that it is placed after them.
+                    self.state.line_suffixes.extend(args, std::iter::once(element));
+                } else {
+                    #[expect(clippy::cast_possible_truncation)]
+                    let dest = self.state.buffer.len() as TextSize;
+                    self.state.source_markers.push(SourceMarker { source: *position, dest });
+                }
+            }
             FormatElement::Line(line_mode) => {
                 if args.mode().is_flat() {
                     match line_mode {
@@ -133,6 +163,7 @@ impl<'a> Printer<'a> {
 
                 self.state.pending_space = false;
                 self.state.pending_indent = indent_stack.indention();
+                self.state.pending_prefix = !self.state.line_prefix.is_empty();
             }
 
             FormatElement::ExpandParent => {
@@ -301,6 +332,11 @@ impl<'a> Printer<'a> {
     }
 
     fn print_text(&mut self, text: &str) {
+        if self.state.pending_prefix {
+            self.state.pending_prefix = false;
+            self.print_str(self.state.line_prefix);
+        }
+
         if !self.state.pending_indent.is_empty() {
             let (indent_char, repeat_count) = match self.options.indent_style() {
                 IndentStyle::Tab => ('\t', 1),
@@ -689,10 +725,14 @@ struct PrinterState<'a> {
     buffer: String,
     pending_indent: Indention,
     pending_space: bool,
+    /// Printed before the indentation of every line, see [`Printer::print_with_prefix`].
+    line_prefix: &'a str,
+    pending_prefix: bool,
     measured_group_fits: bool,
     line_width: usize,
     has_empty_line: bool,
     line_suffixes: LineSuffixes<'a>,
+    source_markers: Vec<SourceMarker>,
     verbatim_markers: Vec<TextRange>,
     group_modes: GroupModes,
     // Re-used queue to measure if a group fits. Optimisation to avoid re-allocating a new
@@ -857,6 +897,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
         let fits_state = FitsState {
             pending_indent: printer.state.pending_indent,
             pending_space: printer.state.pending_space,
+            pending_prefix: printer.state.pending_prefix,
             line_width: printer.state.line_width,
             has_line_suffix: printer.state.line_suffixes.has_pending(),
         };
@@ -1020,6 +1061,8 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             }
             FormatElement::LocatedTokenText { slice, .. } => return Ok(self.fits_text(slice)),
 
+            FormatElement::SourcePosition(_) => {}
+
             FormatElement::LineSuffixBoundary => {
                 if self.state.has_line_suffix {
                     return Ok(Fits::No);
@@ -1158,6 +1201,17 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
     }
 
     fn fits_text(&mut self, text: &str) -> Fits {
+        if std::mem::take(&mut self.state.pending_prefix) {
+            let indent_width = self.options().indent_width().value() as usize;
+            self.state.line_width += self
+                .printer
+                .state
+                .line_prefix
+                .chars()
+                .map(|c| if c == '\t' { indent_width } else { c.width().unwrap_or(0) })
+                .sum::<usize>();
+        }
+
         let indent = std::mem::take(&mut self.state.pending_indent);
         self.state.line_width += indent.level() as usize
             * self.options().indent_width().value() as usize
@@ -1267,6 +1321,7 @@ impl From<bool> for Fits {
 struct FitsState {
     pending_indent: Indention,
     pending_space: bool,
+    pending_prefix: bool,
     has_line_suffix: bool,
     line_width: usize,
 }
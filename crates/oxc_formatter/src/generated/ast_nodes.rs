@@ -33,6 +33,7 @@ pub enum AstNodes<'a> {
     BindingIdentifier(&'a AstNode<'a, BindingIdentifier<'a>>),
     LabelIdentifier(&'a AstNode<'a, LabelIdentifier<'a>>),
     ThisExpression(&'a AstNode<'a, ThisExpression>),
+    ErrorExpression(&'a AstNode<'a, ErrorExpression>),
     ArrayExpression(&'a AstNode<'a, ArrayExpression<'a>>),
     Elision(&'a AstNode<'a, Elision>),
     ObjectExpression(&'a AstNode<'a, ObjectExpression<'a>>),
@@ -72,6 +73,7 @@ pub enum AstNodes<'a> {
     VariableDeclaration(&'a AstNode<'a, VariableDeclaration<'a>>),
     VariableDeclarator(&'a AstNode<'a, VariableDeclarator<'a>>),
     EmptyStatement(&'a AstNode<'a, EmptyStatement>),
+    ErrorStatement(&'a AstNode<'a, ErrorStatement>),
     ExpressionStatement(&'a AstNode<'a, ExpressionStatement<'a>>),
     IfStatement(&'a AstNode<'a, IfStatement<'a>>),
     DoWhileStatement(&'a AstNode<'a, DoWhileStatement<'a>>),
@@ -224,6 +226,7 @@ pub enum SiblingNode<'a> {
     BindingIdentifier(&'a BindingIdentifier<'a>),
     LabelIdentifier(&'a LabelIdentifier<'a>),
     ThisExpression(&'a ThisExpression),
+    ErrorExpression(&'a ErrorExpression),
     ArrayExpression(&'a ArrayExpression<'a>),
     Elision(&'a Elision),
     ObjectExpression(&'a ObjectExpression<'a>),
@@ -262,6 +265,7 @@ pub enum SiblingNode<'a> {
     VariableDeclaration(&'a VariableDeclaration<'a>),
     VariableDeclarator(&'a VariableDeclarator<'a>),
     EmptyStatement(&'a EmptyStatement),
+    ErrorStatement(&'a ErrorStatement),
     ExpressionStatement(&'a ExpressionStatement<'a>),
     IfStatement(&'a IfStatement<'a>),
     DoWhileStatement(&'a DoWhileStatement<'a>),
@@ -443,6 +447,12 @@ impl<'a> From<&'a ThisExpression> for SiblingNode<'a> {
     }
 }
 
+impl<'a> From<&'a ErrorExpression> for SiblingNode<'a> {
+    fn from(node: &'a ErrorExpression) -> Self {
+        SiblingNode::ErrorExpression(node)
+    }
+}
+
 impl<'a> From<&'a ArrayExpression<'a>> for SiblingNode<'a> {
     fn from(node: &'a ArrayExpression<'a>) -> Self {
         SiblingNode::ArrayExpression(node)
@@ -671,6 +681,12 @@ impl<'a> From<&'a EmptyStatement> for SiblingNode<'a> {
     }
 }
 
+impl<'a> From<&'a ErrorStatement> for SiblingNode<'a> {
+    fn from(node: &'a ErrorStatement) -> Self {
+        SiblingNode::ErrorStatement(node)
+    }
+}
+
 impl<'a> From<&'a ExpressionStatement<'a>> for SiblingNode<'a> {
     fn from(node: &'a ExpressionStatement<'a>) -> Self {
         SiblingNode::ExpressionStatement(node)
@@ -1580,6 +1596,7 @@ impl<'a> From<&'a Expression<'a>> for SiblingNode<'a> {
                 SiblingNode::TSInstantiationExpression(inner)
             }
             Expression::V8IntrinsicExpression(inner) => SiblingNode::V8IntrinsicExpression(inner),
+            Expression::ErrorExpression(inner) => SiblingNode::ErrorExpression(inner),
             it @ match_member_expression!(Expression) => {
                 SiblingNode::from(it.to_member_expression())
             }
@@ -1747,6 +1764,7 @@ impl<'a> From<&'a Statement<'a>> for SiblingNode<'a> {
             Statement::TryStatement(inner) => SiblingNode::TryStatement(inner),
             Statement::WhileStatement(inner) => SiblingNode::WhileStatement(inner),
             Statement::WithStatement(inner) => SiblingNode::WithStatement(inner),
+            Statement::ErrorStatement(inner) => SiblingNode::ErrorStatement(inner),
             it @ match_declaration!(Statement) => SiblingNode::from(it.to_declaration()),
             it @ match_module_declaration!(Statement) => {
                 SiblingNode::from(it.to_module_declaration())
@@ -2145,6 +2163,7 @@ impl SiblingNode<'_> {
             Self::BindingIdentifier(n) => n.span(),
             Self::LabelIdentifier(n) => n.span(),
             Self::ThisExpression(n) => n.span(),
+            Self::ErrorExpression(n) => n.span(),
             Self::ArrayExpression(n) => n.span(),
             Self::Elision(n) => n.span(),
             Self::ObjectExpression(n) => n.span(),
@@ -2183,6 +2202,7 @@ impl SiblingNode<'_> {
             Self::VariableDeclaration(n) => n.span(),
             Self::VariableDeclarator(n) => n.span(),
             Self::EmptyStatement(n) => n.span(),
+            Self::ErrorStatement(n) => n.span(),
             Self::ExpressionStatement(n) => n.span(),
             Self::IfStatement(n) => n.span(),
             Self::DoWhileStatement(n) => n.span(),
@@ -2340,6 +2360,7 @@ impl<'a> AstNodes<'a> {
             Self::BindingIdentifier(n) => n.span(),
             Self::LabelIdentifier(n) => n.span(),
             Self::ThisExpression(n) => n.span(),
+            Self::ErrorExpression(n) => n.span(),
             Self::ArrayExpression(n) => n.span(),
             Self::Elision(n) => n.span(),
             Self::ObjectExpression(n) => n.span(),
@@ -2379,6 +2400,7 @@ impl<'a> AstNodes<'a> {
             Self::VariableDeclaration(n) => n.span(),
             Self::VariableDeclarator(n) => n.span(),
             Self::EmptyStatement(n) => n.span(),
+            Self::ErrorStatement(n) => n.span(),
             Self::ExpressionStatement(n) => n.span(),
             Self::IfStatement(n) => n.span(),
             Self::DoWhileStatement(n) => n.span(),
@@ -2533,6 +2555,7 @@ impl<'a> AstNodes<'a> {
             Self::BindingIdentifier(n) => n.parent,
             Self::LabelIdentifier(n) => n.parent,
             Self::ThisExpression(n) => n.parent,
+            Self::ErrorExpression(n) => n.parent,
             Self::ArrayExpression(n) => n.parent,
             Self::Elision(n) => n.parent,
             Self::ObjectExpression(n) => n.parent,
@@ -2572,6 +2595,7 @@ impl<'a> AstNodes<'a> {
             Self::VariableDeclaration(n) => n.parent,
             Self::VariableDeclarator(n) => n.parent,
             Self::EmptyStatement(n) => n.parent,
+            Self::ErrorStatement(n) => n.parent,
             Self::ExpressionStatement(n) => n.parent,
             Self::IfStatement(n) => n.parent,
             Self::DoWhileStatement(n) => n.parent,
@@ -2726,6 +2750,7 @@ impl<'a> AstNodes<'a> {
             Self::BindingIdentifier(n) => SiblingNode::from(n.inner),
             Self::LabelIdentifier(n) => SiblingNode::from(n.inner),
             Self::ThisExpression(n) => SiblingNode::from(n.inner),
+            Self::ErrorExpression(n) => SiblingNode::from(n.inner),
             Self::ArrayExpression(n) => SiblingNode::from(n.inner),
             Self::Elision(n) => SiblingNode::from(n.inner),
             Self::ObjectExpression(n) => SiblingNode::from(n.inner),
@@ -2765,6 +2790,7 @@ impl<'a> AstNodes<'a> {
             Self::VariableDeclaration(n) => SiblingNode::from(n.inner),
             Self::VariableDeclarator(n) => SiblingNode::from(n.inner),
             Self::EmptyStatement(n) => SiblingNode::from(n.inner),
+            Self::ErrorStatement(n) => SiblingNode::from(n.inner),
             Self::ExpressionStatement(n) => SiblingNode::from(n.inner),
             Self::IfStatement(n) => SiblingNode::from(n.inner),
             Self::DoWhileStatement(n) => SiblingNode::from(n.inner),
@@ -2919,6 +2945,7 @@ impl<'a> AstNodes<'a> {
             Self::BindingIdentifier(_) => "BindingIdentifier",
             Self::LabelIdentifier(_) => "LabelIdentifier",
             Self::ThisExpression(_) => "ThisExpression",
+            Self::ErrorExpression(_) => "ErrorExpression",
             Self::ArrayExpression(_) => "ArrayExpression",
             Self::Elision(_) => "Elision",
             Self::ObjectExpression(_) => "ObjectExpression",
@@ -2958,6 +2985,7 @@ impl<'a> AstNodes<'a> {
             Self::VariableDeclaration(_) => "VariableDeclaration",
             Self::VariableDeclarator(_) => "VariableDeclarator",
             Self::EmptyStatement(_) => "EmptyStatement",
+            Self::ErrorStatement(_) => "ErrorStatement",
             Self::ExpressionStatement(_) => "ExpressionStatement",
             Self::IfStatement(_) => "IfStatement",
             Self::DoWhileStatement(_) => "DoWhileStatement",
@@ -3557,6 +3585,14 @@ impl<'a> AstNode<'a, Expression<'a>> {
                     following_node: self.following_node,
                 }))
             }
+            Expression::ErrorExpression(s) => {
+                AstNodes::ErrorExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_node: self.following_node,
+                }))
+            }
             it @ match_member_expression!(Expression) => {
                 return self
                     .allocator
@@ -3710,6 +3746,28 @@ impl<'a> GetSpan for AstNode<'a, ThisExpression> {
     }
 }
 
+impl<'a> AstNode<'a, ErrorExpression> {
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_leading_comments(self.span()).fmt(f)
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_trailing_comments(
+            &self.parent.as_sibling_node(),
+            &SiblingNode::from(self.inner),
+            self.following_node.as_ref(),
+        )
+        .fmt(f)
+    }
+}
+
+impl<'a> GetSpan for AstNode<'a, ErrorExpression> {
+    #[inline]
+    fn span(&self) -> oxc_span::Span {
+        self.inner.span()
+    }
+}
+
 impl<'a> AstNode<'a, ArrayExpression<'a>> {
     #[inline]
     pub fn elements(&self) -> &AstNode<'a, Vec<'a, ArrayExpressionElement<'a>>> {
@@ -5745,6 +5803,14 @@ impl<'a> AstNode<'a, Statement<'a>> {
                 allocator: self.allocator,
                 following_node: self.following_node,
             })),
+            Statement::ErrorStatement(s) => {
+                AstNodes::ErrorStatement(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_node: self.following_node,
+                }))
+            }
             it @ match_declaration!(Statement) => {
                 return self
                     .allocator
@@ -6078,6 +6144,28 @@ impl<'a> GetSpan for AstNode<'a, EmptyStatement> {
     }
 }
 
+impl<'a> AstNode<'a, ErrorStatement> {
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_leading_comments(self.span()).fmt(f)
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        format_trailing_comments(
+            &self.parent.as_sibling_node(),
+            &SiblingNode::from(self.inner),
+            self.following_node.as_ref(),
+        )
+        .fmt(f)
+    }
+}
+
+impl<'a> GetSpan for AstNode<'a, ErrorStatement> {
+    #[inline]
+    fn span(&self) -> oxc_span::Span {
+        self.inner.span()
+    }
+}
+
 impl<'a> AstNode<'a, ExpressionStatement<'a>> {
     #[inline]
     pub fn expression(&self) -> &AstNode<'a, Expression<'a>> {
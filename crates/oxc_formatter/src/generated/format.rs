@@ -345,6 +345,14 @@ impl<'a> Format<'a> for AstNode<'a, Expression<'a>> {
                     following_node: self.following_node,
                 })
                 .fmt(f),
+            Expression::ErrorExpression(inner) => allocator
+                .alloc(AstNode::<ErrorExpression> {
+                    inner,
+                    parent,
+                    allocator,
+                    following_node: self.following_node,
+                })
+                .fmt(f),
             it @ match_member_expression!(Expression) => {
                 let inner = it.to_member_expression();
                 allocator
@@ -435,6 +443,27 @@ impl<'a> Format<'a> for AstNode<'a, ThisExpression> {
     }
 }
 
+impl<'a> Format<'a> for AstNode<'a, ErrorExpression> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let is_suppressed = f.comments().is_suppressed(self.span().start);
+        if !is_suppressed && format_type_cast_comment_node(self, false, f)? {
+            return Ok(());
+        }
+        self.format_leading_comments(f)?;
+        let needs_parentheses = self.needs_parentheses(f);
+        if needs_parentheses {
+            "(".fmt(f)?;
+        }
+        let result =
+            if is_suppressed { FormatSuppressedNode(self.span()).fmt(f) } else { self.write(f) };
+        if needs_parentheses {
+            ")".fmt(f)?;
+        }
+        self.format_trailing_comments(f)?;
+        result
+    }
+}
+
 impl<'a> Format<'a> for AstNode<'a, ArrayExpression<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         let is_suppressed = f.comments().is_suppressed(self.span().start);
@@ -1533,6 +1562,14 @@ impl<'a> Format<'a> for AstNode<'a, Statement<'a>> {
                     following_node: self.following_node,
                 })
                 .fmt(f),
+            Statement::ErrorStatement(inner) => allocator
+                .alloc(AstNode::<ErrorStatement> {
+                    inner,
+                    parent,
+                    allocator,
+                    following_node: self.following_node,
+                })
+                .fmt(f),
             it @ match_declaration!(Statement) => {
                 let inner = it.to_declaration();
                 allocator
@@ -1699,6 +1736,17 @@ impl<'a> Format<'a> for AstNode<'a, EmptyStatement> {
     }
 }
 
+impl<'a> Format<'a> for AstNode<'a, ErrorStatement> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let is_suppressed = f.comments().is_suppressed(self.span().start);
+        self.format_leading_comments(f)?;
+        let result =
+            if is_suppressed { FormatSuppressedNode(self.span()).fmt(f) } else { self.write(f) };
+        self.format_trailing_comments(f)?;
+        result
+    }
+}
+
 impl<'a> Format<'a> for AstNode<'a, ExpressionStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
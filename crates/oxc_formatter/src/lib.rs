@@ -13,11 +13,14 @@ mod generated {
     pub mod ast_nodes;
     mod format;
 }
+mod embed;
 mod formatter;
 mod options;
 mod parentheses;
+mod range;
 mod service;
 mod utils;
+mod verify;
 mod write;
 
 use std::{
@@ -25,19 +28,27 @@ use std::{
     fmt::{self, Display},
     marker::PhantomData,
     mem::{self, transmute},
+    rc::Rc,
     vec::IntoIter,
 };
 
 use oxc_allocator::{Address, Allocator, GetAddress};
 use oxc_ast::{AstKind, ast::*};
+use oxc_span::Span;
 use rustc_hash::{FxHashMap, FxHashSet};
 use write::FormatWrite;
 
+pub use crate::embed::{
+    BuiltinEmbeddedFormatter, EmbeddedFormatter, EmbeddedLanguage, PLACEHOLDER_PREFIX,
+};
 pub use crate::options::*;
-pub use crate::service::source_type::get_supported_source_type;
+pub use crate::range::FormattedRange;
+pub use crate::service::{config::ConfigResolver, source_type::get_supported_source_type};
+pub use crate::verify::VerifyError;
 use crate::{
     formatter::{FormatContext, Formatted, format_element::document::Document},
     generated::ast_nodes::{AstNode, AstNodes},
+    range::{EnclosingEntries, indentation_start},
 };
 
 use self::formatter::prelude::tag::Label;
@@ -46,32 +57,152 @@ pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     options: FormatOptions,
+    embedded_formatter: Option<Rc<dyn EmbeddedFormatter + 'a>>,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, source_text: "", options }
+        Self { allocator, source_text: "", options, embedded_formatter: None }
+    }
+
+    /// Formats code embedded in tagged templates with `embedded_formatter` instead of the
+    /// [`BuiltinEmbeddedFormatter`], when [`FormatOptions::embedded_language_formatting`] is enabled.
+    #[must_use]
+    pub fn with_embedded_formatter(
+        mut self,
+        embedded_formatter: impl EmbeddedFormatter + 'a,
+    ) -> Self {
+        self.embedded_formatter = Some(Rc::new(embedded_formatter));
+        self
     }
 
     /// Formats the given AST `Program` and returns the IR before printing.
     pub fn doc(mut self, program: &'a Program<'a>) -> Document<'a> {
-        let formatted = self.format(program);
+        let formatted = self.format(program, None);
         formatted.into_document()
     }
 
     /// Formats the given AST `Program` and returns the formatted string.
     pub fn build(mut self, program: &Program<'a>) -> String {
-        let formatted = self.format(program);
+        let formatted = self.format(program, None);
         formatted.print().unwrap().into_code()
     }
 
-    fn format(mut self, program: &'a Program<'a>) -> Formatted<'a> {
+    /// Formats the given AST `Program` and returns the formatted string,
+    /// with every line starting with `prefix`.
+    ///
+    /// Used for code embedded in other languages, e.g. the `<script>` blocks of Vue files,
+    /// where `prefix` is the indentation of the block.
+    pub fn build_with_prefix(self, program: &Program<'a>, prefix: &str) -> String {
+        let formatted = self.format(program, None);
+        formatted.print_with_prefix(prefix).unwrap().into_code()
+    }
+
+    /// Formats the smallest list of statements, class members or object properties enclosing `range`,
+    /// and returns the formatted code together with the span of the source text it replaces.
+    ///
+    /// The whole `Program` is formatted and the code of the enclosing entries is taken from the printed code,
+    /// so it is indented consistently with the surrounding code.
+    /// Returns `None` when `range` does not intersect with any of these entries.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn build_range(self, program: &'a Program<'a>, range: Span) -> Option<FormattedRange> {
+        let (first, last) = EnclosingEntries::find(program, range)?;
+        let source_text = program.source_text;
+
+        let formatted = self.format(program, Some((first, last)));
+        let source = formatted.context().range_entries()?.source;
+        let printed = formatted.print().unwrap();
+
+        let start = printed.sourcemap().iter().find(|marker| marker.source == first.start);
+        let end = printed.sourcemap().iter().rev().find(|marker| marker.source == last.end);
+        let (Some(start), Some(end)) = (start, end) else {
+            // The entries are not printed on their own, fall back to the whole program
+            return Some(FormattedRange {
+                span: Span::new(0, source_text.len() as u32),
+                code: printed.into_code(),
+            });
+        };
+
+        let code = printed.as_code();
+        let (mut start, end) = (start.dest as usize, end.dest as usize);
+        // Markers are placed before the separator, skip the line breaks and indentation before the entry
+        start += code[start..end].len() - code[start..end].trim_ascii_start().len();
+        let mut span = source;
+        // Replace the indentation of the first line too, when the entries start on their own line
+        if let (Some(code_start), Some(source_start)) =
+            (indentation_start(code, start), indentation_start(source_text, source.start as usize))
+        {
+            start = code_start;
+            span.start = source_start as u32;
+        }
+
+        Some(FormattedRange { span, code: code[start..end].to_string() })
+    }
+
+    /// Formats the given AST `Program`, and verifies that the formatted code is equivalent and stable:
+    ///
+    /// 1. The formatted code is parsed again, and its AST is compared with `program`,
+    ///    ignoring spans, parentheses, comments and the changes formatting is allowed to make
+    ///    (e.g. quotes of property keys, whitespace in JSX text or the order of sorted imports).
+    /// 2. The formatted code is formatted a second time, and must not change.
+    ///
+    /// Returns the formatted code if both checks pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyError`] if the formatted code has syntax errors, or if either check fails.
+    pub fn verify(self, program: &Program<'a>) -> Result<String, VerifyError> {
+        let allocator = self.allocator;
+        let options = self.options.clone();
+        let embedded_formatter = self.embedded_formatter.clone();
+        let formatter = || Self {
+            allocator,
+            source_text: "",
+            options: options.clone(),
+            embedded_formatter: embedded_formatter.clone(),
+        };
+
+        let formatted = self.build(program);
+        let ret = verify::parse(allocator, allocator.alloc_str(&formatted), program.source_type);
+        if !ret.errors.is_empty() {
+            return Err(VerifyError::InvalidSyntax { formatted, errors: ret.errors });
+        }
+
+        if let Some((original_span, changed_span)) =
+            verify::find_mismatch(program, &ret.program, allocator, &options)
+        {
+            let original = original_span.source_text(program.source_text).to_string();
+            let changed = changed_span.source_text(&formatted).to_string();
+            return Err(VerifyError::AstMismatch {
+                formatted,
+                original,
+                changed,
+                span: original_span,
+            });
+        }
+
+        let reformatted = formatter().build(&ret.program);
+        if reformatted != formatted {
+            return Err(VerifyError::NotIdempotent { formatted, reformatted });
+        }
+        Ok(formatted)
+    }
+
+    fn format(
+        mut self,
+        program: &'a Program<'a>,
+        range_entries: Option<(Span, Span)>,
+    ) -> Formatted<'a> {
         let parent = self.allocator.alloc(AstNodes::Dummy());
         let program_node = AstNode::new(program, parent, self.allocator);
 
         let source_text = program.source_text;
         self.source_text = source_text;
-        let context = FormatContext::new(program, self.allocator, self.options);
+        let mut context = FormatContext::new(program, self.allocator, self.options)
+            .with_embedded_formatter(self.embedded_formatter);
+        if let Some((first, last)) = range_entries {
+            context = context.with_range_entries(first, last);
+        }
         formatter::format(
             program,
             context,
@@ -62,6 +62,13 @@ pub struct FormatOptions {
     /// - `"start"`: Places the operator at the beginning of the next line.
     /// - `"end"`: Places the operator at the end of the current line (default).
     pub experimental_operator_position: OperatorPosition,
+
+    /// Sort import declarations. Disabled by default.
+    pub sort_imports: Option<SortImports>,
+
+    /// Whether to format code embedded in tagged templates, like the CSS of ``styled.div`...` ``
+    /// or GraphQL queries in ``gql`...` ``. Defaults to "off".
+    pub embedded_language_formatting: EmbeddedLanguageFormatting,
 }
 
 impl FormatOptions {
@@ -82,6 +89,8 @@ impl FormatOptions {
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
             experimental_operator_position: OperatorPosition::default(),
+            sort_imports: None,
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
         }
     }
 
@@ -106,7 +115,9 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
         writeln!(f, "Expand lists: {}", self.expand)?;
-        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)
+        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)?;
+        writeln!(f, "Sort imports: {}", if self.sort_imports.is_some() { "On" } else { "Off" })?;
+        writeln!(f, "Embedded language formatting: {}", self.embedded_language_formatting)
     }
 }
 
@@ -908,3 +919,66 @@ impl fmt::Display for OperatorPosition {
         f.write_str(s)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EmbeddedLanguageFormatting {
+    /// Format embedded code if the language is recognized.
+    Auto,
+    /// Never format embedded code.
+    #[default]
+    Off,
+}
+
+impl EmbeddedLanguageFormatting {
+    pub const fn is_auto(self) -> bool {
+        matches!(self, Self::Auto)
+    }
+}
+
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "off" => Ok(Self::Off),
+            _ => Err("Value not supported for EmbeddedLanguageFormatting"),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedLanguageFormatting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            EmbeddedLanguageFormatting::Auto => "Auto",
+            EmbeddedLanguageFormatting::Off => "Off",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Options for sorting import declarations.
+///
+/// Contiguous runs of import declarations are grouped and sorted by their source, and the named
+/// specifiers of every import are sorted by name. Imports are never moved across side-effect-only
+/// imports like `import "polyfill"`, and comments are moved along with the import they belong to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SortImports {
+    /// The groups of imports, in the order they are printed. Every group is a list of patterns
+    /// matched against the import source, where `*` matches any sequence of characters, e.g.
+    /// `[["@/*"], ["./*", "../*"]]`. Imports are placed in the first group they match, imports
+    /// which don't match any group are printed before all groups. Defaults to no groups.
+    pub groups: Vec<Vec<String>>,
+
+    /// Whether to compare sources and names case-insensitively. Defaults to true.
+    pub ignore_case: bool,
+
+    /// Whether to separate groups with an empty line. Defaults to true.
+    pub newlines_between: bool,
+}
+
+impl Default for SortImports {
+    fn default() -> Self {
+        Self { groups: Vec::new(), ignore_case: true, newlines_between: true }
+    }
+}
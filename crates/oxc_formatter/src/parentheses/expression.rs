@@ -60,6 +60,7 @@ impl<'a> NeedsParentheses<'a> for AstNode<'a, Expression<'a>> {
             AstNodes::TSNonNullExpression(it) => it.needs_parentheses(f),
             AstNodes::TSInstantiationExpression(it) => it.needs_parentheses(f),
             AstNodes::V8IntrinsicExpression(it) => it.needs_parentheses(f),
+            AstNodes::ErrorExpression(it) => it.needs_parentheses(f),
             AstNodes::StaticMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::ComputedMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::PrivateFieldExpression(it) => it.needs_parentheses(f),
@@ -198,6 +199,13 @@ impl<'a> NeedsParentheses<'a> for AstNode<'a, ThisExpression> {
     }
 }
 
+impl<'a> NeedsParentheses<'a> for AstNode<'a, ErrorExpression> {
+    #[inline]
+    fn needs_parentheses(&self, f: &Formatter<'_, 'a>) -> bool {
+        false
+    }
+}
+
 impl<'a> NeedsParentheses<'a> for AstNode<'a, ArrayExpression<'a>> {
     #[inline]
     fn needs_parentheses(&self, f: &Formatter<'_, 'a>) -> bool {
@@ -0,0 +1,212 @@
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_span::{GetSpan, Span};
+
+/// The formatted code of a range of the source text, returned by [`crate::Formatter::build_range`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedRange {
+    /// The span of the source text to replace with `code`.
+    pub span: Span,
+    /// The formatted code.
+    pub code: String,
+}
+
+/// Finds the innermost list of statements, class members or object properties enclosing a range,
+/// and returns the first and the last entry of that list which intersect with the range.
+pub struct EnclosingEntries {
+    range: Span,
+    entries: Option<(Span, Span)>,
+}
+
+impl EnclosingEntries {
+    pub fn find(program: &Program, range: Span) -> Option<(Span, Span)> {
+        let mut finder = Self { range, entries: None };
+        finder.visit_program(program);
+        finder.entries
+    }
+
+    fn enter_list<I: IntoIterator<Item = Span>>(&mut self, container: Span, spans: I) {
+        // Lists are visited from the outermost to the innermost, the innermost list wins
+        // as long as the whole range is inside of it.
+        if self.range.start < container.start || container.end < self.range.end {
+            return;
+        }
+        let mut intersecting = spans
+            .into_iter()
+            .filter(|span| span.start <= self.range.end && self.range.start <= span.end);
+        if let Some(first) = intersecting.next() {
+            self.entries = Some((first, intersecting.last().unwrap_or(first)));
+        }
+    }
+
+    fn enter_statements(&mut self, container: Span, statements: &[Statement]) {
+        // Empty statements are removed by the formatter
+        self.enter_list(
+            container,
+            statements
+                .iter()
+                .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)))
+                .map(GetSpan::span),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for EnclosingEntries {
+    fn visit_program(&mut self, it: &Program<'a>) {
+        self.enter_statements(it.span, &it.body);
+        walk::walk_program(self, it);
+    }
+
+    fn visit_block_statement(&mut self, it: &BlockStatement<'a>) {
+        self.enter_statements(it.span, &it.body);
+        walk::walk_block_statement(self, it);
+    }
+
+    fn visit_function_body(&mut self, it: &FunctionBody<'a>) {
+        self.enter_statements(it.span, &it.statements);
+        walk::walk_function_body(self, it);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        // The body of `() => expr` is not a list of statements in the formatted code
+        if let Some(expression) = it.get_expression() {
+            self.visit_formal_parameters(&it.params);
+            self.visit_expression(expression);
+        } else {
+            walk::walk_arrow_function_expression(self, it);
+        }
+    }
+
+    fn visit_static_block(&mut self, it: &StaticBlock<'a>) {
+        self.enter_statements(it.span, &it.body);
+        walk::walk_static_block(self, it);
+    }
+
+    fn visit_switch_case(&mut self, it: &SwitchCase<'a>) {
+        self.enter_statements(it.span, &it.consequent);
+        walk::walk_switch_case(self, it);
+    }
+
+    fn visit_ts_module_block(&mut self, it: &TSModuleBlock<'a>) {
+        self.enter_statements(it.span, &it.body);
+        walk::walk_ts_module_block(self, it);
+    }
+
+    fn visit_class_body(&mut self, it: &ClassBody<'a>) {
+        self.enter_list(it.span, it.body.iter().map(GetSpan::span));
+        walk::walk_class_body(self, it);
+    }
+
+    fn visit_object_expression(&mut self, it: &ObjectExpression<'a>) {
+        self.enter_list(it.span, it.properties.iter().map(GetSpan::span));
+        walk::walk_object_expression(self, it);
+    }
+}
+
+/// Returns the start of the line of `position` in `text`,
+/// if there is nothing but indentation between them.
+pub fn indentation_start(text: &str, position: usize) -> Option<usize> {
+    let line_start = text[..position].rfind('\n').map_or(0, |index| index + 1);
+    text[line_start..position].bytes().all(|b| matches!(b, b' ' | b'\t')).then_some(line_start)
+}
+
+#[cfg(test)]
+mod test {
+    use cow_utils::CowUtils;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{FormatOptions, Formatter};
+
+    /// Formats the range between the first two `|` of `source_text`, and returns the edited source text.
+    fn format_range(source_text: &str) -> String {
+        let start = source_text.find('|').unwrap();
+        let end = source_text[start + 1..].find('|').unwrap() + start;
+        let source_text = source_text.cow_replace('|', "").into_owned();
+
+        let allocator = Allocator::new();
+        let ret = Parser::new(&allocator, &source_text, SourceType::tsx()).parse();
+        assert!(ret.errors.is_empty());
+        #[expect(clippy::cast_possible_truncation)]
+        let range = Span::new(start as u32, end as u32);
+        let formatted = Formatter::new(&allocator, FormatOptions::default())
+            .build_range(&ret.program, range)
+            .unwrap();
+
+        let mut result = source_text.clone();
+        result.replace_range(
+            formatted.span.start as usize..formatted.span.end as usize,
+            &formatted.code,
+        );
+        result
+    }
+
+    #[test]
+    fn statements() {
+        assert_eq!(
+            format_range("const a   =  1;\nconst |b|   =   2;\nconst c   =  3;\n"),
+            "const a   =  1;\nconst b = 2;\nconst c   =  3;\n"
+        );
+        assert_eq!(
+            format_range("const a   =  1;\nconst |b   =   2;\nconst c|   =  3;\nconst d   =  4;\n"),
+            "const a   =  1;\nconst b = 2;\nconst c = 3;\nconst d   =  4;\n"
+        );
+    }
+
+    #[test]
+    fn nested_statements() {
+        assert_eq!(
+            format_range("function f() {\n      let |x   = 1|;\n  if (x) {\n      y( )\n  }\n}\n"),
+            "function f() {\n  let x = 1;\n  if (x) {\n      y( )\n  }\n}\n"
+        );
+        assert_eq!(
+            format_range("function f() {\n  if (x) {\n      |y( )|\n  }\n}\n"),
+            "function f() {\n  if (x) {\n    y();\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn class_members() {
+        assert_eq!(
+            format_range("class A {\n  a  =  1\n    |b()   { return 2 }|\n}\n"),
+            "class A {\n  a  =  1\n  b() {\n    return 2;\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn object_properties() {
+        assert_eq!(
+            format_range("const o = {\n  a:   1,\n      |b|:2,\n  c:   3\n};\n"),
+            "const o = {\n  a:   1,\n  b: 2,\n  c:   3\n};\n"
+        );
+        // The trailing separator is added when the object breaks
+        assert_eq!(
+            format_range("const o = {\n  a:   1,\n  |c|:   3\n};\n"),
+            "const o = {\n  a:   1,\n  c: 3,\n};\n"
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            format_range("a;\n// leading\n|b|   ;  // trailing\nc  ;\n"),
+            "a;\n// leading\nb; // trailing\nc  ;\n"
+        );
+        assert_eq!(
+            format_range("a;\n   /* leading */   |b|   ;\nc  ;\n"),
+            "a;\n/* leading */ b;\nc  ;\n"
+        );
+    }
+
+    #[test]
+    fn outside_of_entries() {
+        let allocator = Allocator::new();
+        let ret = Parser::new(&allocator, "\n\n", SourceType::mjs()).parse();
+        assert!(
+            Formatter::new(&allocator, FormatOptions::default())
+                .build_range(&ret.program, Span::new(0, 1))
+                .is_none()
+        );
+    }
+}
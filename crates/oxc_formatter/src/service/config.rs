@@ -0,0 +1,489 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Deserializer};
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+};
+
+/// Config files looked up in each directory, in order of precedence.
+/// `package.json` is checked last, and only counts when it has a `prettier` field.
+const CONFIG_FILE_NAMES: [&str; 7] = [
+    ".oxfmtrc.json",
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.json5",
+    ".prettierrc.toml",
+];
+
+const EDITORCONFIG_FILE_NAME: &str = ".editorconfig";
+
+/// Resolves the [`FormatOptions`] of a file from the nearest config file and the `.editorconfig` files above it,
+/// the same way for `oxfmt` and the language server.
+///
+/// Lookups are cached per directory, so every config file is read at most once.
+#[derive(Default)]
+pub struct ConfigResolver {
+    /// The options which config files are applied on top of.
+    base_options: FormatOptions,
+    directories: Mutex<FxHashMap<PathBuf, Arc<DirectoryConfig>>>,
+}
+
+#[derive(Default)]
+struct DirectoryConfig {
+    /// The nearest config file of this directory.
+    config: Option<Arc<ConfigFile>>,
+    /// `.editorconfig` files which apply to this directory, from the outermost to the nearest.
+    editorconfigs: Vec<Arc<EditorConfig>>,
+}
+
+struct ConfigFile {
+    path: PathBuf,
+    /// Parse errors are kept, so they are reported for every file using this config.
+    config: Result<Oxfmtrc, String>,
+}
+
+impl ConfigResolver {
+    /// Creates a resolver which applies config files on top of `base_options` instead of the defaults,
+    /// e.g. the options of an editor.
+    pub fn new(base_options: FormatOptions) -> Self {
+        Self { base_options, directories: Mutex::default() }
+    }
+
+    /// Resolve the options to format the file at `path` with.
+    ///
+    /// Options are applied on top of the base options in this order:
+    /// `.editorconfig` sections, the nearest config file, then its matching `overrides`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a diagnostic when the nearest config file can not be parsed or contains invalid values.
+    pub fn resolve(&self, path: &Path) -> Result<FormatOptions, OxcDiagnostic> {
+        let mut options = self.base_options.clone();
+        let Some(dir) = path.parent() else { return Ok(options) };
+        let directory = self.directory(dir);
+
+        let mut properties = EditorConfigProperties::default();
+        for editorconfig in &directory.editorconfigs {
+            editorconfig.collect(path, &mut properties);
+        }
+        properties.apply(&mut options);
+
+        if let Some(config_file) = &directory.config {
+            let with_path = |err: &str| {
+                OxcDiagnostic::error(format!(
+                    "Failed to load config {}: {err}",
+                    config_file.path.display()
+                ))
+            };
+            let config = config_file.config.as_ref().map_err(|err| with_path(err))?;
+            let config_dir = config_file.path.parent().unwrap_or(dir);
+            config.apply(path, config_dir, &mut options).map_err(|err| with_path(&err))?;
+        }
+
+        Ok(options)
+    }
+
+    fn directory(&self, dir: &Path) -> Arc<DirectoryConfig> {
+        if let Some(directory) = self.directories.lock().unwrap().get(dir) {
+            return Arc::clone(directory);
+        }
+
+        let parent = dir.parent().map(|parent| self.directory(parent)).unwrap_or_default();
+
+        let config = ConfigFile::find(dir).map(Arc::new).or_else(|| parent.config.clone());
+        let editorconfigs = match EditorConfig::load(dir) {
+            Some(editorconfig) if editorconfig.root => vec![Arc::new(editorconfig)],
+            Some(editorconfig) => {
+                let mut editorconfigs = parent.editorconfigs.clone();
+                editorconfigs.push(Arc::new(editorconfig));
+                editorconfigs
+            }
+            None => parent.editorconfigs.clone(),
+        };
+
+        let directory = Arc::new(DirectoryConfig { config, editorconfigs });
+        self.directories.lock().unwrap().insert(dir.to_path_buf(), Arc::clone(&directory));
+        directory
+    }
+}
+
+impl ConfigFile {
+    fn find(dir: &Path) -> Option<Self> {
+        for file_name in CONFIG_FILE_NAMES {
+            let path = dir.join(file_name);
+            if let Ok(mut string) = fs::read_to_string(&path) {
+                let config = match file_name {
+                    // `.prettierrc` can be written in YAML as well, JSON configs are objects
+                    ".prettierrc" if !string.trim_start().starts_with('{') => parse_yaml(&string),
+                    ".prettierrc.yaml" | ".prettierrc.yml" => parse_yaml(&string),
+                    // Reported instead of falling back to the defaults, so the config is not ignored silently
+                    ".prettierrc.json5" | ".prettierrc.toml" => Err(
+                        "JSON5 and TOML config files are not supported, use JSON or YAML instead"
+                            .to_string(),
+                    ),
+                    // jsonc support
+                    _ => json_strip_comments::strip(&mut string)
+                        .map_err(|err| format!("{err:?}"))
+                        .and_then(|()| {
+                            serde_json::from_str(&string).map_err(|err| err.to_string())
+                        }),
+                };
+                return Some(Self { path, config });
+            }
+        }
+
+        let path = dir.join("package.json");
+        let string = fs::read_to_string(&path).ok()?;
+        let mut package_json = serde_json::from_str::<serde_json::Value>(&string).ok()?;
+        let config = match package_json.get_mut("prettier")?.take() {
+            serde_json::Value::String(_) => {
+                Err("Shared configs in the `prettier` field are not supported".to_string())
+            }
+            value => serde_json::from_value(value).map_err(|err| err.to_string()),
+        };
+        Some(Self { path, config })
+    }
+}
+
+/// Parse a YAML config, deserialized the same way as JSON configs. An empty file uses the defaults.
+fn parse_yaml(source: &str) -> Result<Oxfmtrc, String> {
+    match oxc_yaml::parse(source)? {
+        serde_json::Value::Null => Ok(Oxfmtrc::default()),
+        value => serde_json::from_value(value).map_err(|err| err.to_string()),
+    }
+}
+
+/// The content of a config file.
+///
+/// Uses the same keys as Prettier, so an existing `.prettierrc` can be used as-is.
+/// Options which are not supported yet are ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Oxfmtrc {
+    #[serde(flatten)]
+    options: Options,
+    overrides: Vec<OxfmtOverride>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct OxfmtOverride {
+    files: GlobSet,
+    exclude_files: GlobSet,
+    options: Options,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Options {
+    use_tabs: Option<bool>,
+    tab_width: Option<u8>,
+    end_of_line: Option<String>,
+    print_width: Option<u16>,
+    single_quote: Option<bool>,
+    jsx_single_quote: Option<bool>,
+    quote_props: Option<String>,
+    trailing_comma: Option<String>,
+    semi: Option<bool>,
+    arrow_parens: Option<String>,
+    bracket_spacing: Option<bool>,
+    bracket_same_line: Option<bool>,
+    single_attribute_per_line: Option<bool>,
+    experimental_operator_position: Option<String>,
+    embedded_language_formatting: Option<String>,
+    experimental_sort_imports: Option<SortImportsOptions>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct SortImportsOptions {
+    groups: Option<Vec<Vec<String>>>,
+    ignore_case: Option<bool>,
+    newlines_between: Option<bool>,
+}
+
+impl Oxfmtrc {
+    fn apply(
+        &self,
+        path: &Path,
+        config_dir: &Path,
+        options: &mut FormatOptions,
+    ) -> Result<(), String> {
+        self.options.apply(options)?;
+
+        if self.overrides.is_empty() {
+            return Ok(());
+        }
+        // Globs in `overrides` are relative to the config file
+        let relative_path = path.strip_prefix(config_dir).unwrap_or(path).to_string_lossy();
+        let relative_path = relative_path.cow_replace('\\', "/");
+        for r#override in &self.overrides {
+            if r#override.files.is_match(&relative_path)
+                && !r#override.exclude_files.is_match(&relative_path)
+            {
+                r#override.options.apply(options)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Options {
+    fn apply(&self, options: &mut FormatOptions) -> Result<(), String> {
+        if let Some(use_tabs) = self.use_tabs {
+            options.indent_style = if use_tabs { IndentStyle::Tab } else { IndentStyle::Space };
+        }
+        if let Some(tab_width) = self.tab_width {
+            options.indent_width = IndentWidth::try_from(tab_width).map_err(|_| {
+                format!(
+                    "`tabWidth` should be between {} and {}",
+                    IndentWidth::MIN,
+                    IndentWidth::MAX
+                )
+            })?;
+        }
+        if let Some(end_of_line) = &self.end_of_line {
+            // `auto` keeps the default, there is no detection of existing line endings yet
+            if end_of_line != "auto" {
+                options.line_ending = parse_option("endOfLine", end_of_line)?;
+            }
+        }
+        if let Some(print_width) = self.print_width {
+            options.line_width = LineWidth::try_from(print_width).map_err(|_| {
+                format!("`printWidth` should be between {} and {}", LineWidth::MIN, LineWidth::MAX)
+            })?;
+        }
+        if let Some(single_quote) = self.single_quote {
+            options.quote_style = quote_style(single_quote);
+        }
+        if let Some(jsx_single_quote) = self.jsx_single_quote {
+            options.jsx_quote_style = quote_style(jsx_single_quote);
+        }
+        if let Some(quote_props) = &self.quote_props {
+            options.quote_properties = parse_option::<QuoteProperties>("quoteProps", quote_props)?;
+        }
+        if let Some(trailing_comma) = &self.trailing_comma {
+            options.trailing_commas =
+                parse_option::<TrailingCommas>("trailingComma", trailing_comma)?;
+        }
+        if let Some(semi) = self.semi {
+            options.semicolons = if semi { Semicolons::Always } else { Semicolons::AsNeeded };
+        }
+        if let Some(arrow_parens) = &self.arrow_parens {
+            options.arrow_parentheses =
+                parse_option::<ArrowParentheses>("arrowParens", arrow_parens)?;
+        }
+        if let Some(bracket_spacing) = self.bracket_spacing {
+            options.bracket_spacing = BracketSpacing::from(bracket_spacing);
+        }
+        if let Some(bracket_same_line) = self.bracket_same_line {
+            options.bracket_same_line = BracketSameLine::from(bracket_same_line);
+        }
+        if let Some(single_attribute_per_line) = self.single_attribute_per_line {
+            options.attribute_position = if single_attribute_per_line {
+                AttributePosition::Multiline
+            } else {
+                AttributePosition::Auto
+            };
+        }
+        if let Some(operator_position) = &self.experimental_operator_position {
+            options.experimental_operator_position = parse_option::<OperatorPosition>(
+                "experimentalOperatorPosition",
+                operator_position,
+            )?;
+        }
+        if let Some(embedded_language_formatting) = &self.embedded_language_formatting {
+            options.embedded_language_formatting = parse_option::<EmbeddedLanguageFormatting>(
+                "embeddedLanguageFormatting",
+                embedded_language_formatting,
+            )?;
+        }
+        if let Some(sort_imports) = &self.experimental_sort_imports {
+            // Options which are not set are inherited, e.g. from the config an override belongs to
+            let options = options.sort_imports.get_or_insert_default();
+            if let Some(groups) = &sort_imports.groups {
+                options.groups.clone_from(groups);
+            }
+            if let Some(ignore_case) = sort_imports.ignore_case {
+                options.ignore_case = ignore_case;
+            }
+            if let Some(newlines_between) = sort_imports.newlines_between {
+                options.newlines_between = newlines_between;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote { QuoteStyle::Single } else { QuoteStyle::Double }
+}
+
+fn parse_option<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Unsupported value `{value}` for `{name}`"))
+}
+
+/// A set of glob patterns, matched against paths relative to the config file.
+/// Patterns without a `/` match files in any directory.
+#[derive(Debug, Default)]
+struct GlobSet(Vec<String>);
+
+impl<'de> Deserialize<'de> for GlobSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let patterns = match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(pattern) => vec![pattern],
+            OneOrMany::Many(patterns) => patterns,
+        };
+        Ok(Self(patterns.iter().map(|pattern| normalize_glob(pattern)).collect()))
+    }
+}
+
+impl GlobSet {
+    fn is_match(&self, path: &str) -> bool {
+        self.0.iter().any(|glob| fast_glob::glob_match(glob, path))
+    }
+}
+
+fn normalize_glob(pattern: &str) -> String {
+    if pattern.contains('/') {
+        pattern.strip_prefix('/').unwrap_or(pattern).to_string()
+    } else {
+        format!("**/{pattern}")
+    }
+}
+
+/// A parsed `.editorconfig` file, see <https://editorconfig.org>.
+///
+/// Only the properties which map onto [`FormatOptions`] are kept.
+struct EditorConfig {
+    dir: PathBuf,
+    root: bool,
+    sections: Vec<(String, EditorConfigProperties)>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct EditorConfigProperties {
+    indent_style: Option<IndentStyle>,
+    indent_size: Option<IndentSize>,
+    tab_width: Option<u8>,
+    max_line_length: Option<u16>,
+    end_of_line: Option<LineEnding>,
+}
+
+#[derive(Clone, Copy)]
+enum IndentSize {
+    /// `indent_size = tab`, uses `tab_width`.
+    Tab,
+    Width(u8),
+}
+
+impl EditorConfig {
+    fn load(dir: &Path) -> Option<Self> {
+        let source = fs::read_to_string(dir.join(EDITORCONFIG_FILE_NAME)).ok()?;
+        Some(Self::parse(dir, &source))
+    }
+
+    fn parse(dir: &Path, source: &str) -> Self {
+        let mut root = false;
+        let mut sections: Vec<(String, EditorConfigProperties)> = vec![];
+
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                sections.push((normalize_glob(glob), EditorConfigProperties::default()));
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let key = key.trim().cow_to_ascii_lowercase();
+            let value = value.trim().cow_to_ascii_lowercase();
+
+            let Some((_, properties)) = sections.last_mut() else {
+                // Only `root` is allowed before the first section
+                if key == "root" {
+                    root = value == "true";
+                }
+                continue;
+            };
+            // Invalid values are ignored, as the spec requires
+            match key.as_ref() {
+                "indent_style" => properties.indent_style = value.parse().ok(),
+                "indent_size" if value == "tab" => properties.indent_size = Some(IndentSize::Tab),
+                "indent_size" => properties.indent_size = value.parse().ok().map(IndentSize::Width),
+                "tab_width" => properties.tab_width = value.parse().ok(),
+                "max_line_length" => properties.max_line_length = value.parse().ok(),
+                "end_of_line" => properties.end_of_line = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Self { dir: dir.to_path_buf(), root, sections }
+    }
+
+    /// Merge the properties of all sections matching `path` into `properties`, later sections win.
+    fn collect(&self, path: &Path, properties: &mut EditorConfigProperties) {
+        let Ok(relative_path) = path.strip_prefix(&self.dir) else { return };
+        let relative_path = relative_path.to_string_lossy();
+        let relative_path = relative_path.cow_replace('\\', "/");
+        for (glob, section) in &self.sections {
+            if fast_glob::glob_match(glob, relative_path.as_ref()) {
+                properties.merge(*section);
+            }
+        }
+    }
+}
+
+impl EditorConfigProperties {
+    fn merge(&mut self, other: Self) {
+        self.indent_style = other.indent_style.or(self.indent_style);
+        self.indent_size = other.indent_size.or(self.indent_size);
+        self.tab_width = other.tab_width.or(self.tab_width);
+        self.max_line_length = other.max_line_length.or(self.max_line_length);
+        self.end_of_line = other.end_of_line.or(self.end_of_line);
+    }
+
+    fn apply(self, options: &mut FormatOptions) {
+        if let Some(indent_style) = self.indent_style {
+            options.indent_style = indent_style;
+        }
+        // `indent_size = tab` and a missing `indent_size` both fall back to `tab_width`
+        let indent_width = match self.indent_size {
+            Some(IndentSize::Width(width)) => Some(width),
+            Some(IndentSize::Tab) | None => self.tab_width,
+        };
+        if let Some(indent_width) = indent_width.and_then(|width| IndentWidth::try_from(width).ok())
+        {
+            options.indent_width = indent_width;
+        }
+        if let Some(line_width) =
+            self.max_line_length.and_then(|width| LineWidth::try_from(width).ok())
+        {
+            options.line_width = line_width;
+        }
+        if let Some(line_ending) = self.end_of_line {
+            options.line_ending = line_ending;
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod format_node_without_trailing_comments;
 pub mod jsx;
 pub mod member_chain;
 pub mod object;
+pub mod sort_imports;
 pub mod string_utils;
 pub mod suppressed;
 pub mod typecast;
@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+
+use oxc_ast::ast::{ImportDeclaration, Statement};
+use oxc_span::GetSpan;
+use oxc_syntax::import_source::{compare_names, matches_pattern};
+
+use crate::{
+    Buffer, Format, FormatResult,
+    formatter::{Formatter, prelude::*, trivia::FormatLeadingComments},
+    generated::ast_nodes::AstNode,
+    options::SortImports,
+    write,
+};
+
+/// Returns the import declaration of `statement`, if it can be moved by sorting.
+///
+/// Side-effect-only imports (`import "polyfill"` and `import {} from "polyfill"`) can't be moved,
+/// as the order of their evaluation may matter.
+pub fn as_sortable_import<'a, 'b>(
+    statement: &'b Statement<'a>,
+) -> Option<&'b ImportDeclaration<'a>> {
+    match statement {
+        Statement::ImportDeclaration(import)
+            if import.specifiers.as_ref().is_some_and(|specifiers| !specifiers.is_empty()) =>
+        {
+            Some(import)
+        }
+        _ => None,
+    }
+}
+
+/// Formats a contiguous run of import declarations, sorted and grouped by [`SortImports`].
+///
+/// Every import is formatted along with its comments in source order, then printed in sorted order.
+pub struct FormatSortedImports<'a, 'b> {
+    pub imports: &'b [&'b AstNode<'a, Statement<'a>>],
+    pub options: &'b SortImports,
+}
+
+impl<'a> Format<'a> for FormatSortedImports<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        // Comments separated from the first import by an empty line (e.g. a license header)
+        // stay in place instead of moving along with it.
+        let first_start = self.imports[0].span().start;
+        let comments = f.context().comments().comments_before(first_start);
+        if let Some(detached) =
+            comments.iter().rposition(|comment| f.source_text().lines_after(comment.span.end) > 1)
+        {
+            write!(f, [FormatLeadingComments::Comments(&comments[..=detached])])?;
+        }
+
+        let mut entries = Vec::with_capacity(self.imports.len());
+        for import in self.imports {
+            let Some(declaration) = as_sortable_import(import.as_ref()) else {
+                unreachable!("Only sortable imports are passed to `FormatSortedImports`");
+            };
+            let source = declaration.source.value.as_str();
+            entries.push((self.options.group_of(source), source, f.intern(import)?));
+        }
+        // `sort_by` is stable, imports of the same source keep their order
+        entries.sort_by(|(a_group, a_source, _), (b_group, b_source, _)| {
+            a_group
+                .cmp(b_group)
+                .then_with(|| compare_names(a_source, b_source, self.options.ignore_case))
+        });
+
+        let mut previous_group = None;
+        for (group, _, element) in entries {
+            if let Some(previous_group) = previous_group {
+                if previous_group != group && self.options.newlines_between {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            previous_group = Some(group);
+            if let Some(element) = element {
+                f.write_element(element)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SortImports {
+    /// Returns the index of the group of an import `source`.
+    /// Imports which don't match any group come first.
+    fn group_of(&self, source: &str) -> usize {
+        self.groups
+            .iter()
+            .position(|patterns| patterns.iter().any(|pattern| matches_pattern(pattern, source)))
+            .map_or(0, |index| index + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::{FormatOptions, Formatter, SortImports};
+
+    fn format(source_text: &str, sort_imports: SortImports) -> String {
+        let allocator = Allocator::new();
+        let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        assert!(ret.errors.is_empty());
+        let options =
+            FormatOptions { sort_imports: Some(sort_imports), ..FormatOptions::default() };
+        Formatter::new(&allocator, options).build(&ret.program)
+    }
+
+    #[test]
+    fn sort_sources() {
+        assert_eq!(
+            format(
+                "import { b } from \"b\";\nimport { a } from \"a\";\nimport A from \"A\";\n",
+                SortImports::default()
+            ),
+            "import A from \"A\";\nimport { a } from \"a\";\nimport { b } from \"b\";\n"
+        );
+        assert_eq!(
+            format(
+                "import { c } from \"c\";\nimport B from \"B\";\nimport { a } from \"a\";\n",
+                SortImports::default()
+            ),
+            "import { a } from \"a\";\nimport B from \"B\";\nimport { c } from \"c\";\n"
+        );
+        assert_eq!(
+            format(
+                "import { b } from \"b\";\nimport A from \"A\";\nimport { a } from \"a\";\n",
+                SortImports { ignore_case: false, ..SortImports::default() }
+            ),
+            "import A from \"A\";\nimport { a } from \"a\";\nimport { b } from \"b\";\n"
+        );
+    }
+
+    #[test]
+    fn groups() {
+        let sort_imports = SortImports {
+            groups: vec![vec!["@/*".into()], vec!["./*".into(), "../*".into()]],
+            ..SortImports::default()
+        };
+        assert_eq!(
+            format(
+                "import a from \"./a\";\nimport b from \"../b\";\n\nimport c from \"@/c\";\nimport react from \"react\";\n",
+                sort_imports.clone()
+            ),
+            "import react from \"react\";\n\nimport c from \"@/c\";\n\nimport b from \"../b\";\nimport a from \"./a\";\n"
+        );
+        assert_eq!(
+            format(
+                "import a from \"./a\";\nimport react from \"react\";\n",
+                SortImports { newlines_between: false, ..sort_imports }
+            ),
+            "import react from \"react\";\nimport a from \"./a\";\n"
+        );
+    }
+
+    #[test]
+    fn specifiers() {
+        assert_eq!(
+            format("import D, { c, type B, a as z } from \"x\";\n", SortImports::default()),
+            "import D, { a as z, type B, c } from \"x\";\n"
+        );
+        // Specifiers with comments are left alone
+        assert_eq!(
+            format("import {\n  c,\n  // b\n  b,\n} from \"x\";\n", SortImports::default()),
+            "import {\n  c,\n  // b\n  b,\n} from \"x\";\n"
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            format(
+                "// header\n\n// b\nimport b from \"b\"; // b trailing\n/* a */\nimport a from \"a\";\n\nfoo();\n",
+                SortImports::default()
+            ),
+            "// header\n\n/* a */\nimport a from \"a\";\n// b\nimport b from \"b\"; // b trailing\n\nfoo();\n"
+        );
+    }
+
+    #[test]
+    fn side_effect_imports() {
+        assert_eq!(
+            format(
+                "import c from \"c\";\nimport a from \"a\";\nimport \"polyfill\";\nimport b from \"b\";\nimport {} from \"empty\";\nimport a2 from \"a\";\n",
+                SortImports::default()
+            ),
+            "import a from \"a\";\nimport c from \"c\";\nimport \"polyfill\";\nimport b from \"b\";\nimport {} from \"empty\";\nimport a2 from \"a\";\n"
+        );
+    }
+}
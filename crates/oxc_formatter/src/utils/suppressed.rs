@@ -1,11 +1,26 @@
+use std::iter::Peekable;
+
+use oxc_ast::ast::Statement;
 use oxc_span::{GetSpan, Span};
 
 use crate::{
     Buffer, Format, FormatResult, format_args,
-    formatter::{Formatter, prelude::*, trivia::FormatLeadingComments},
+    formatter::{
+        Comments, Formatter, SourceText,
+        prelude::*,
+        trivia::{FormatLeadingComments, format_leading_comments},
+    },
+    generated::ast_nodes::AstNode,
     write,
 };
 
+/// Marks the start of a region of statements which are printed as-is.
+const IGNORE_START: &str = "oxfmt-ignore-start";
+/// Marks the end of a region started by [`IGNORE_START`].
+const IGNORE_END: &str = "oxfmt-ignore-end";
+/// Prints the whole file as-is, when it is in a comment before the first statement.
+const IGNORE_FILE: &str = "oxfmt-ignore-file";
+
 pub struct FormatSuppressedNode(pub Span);
 
 impl FormatSuppressedNode {
@@ -29,3 +44,122 @@ fn mark_comments_as_printed_before(end: u32, f: &mut Formatter<'_, '_>) {
     let count = f.comments().unprinted_comments().iter().take_while(|c| c.span.end <= end).count();
     f.context_mut().comments_mut().increase_printed_count_by(count);
 }
+
+/// Returns `true` if a comment before `start`, the start of the first statement of the file,
+/// is an `oxfmt-ignore-file` comment.
+pub fn is_file_ignored(start: u32, f: &Formatter<'_, '_>) -> bool {
+    let comments = f.comments();
+    comments
+        .comments_before(start)
+        .iter()
+        .any(|comment| comments.comment_text(comment) == IGNORE_FILE)
+}
+
+/// A region of statements between an `oxfmt-ignore-start` comment and an `oxfmt-ignore-end` comment,
+/// which is printed as-is.
+///
+/// A region without an `oxfmt-ignore-end` comment lasts until the end of its statement list.
+pub struct FormatIgnoredRegion(pub Span);
+
+impl FormatIgnoredRegion {
+    /// Returns the region starting at `first` if `first` is preceded by an `oxfmt-ignore-start` comment,
+    /// and takes the other statements of the region from `rest`.
+    ///
+    /// `previous_end` is the end of the statement before `first`, or 0 if there is none.
+    /// Only the comments after `previous_end` are looked at, so `comments` doesn't need to be up to date.
+    pub fn take<'a, 'b>(
+        first: &AstNode<'a, Statement<'a>>,
+        previous_end: u32,
+        rest: &mut Peekable<impl Iterator<Item = &'b AstNode<'a, Statement<'a>>>>,
+        comments: &Comments<'a>,
+        source_text: SourceText<'a>,
+    ) -> Option<Self>
+    where
+        'a: 'b,
+    {
+        let has_comment = |start: u32, end: u32, text: &str| {
+            comments
+                .comments_in_range(start, end)
+                .iter()
+                .any(|comment| comments.comment_text(comment) == text)
+        };
+
+        let span = first.span();
+        if !has_comment(previous_end, span.start, IGNORE_START) {
+            return None;
+        }
+        let mut end = span.end;
+        while let Some(next) = rest.next_if(|next| !has_comment(end, next.span().start, IGNORE_END))
+        {
+            end = next.span().end;
+        }
+        // Comments at the end of the line of the last statement belong to the region
+        let last_end = end;
+        for comment in
+            comments.comments_after(last_end).iter().skip_while(|c| c.span.start < last_end)
+        {
+            if !source_text.all_bytes_match(end, comment.span.start, |b| matches!(b, b' ' | b'\t'))
+            {
+                break;
+            }
+            end = comment.span.end;
+        }
+        Some(Self(Span::new(span.start, end)))
+    }
+}
+
+impl<'a> Format<'a> for FormatIgnoredRegion {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [format_leading_comments(self.0), FormatSuppressedNode(self.0)])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::{FormatOptions, Formatter};
+
+    fn format(source_text: &str) -> String {
+        let allocator = Allocator::new();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        Formatter::new(&allocator, FormatOptions::default()).build(&ret.program)
+    }
+
+    #[test]
+    fn ignore_node() {
+        assert_eq!(
+            format(
+                "// oxfmt-ignore\nconst  a  =  [1,2];\nconst  b  =  {\n  // prettier-ignore\n  c:  [3,4],\n  d:  5 };\n"
+            ),
+            "// oxfmt-ignore\nconst  a  =  [1,2];\nconst b = {\n  // prettier-ignore\n  c:  [3,4],\n  d: 5,\n};\n"
+        );
+    }
+
+    #[test]
+    fn ignore_region() {
+        assert_eq!(
+            format(
+                "a( 1 );\n\n// oxfmt-ignore-start\nb( 2 );\n\nc( 3 ); // c\n// oxfmt-ignore-end\nd( 4 );\nfunction f() {\n  /* oxfmt-ignore-start */\n  e( 5 )\n}\n"
+            ),
+            "a(1);\n\n// oxfmt-ignore-start\nb( 2 );\n\nc( 3 ); // c\n// oxfmt-ignore-end\nd(4);\nfunction f() {\n  /* oxfmt-ignore-start */\n  e( 5 )\n}\n"
+        );
+        // An end without a start is an ordinary comment
+        assert_eq!(
+            format("a( 1 );\n// oxfmt-ignore-end\nb( 2 );\n"),
+            "a(1);\n// oxfmt-ignore-end\nb(2);\n"
+        );
+    }
+
+    #[test]
+    fn ignore_file() {
+        let source_text =
+            "#!/usr/bin/env node\n/* oxfmt-ignore-file */\n'use strict'\nconst  a  =  1\n";
+        assert_eq!(format(source_text), source_text);
+        // Only comments before the first statement count
+        assert_eq!(format("a( 1 )\n// oxfmt-ignore-file\n"), "a(1);\n// oxfmt-ignore-file\n");
+    }
+}
@@ -0,0 +1,471 @@
+use std::fmt::{self, Display};
+
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser, ParserReturn};
+use oxc_span::{ContentEq, GetSpan, SPAN, Span};
+use similar::TextDiff;
+
+use crate::{EmbeddedLanguage, EmbeddedLanguageFormatting, FormatOptions};
+
+/// The reason why formatting a program failed verification, returned by [`crate::Formatter::verify`].
+#[derive(Debug)]
+pub enum VerifyError {
+    /// The formatted code has syntax errors.
+    InvalidSyntax { formatted: String, errors: Vec<OxcDiagnostic> },
+    /// The formatted code is parsed into a different AST.
+    ///
+    /// `original` and `changed` are the code of the first hashbang, directive or statement
+    /// which differs, in the source text and in the formatted code.
+    /// `span` is the span of `original` in the source text.
+    AstMismatch { formatted: String, original: String, changed: String, span: Span },
+    /// Formatting the formatted code changes it again.
+    NotIdempotent { formatted: String, reformatted: String },
+}
+
+impl VerifyError {
+    /// The formatted code which failed verification.
+    pub fn formatted(&self) -> &str {
+        match self {
+            Self::InvalidSyntax { formatted, .. }
+            | Self::AstMismatch { formatted, .. }
+            | Self::NotIdempotent { formatted, .. } => formatted,
+        }
+    }
+
+    /// Returns a unified diff of the code which differs, `None` for [`VerifyError::InvalidSyntax`].
+    pub fn diff(&self) -> Option<String> {
+        let (old, new, old_header, new_header) = match self {
+            Self::InvalidSyntax { .. } => return None,
+            Self::AstMismatch { original, changed, .. } => {
+                (original, changed, "original", "formatted")
+            }
+            Self::NotIdempotent { formatted, reformatted } => {
+                (formatted, reformatted, "formatted", "formatted twice")
+            }
+        };
+        let diff = TextDiff::from_lines(old.as_str(), new.as_str())
+            .unified_diff()
+            .missing_newline_hint(false)
+            .header(old_header, new_header)
+            .to_string();
+        Some(diff)
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax { errors, .. } => {
+                write!(f, "The formatted code has syntax errors")?;
+                if let Some(error) = errors.first() {
+                    write!(f, ": {error}")?;
+                }
+                Ok(())
+            }
+            Self::AstMismatch { .. } => write!(f, "Formatting changes the AST"),
+            Self::NotIdempotent { .. } => {
+                write!(f, "Formatting the formatted code changes it again")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// Parses the formatted code, with all syntax features enabled like `oxfmt` does.
+pub fn parse<'a>(
+    allocator: &'a Allocator,
+    formatted: &'a str,
+    source_type: SourceType,
+) -> ParserReturn<'a> {
+    Parser::new(allocator, formatted, source_type)
+        .with_options(ParseOptions {
+            parse_regular_expression: false,
+            allow_v8_intrinsics: true,
+            allow_return_outside_function: true,
+            // `oxc_formatter` expects this to be false
+            preserve_parens: false,
+            tokens: false,
+            error_recovery: false,
+        })
+        .parse()
+}
+
+/// Compares the AST of the source text with the AST of the formatted code, ignoring spans and trivia.
+///
+/// Returns the spans of the first hashbang, directive or statement which differs.
+pub fn find_mismatch<'a>(
+    original: &Program<'a>,
+    formatted: &Program<'a>,
+    allocator: &'a Allocator,
+    options: &FormatOptions,
+) -> Option<(Span, Span)> {
+    let mut normalizer = Normalizer::new(allocator, options);
+    let mut original = original.clone_in(allocator);
+    let mut formatted = formatted.clone_in(allocator);
+    normalizer.visit_program(&mut original);
+    normalizer.visit_program(&mut formatted);
+
+    if !original.hashbang.content_eq(&formatted.hashbang) {
+        let span = |hashbang: &Option<Hashbang>| hashbang.as_ref().map_or(SPAN, GetSpan::span);
+        return Some((span(&original.hashbang), span(&formatted.hashbang)));
+    }
+    first_mismatch(&original.directives, &formatted.directives)
+        .or_else(|| first_mismatch(&original.body, &formatted.body))
+}
+
+/// Returns the spans of the first nodes which differ, an empty span stands for a missing node.
+fn first_mismatch<T: ContentEq + GetSpan>(a: &[T], b: &[T]) -> Option<(Span, Span)> {
+    let index = (0..a.len().max(b.len())).find(|&index| match (a.get(index), b.get(index)) {
+        (Some(a), Some(b)) => !a.content_eq(b),
+        _ => true,
+    })?;
+    let span = |nodes: &[T]| nodes.get(index).map_or(SPAN, GetSpan::span);
+    Some((span(a), span(b)))
+}
+
+/// Rewrites the differences which formatting is allowed to make into a canonical form:
+///
+/// * Empty statements are removed.
+/// * Non-computed property keys are all identifiers, as quotes are added or removed by `quoteProps`.
+/// * Adjacent JSX text and string children like `{" "}` are merged, with the whitespace JSX ignores
+///   removed and the remaining whitespace collapsed.
+/// * Import declarations and their specifiers are sorted, when [`FormatOptions::sort_imports`] is enabled.
+/// * The whitespace in templates of embedded languages is normalized, when [`FormatOptions::embedded_language_formatting`] is enabled.
+struct Normalizer<'a> {
+    ast: AstBuilder<'a>,
+    sort_imports: bool,
+    embedded_language_formatting: bool,
+}
+
+impl<'a> Normalizer<'a> {
+    fn new(allocator: &'a Allocator, options: &FormatOptions) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            sort_imports: options.sort_imports.is_some(),
+            embedded_language_formatting: options.embedded_language_formatting
+                == EmbeddedLanguageFormatting::Auto,
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for Normalizer<'a> {
+    fn visit_statements(&mut self, it: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        it.retain(|statement| !matches!(statement, Statement::EmptyStatement(_)));
+        if self.sort_imports {
+            // Sorting is stable, so the imports of a run end up in the same order
+            // whichever order they had before
+            let mut start = 0;
+            while start < it.len() {
+                let end =
+                    start + it[start..].iter().take_while(|s| import_source(s).is_some()).count();
+                it[start..end].sort_by(|a, b| import_source(a).cmp(&import_source(b)));
+                start = end + 1;
+            }
+        }
+        walk_mut::walk_statements(self, it);
+    }
+
+    fn visit_import_declaration(&mut self, it: &mut ImportDeclaration<'a>) {
+        if self.sort_imports
+            && let Some(specifiers) = &mut it.specifiers
+        {
+            specifiers.sort_by(|a, b| a.local().name.cmp(&b.local().name));
+        }
+        walk_mut::walk_import_declaration(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        let name = match it {
+            PropertyKey::StringLiteral(literal) => literal.value,
+            PropertyKey::NumericLiteral(literal) => self.ast.atom(&literal.value.to_string()),
+            _ => return walk_mut::walk_property_key(self, it),
+        };
+        *it = self.ast.property_key_static_identifier(SPAN, name);
+    }
+
+    fn visit_jsx_children(&mut self, it: &mut oxc_allocator::Vec<'a, JSXChild<'a>>) {
+        let mut children = oxc_allocator::Vec::with_capacity_in(it.len(), self.ast.allocator);
+        let mut text = String::new();
+        for child in it.drain(..) {
+            match &child {
+                JSXChild::Text(jsx_text) => text.push_str(&jsx_text_value(&jsx_text.value)),
+                JSXChild::ExpressionContainer(container)
+                    if matches!(container.expression, JSXExpression::StringLiteral(_)) =>
+                {
+                    if let JSXExpression::StringLiteral(literal) = &container.expression {
+                        text.push_str(&literal.value);
+                    }
+                }
+                _ => {
+                    self.push_jsx_text(&mut children, &mut text);
+                    children.push(child);
+                }
+            }
+        }
+        self.push_jsx_text(&mut children, &mut text);
+        *it = children;
+        walk_mut::walk_jsx_children(self, it);
+    }
+
+    fn visit_tagged_template_expression(&mut self, it: &mut TaggedTemplateExpression<'a>) {
+        if self.embedded_language_formatting
+            && let Some(language) = EmbeddedLanguage::from_tag(&it.tag)
+        {
+            let last = it.quasi.quasis.len() - 1;
+            for (index, quasi) in it.quasi.quasis.iter_mut().enumerate() {
+                let text = self.ast.atom(&normalize_embedded_text(
+                    &quasi.value.raw,
+                    language,
+                    index == 0,
+                    index == last,
+                ));
+                quasi.value.raw = text;
+                quasi.value.cooked = Some(text);
+            }
+        }
+        walk_mut::walk_tagged_template_expression(self, it);
+    }
+}
+
+impl<'a> Normalizer<'a> {
+    /// Pushes the merged `text` as a single child with its whitespace collapsed, unless it's empty.
+    fn push_jsx_text(
+        &self,
+        children: &mut oxc_allocator::Vec<'a, JSXChild<'a>>,
+        text: &mut String,
+    ) {
+        if !text.is_empty() {
+            let mut collapsed = String::with_capacity(text.len());
+            for c in text.chars() {
+                if !c.is_whitespace() {
+                    collapsed.push(c);
+                } else if !collapsed.ends_with(' ') {
+                    collapsed.push(' ');
+                }
+            }
+            children.push(self.ast.jsx_child_text(SPAN, self.ast.atom(&collapsed), None));
+            text.clear();
+        }
+    }
+}
+
+/// The text of a JSX text child as JSX interprets it: lines are trimmed,
+/// and the lines which are not empty are joined by a space.
+fn jsx_text_value(text: &str) -> String {
+    let lines = text.lines().collect::<std::vec::Vec<_>>();
+    let last_non_empty = lines.iter().rposition(|line| !line.trim_matches([' ', '\t']).is_empty());
+    let mut value = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let mut line = *line;
+        if index > 0 {
+            line = line.trim_start_matches([' ', '\t']);
+        }
+        if index < lines.len() - 1 {
+            line = line.trim_end_matches([' ', '\t']);
+        }
+        if !line.is_empty() {
+            value.push_str(line);
+            if Some(index) != last_non_empty {
+                value.push(' ');
+            }
+        }
+    }
+    value
+}
+
+/// Normalizes the whitespace of the text of an embedded template, as the embedded formatter
+/// reformats it: whitespace next to punctuation is removed, and other whitespace is collapsed
+/// into a single space. Strings are kept as is.
+///
+/// Commas are insignificant in GraphQL like whitespace, and semicolons before `}` are optional in CSS.
+/// `is_first` and `is_last` tell if the text starts or ends the template, rather than an `${}` expression.
+fn normalize_embedded_text(
+    text: &str,
+    language: EmbeddedLanguage,
+    is_first: bool,
+    is_last: bool,
+) -> String {
+    let is_whitespace =
+        |c: char| c.is_whitespace() || (c == ',' && language == EmbeddedLanguage::GraphQL);
+    let is_punctuation = |c: char| {
+        matches!(
+            c,
+            '{' | '}' | '(' | ')' | '[' | ']' | ':' | ';' | ',' | '>' | '+' | '~' | '=' | '|'
+        )
+    };
+
+    let mut out = String::with_capacity(text.len());
+    let mut quote = None;
+    let mut escaped = false;
+    let mut pending_space = false;
+    for c in text.chars() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            out.push(c);
+            continue;
+        }
+        if is_whitespace(c) {
+            pending_space = true;
+            continue;
+        }
+        if std::mem::take(&mut pending_space)
+            && !is_punctuation(c)
+            && out.chars().last().map_or(!is_first, |last| !is_punctuation(last))
+        {
+            out.push(' ');
+        }
+        if c == '}' && language == EmbeddedLanguage::Css && out.ends_with(';') {
+            out.pop();
+        }
+        if matches!(c, '"' | '\'') {
+            quote = Some(c);
+        }
+        out.push(c);
+    }
+    if pending_space && !is_last && out.chars().last().is_none_or(|last| !is_punctuation(last)) {
+        out.push(' ');
+    }
+    if is_last && language == EmbeddedLanguage::Css && out.ends_with(';') {
+        out.pop();
+    }
+    out
+}
+
+/// The source of an import declaration which can be moved by sorting.
+fn import_source<'b>(statement: &'b Statement) -> Option<&'b str> {
+    crate::utils::sort_imports::as_sortable_import(statement)
+        .map(|import| import.source.value.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SourceType;
+
+    use super::{VerifyError, find_mismatch, parse};
+    use crate::{FormatOptions, Formatter, SortImports};
+
+    /// Returns the code of the first statements which differ between `a` and `b`.
+    fn mismatch(a: &str, b: &str, options: &FormatOptions) -> Option<(String, String)> {
+        let allocator = Allocator::new();
+        let a_ret = parse(&allocator, a, SourceType::tsx());
+        let b_ret = parse(&allocator, b, SourceType::tsx());
+        assert!(a_ret.errors.is_empty() && b_ret.errors.is_empty());
+        let (a_span, b_span) = find_mismatch(&a_ret.program, &b_ret.program, &allocator, options)?;
+        Some((a_span.source_text(a).to_string(), b_span.source_text(b).to_string()))
+    }
+
+    #[test]
+    fn equivalent() {
+        let options = FormatOptions::default();
+        for (a, b) in [
+            ("a = ((1 + 2)) ;; // comment", "a = 1 + 2;"),
+            ("x = { 'a': 1, \"b-c\": 2, 3: 4 }", "x = { a: 1, 'b-c': 2, '3': 4 }"),
+            ("x = <a>\n  hello   world{\" \"}\n  <b />\n</a>", "x = <a>hello world <b /></a>"),
+            ("'use strict'; x = `a${ b + c }`", "\"use strict\";\nx = `a${b + c}`;"),
+        ] {
+            assert_eq!(mismatch(a, b, &options), None, "{a}");
+        }
+    }
+
+    #[test]
+    fn different() {
+        let options = FormatOptions::default();
+        assert_eq!(
+            mismatch("a();\nb = 1 + 2;\nc();", "a();\nb = (1 + 2) * 3;\nc();", &options),
+            Some(("b = 1 + 2;".to_string(), "b = (1 + 2) * 3;".to_string()))
+        );
+        assert_eq!(
+            mismatch("a();\nb();", "a();", &options),
+            Some(("b();".to_string(), String::new()))
+        );
+        assert_eq!(
+            mismatch(
+                "import { b } from 'b';\nimport { a } from 'a';",
+                "import { a } from 'a';\nimport { b } from 'b';",
+                &options
+            ),
+            Some(("import { b } from 'b';".to_string(), "import { a } from 'a';".to_string()))
+        );
+        assert!(mismatch("x = css`a`", "x = css`b`", &options).is_some());
+        assert!(mismatch("x = <a>{\" \"}b</a>", "x = <a>b</a>", &options).is_some());
+        assert!(mismatch("x = <a>a b</a>", "x = <a>ab</a>", &options).is_some());
+    }
+
+    #[test]
+    fn normalized_by_options() {
+        let options = FormatOptions {
+            sort_imports: Some(SortImports::default()),
+            embedded_language_formatting: crate::EmbeddedLanguageFormatting::Auto,
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            mismatch(
+                "import { c, b } from 'b';\nimport a from 'a';\nimport 'side-effect';\nimport z from 'z';",
+                "import a from 'a';\nimport { b, c } from 'b';\nimport 'side-effect';\nimport z from 'z';",
+                &options
+            ),
+            None
+        );
+        assert_eq!(mismatch("x = css`color:red`", "x = css`\n  color: red;\n`", &options), None);
+        assert_eq!(
+            mismatch(
+                "x = css`a>b{margin:0 ${m}}`",
+                "x = css`\n  a > b {\n    margin: 0 ${m};\n  }\n`",
+                &options
+            ),
+            None
+        );
+        assert_eq!(
+            mismatch(
+                "x = gql`{ a(b: 1,c: 2) }`",
+                "x = gql`\n  {\n    a(b: 1, c: 2)\n  }\n`",
+                &options
+            ),
+            None
+        );
+        // Only the whitespace of embedded templates is normalized
+        assert!(mismatch("x = css`color: red;`", "x = css`color: blue;`", &options).is_some());
+        assert!(mismatch("x = css`a b{}`", "x = css`ab {}`", &options).is_some());
+        // Side-effect imports can't be moved
+        assert!(
+            mismatch("import 'b';\nimport 'a';", "import 'a';\nimport 'b';", &options).is_some()
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let allocator = Allocator::new();
+        let source_text =
+            "const x = { 'a': 1 };;\nfunction f() { return <div>  {\" \"}a  </div> }\n";
+        let ret = parse(&allocator, source_text, SourceType::tsx());
+        let formatted = Formatter::new(&allocator, FormatOptions::default()).verify(&ret.program);
+        assert_eq!(
+            formatted.unwrap(),
+            "const x = { a: 1 };\nfunction f() {\n  return <div> a </div>;\n}\n"
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let error = VerifyError::NotIdempotent {
+            formatted: "a;\nb;\n".to_string(),
+            reformatted: "a;\nc;\n".to_string(),
+        };
+        assert_eq!(
+            error.diff().unwrap(),
+            "--- formatted\n+++ formatted twice\n@@ -1,2 +1,2 @@\n a;\n-b;\n+c;\n"
+        );
+        assert_eq!(error.to_string(), "Formatting the formatted code changes it again");
+    }
+}
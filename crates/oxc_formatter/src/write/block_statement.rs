@@ -11,16 +11,37 @@ use crate::{
         trivia::{DanglingIndentMode, FormatDanglingComments},
     },
     generated::ast_nodes::{AstNode, AstNodes},
+    utils::suppressed::FormatIgnoredRegion,
     write,
 };
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        f.join_nodes_with_hardline()
-            .entries(
-                self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_))),
-            )
-            .finish()
+        let mut statements = self
+            .iter()
+            .filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
+            .peekable();
+        let comments = f.context().comments().clone();
+        let source_text = f.context().source_text();
+        let mut join = f.join_nodes_with_hardline();
+        let mut previous_end = 0;
+        while let Some(stmt) = statements.next() {
+            let span = if let Some(region) = FormatIgnoredRegion::take(
+                stmt,
+                previous_end,
+                &mut statements,
+                &comments,
+                source_text,
+            ) {
+                join.entry(region.0, &region);
+                region.0
+            } else {
+                join.entry(stmt.span(), stmt);
+                stmt.span()
+            };
+            previous_end = span.end;
+        }
+        join.finish()
     }
 }
 
@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
-use oxc_syntax::identifier::is_identifier_name;
+use oxc_syntax::{identifier::is_identifier_name, import_source::compare_names};
 
 use crate::{
     Format, FormatResult, FormatTrailingCommas, QuoteProperties, TrailingSeparator, best_fitting,
@@ -87,7 +89,22 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, ImportDeclarationSpecifier<'a>>> {
                         &format_once(|f| {
                             let trailing_separator =
                                 FormatTrailingCommas::ES5.trailing_separator(f.options());
-                            let iter = FormatSeparatedIter::new(specifiers_iter, ",")
+                            let mut specifiers = specifiers_iter.collect::<std::vec::Vec<_>>();
+                            if let Some(sort_imports) = &f.options().sort_imports
+                                && !f.comments().has_comment_in_range(
+                                    specifiers[0].span().start,
+                                    specifiers[specifiers.len() - 1].span().end,
+                                )
+                            {
+                                specifiers.sort_by(|a, b| {
+                                    compare_names(
+                                        &specifier_name(a),
+                                        &specifier_name(b),
+                                        sort_imports.ignore_case,
+                                    )
+                                });
+                            }
+                            let iter = FormatSeparatedIter::new(specifiers.into_iter(), ",")
                                 .with_trailing_separator(trailing_separator)
                                 .map(|specifier| {
                                     format_once(move |f| {
@@ -122,6 +139,19 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, ImportDeclarationSpecifier<'a>>> {
     }
 }
 
+/// The name a named import specifier is sorted by, i.e. the name of the imported export.
+fn specifier_name<'a>(specifier: &ImportDeclarationSpecifier<'a>) -> Cow<'a, str> {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => specifier.imported.name().into(),
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+            specifier.local.name.as_str().into()
+        }
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+            specifier.local.name.as_str().into()
+        }
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, ImportSpecifier<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         let comments = f.context().comments().comments_before(self.local.span.end);
@@ -128,6 +128,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ThisExpression> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, ErrorExpression> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        FormatSuppressedNode(self.span).fmt(f)
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, ArrayExpression<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         FormatArrayExpression::new(self).fmt(f)
@@ -834,6 +840,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, LabeledStatement<'a>> {
     }
 }
 
+impl<'a> FormatWrite<'a> for AstNode<'a, ErrorStatement> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        FormatSuppressedNode(self.span).fmt(f)
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, DebuggerStatement> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, ["debugger", OptionalSemicolon])
@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use oxc_allocator::{Address, Vec};
 use oxc_ast::{ast::*, match_expression};
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::identifier::{ZWNBSP, is_line_terminator};
 
 use crate::{
@@ -13,7 +13,9 @@ use crate::{
         call_expression::is_test_call_expression,
         is_long_curried_call,
         member_chain::simple_argument::SimpleArgument,
+        sort_imports::{FormatSortedImports, as_sortable_import},
         string_utils::{FormatLiteralStringToken, StringLiteralParentKind},
+        suppressed::{FormatIgnoredRegion, FormatSuppressedNode, is_file_ignored},
     },
     write,
     write::semicolon::OptionalSemicolon,
@@ -23,6 +25,16 @@ use super::FormatWrite;
 
 impl<'a> FormatWrite<'a> for AstNode<'a, Program<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let first_start = self
+            .directives
+            .first()
+            .map(GetSpan::span)
+            .or_else(|| self.body.first().map(GetSpan::span))
+            .map_or(self.span.end, |span| span.start);
+        if is_file_ignored(first_start, f) {
+            return FormatSuppressedNode(self.span).fmt(f);
+        }
+
         let format_trailing_comments = format_once(|f| {
             let comments = f.context().comments().comments_before(self.span.end);
             FormatTrailingComments::Comments(comments).fmt(f)
@@ -54,10 +66,43 @@ impl<'a> Deref for FormatProgramBody<'a, '_> {
 
 impl<'a> Format<'a> for FormatProgramBody<'a, '_> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let sort_imports = f.options().sort_imports.clone();
+        let comments = f.context().comments().clone();
+        let source_text = f.context().source_text();
         let mut join = f.join_nodes_with_hardline();
-        for stmt in
-            self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
-        {
+        let mut statements = self
+            .iter()
+            .filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
+            .peekable();
+        let mut previous_end = 0;
+        while let Some(stmt) = statements.next() {
+            if let Some(region) = FormatIgnoredRegion::take(
+                stmt,
+                previous_end,
+                &mut statements,
+                &comments,
+                source_text,
+            ) {
+                join.entry(region.0, &region);
+                previous_end = region.0.end;
+                continue;
+            }
+
+            if let Some(sort_imports) = &sort_imports
+                && as_sortable_import(stmt.as_ref()).is_some()
+            {
+                let mut imports = vec![stmt];
+                while let Some(import) =
+                    statements.next_if(|stmt| as_sortable_import(stmt.as_ref()).is_some())
+                {
+                    imports.push(import);
+                }
+                let span = Span::new(stmt.span().start, imports[imports.len() - 1].span().end);
+                join.entry(span, &FormatSortedImports { imports: &imports, options: sort_imports });
+                previous_end = span.end;
+                continue;
+            }
+
             let span = match stmt.as_ref() {
                 // `@decorator export class A {}`
                 // Get the span of the decorator.
@@ -88,6 +133,7 @@ impl<'a> Format<'a> for FormatProgramBody<'a, '_> {
             };
 
             join.entry(span, stmt);
+            previous_end = stmt.span().end;
         }
         join.finish()
     }
@@ -8,7 +8,9 @@ use oxc_span::{GetSpan, Span};
 use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{
-    IndentWidth, format, format_args,
+    EmbeddedLanguage, IndentWidth, PLACEHOLDER_PREFIX,
+    embed::split_placeholders,
+    format, format_args,
     formatter::{
         Format, FormatElement, FormatResult, Formatter, VecBuffer,
         buffer::RemoveSoftLinesBuffer,
@@ -46,6 +48,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
             let template = &EachTemplateTable::from_template(quasi, f)?;
             // Use table formatting
             write!(f, template)
+        } else if let Some(language) = EmbeddedLanguage::from_tag(&self.tag)
+            && let Some(code) = format_embedded_code(quasi, language, f)
+        {
+            write!(f, FormatEmbeddedTemplate { quasi, code })
         } else {
             let template = TemplateLike::TemplateLiteral(quasi);
             write!(f, template)
@@ -53,6 +59,84 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
     }
 }
 
+/// Formats the code of a template in an embedded `language`, with its expressions replaced by placeholders.
+///
+/// Returns `None` if formatting embedded code is disabled, the code can't be formatted,
+/// or the formatted code doesn't keep every placeholder exactly once.
+fn format_embedded_code<'a>(
+    quasi: &TemplateLiteral<'a>,
+    language: EmbeddedLanguage,
+    f: &Formatter<'_, 'a>,
+) -> Option<&'a str> {
+    let embedded_formatter = f.context().embedded_formatter()?;
+
+    let mut code = String::new();
+    for (index, element) in quasi.quasis.iter().enumerate() {
+        code.push_str(element.value.raw.as_str());
+        if index < quasi.expressions.len() {
+            code.push_str(PLACEHOLDER_PREFIX);
+            code.push_str(&index.to_string());
+        }
+    }
+
+    let formatted = embedded_formatter.format(language, &code, f.options())?;
+
+    let mut placeholders = vec![0u32; quasi.expressions.len()];
+    for part in split_placeholders(&formatted) {
+        if let Err(index) = part {
+            *placeholders.get_mut(index)? += 1;
+        }
+    }
+    if placeholders.iter().any(|count| *count != 1) {
+        return None;
+    }
+    Some(f.context().allocator().alloc_str(&formatted))
+}
+
+/// Prints the formatted embedded code of a template, indented on its own lines.
+struct FormatEmbeddedTemplate<'a, 'b> {
+    quasi: &'b AstNode<'a, TemplateLiteral<'a>>,
+    code: &'a str,
+}
+
+impl<'a> Format<'a> for FormatEmbeddedTemplate<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if self.code.trim().is_empty() {
+            return write!(f, "``");
+        }
+
+        let expressions = self.quasi.expressions().iter().collect::<Vec<_>>();
+        let format_lines = format_with(|f| {
+            let mut is_first = true;
+            let mut has_empty_line = false;
+            for line in self.code.lines() {
+                if line.trim().is_empty() {
+                    has_empty_line = true;
+                    continue;
+                }
+                if !is_first {
+                    write!(f, [if has_empty_line { empty_line() } else { hard_line_break() }])?;
+                }
+                is_first = false;
+                has_empty_line = false;
+
+                for part in split_placeholders(line.trim_end()) {
+                    match part {
+                        Ok(text) => write!(f, dynamic_text(text))?,
+                        Err(index) => {
+                            let expression = TemplateExpression::Expression(expressions[index]);
+                            let options = FormatTemplateExpressionOptions::default();
+                            write!(f, FormatTemplateExpression::new(&expression, options))?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        });
+        write!(f, ["`", block_indent(&format_lines), "`"])
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, TemplateElement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, dynamic_text(self.value.raw.as_str()))
@@ -0,0 +1,4 @@
+const greeting = "hello";
+function greet(name: string) {
+    return greeting + name;
+}
@@ -1,4 +1,4 @@
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use futures::future::join_all;
 use log::{debug, info, warn};
@@ -6,29 +6,80 @@ use rustc_hash::FxBuildHasher;
 use serde_json::json;
 use tokio::sync::{OnceCell, RwLock, SetError};
 use tower_lsp_server::{
-    Client, LanguageServer,
+    Client, LanguageServer, UriExt,
     jsonrpc::{Error, ErrorCode, Result},
     lsp_types::{
-        CodeActionParams, CodeActionResponse, ConfigurationItem, Diagnostic,
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
-        DidChangeWatchedFilesRegistrationOptions, DidChangeWorkspaceFoldersParams,
-        DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-        DocumentFormattingParams, ExecuteCommandParams, InitializeParams, InitializeResult,
-        InitializedParams, Registration, ServerInfo, TextEdit, Unregistration, Uri, WorkspaceEdit,
+        CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+        ConfigurationItem, Diagnostic, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+        DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
+        DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+        DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
+        DocumentDiagnosticReportResult, DocumentFormattingParams, DocumentHighlight,
+        DocumentHighlightParams, DocumentOnTypeFormattingParams,
+        DocumentOnTypeFormattingRegistrationOptions, DocumentRangeFormattingParams,
+        ExecuteCommandParams, FullDocumentDiagnosticReport, GotoDefinitionParams,
+        GotoDefinitionResponse, Hover, HoverParams, InitializeParams, InitializeResult,
+        InitializedParams, Location, PrepareRenameResponse, ProgressToken, ReferenceParams,
+        Registration, RelatedFullDocumentDiagnosticReport, RenameParams, ServerInfo,
+        TextDocumentPositionParams, TextEdit, Unregistration, Uri, WorkDoneProgressCreateParams,
+        WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult,
+        WorkspaceDocumentDiagnosticReport, WorkspaceEdit, WorkspaceFullDocumentDiagnosticReport,
+        request::WorkDoneProgressCreate,
     },
 };
 
 use crate::{
     ConcurrentHashMap,
     capabilities::Capabilities,
-    code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
-    commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs},
+    code_actions::{CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, organize_imports_code_action},
+    commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs, LINT_WORKSPACE_COMMAND_ID},
     file_system::LSPFileSystem,
     linter::server_linter::ServerLinterRun,
+    navigation::{Document, DocumentCache},
     options::{Options, WorkspaceOption},
+    organize_imports::organize_imports,
     worker::WorkspaceWorker,
 };
 
+/// The characters triggering `textDocument/onTypeFormatting`.
+const ON_TYPE_FORMATTING_TRIGGER_CHARACTERS: [&str; 2] = [";", "}"];
+
+/// The dynamic registrations of the formatting requests the client supports.
+fn formatting_registrations(capabilities: &Capabilities) -> Vec<Registration> {
+    let mut registrations = vec![];
+    if capabilities.dynamic_formatting {
+        registrations.push(Registration {
+            id: "dynamic-formatting".to_string(),
+            method: "textDocument/formatting".to_string(),
+            register_options: None,
+        });
+    }
+    if capabilities.dynamic_range_formatting {
+        registrations.push(Registration {
+            id: "dynamic-range-formatting".to_string(),
+            method: "textDocument/rangeFormatting".to_string(),
+            register_options: None,
+        });
+    }
+    if capabilities.dynamic_on_type_formatting {
+        registrations.push(Registration {
+            id: "dynamic-on-type-formatting".to_string(),
+            method: "textDocument/onTypeFormatting".to_string(),
+            register_options: Some(json!(DocumentOnTypeFormattingRegistrationOptions {
+                document_selector: None,
+                first_trigger_character: ON_TYPE_FORMATTING_TRIGGER_CHARACTERS[0].to_string(),
+                more_trigger_character: Some(
+                    ON_TYPE_FORMATTING_TRIGGER_CHARACTERS[1..]
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                ),
+            })),
+        });
+    }
+    registrations
+}
+
 /// The Backend implements the LanguageServer trait to handle LSP requests and notifications.
 ///
 /// It manages multiple WorkspaceWorkers, each corresponding to a workspace folder.
@@ -54,16 +105,22 @@ pub struct Backend {
     // WorkspaceWorkers are only written on 2 occasions:
     // 1. `initialize` request with workspace folders
     // 2. `workspace/didChangeWorkspaceFolders` request
-    workspace_workers: Arc<RwLock<Vec<WorkspaceWorker>>>,
+    // Long running tasks like linting the workspace copy the workers, so the lock is not held.
+    workspace_workers: Arc<RwLock<Vec<Arc<WorkspaceWorker>>>>,
     // Capabilities of the language server, set once during `initialize` request.
     // Depending on the client capabilities, the server supports different capabilities.
     capabilities: OnceCell<Capabilities>,
     // A simple in-memory file system to store the content of open files.
-    // The client will send the content of in-memory files on `textDocument/didOpen` and `textDocument/didChange`.
-    // This is only needed when the client supports `textDocument/formatting` request.
-    file_system: Arc<RwLock<LSPFileSystem>>,
+    // The client will send the content on `textDocument/didOpen`, and the incremental changes on `textDocument/didChange`.
+    file_system: LSPFileSystem,
+    // Parsed open documents for navigation requests, reused until the document changes.
+    documents: DocumentCache,
 }
 
+/// How long to wait for more changes before linting a changed file.
+/// Lint runs for older versions of a file are skipped, so typing in large files stays responsive.
+const DID_CHANGE_DEBOUNCE: Duration = Duration::from_millis(100);
+
 impl LanguageServer for Backend {
     /// Initialize the language server with the given parameters.
     /// This method sets up workspace workers, capabilities, and starts the
@@ -107,11 +164,13 @@ impl LanguageServer for Backend {
         let workers = if let Some(workspace_folders) = &params.workspace_folders {
             workspace_folders
                 .iter()
-                .map(|workspace_folder| WorkspaceWorker::new(workspace_folder.uri.clone()))
+                .map(|workspace_folder| {
+                    Arc::new(WorkspaceWorker::new(workspace_folder.uri.clone()))
+                })
                 .collect()
         // client sent deprecated root uri
         } else if let Some(root_uri) = params.root_uri {
-            vec![WorkspaceWorker::new(root_uri)]
+            vec![Arc::new(WorkspaceWorker::new(root_uri))]
         // client is in single file mode, create no workers
         } else {
             vec![]
@@ -172,7 +231,7 @@ impl LanguageServer for Backend {
             return;
         };
 
-        let workers = &*self.workspace_workers.read().await;
+        let workers = &self.workspace_workers.read().await.clone();
         let needed_configurations =
             ConcurrentHashMap::with_capacity_and_hasher(workers.len(), FxBuildHasher);
         let needed_configurations = needed_configurations.pin_owned();
@@ -214,41 +273,40 @@ impl LanguageServer for Backend {
             }
         }
 
-        if capabilities.dynamic_formatting {
-            // check if one workspace has formatting enabled
-            let mut started_worker = false;
-            for worker in workers {
-                if worker.has_active_formatter().await {
-                    started_worker = true;
-                    break;
-                }
-            }
-
-            if started_worker {
-                registrations.push(Registration {
-                    id: "dynamic-formatting".to_string(),
-                    method: "textDocument/formatting".to_string(),
-                    register_options: None,
-                });
+        // check if one workspace has formatting enabled
+        let mut started_worker = false;
+        for worker in workers {
+            if worker.has_active_formatter().await {
+                started_worker = true;
+                break;
             }
         }
-
-        if registrations.is_empty() {
-            return;
+        if started_worker {
+            registrations.extend(formatting_registrations(capabilities));
         }
-        if let Err(err) = self.client.register_capability(registrations).await {
+
+        if !registrations.is_empty()
+            && let Err(err) = self.client.register_capability(registrations).await
+        {
             warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
         }
+
+        let mut lint_workspace_workers = vec![];
+        for worker in workers {
+            if worker.lints_workspace().await {
+                lint_workspace_workers.push(Arc::clone(worker));
+            }
+        }
+        self.lint_workspaces(&lint_workspace_workers, None).await;
     }
 
-    /// This method clears all diagnostics and the in-memory file system if dynamic formatting is enabled.
+    /// This method clears all diagnostics and the in-memory file system.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#shutdown>
     async fn shutdown(&self) -> Result<()> {
         self.clear_all_diagnostics().await;
-        if self.capabilities.get().is_some_and(|option| option.dynamic_formatting) {
-            self.file_system.write().await.clear();
-        }
+        self.file_system.clear();
+        self.documents.clear();
         Ok(())
     }
 
@@ -259,7 +317,7 @@ impl LanguageServer for Backend {
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeConfiguration>
     async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
-        let workers = self.workspace_workers.read().await;
+        let workers = self.workspace_workers.read().await.clone();
         let new_diagnostics: papaya::HashMap<String, Vec<Diagnostic>, FxBuildHasher> =
             ConcurrentHashMap::default();
         let mut removing_registrations = vec![];
@@ -295,7 +353,7 @@ impl LanguageServer for Backend {
         {
             let configs = self
                 .request_workspace_configuration(
-                    workers.iter().map(WorkspaceWorker::get_root_uri).collect(),
+                    workers.iter().map(|worker| worker.get_root_uri()).collect(),
                 )
                 .await;
 
@@ -319,6 +377,7 @@ impl LanguageServer for Backend {
         };
 
         let mut global_formatting_added = false;
+        let mut lint_workspace_workers = vec![];
 
         for option in resolved_options {
             let Some(worker) =
@@ -327,11 +386,11 @@ impl LanguageServer for Backend {
                 continue;
             };
 
-            let (diagnostics, watcher, formatter_activated) =
+            let linted_workspace = worker.lints_workspace().await;
+            let (diagnostics, watchers, formatter_activated) =
                 worker.did_change_configuration(&option.options).await;
 
-            if formatter_activated && self.capabilities.get().is_some_and(|c| c.dynamic_formatting)
-            {
+            if formatter_activated {
                 global_formatting_added = true;
             }
 
@@ -344,7 +403,16 @@ impl LanguageServer for Backend {
                 }
             }
 
-            if let Some(watcher) = watcher
+            // lint the whole workspace, or clear the diagnostics of files which are not opened
+            if !linted_workspace && option.options.lint.lint_workspace {
+                lint_workspace_workers.push(Arc::clone(worker));
+            } else if linted_workspace && !option.options.lint.lint_workspace {
+                for (uri, diagnostics) in self.clear_unopened_diagnostics(worker).await {
+                    new_diagnostics.pin().insert(uri, diagnostics);
+                }
+            }
+
+            if let Some(watchers) = watchers
                 && self.capabilities.get().is_some_and(|capabilities| capabilities.dynamic_watchers)
             {
                 // remove the old watcher
@@ -357,7 +425,7 @@ impl LanguageServer for Backend {
                     id: format!("watcher-{}", worker.get_root_uri().as_str()),
                     method: "workspace/didChangeWatchedFiles".to_string(),
                     register_options: Some(json!(DidChangeWatchedFilesRegistrationOptions {
-                        watchers: vec![watcher]
+                        watchers
                     })),
                 });
             }
@@ -375,12 +443,8 @@ impl LanguageServer for Backend {
 
         // override the existing formatting registration
         // do not remove the registration, because other workspaces might still need it
-        if global_formatting_added {
-            adding_registrations.push(Registration {
-                id: "dynamic-formatting".to_string(),
-                method: "textDocument/formatting".to_string(),
-                register_options: None,
-            });
+        if global_formatting_added && let Some(capabilities) = self.capabilities.get() {
+            adding_registrations.extend(formatting_registrations(capabilities));
         }
 
         if !removing_registrations.is_empty()
@@ -393,9 +457,12 @@ impl LanguageServer for Backend {
         {
             warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
         }
+
+        self.lint_workspaces(&lint_workspace_workers, None).await;
     }
 
-    /// This notification is sent when a configuration file of a tool changes (example: `.oxlintrc.json`).
+    /// This notification is sent when a configuration file of a tool changes (example: `.oxlintrc.json`),
+    /// or a lintable file changes while the whole workspace is linted.
     /// The server will re-lint the affected files and send updated diagnostics.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_didChangeWatchedFiles>
@@ -414,7 +481,9 @@ impl LanguageServer for Backend {
             else {
                 continue;
             };
-            let Some(diagnostics) = worker.did_change_watched_files(file_event).await else {
+            let content = self.file_system.get(&file_event.uri);
+            let Some(diagnostics) = worker.did_change_watched_files(file_event, content).await
+            else {
                 continue;
             };
 
@@ -494,15 +563,31 @@ impl LanguageServer for Backend {
                         watchers: worker.init_watchers().await
                     })),
                 });
-                workers.push(worker);
+                workers.push(Arc::new(worker));
             }
         // client does not support the request
         } else {
-            for folder in params.event.added {
-                let worker = WorkspaceWorker::new(folder.uri);
+            for folder in &params.event.added {
+                let worker = WorkspaceWorker::new(folder.uri.clone());
                 // use default options
                 worker.start_worker(&default_options).await;
-                workers.push(worker);
+                workers.push(Arc::new(worker));
+            }
+        }
+
+        let added_workers = workers
+            .iter()
+            .filter(|worker| {
+                params.event.added.iter().any(|folder| worker.get_root_uri() == &folder.uri)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        drop(workers);
+
+        let mut lint_workspace_workers = vec![];
+        for worker in added_workers {
+            if worker.lints_workspace().await {
+                lint_workspace_workers.push(worker);
             }
         }
 
@@ -520,9 +605,10 @@ impl LanguageServer for Backend {
                 warn!("sending unregisterCapability.didChangeWatchedFiles failed: {err}");
             }
         }
+
+        self.lint_workspaces(&lint_workspace_workers, None).await;
     }
 
-    /// It will remove the in-memory file content, because the file is saved to disk.
     /// It will re-lint the file and send updated diagnostics, if necessary.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_didSave>
@@ -534,67 +620,80 @@ impl LanguageServer for Backend {
             return;
         };
 
-        if self.capabilities.get().is_some_and(|option| option.dynamic_formatting) {
-            // saving the file means we can read again from the file system
-            self.file_system.write().await.remove(uri);
-        }
-
         if let Some(diagnostics) = worker.lint_file(uri, None, ServerLinterRun::OnSave).await {
-            self.client
-                .publish_diagnostics(
-                    uri.clone(),
-                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                    None,
-                )
-                .await;
+            self.publish_diagnostics(
+                uri,
+                diagnostics.into_iter().map(|d| d.diagnostic).collect(),
+                None,
+            )
+            .await;
         }
     }
-    /// It will update the in-memory file content if the client supports dynamic formatting.
-    /// It will re-lint the file and send updated diagnostics, if necessary.
+    /// It will apply the incremental changes to the in-memory file content.
+    /// It will re-lint the file and send updated diagnostics, if necessary and the client does not pull diagnostics.
+    /// Linting is debounced, and skipped when a newer version of the file arrives in the meantime.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_didChange>
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = &params.text_document.uri;
+        let version = params.text_document.version;
+        // Apply the changes before awaiting anything, so they are applied in the order they were sent.
+        let Some(content) = self.file_system.apply_changes(uri, version, &params.content_changes)
+        else {
+            warn!("received changes for a file which is not open: {}", uri.as_str());
+            return;
+        };
+        // The client will pull the diagnostics of the new version.
+        if self.is_pull_diagnostics() {
+            return;
+        }
+
+        tokio::time::sleep(DID_CHANGE_DEBOUNCE).await;
+        if self.file_system.version(uri) != Some(version) {
+            return;
+        }
+
         let workers = self.workspace_workers.read().await;
         let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
             return;
         };
-        let content = params.content_changes.first().map(|c| c.text.clone());
 
-        if self.capabilities.get().is_some_and(|option| option.dynamic_formatting)
-            && let Some(content) = &content
-        {
-            self.file_system.write().await.set(uri, content.to_string());
+        let Some(diagnostics) = worker.lint_file(uri, Some(content), ServerLinterRun::OnType).await
+        else {
+            return;
+        };
+        // The diagnostics are outdated, a newer version will publish its own.
+        if self.file_system.version(uri) != Some(version) {
+            return;
         }
 
-        if let Some(diagnostics) = worker.lint_file(uri, content, ServerLinterRun::OnType).await {
-            self.client
-                .publish_diagnostics(
-                    uri.clone(),
-                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                    Some(params.text_document.version),
-                )
-                .await;
-        }
+        self.client
+            .publish_diagnostics(
+                uri.clone(),
+                diagnostics.into_iter().map(|d| d.diagnostic).collect(),
+                Some(version),
+            )
+            .await;
     }
 
-    /// It will add the in-memory file content if the client supports dynamic formatting.
-    /// It will lint the file and send diagnostics, if necessary.
+    /// It will add the in-memory file content.
+    /// It will lint the file and send diagnostics, if necessary and the client does not pull diagnostics.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_didOpen>
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         let uri = &params.text_document.uri;
+        let content = params.text_document.text;
+        self.file_system.open(uri, &content, params.text_document.version);
+        // The client will pull the diagnostics of the opened file.
+        if self.is_pull_diagnostics() {
+            return;
+        }
+
         let workers = self.workspace_workers.read().await;
         let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
             return;
         };
 
-        let content = params.text_document.text;
-
-        if self.capabilities.get().is_some_and(|option| option.dynamic_formatting) {
-            self.file_system.write().await.set(uri, content.to_string());
-        }
-
         if let Some(diagnostics) =
             worker.lint_file(uri, Some(content), ServerLinterRun::Always).await
         {
@@ -608,40 +707,65 @@ impl LanguageServer for Backend {
         }
     }
 
-    /// It will remove the in-memory file content if the client supports dynamic formatting.
+    /// It will remove the in-memory file content.
     /// It will clear the diagnostics (internally) for the closed file.
+    /// When the whole workspace is linted, the file is linted again with its content on disk instead.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_didClose>
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = &params.text_document.uri;
+        self.file_system.remove(uri);
+        self.documents.remove(uri);
         let workers = self.workspace_workers.read().await;
         let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
             return;
         };
-        if self.capabilities.get().is_some_and(|option| option.dynamic_formatting) {
-            self.file_system.write().await.remove(uri);
+        if !worker.lints_workspace().await {
+            worker.remove_diagnostics(&params.text_document.uri).await;
+            return;
+        }
+        if let Some(diagnostics) = worker.lint_file(uri, None, ServerLinterRun::Always).await {
+            self.publish_diagnostics(
+                uri,
+                diagnostics.into_iter().map(|d| d.diagnostic).collect(),
+                None,
+            )
+            .await;
         }
-        worker.remove_diagnostics(&params.text_document.uri).await;
     }
 
     /// It will return code actions or commands for the given range.
     /// The client can send `context.only` to `source.fixAll.oxc` to fix all diagnostics of the file.
+    /// The client can send `context.only` to `source.organizeImports` or `source` to organize the imports of the file,
+    /// which does not depend on any lint rule.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_codeAction>
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = &params.text_document.uri;
-        let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
-            return Ok(None);
-        };
-
-        let is_source_fix_all_oxc = params
-            .context
-            .only
-            .is_some_and(|only| only.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
+        let only = params.context.only.unwrap_or_default();
+        let mut code_actions = vec![];
+
+        if (only.contains(&CodeActionKind::SOURCE_ORGANIZE_IMPORTS)
+            || only.contains(&CodeActionKind::SOURCE))
+            && let Some(source_text) = self.read_file(uri)
+            && let Some(code_action) = organize_imports(uri, &source_text)
+                .and_then(|text_edits| organize_imports_code_action(uri, text_edits))
+        {
+            code_actions.push(CodeActionOrCommand::CodeAction(code_action));
+        }
 
-        let code_actions =
-            worker.get_code_actions_or_commands(uri, &params.range, is_source_fix_all_oxc).await;
+        // the client only wants to organize the imports, no need to lint the file
+        if !only.contains(&CodeActionKind::SOURCE_ORGANIZE_IMPORTS) {
+            let workers = self.workspace_workers.read().await;
+            if let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) {
+                let is_source_fix_all_oxc = only.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC);
+                code_actions.extend(
+                    worker
+                        .get_code_actions_or_commands(uri, &params.range, is_source_fix_all_oxc)
+                        .await,
+                );
+            }
+        }
 
         if code_actions.is_empty() {
             return Ok(None);
@@ -651,7 +775,7 @@ impl LanguageServer for Backend {
     }
 
     /// It will execute the given command with the provided arguments.
-    /// Currently, only the `fixAll` command is supported.
+    /// The `fixAll` and `lintWorkspace` commands are supported.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_executeCommand>
     async fn execute_command(
@@ -687,6 +811,13 @@ impl LanguageServer for Backend {
             return Ok(None);
         }
 
+        if params.command == LINT_WORKSPACE_COMMAND_ID {
+            let workers = self.workspace_workers.read().await.clone();
+            self.lint_workspaces(&workers, params.work_done_progress_params.work_done_token).await;
+
+            return Ok(None);
+        }
+
         Err(Error::invalid_request())
     }
 
@@ -699,7 +830,199 @@ impl LanguageServer for Backend {
         let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
             return Ok(None);
         };
-        Ok(worker.format_file(uri, self.file_system.read().await.get(uri)).await)
+        Ok(worker.format_file(uri, self.file_system.get(uri)).await)
+    }
+
+    /// It will return text edits to format the statements enclosing the range, if formatting is enabled for the workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_rangeFormatting>
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        Ok(worker.format_range(uri, self.file_system.get(uri), params.range).await)
+    }
+
+    /// It will return text edits to format the statement ended by the typed `;` or `}`, if formatting is enabled for the workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_onTypeFormatting>
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let uri = &text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+            return Ok(None);
+        };
+        Ok(worker.format_on_type(uri, self.file_system.get(uri), position).await)
+    }
+
+    /// It will return the documentation of the rules reporting diagnostics at the given position.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_hover>
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) =
+            workers.iter().find(|worker| worker.is_responsible_for_uri(&text_document.uri))
+        else {
+            return Ok(None);
+        };
+        Ok(worker.get_hover(&text_document.uri, position).await)
+    }
+
+    /// It will lint the file and return its diagnostics, when the client pulls diagnostics.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_diagnostic>
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let diagnostics = match workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) {
+            Some(worker) => {
+                worker.pull_diagnostics(uri, self.file_system.get(uri)).await.unwrap_or_default()
+            }
+            None => vec![],
+        };
+
+        Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+            RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: None,
+                    items: diagnostics.into_iter().map(|d| d.diagnostic).collect(),
+                },
+            },
+        )))
+    }
+
+    /// It will return the cached diagnostics of all linted files, when the client pulls diagnostics.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_diagnostic>
+    async fn workspace_diagnostic(
+        &self,
+        _params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let mut items = vec![];
+        let workers = self.workspace_workers.read().await;
+        for worker in workers.iter() {
+            for (uri, diagnostics) in worker.get_all_cached_diagnostics().await {
+                let version = self.file_system.version(&uri).map(i64::from);
+                items.push(WorkspaceDocumentDiagnosticReport::Full(
+                    WorkspaceFullDocumentDiagnosticReport {
+                        uri,
+                        version,
+                        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                            result_id: None,
+                            items: diagnostics.into_iter().map(|d| d.diagnostic).collect(),
+                        },
+                    },
+                ));
+            }
+        }
+
+        Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items }))
+    }
+
+    /// It will return the definition of the binding at the given position.
+    /// Imported bindings are resolved to the export of the imported module.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_definition>
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        let location = self
+            .with_document(&text_document.uri, |document| {
+                document.definition(position, |path| {
+                    Uri::from_file_path(path).and_then(|uri| self.read_file(&uri))
+                })
+            })
+            .await
+            .flatten();
+        Ok(location.map(GotoDefinitionResponse::Scalar))
+    }
+
+    /// It will return all references to the binding at the given position in the document.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_references>
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        Ok(self
+            .with_document(&text_document.uri, |document| {
+                document.references(position, params.context.include_declaration)
+            })
+            .await
+            .flatten())
+    }
+
+    /// It will highlight all reads and writes of the binding at the given position.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_documentHighlight>
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        Ok(self
+            .with_document(&text_document.uri, |document| document.highlights(position))
+            .await
+            .flatten())
+    }
+
+    /// It will return the range of the binding at the given position, if it can be renamed.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_prepareRename>
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let range = self
+            .with_document(&params.text_document.uri, |document| {
+                document.prepare_rename(params.position)
+            })
+            .await
+            .flatten();
+        Ok(range.map(PrepareRenameResponse::Range))
+    }
+
+    /// It will rename the binding at the given position in the document.
+    /// Exported declarations are not renamed, as it would break the modules importing them.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_rename>
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let Some(text_edits) = self
+            .with_document(&text_document.uri, |document| {
+                document.rename(position, &params.new_name)
+            })
+            .await
+            .transpose()
+            .map_err(Error::invalid_params)?
+            .flatten()
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(text_document.uri, text_edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }))
     }
 }
 
@@ -713,10 +1036,34 @@ impl Backend {
             client,
             workspace_workers: Arc::new(RwLock::new(vec![])),
             capabilities: OnceCell::new(),
-            file_system: Arc::new(RwLock::new(LSPFileSystem::default())),
+            file_system: LSPFileSystem::default(),
+            documents: DocumentCache::default(),
         }
     }
 
+    /// The content of the document, from the in-memory file system if it is open, or from disk.
+    fn read_file(&self, uri: &Uri) -> Option<String> {
+        self.file_system.get(uri).or_else(|| std::fs::read_to_string(uri.to_file_path()?).ok())
+    }
+
+    /// Runs `f` with the parsed document.
+    /// Open documents are parsed once per version, and reparsed incrementally after they change.
+    /// Files on disk are parsed on every call.
+    async fn with_document<T>(&self, uri: &Uri, f: impl FnOnce(&Document) -> T) -> Option<T> {
+        let cached =
+            self.file_system.version(uri).and_then(|version| self.documents.get(uri, version));
+        let document = if let Some(document) = cached {
+            document
+        } else if let Some((source_text, version)) = self.file_system.get_with_version(uri) {
+            self.documents.update(uri, version, source_text).await?
+        } else {
+            let source_text = std::fs::read_to_string(uri.to_file_path()?).ok()?;
+            return Some(f(&Document::new(uri.clone(), source_text)?));
+        };
+        let document = document.lock().await;
+        Some(f(&document))
+    }
+
     /// Request the workspace configuration from the client
     /// and return the options for each workspace folder.
     /// The check if the client support workspace configuration, should be done before.
@@ -759,8 +1106,162 @@ impl Backend {
         self.publish_all_diagnostics(&cleared_diagnostics).await;
     }
 
+    /// Returns `true` if the client pulls diagnostics with `textDocument/diagnostic`,
+    /// instead of the server publishing them.
+    fn is_pull_diagnostics(&self) -> bool {
+        self.capabilities.get().is_some_and(|capabilities| capabilities.pull_diagnostics)
+    }
+
+    /// Ask the client to pull the diagnostics again, when the client supports it.
+    async fn refresh_diagnostics(&self) {
+        if !self.capabilities.get().is_some_and(|capabilities| capabilities.diagnostic_refresh) {
+            return;
+        }
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            warn!("sending workspace/diagnostic/refresh failed: {err}");
+        }
+    }
+
+    /// Publish diagnostics for a file.
+    /// When the client pulls diagnostics, it is asked to pull them again instead.
+    async fn publish_diagnostics(
+        &self,
+        uri: &Uri,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) {
+        if self.is_pull_diagnostics() {
+            self.refresh_diagnostics().await;
+            return;
+        }
+        self.client.publish_diagnostics(uri.clone(), diagnostics, version).await;
+    }
+
+    /// Lint all files of the workspace folders and publish their diagnostics.
+    /// Opened files are linted with their in-memory content.
+    /// The progress is reported with `$/progress`, to the token passed by the client
+    /// or to a token created by the server when the client supports it.
+    async fn lint_workspaces(
+        &self,
+        workers: &[Arc<WorkspaceWorker>],
+        token: Option<ProgressToken>,
+    ) {
+        if workers.is_empty() {
+            return;
+        }
+
+        let files = join_all(workers.iter().map(|worker| worker.workspace_files())).await;
+        let total = files.iter().map(Vec::len).sum::<usize>();
+
+        let token = match token {
+            Some(token) => Some(token),
+            None => self.create_work_done_progress().await,
+        };
+        let progress = match token {
+            Some(token) => Some(
+                self.client
+                    .progress(token, "oxc: Linting workspace")
+                    .with_message(format!("0/{total} files"))
+                    .with_percentage(0)
+                    .begin()
+                    .await,
+            ),
+            None => None,
+        };
+
+        // the files are linted on blocking tasks, so only the progress is reported from here
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let lint = join_all(workers.iter().zip(files).map(|(worker, uris)| {
+            let files = uris
+                .into_iter()
+                .map(|uri| {
+                    let content = self.file_system.get(&uri);
+                    (uri, content)
+                })
+                .collect();
+            Arc::clone(worker).lint_workspace(files, sender.clone())
+        }));
+        drop(sender);
+        let report_progress = async {
+            let mut linted = 0;
+            let mut percentage = 0;
+            while receiver.recv().await.is_some() {
+                linted += 1;
+                // only report when the percentage changes, to not flood the client for large workspaces
+                let current_percentage = u32::try_from(linted * 100 / total).unwrap_or(100);
+                if let Some(progress) = &progress
+                    && current_percentage != percentage
+                {
+                    percentage = current_percentage;
+                    progress
+                        .report_with_message(format!("{linted}/{total} files"), current_percentage)
+                        .await;
+                }
+            }
+        };
+        let (diagnostics, ()) = tokio::join!(lint, report_progress);
+        let diagnostics = diagnostics
+            .into_iter()
+            .flatten()
+            .map(|(uri, reports)| {
+                (uri.to_string(), reports.into_iter().map(|d| d.diagnostic).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        self.publish_all_diagnostics(&diagnostics).await;
+        if let Some(progress) = progress {
+            progress.finish_with_message(format!("{total} files")).await;
+        }
+    }
+
+    /// Create a token for `$/progress` with `window/workDoneProgress/create`, when the client supports it.
+    async fn create_work_done_progress(&self) -> Option<ProgressToken> {
+        if !self.capabilities.get().is_some_and(|capabilities| capabilities.work_done_progress) {
+            return None;
+        }
+        let token = ProgressToken::String("oxc/lintWorkspace".to_string());
+        if let Err(err) = self
+            .client
+            .send_request::<WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+        {
+            warn!("sending window/workDoneProgress/create failed: {err}");
+            return None;
+        }
+        Some(token)
+    }
+
+    /// Remove the cached diagnostics of all files of the worker, which are not opened.
+    /// Returns the cleared diagnostics, which should be published.
+    async fn clear_unopened_diagnostics(
+        &self,
+        worker: &WorkspaceWorker,
+    ) -> Vec<(String, Vec<Diagnostic>)> {
+        let mut cleared_diagnostics = vec![];
+        for (uri, diagnostics) in worker.get_clear_diagnostics().await {
+            let Ok(file_uri) = Uri::from_str(&uri) else {
+                continue;
+            };
+            if self.file_system.get(&file_uri).is_some() {
+                continue;
+            }
+            worker.remove_diagnostics(&file_uri).await;
+            cleared_diagnostics.push((uri, diagnostics));
+        }
+        cleared_diagnostics
+    }
+
     /// Publish diagnostics for all files.
+    /// When the client pulls diagnostics, it is asked to pull them again instead.
     async fn publish_all_diagnostics(&self, result: &[(String, Vec<Diagnostic>)]) {
+        if self.is_pull_diagnostics() {
+            if !result.is_empty() {
+                self.refresh_diagnostics().await;
+            }
+            return;
+        }
         join_all(result.iter().map(|(path, diagnostics)| {
             self.client.publish_diagnostics(Uri::from_str(path).unwrap(), diagnostics.clone(), None)
         }))
@@ -1,11 +1,16 @@
 use tower_lsp_server::lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    ExecuteCommandOptions, OneOf, SaveOptions, ServerCapabilities, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions,
-    WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    DiagnosticOptions, DiagnosticServerCapabilities, ExecuteCommandOptions,
+    HoverProviderCapability, OneOf, RenameOptions, SaveOptions, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions, WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities,
+    WorkspaceServerCapabilities,
 };
 
-use crate::{code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID};
+use crate::{
+    code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
+    commands::{FIX_ALL_COMMAND_ID, LINT_WORKSPACE_COMMAND_ID},
+};
 
 #[derive(Clone, Default)]
 pub struct Capabilities {
@@ -15,6 +20,11 @@ pub struct Capabilities {
     pub workspace_configuration: bool,
     pub dynamic_watchers: bool,
     pub dynamic_formatting: bool,
+    pub dynamic_range_formatting: bool,
+    pub dynamic_on_type_formatting: bool,
+    pub pull_diagnostics: bool,
+    pub diagnostic_refresh: bool,
+    pub work_done_progress: bool,
 }
 
 impl From<ClientCapabilities> for Capabilities {
@@ -35,6 +45,13 @@ impl From<ClientCapabilities> for Capabilities {
             .workspace
             .as_ref()
             .is_some_and(|workspace| workspace.configuration.is_some_and(|config| config));
+        let diagnostic_refresh = value.workspace.as_ref().is_some_and(|workspace| {
+            workspace
+                .diagnostic
+                .as_ref()
+                .is_some_and(|diagnostic| diagnostic.refresh_support.is_some_and(|refresh| refresh))
+        });
+
         let dynamic_watchers = value.workspace.is_some_and(|workspace| {
             workspace.did_change_watched_files.is_some_and(|watched_files| {
                 watched_files.dynamic_registration.is_some_and(|dynamic| dynamic)
@@ -45,7 +62,30 @@ impl From<ClientCapabilities> for Capabilities {
                 formatting.dynamic_registration.is_some_and(|dynamic| dynamic)
             })
         });
+        let dynamic_range_formatting = value.text_document.as_ref().is_some_and(|text_document| {
+            text_document.range_formatting.is_some_and(|formatting| {
+                formatting.dynamic_registration.is_some_and(|dynamic| dynamic)
+            })
+        });
+        let dynamic_on_type_formatting =
+            value.text_document.as_ref().is_some_and(|text_document| {
+                text_document.on_type_formatting.is_some_and(|formatting| {
+                    formatting.dynamic_registration.is_some_and(|dynamic| dynamic)
+                })
+            });
+
+        let work_done_progress = value
+            .window
+            .as_ref()
+            .is_some_and(|window| window.work_done_progress.is_some_and(|progress| progress));
 
+        // Diagnostics change without a request from the client, e.g. when a configuration file
+        // changes. Clients which can not be asked to pull them again get them pushed instead.
+        let pull_diagnostics = diagnostic_refresh
+            && value
+                .text_document
+                .as_ref()
+                .is_some_and(|text_document| text_document.diagnostic.is_some());
         Self {
             code_action_provider,
             workspace_apply_edit,
@@ -53,6 +93,11 @@ impl From<ClientCapabilities> for Capabilities {
             workspace_configuration,
             dynamic_watchers,
             dynamic_formatting,
+            dynamic_range_formatting,
+            dynamic_on_type_formatting,
+            pull_diagnostics,
+            diagnostic_refresh,
+            work_done_progress,
         }
     }
 }
@@ -62,7 +107,7 @@ impl From<Capabilities> for ServerCapabilities {
         Self {
             text_document_sync: Some(TextDocumentSyncCapability::Options(
                 TextDocumentSyncOptions {
-                    change: Some(TextDocumentSyncKind::FULL),
+                    change: Some(TextDocumentSyncKind::INCREMENTAL),
                     open_close: Some(true),
                     save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
                         include_text: Some(false),
@@ -82,6 +127,7 @@ impl From<Capabilities> for ServerCapabilities {
                     code_action_kinds: Some(vec![
                         CodeActionKind::QUICKFIX,
                         CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
+                        CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
                     ]),
                     work_done_progress_options: WorkDoneProgressOptions {
                         work_done_progress: None,
@@ -93,7 +139,10 @@ impl From<Capabilities> for ServerCapabilities {
             },
             execute_command_provider: if value.workspace_execute_command {
                 Some(ExecuteCommandOptions {
-                    commands: vec![FIX_ALL_COMMAND_ID.to_string()],
+                    commands: vec![
+                        FIX_ALL_COMMAND_ID.to_string(),
+                        LINT_WORKSPACE_COMMAND_ID.to_string(),
+                    ],
                     ..Default::default()
                 })
             } else {
@@ -101,6 +150,27 @@ impl From<Capabilities> for ServerCapabilities {
             },
             // the server supports formatting, but it will tell the client if he enabled the setting
             document_formatting_provider: None,
+            definition_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            document_highlight_provider: Some(OneOf::Left(true)),
+            rename_provider: Some(OneOf::Right(RenameOptions {
+                prepare_provider: Some(true),
+                work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+            })),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            // the server pushes diagnostics with `textDocument/publishDiagnostics`, unless the client can pull and refresh them
+            diagnostic_provider: if value.pull_diagnostics {
+                Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                    identifier: Some("oxc".into()),
+                    inter_file_dependencies: false,
+                    workspace_diagnostics: true,
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                }))
+            } else {
+                None
+            },
             ..ServerCapabilities::default()
         }
     }
@@ -110,9 +180,10 @@ impl From<Capabilities> for ServerCapabilities {
 mod test {
     use tower_lsp_server::lsp_types::{
         ClientCapabilities, CodeActionClientCapabilities, CodeActionKindLiteralSupport,
-        CodeActionLiteralSupport, DidChangeWatchedFilesClientCapabilities,
-        DynamicRegistrationClientCapabilities, TextDocumentClientCapabilities,
-        WorkspaceClientCapabilities,
+        CodeActionLiteralSupport, DiagnosticClientCapabilities,
+        DiagnosticWorkspaceClientCapabilities, DidChangeWatchedFilesClientCapabilities,
+        DynamicRegistrationClientCapabilities, ServerCapabilities, TextDocumentClientCapabilities,
+        WindowClientCapabilities, WorkspaceClientCapabilities,
     };
 
     use super::Capabilities;
@@ -231,6 +302,23 @@ mod test {
         let capabilities = Capabilities::from(client_capabilities);
 
         assert!(capabilities.workspace_execute_command);
+        let commands = ServerCapabilities::from(capabilities).execute_command_provider.unwrap();
+        assert_eq!(commands.commands, ["oxc.fixAll", "oxc.lintWorkspace"]);
+    }
+
+    #[test]
+    fn test_work_done_progress_vscode() {
+        let client_capabilities = ClientCapabilities {
+            window: Some(WindowClientCapabilities {
+                work_done_progress: Some(true),
+                ..WindowClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.work_done_progress);
+        assert!(!Capabilities::from(ClientCapabilities::default()).work_done_progress);
     }
 
     #[test]
@@ -282,4 +370,61 @@ mod test {
         let capabilities = Capabilities::from(client_capabilities);
         assert!(capabilities.dynamic_watchers);
     }
+
+    #[test]
+    fn test_pull_diagnostics_vscode() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities {
+                    dynamic_registration: Some(true),
+                    related_document_support: Some(false),
+                }),
+                ..Default::default()
+            }),
+            workspace: Some(WorkspaceClientCapabilities {
+                diagnostic: Some(DiagnosticWorkspaceClientCapabilities {
+                    refresh_support: Some(true),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.pull_diagnostics);
+        assert!(capabilities.diagnostic_refresh);
+        assert!(ServerCapabilities::from(capabilities).diagnostic_provider.is_some());
+    }
+
+    #[test]
+    fn test_pull_diagnostics_without_refresh_support() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities::default()),
+                ..Default::default()
+            }),
+            workspace: Some(WorkspaceClientCapabilities {
+                diagnostic: Some(DiagnosticWorkspaceClientCapabilities { refresh_support: None }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(!capabilities.pull_diagnostics);
+        assert!(!capabilities.diagnostic_refresh);
+        assert!(ServerCapabilities::from(capabilities).diagnostic_provider.is_none());
+    }
+
+    #[test]
+    fn test_pull_diagnostics_nvim() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities::default()),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(!capabilities.pull_diagnostics);
+        assert!(ServerCapabilities::from(capabilities).diagnostic_provider.is_none());
+    }
 }
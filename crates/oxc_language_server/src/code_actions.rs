@@ -102,6 +102,29 @@ pub fn apply_all_fix_code_action<'a>(
     })
 }
 
+/// Create a `source.organizeImports` code action, applying the text edits of [`organize_imports`](crate::organize_imports::organize_imports).
+/// Returns [`None`] when the imports are organized already.
+pub fn organize_imports_code_action(uri: &Uri, text_edits: Vec<TextEdit>) -> Option<CodeAction> {
+    if text_edits.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: "Organize Imports".to_string(),
+        kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+        is_preferred: None,
+        edit: Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(uri.clone(), text_edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
+
 /// Collect all text edits from the provided diagnostic reports, which can be applied at once.
 /// This is useful for implementing a "fix all" code action / command that applies multiple fixes in one go.
 pub fn fix_all_text_edit<'a>(reports: impl Iterator<Item = &'a DiagnosticReport>) -> Vec<TextEdit> {
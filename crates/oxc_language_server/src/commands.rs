@@ -2,6 +2,9 @@ use serde::Deserialize;
 
 pub const FIX_ALL_COMMAND_ID: &str = "oxc.fixAll";
 
+/// Lint all files of the workspace folders, it takes no arguments.
+pub const LINT_WORKSPACE_COMMAND_ID: &str = "oxc.lintWorkspace";
+
 #[derive(Deserialize)]
 pub struct FixAllCommandArgs {
     pub uri: String,
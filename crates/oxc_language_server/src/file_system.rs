@@ -1,10 +1,18 @@
-use tower_lsp_server::lsp_types::Uri;
+use oxc_data_structures::rope::Rope;
+use tower_lsp_server::lsp_types::{Position, TextDocumentContentChangeEvent, Uri};
 
 use crate::ConcurrentHashMap;
 
+/// The content of an open document, as last synced by the client.
+#[derive(Debug, Clone)]
+struct TextDocument {
+    text: Rope,
+    version: i32,
+}
+
 #[derive(Debug, Default)]
 pub struct LSPFileSystem {
-    files: ConcurrentHashMap<Uri, String>,
+    files: ConcurrentHashMap<Uri, TextDocument>,
 }
 
 impl LSPFileSystem {
@@ -12,15 +20,150 @@ impl LSPFileSystem {
         self.files.pin().clear();
     }
 
-    pub fn set(&self, uri: &Uri, content: String) {
-        self.files.pin().insert(uri.clone(), content);
+    pub fn open(&self, uri: &Uri, content: &str, version: i32) {
+        self.files
+            .pin()
+            .insert(uri.clone(), TextDocument { text: Rope::from_str(content), version });
+    }
+
+    /// Apply the changes sent by the client with `textDocument/didChange`, in order.
+    /// Returns the new content of the document, or [`None`] if the document is not open.
+    pub fn apply_changes(
+        &self,
+        uri: &Uri,
+        version: i32,
+        changes: &[TextDocumentContentChangeEvent],
+    ) -> Option<String> {
+        let files = self.files.pin();
+        let document = files.update(uri.clone(), |document| {
+            // `Rope` clones are cheap, the unchanged chunks are shared.
+            let mut text = document.text.clone();
+            for change in changes {
+                if let Some(range) = change.range {
+                    let start = position_to_char(&text, range.start);
+                    let end = position_to_char(&text, range.end).max(start);
+                    text.remove(start..end);
+                    text.insert(start, &change.text);
+                } else {
+                    text = Rope::from_str(&change.text);
+                }
+            }
+            TextDocument { text, version }
+        })?;
+        Some(document.text.to_string())
     }
 
     pub fn get(&self, uri: &Uri) -> Option<String> {
-        self.files.pin().get(uri).cloned()
+        self.files.pin().get(uri).map(|document| document.text.to_string())
+    }
+
+    /// The content and version of the document, as last synced by the client.
+    pub fn get_with_version(&self, uri: &Uri) -> Option<(String, i32)> {
+        self.files.pin().get(uri).map(|document| (document.text.to_string(), document.version))
+    }
+
+    /// The version of the document, as last synced by the client.
+    pub fn version(&self, uri: &Uri) -> Option<i32> {
+        self.files.pin().get(uri).map(|document| document.version)
     }
 
     pub fn remove(&self, uri: &Uri) {
         self.files.pin().remove(uri);
     }
 }
+
+/// Convert an LSP position (UTF-16 based) to a char index in the rope.
+/// Positions past the end of a line are clamped to the end of the line.
+pub fn position_to_char(rope: &Rope, position: Position) -> usize {
+    let line_index = position.line as usize;
+    if line_index >= rope.len_lines() {
+        return rope.len_chars();
+    }
+
+    let line = rope.line(line_index);
+    let mut line_len = line.len_chars();
+    while line_len > 0 && matches!(line.char(line_len - 1), '\n' | '\r') {
+        line_len -= 1;
+    }
+    let character = (position.character as usize).min(line.char_to_utf16_cu(line_len));
+
+    rope.line_to_char(line_index) + line.utf16_cu_to_char(character)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tower_lsp_server::lsp_types::{Position, Range, TextDocumentContentChangeEvent, Uri};
+
+    use super::LSPFileSystem;
+
+    fn change(
+        range: Option<((u32, u32), (u32, u32))>,
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|((start_line, start_character), (end_line, end_character))| Range {
+                start: Position::new(start_line, start_character),
+                end: Position::new(end_line, end_character),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_incremental_changes() {
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let file_system = LSPFileSystem::default();
+        file_system.open(&uri, "const a = 1;\nconst 🍄 = 2;\r\nfoo();\n", 1);
+
+        let content = file_system.apply_changes(
+            &uri,
+            2,
+            &[
+                // replace `a` with `b`
+                change(Some(((0, 6), (0, 7))), "b"),
+                // insert after the emoji, which is 2 UTF-16 code units
+                change(Some(((1, 8), (1, 8))), "x"),
+                // past the end of the line is clamped before the line break
+                change(Some(((1, 100), (1, 100))), " // c"),
+                // remove across lines
+                change(Some(((0, 12), (1, 0))), " "),
+                // insert at the end of the document
+                change(Some(((3, 0), (3, 0))), "bar();\n"),
+            ],
+        );
+
+        assert_eq!(
+            content.as_deref(),
+            Some("const b = 1; const 🍄x = 2; // c\r\nfoo();\nbar();\n")
+        );
+        assert_eq!(file_system.version(&uri), Some(2));
+        assert_eq!(file_system.get(&uri), content);
+    }
+
+    #[test]
+    fn apply_full_change() {
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let file_system = LSPFileSystem::default();
+        file_system.open(&uri, "const a = 1;", 1);
+
+        let content = file_system.apply_changes(
+            &uri,
+            2,
+            &[change(None, "let a;\n"), change(Some(((1, 0), (1, 0))), "a = 1;")],
+        );
+
+        assert_eq!(content.as_deref(), Some("let a;\na = 1;"));
+    }
+
+    #[test]
+    fn apply_changes_to_closed_document() {
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let file_system = LSPFileSystem::default();
+
+        assert_eq!(file_system.apply_changes(&uri, 2, &[change(None, "let a;")]), None);
+        assert_eq!(file_system.version(&uri), None);
+    }
+}
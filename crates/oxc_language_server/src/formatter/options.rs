@@ -1,10 +1,65 @@
-use serde::{Deserialize, Deserializer, Serialize, de::Error};
-use serde_json::Value;
+use std::str::FromStr;
 
-#[derive(Debug, Default, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
+use log::info;
+use oxc_formatter::{
+    ArrowParentheses, BracketSameLine, BracketSpacing, IndentStyle, IndentWidth, LineWidth,
+    QuoteStyle, Semicolons, TrailingCommas,
+};
+use serde::{Deserialize, Deserializer, de::Error};
+use serde_json::{Map, Value};
+
+/// Options of the formatter. Options which are not set fall back to the formatter defaults.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct FormatOptions {
     pub experimental: bool,
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<IndentWidth>,
+    pub line_width: Option<LineWidth>,
+    pub quote_style: Option<QuoteStyle>,
+    pub jsx_quote_style: Option<QuoteStyle>,
+    pub semicolons: Option<Semicolons>,
+    pub trailing_commas: Option<TrailingCommas>,
+    pub arrow_parentheses: Option<ArrowParentheses>,
+    pub bracket_spacing: Option<BracketSpacing>,
+    pub bracket_same_line: Option<BracketSameLine>,
+}
+
+impl FormatOptions {
+    /// The options for [`oxc_formatter::Formatter`].
+    pub fn to_format_options(&self) -> oxc_formatter::FormatOptions {
+        let mut options = oxc_formatter::FormatOptions::default();
+        if let Some(indent_style) = self.indent_style {
+            options.indent_style = indent_style;
+        }
+        if let Some(indent_width) = self.indent_width {
+            options.indent_width = indent_width;
+        }
+        if let Some(line_width) = self.line_width {
+            options.line_width = line_width;
+        }
+        if let Some(quote_style) = self.quote_style {
+            options.quote_style = quote_style;
+        }
+        if let Some(jsx_quote_style) = self.jsx_quote_style {
+            options.jsx_quote_style = jsx_quote_style;
+        }
+        if let Some(semicolons) = self.semicolons {
+            options.semicolons = semicolons;
+        }
+        if let Some(trailing_commas) = self.trailing_commas {
+            options.trailing_commas = trailing_commas;
+        }
+        if let Some(arrow_parentheses) = self.arrow_parentheses {
+            options.arrow_parentheses = arrow_parentheses;
+        }
+        if let Some(bracket_spacing) = self.bracket_spacing {
+            options.bracket_spacing = bracket_spacing;
+        }
+        if let Some(bracket_same_line) = self.bracket_same_line {
+            options.bracket_same_line = bracket_same_line;
+        }
+        options
+    }
 }
 
 impl<'de> Deserialize<'de> for FormatOptions {
@@ -29,12 +84,57 @@ impl TryFrom<Value> for FormatOptions {
             experimental: object
                 .get("fmt.experimental")
                 .is_some_and(|run| serde_json::from_value::<bool>(run.clone()).unwrap_or_default()),
+            indent_style: parse_str_option(object, "fmt.indentStyle"),
+            indent_width: parse_number_option(object, "fmt.indentWidth", |value| {
+                IndentWidth::try_from(u8::try_from(value).ok()?).ok()
+            }),
+            line_width: parse_number_option(object, "fmt.lineWidth", |value| {
+                LineWidth::try_from(u16::try_from(value).ok()?).ok()
+            }),
+            quote_style: parse_str_option(object, "fmt.quoteStyle"),
+            jsx_quote_style: parse_str_option(object, "fmt.jsxQuoteStyle"),
+            semicolons: parse_str_option(object, "fmt.semicolons"),
+            trailing_commas: parse_str_option(object, "fmt.trailingCommas"),
+            arrow_parentheses: parse_str_option(object, "fmt.arrowParentheses"),
+            bracket_spacing: object
+                .get("fmt.bracketSpacing")
+                .and_then(Value::as_bool)
+                .map(BracketSpacing::from),
+            bracket_same_line: object
+                .get("fmt.bracketSameLine")
+                .and_then(Value::as_bool)
+                .map(BracketSameLine::from),
         })
     }
 }
 
+/// Parse a string option, invalid values are ignored.
+fn parse_str_option<T: FromStr>(object: &Map<String, Value>, key: &str) -> Option<T> {
+    let value = object.get(key)?;
+    let parsed = value.as_str().and_then(|value| value.parse().ok());
+    if parsed.is_none() {
+        info!("invalid value `{value}` for `{key}`, fallback to the default");
+    }
+    parsed
+}
+
+/// Parse a number option, invalid values are ignored.
+fn parse_number_option<T>(
+    object: &Map<String, Value>,
+    key: &str,
+    convert: impl FnOnce(u64) -> Option<T>,
+) -> Option<T> {
+    let value = object.get(key)?;
+    let parsed = value.as_u64().and_then(convert);
+    if parsed.is_none() {
+        info!("invalid value `{value}` for `{key}`, fallback to the default");
+    }
+    parsed
+}
+
 #[cfg(test)]
 mod test {
+    use oxc_formatter::{ArrowParentheses, BracketSpacing, QuoteStyle, Semicolons, TrailingCommas};
     use serde_json::json;
 
     use super::FormatOptions;
@@ -66,4 +166,43 @@ mod test {
         let options = FormatOptions::try_from(json).unwrap();
         assert!(!options.experimental);
     }
+
+    #[test]
+    fn test_format_options_json() {
+        let json = json!({
+            "fmt.experimental": true,
+            "fmt.indentStyle": "tab",
+            "fmt.indentWidth": 4,
+            "fmt.lineWidth": 120,
+            "fmt.quoteStyle": "single",
+            "fmt.semicolons": "as-needed",
+            "fmt.trailingCommas": "es5",
+            "fmt.arrowParentheses": "avoid",
+            "fmt.bracketSpacing": false,
+        });
+
+        let options = FormatOptions::try_from(json).unwrap().to_format_options();
+        assert!(options.indent_style.is_tab());
+        assert_eq!(options.indent_width.value(), 4);
+        assert_eq!(options.line_width.value(), 120);
+        assert_eq!(options.quote_style, QuoteStyle::Single);
+        assert_eq!(options.jsx_quote_style, QuoteStyle::Double);
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+        assert_eq!(options.trailing_commas, TrailingCommas::Es5);
+        assert_eq!(options.arrow_parentheses, ArrowParentheses::AsNeeded);
+        assert_eq!(options.bracket_spacing, BracketSpacing::from(false));
+    }
+
+    #[test]
+    fn test_invalid_format_options_json() {
+        let json = json!({
+            "fmt.indentStyle": "tabs",
+            "fmt.indentWidth": 100,
+            "fmt.lineWidth": "120",
+            "fmt.quoteStyle": true,
+        });
+
+        let options = FormatOptions::try_from(json).unwrap();
+        assert_eq!(options, FormatOptions::default());
+    }
 }
@@ -1,37 +1,102 @@
+use log::warn;
 use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
 use oxc_data_structures::rope::{Rope, get_line_column};
-use oxc_formatter::{FormatOptions, Formatter, get_supported_source_type};
+use oxc_formatter::{ConfigResolver, FormattedRange, Formatter, get_supported_source_type};
 use oxc_parser::{ParseOptions, Parser};
+use oxc_span::Span;
 use tower_lsp_server::{
     UriExt,
     lsp_types::{Position, Range, TextEdit, Uri},
 };
 
-pub struct ServerFormatter;
+use crate::{file_system::position_to_char, formatter::options::FormatOptions};
+
+pub struct ServerFormatter {
+    options: FormatOptions,
+}
 
 impl ServerFormatter {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
     }
 
-    #[expect(clippy::unused_self)]
+    /// Format the whole file.
     pub fn run_single(&self, uri: &Uri, content: Option<String>) -> Option<Vec<TextEdit>> {
+        let source_text = read_source_text(uri, content)?;
+        // A closure is needed for the higher-ranked lifetime of `build`
+        #[expect(clippy::redundant_closure_for_method_calls)]
+        let code = self.format(uri, &source_text, |formatter, program| formatter.build(program))?;
+
+        // nothing has changed
+        if code == source_text {
+            return Some(vec![]);
+        }
+
+        let (start, end, replacement) = compute_minimal_text_edit(&source_text, &code);
+        let rope = Rope::from(source_text.as_str());
+
+        Some(vec![TextEdit::new(
+            offsets_to_range(&rope, &source_text, start, end),
+            replacement.to_string(),
+        )])
+    }
+
+    /// Format the smallest statements, class members or object properties enclosing `range`.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn run_range(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        range: Range,
+    ) -> Option<Vec<TextEdit>> {
+        let source_text = read_source_text(uri, content)?;
+        let rope = Rope::from(source_text.as_str());
+        let start = rope.char_to_byte(position_to_char(&rope, range.start)) as u32;
+        let end = rope.char_to_byte(position_to_char(&rope, range.end)) as u32;
+        let formatted = self.format(uri, &source_text, |formatter, program| {
+            formatter.build_range(program, Span::new(start, end.max(start)))
+        })?;
+
+        // nothing to format in the range
+        let Some(FormattedRange { span, code }) = formatted else {
+            return Some(vec![]);
+        };
+        let original = span.source_text(&source_text);
+        if original == code {
+            return Some(vec![]);
+        }
+
+        let (start, end, replacement) = compute_minimal_text_edit(original, &code);
+        Some(vec![TextEdit::new(
+            offsets_to_range(&rope, &source_text, span.start + start, span.start + end),
+            replacement.to_string(),
+        )])
+    }
+
+    /// Format the statement ended by the character typed before `position`, e.g. `;` or `}`.
+    pub fn run_on_type(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        position: Position,
+    ) -> Option<Vec<TextEdit>> {
+        let start = Position::new(position.line, position.character.saturating_sub(1));
+        self.run_range(uri, content, Range::new(start, position))
+    }
+
+    /// Parse the source text, and format it with `build` when there are no syntax errors.
+    fn format<T>(
+        &self,
+        uri: &Uri,
+        source_text: &str,
+        build: impl for<'a> FnOnce(Formatter<'a>, &'a Program<'a>) -> T,
+    ) -> Option<T> {
         let path = uri.to_file_path()?;
         let source_type = get_supported_source_type(&path)?;
-        let source_text = if let Some(content) = content {
-            content
-        } else {
-            #[cfg(not(all(test, windows)))]
-            let source_text = std::fs::read_to_string(&path).ok()?;
-            #[cfg(all(test, windows))]
-            #[expect(clippy::disallowed_methods)] // no `cow_replace` in tests are fine
-            // On Windows, convert CRLF to LF for consistent formatting results
-            let source_text = std::fs::read_to_string(&path).ok()?.replace("\r\n", "\n");
-            source_text
-        };
 
         let allocator = Allocator::new();
-        let ret = Parser::new(&allocator, &source_text, source_type)
+        let ret = Parser::new(&allocator, source_text, source_type)
             .with_options(ParseOptions {
                 parse_regular_expression: false,
                 // Enable all syntax features
@@ -39,6 +104,8 @@ impl ServerFormatter {
                 allow_return_outside_function: true,
                 // `oxc_formatter` expects this to be false
                 preserve_parens: false,
+                tokens: false,
+                error_recovery: false,
             })
             .parse();
 
@@ -46,27 +113,40 @@ impl ServerFormatter {
             return None;
         }
 
-        let options = FormatOptions::default();
-        let code = Formatter::new(&allocator, options).build(&ret.program);
-
-        // nothing has changed
-        if code == source_text {
-            return Some(vec![]);
-        }
+        // Config files are applied on top of the editor options. They are resolved again for every
+        // request, so changes to them are picked up without watching them.
+        let options = match ConfigResolver::new(self.options.to_format_options()).resolve(&path) {
+            Ok(options) => options,
+            Err(error) => {
+                warn!("{error}");
+                return None;
+            }
+        };
 
-        let (start, end, replacement) = compute_minimal_text_edit(&source_text, &code);
-        let rope = Rope::from(source_text.as_str());
-        let (start_line, start_character) = get_line_column(&rope, start, &source_text);
-        let (end_line, end_character) = get_line_column(&rope, end, &source_text);
+        let program = allocator.alloc(ret.program);
+        Some(build(Formatter::new(&allocator, options), program))
+    }
+}
 
-        Some(vec![TextEdit::new(
-            Range::new(
-                Position::new(start_line, start_character),
-                Position::new(end_line, end_character),
-            ),
-            replacement.to_string(),
-        )])
+fn read_source_text(uri: &Uri, content: Option<String>) -> Option<String> {
+    if let Some(content) = content {
+        return Some(content);
     }
+
+    let path = uri.to_file_path()?;
+    #[cfg(not(all(test, windows)))]
+    let source_text = std::fs::read_to_string(&path).ok()?;
+    #[cfg(all(test, windows))]
+    #[expect(clippy::disallowed_methods)] // no `cow_replace` in tests are fine
+    // On Windows, convert CRLF to LF for consistent formatting results
+    let source_text = std::fs::read_to_string(&path).ok()?.replace("\r\n", "\n");
+    Some(source_text)
+}
+
+fn offsets_to_range(rope: &Rope, source_text: &str, start: u32, end: u32) -> Range {
+    let (start_line, start_character) = get_line_column(rope, start, source_text);
+    let (end_line, end_character) = get_line_column(rope, end, source_text);
+    Range::new(Position::new(start_line, start_character), Position::new(end_line, end_character))
 }
 
 /// Returns the minimal text edit (start, end, replacement) to transform `source_text` into `formatted_text`
@@ -112,7 +192,12 @@ fn compute_minimal_text_edit<'a>(
 
 #[cfg(test)]
 mod tests {
-    use super::compute_minimal_text_edit;
+    use std::str::FromStr;
+
+    use oxc_formatter::{QuoteStyle, Semicolons};
+    use tower_lsp_server::lsp_types::{Position, Range, Uri};
+
+    use super::{ServerFormatter, compute_minimal_text_edit};
     use crate::formatter::{options::FormatOptions, tester::Tester};
 
     #[test]
@@ -196,9 +281,77 @@ mod tests {
         assert_eq!((start, end, replacement), (0, 0, "b"));
     }
 
+    #[test]
+    fn test_range_formatting() {
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let formatter = ServerFormatter::new(FormatOptions::default());
+        let source_text = "const a   =   1\nfunction foo( ) {\n  return   a\n}\nconst b   =   2\n";
+
+        // only the innermost statement is formatted
+        let edits = formatter
+            .run_range(
+                &uri,
+                Some(source_text.to_string()),
+                Range::new(Position::new(2, 2), Position::new(2, 4)),
+            )
+            .unwrap();
+        let edits: Vec<_> = edits.into_iter().map(|edit| (edit.range, edit.new_text)).collect();
+        assert_eq!(
+            edits,
+            vec![(Range::new(Position::new(2, 9), Position::new(2, 12)), "a;".to_string())]
+        );
+
+        // on type formatting after the `}` formats the whole function, but not the statements around it
+        let edits = formatter
+            .run_on_type(&uri, Some(source_text.to_string()), Position::new(3, 1))
+            .unwrap();
+        let edits: Vec<_> = edits.into_iter().map(|edit| (edit.range, edit.new_text)).collect();
+        assert_eq!(
+            edits,
+            vec![(
+                Range::new(Position::new(1, 13), Position::new(2, 12)),
+                ") {\n  return a;".to_string()
+            )]
+        );
+
+        // syntax errors are not formatted
+        assert!(formatter.run_range(&uri, Some("const".to_string()), Range::default()).is_none());
+    }
+
+    #[test]
+    fn test_formatter_options() {
+        let uri = Uri::from_str("file:///test.js").unwrap();
+        let formatter = ServerFormatter::new(FormatOptions {
+            quote_style: Some(QuoteStyle::Single),
+            semicolons: Some(Semicolons::AsNeeded),
+            ..FormatOptions::default()
+        });
+
+        let edits = formatter.run_single(&uri, Some("foo(\"bar\");\n".to_string())).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "'bar')");
+    }
+
     #[test]
     fn test_formatter() {
-        Tester::new("fixtures/formatter/basic", Some(FormatOptions { experimental: true }))
-            .format_and_snapshot_single_file("basic.ts");
+        Tester::new(
+            "fixtures/formatter/basic",
+            Some(FormatOptions { experimental: true, ..Default::default() }),
+        )
+        .format_and_snapshot_single_file("basic.ts");
+    }
+
+    #[test]
+    fn test_formatter_config_file() {
+        // `.prettierrc` and `.editorconfig` files are applied on top of the editor options
+        Tester::new(
+            "fixtures/formatter/config_file",
+            Some(FormatOptions {
+                experimental: true,
+                semicolons: Some(Semicolons::Always),
+                ..Default::default()
+            }),
+        )
+        .format_and_snapshot_single_file("config.ts");
     }
 }
@@ -0,0 +1,115 @@
+use oxc_linter::{find_rule_by_code, rules::RuleEnum};
+use tower_lsp_server::lsp_types::{
+    Hover, HoverContents, MarkupContent, MarkupKind, NumberOrString, Position,
+};
+
+use crate::linter::error_with_position::DiagnosticReport;
+
+/// Create a hover with the documentation of the rules reporting a diagnostic at `position`.
+/// Returns [`None`] when no diagnostic at `position` belongs to a rule with documentation.
+pub fn rule_documentation_hover<'a>(
+    reports: impl Iterator<Item = &'a DiagnosticReport>,
+    position: Position,
+) -> Option<Hover> {
+    let mut codes: Vec<&str> = vec![];
+    let mut range = None;
+    let mut sections = vec![];
+
+    for report in reports {
+        let diagnostic = &report.diagnostic;
+        if position < diagnostic.range.start || position > diagnostic.range.end {
+            continue;
+        }
+        let Some(NumberOrString::String(code)) = &diagnostic.code else {
+            continue;
+        };
+        // multiple diagnostics of the same rule, show the documentation only once
+        if codes.contains(&code.as_str()) {
+            continue;
+        }
+        let Some(documentation) = find_rule_by_code(code).and_then(RuleEnum::documentation) else {
+            continue;
+        };
+
+        codes.push(code);
+        range.get_or_insert(diagnostic.range);
+        let mut section = format!("**{code}**\n\n{}", documentation.trim_end());
+        if let Some(code_description) = &diagnostic.code_description {
+            section.push_str("\n\n[Documentation](");
+            section.push_str(code_description.href.as_str());
+            section.push(')');
+        }
+        sections.push(section);
+    }
+
+    if sections.is_empty() {
+        return None;
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tower_lsp_server::lsp_types::{
+        CodeDescription, Diagnostic, HoverContents, NumberOrString, Position, Range, Uri,
+    };
+
+    use super::rule_documentation_hover;
+    use crate::linter::error_with_position::DiagnosticReport;
+
+    fn report(code: &str, range: Range) -> DiagnosticReport {
+        DiagnosticReport {
+            diagnostic: Diagnostic {
+                range,
+                code: Some(NumberOrString::String(code.to_string())),
+                code_description: Some(CodeDescription {
+                    href: Uri::from_str(
+                        "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html",
+                    )
+                    .unwrap(),
+                }),
+                ..Diagnostic::default()
+            },
+            ..DiagnosticReport::default()
+        }
+    }
+
+    #[test]
+    fn hover_on_diagnostic() {
+        let range = Range::new(Position::new(1, 0), Position::new(1, 9));
+        let reports = [
+            report("eslint(no-debugger)", range),
+            report("eslint(no-debugger)", range),
+            report("eslint(unknown-rule)", range),
+        ];
+
+        let hover = rule_documentation_hover(reports.iter(), Position::new(1, 4)).unwrap();
+        assert_eq!(hover.range, Some(range));
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown hover");
+        };
+        assert!(markup.value.starts_with("**eslint(no-debugger)**\n\n### What it does"));
+        assert!(markup.value.ends_with(
+            "[Documentation](https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html)"
+        ));
+        assert!(!markup.value.contains("---"));
+    }
+
+    #[test]
+    fn hover_outside_diagnostic() {
+        let range = Range::new(Position::new(1, 0), Position::new(1, 9));
+        let reports = [report("eslint(no-debugger)", range)];
+
+        assert!(rule_documentation_hover(reports.iter(), Position::new(0, 4)).is_none());
+        assert!(rule_documentation_hover(reports.iter(), Position::new(1, 10)).is_none());
+    }
+}
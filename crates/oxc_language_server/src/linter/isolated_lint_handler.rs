@@ -166,7 +166,7 @@ impl IsolatedLintHandler {
             .collect()
     }
 
-    fn should_lint_path(path: &Path) -> bool {
+    pub fn should_lint_path(path: &Path) -> bool {
         static WANTED_EXTENSIONS: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
         let wanted_exts =
             WANTED_EXTENSIONS.get_or_init(|| LINTABLE_EXTENSIONS.iter().copied().collect());
@@ -30,6 +30,8 @@ pub struct LintOptions {
     pub ts_config_path: Option<String>,
     pub unused_disable_directives: UnusedDisableDirectives,
     pub type_aware: bool,
+    /// Lint all files of the workspace folder, not only the opened ones.
+    pub lint_workspace: bool,
     pub flags: FxHashMap<String, String>,
 }
 
@@ -107,6 +109,9 @@ impl TryFrom<Value> for LintOptions {
             type_aware: object
                 .get("typeAware")
                 .is_some_and(|key| serde_json::from_value::<bool>(key.clone()).unwrap_or_default()),
+            lint_workspace: object
+                .get("lintWorkspace")
+                .is_some_and(|key| serde_json::from_value::<bool>(key.clone()).unwrap_or_default()),
             flags,
         })
     }
@@ -126,6 +131,7 @@ mod test {
             "configPath": "./custom.json",
             "unusedDisableDirectives": "warn",
             "typeAware": true,
+            "lintWorkspace": true,
             "flags": {
                 "disable_nested_config": "true",
                 "fix_kind": "dangerous_fix"
@@ -137,6 +143,7 @@ mod test {
         assert_eq!(options.config_path, Some("./custom.json".into()));
         assert_eq!(options.unused_disable_directives, UnusedDisableDirectives::Warn);
         assert!(options.type_aware);
+        assert!(options.lint_workspace);
         assert_eq!(options.flags.get("disable_nested_config"), Some(&"true".to_string()));
         assert_eq!(options.flags.get("fix_kind"), Some(&"dangerous_fix".to_string()));
     }
@@ -150,6 +157,7 @@ mod test {
         assert_eq!(options.config_path, None);
         assert_eq!(options.unused_disable_directives, UnusedDisableDirectives::Allow);
         assert!(!options.type_aware);
+        assert!(!options.lint_workspace);
         assert!(options.flags.is_empty());
     }
 
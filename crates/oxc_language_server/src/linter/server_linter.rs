@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -5,12 +6,13 @@ use std::sync::Arc;
 use ignore::gitignore::Gitignore;
 use log::{debug, warn};
 use oxc_linter::{AllowWarnDeny, LintIgnoreMatcher};
+use rayon::prelude::*;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tokio::sync::Mutex;
 use tower_lsp_server::lsp_types::Uri;
 
 use oxc_linter::{
-    Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions, Oxlintrc,
+    Config, ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions, Oxlintrc, Walk,
 };
 use tower_lsp_server::UriExt;
 
@@ -34,6 +36,8 @@ pub enum ServerLinterRun {
 
 pub struct ServerLinter {
     isolated_linter: Arc<Mutex<IsolatedLintHandler>>,
+    /// Creates more isolated linters, to lint the files of the workspace in parallel
+    isolated_linter_config: (LintOptions, ConfigStore, IsolatedLintHandlerOptions),
     tsgo_linter: Arc<Option<TsgoLinter>>,
     ignore_matcher: LintIgnoreMatcher,
     gitignore_glob: Vec<Gitignore>,
@@ -79,6 +83,7 @@ impl ServerLinterDiagnostics {
         let mut files = Vec::with_capacity(isolated_files.len() + tsgo_files.len());
         files.extend(isolated_files);
         files.extend(tsgo_files);
+        files.sort_unstable();
         files.dedup();
         files
     }
@@ -150,21 +155,23 @@ impl ServerLinter {
             external_plugin_store,
         );
 
+        let isolated_linter_options = IsolatedLintHandlerOptions {
+            use_cross_module,
+            root_path: root_path.to_path_buf(),
+            tsconfig_path: options.ts_config_path.as_ref().map(|path| {
+                let path = Path::new(path).to_path_buf();
+                if path.is_relative() { root_path.join(path) } else { path }
+            }),
+        };
         let isolated_linter = IsolatedLintHandler::new(
             lint_options,
             config_store.clone(), // clone because tsgo linter needs it
-            &IsolatedLintHandlerOptions {
-                use_cross_module,
-                root_path: root_path.to_path_buf(),
-                tsconfig_path: options.ts_config_path.as_ref().map(|path| {
-                    let path = Path::new(path).to_path_buf();
-                    if path.is_relative() { root_path.join(path) } else { path }
-                }),
-            },
+            &isolated_linter_options,
         );
 
         Self {
             isolated_linter: Arc::new(Mutex::new(isolated_linter)),
+            isolated_linter_config: (lint_options, config_store.clone(), isolated_linter_options),
             ignore_matcher: LintIgnoreMatcher::new(
                 &base_patterns,
                 &root_path,
@@ -295,6 +302,25 @@ impl ServerLinter {
         map
     }
 
+    /// Collect the lintable files inside `root_uri`, which are not ignored.
+    /// The files are sorted, so the workspace is linted in a stable order.
+    pub fn workspace_files(&self, root_uri: &Uri) -> Vec<Uri> {
+        let Some(root_path) = root_uri.to_file_path() else {
+            return vec![];
+        };
+
+        // walk the workspace the same way `oxlint` walks the paths to lint
+        let mut uris =
+            Walk::new(&[root_path.into_owned()], Some(OsStr::new(".eslintignore")), None)
+                .paths()
+                .iter()
+                .filter_map(|path| Uri::from_file_path(Path::new(path)))
+                .filter(|uri| !self.is_ignored(uri))
+                .collect::<Vec<_>>();
+        uris.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        uris
+    }
+
     fn is_ignored(&self, uri: &Uri) -> bool {
         let Some(uri_path) = uri.to_file_path() else {
             return true;
@@ -366,6 +392,47 @@ impl ServerLinter {
         self.diagnostics.get_diagnostics(&uri.to_string())
     }
 
+    /// Lint the files of the workspace on the rayon thread pool, calling `on_linted` after each file.
+    /// Each file is linted with its `content` when it is open, or else as saved on disk.
+    ///
+    /// This blocks, so call it from a blocking task.
+    pub fn lint_workspace(
+        &self,
+        files: Vec<(Uri, Option<String>)>,
+        on_linted: impl Fn() + Sync,
+    ) -> Vec<(Uri, Vec<DiagnosticReport>)> {
+        let (lint_options, config_store, isolated_linter_options) = &self.isolated_linter_config;
+        files
+            .into_par_iter()
+            .map_init(
+                || {
+                    IsolatedLintHandler::new(
+                        *lint_options,
+                        config_store.clone(),
+                        isolated_linter_options,
+                    )
+                },
+                |isolated_linter, (uri, content)| {
+                    let reports = if self.is_ignored(&uri) {
+                        None
+                    } else {
+                        let key = uri.to_string();
+                        let diagnostics = isolated_linter.run_single(&uri, content.clone());
+                        self.diagnostics.isolated_linter.pin().insert(key.clone(), diagnostics);
+                        if let Some(tsgo_linter) = self.tsgo_linter.as_ref() {
+                            let diagnostics = tsgo_linter.lint_file(&uri, content);
+                            self.diagnostics.tsgo_linter.pin().insert(key.clone(), diagnostics);
+                        }
+                        self.diagnostics.get_diagnostics(&key)
+                    };
+                    on_linted();
+                    reports.map(|reports| (uri, reports))
+                },
+            )
+            .flatten()
+            .collect()
+    }
+
     pub fn needs_restart(old_options: &LSPLintOptions, new_options: &LSPLintOptions) -> bool {
         old_options.config_path != new_options.config_path
             || old_options.ts_config_path != new_options.ts_config_path
@@ -413,7 +480,7 @@ mod test {
             options::{LintOptions, Run, UnusedDisableDirectives},
             server_linter::{ServerLinter, ServerLinterDiagnostics, normalize_path},
         },
-        tester::{Tester, get_file_path},
+        tester::{Tester, get_file_path, get_file_uri},
     };
     use rustc_hash::FxHashMap;
 
@@ -648,6 +715,17 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn test_workspace_files_ignore_patterns() {
+        let root_uri = get_file_uri("fixtures/linter/ignore_patterns");
+        let server_linter = ServerLinter::new(&root_uri, &LintOptions::default());
+
+        assert_eq!(
+            server_linter.workspace_files(&root_uri),
+            [get_file_uri("fixtures/linter/ignore_patterns/another_config/not-ignored-file.ts")]
+        );
+    }
+
     #[test]
     fn test_ts_alias() {
         Tester::new(
@@ -3,10 +3,8 @@ use std::{
     sync::{Arc, OnceLock},
 };
 
-use oxc_linter::{
-    ConfigStore, LINTABLE_EXTENSIONS, TsGoLintState, loader::LINT_PARTIAL_LOADER_EXTENSIONS,
-    read_to_string,
-};
+use oxc_linter::{ConfigStore, LINTABLE_EXTENSIONS, TsGoLintState, read_to_string};
+use oxc_partial_loader::PARTIAL_LOADER_EXTENSIONS;
 use rustc_hash::FxHashSet;
 use tower_lsp_server::{UriExt, lsp_types::Uri};
 
@@ -51,7 +49,7 @@ impl TsgoLinter {
         let wanted_exts = WANTED_EXTENSIONS.get_or_init(|| {
             LINTABLE_EXTENSIONS
                 .iter()
-                .filter(|ext| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(ext))
+                .filter(|ext| !PARTIAL_LOADER_EXTENSIONS.contains(ext))
                 .copied()
                 .collect()
         });
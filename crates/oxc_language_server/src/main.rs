@@ -7,8 +7,11 @@ mod code_actions;
 mod commands;
 mod file_system;
 mod formatter;
+mod hover;
 mod linter;
+mod navigation;
 mod options;
+mod organize_imports;
 #[cfg(test)]
 mod tester;
 mod worker;
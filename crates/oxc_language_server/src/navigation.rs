@@ -0,0 +1,925 @@
+//! Semantic navigation for local and module-level bindings:
+//! go to definition, find references, document highlights and rename.
+//!
+//! A [`Document`] parses the file once, with the `<script>` blocks of Vue, Svelte and Astro files
+//! loaded by the [`PartialLoader`]. Open documents are kept in the [`DocumentCache`], and reparsed
+//! incrementally when they change.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use self_cell::self_cell;
+use tokio::sync::Mutex;
+
+use oxc_allocator::{Allocator, CloneIn, TakeIn};
+use oxc_ast::{
+    AstKind,
+    ast::{BindingIdentifier, Program},
+};
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_parser::{ParseOptions, Parser, ParserReturn};
+use oxc_partial_loader::{JavaScriptSource, PartialLoader};
+use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_semantic::{NodeId, Reference, ScopeId, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, SourceType, Span, VALID_EXTENSIONS};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    keyword::is_reserved_keyword,
+    module_record::{ExportEntry, ExportExportName, ImportImportName, ModuleRecord},
+};
+use tower_lsp_server::{
+    UriExt,
+    lsp_types::{
+        DocumentHighlight, DocumentHighlightKind, Location, Position, Range, TextEdit, Uri,
+    },
+};
+
+use crate::{ConcurrentHashMap, file_system::position_to_char};
+
+/// Parsed open documents, reused by navigation requests until the document changes.
+#[derive(Default)]
+pub struct DocumentCache {
+    documents: ConcurrentHashMap<Uri, (i32, Arc<Mutex<Document>>)>,
+}
+
+impl DocumentCache {
+    /// The document at `uri`, if it was parsed at `version`.
+    pub fn get(&self, uri: &Uri, version: i32) -> Option<Arc<Mutex<Document>>> {
+        self.documents
+            .pin()
+            .get(uri)
+            .filter(|(cached_version, _)| *cached_version == version)
+            .map(|(_, document)| Arc::clone(document))
+    }
+
+    /// Caches the document at `uri` with the content of `version`.
+    /// A cached previous version is updated in place, see [`Document::update`].
+    pub async fn update(
+        &self,
+        uri: &Uri,
+        version: i32,
+        source_text: String,
+    ) -> Option<Arc<Mutex<Document>>> {
+        let previous = self.documents.pin().get(uri).map(|(_, document)| Arc::clone(document));
+        let document = if let Some(document) = previous {
+            document.lock().await.update(source_text);
+            document
+        } else {
+            Arc::new(Mutex::new(Document::new(uri.clone(), source_text)?))
+        };
+        self.documents.pin().insert(uri.clone(), (version, Arc::clone(&document)));
+        Some(document)
+    }
+
+    pub fn remove(&self, uri: &Uri) {
+        self.documents.pin().remove(uri);
+    }
+
+    pub fn clear(&self) {
+        self.documents.pin().clear();
+    }
+}
+
+/// A file parsed for navigation.
+pub struct Document {
+    uri: Uri,
+    path: PathBuf,
+    source_text: String,
+    rope: Rope,
+    scripts: ParsedScripts,
+    /// Bytes used by the arena after the last full parse, see [`Document::update`].
+    parsed_bytes: usize,
+}
+
+/// The arena of a document is replaced by a full parse once it has grown to this many times its
+/// size after the last full parse, as the ASTs replaced by incremental reparses are not freed.
+const MAX_ARENA_GROWTH: usize = 4;
+
+self_cell! {
+    struct ParsedScripts {
+        owner: Allocator,
+        #[not_covariant]
+        dependent: Scripts,
+    }
+}
+
+// SAFETY: `Scripts` is not `Send` only because it holds references into the arena (the source text,
+// AST nodes with `Cell` fields, arena `Box`es and `Vec`s). Sending `ParsedScripts` to another thread
+// is sound because:
+// - The owner moves as a unit. `self_cell` keeps the owner and the dependent in one heap allocation,
+//   and the arena chunks they point into are never moved or freed before the dependent is dropped,
+//   so every reference moves to the other thread together with what it points to.
+// - No shared alias escapes. The dependent is only reachable through `with_dependent` and
+//   `with_dependent_mut`, whose closures cannot return a reference to it (`#[not_covariant]`), and the
+//   public `Document` methods return owned values. The AST `Cell`s are written only by `SemanticBuilder`
+//   in `ParsedScripts::new` and in `with_dependent_mut`, which borrows the cell mutably, so no other
+//   thread can observe them while the cell moves. `Document` is shared behind a `Mutex`,
+//   which is what makes it usable from several tasks; `ParsedScripts` is not `Sync`.
+// - There is no thread-local state. `Allocator`, the parser output, `Semantic` and `ModuleRecord`
+//   neither read nor store thread-locals or `Rc`s, so nothing ties them to the thread they were built on.
+unsafe impl Send for ParsedScripts {}
+
+type Scripts<'a> = Vec<Script<'a>>;
+
+/// A parsed `<script>` block, or the whole file for JavaScript and TypeScript files.
+struct Script<'a> {
+    source: JavaScriptSource<'a>,
+    program: &'a Program<'a>,
+    semantic: Semantic<'a>,
+    /// The parser result without the program, which is moved into the arena for `semantic`.
+    /// Kept to reparse the script after an edit.
+    parsed: ParserReturn<'a>,
+}
+
+/// The symbol of the identifier at the cursor, in the `<script>` block containing the cursor.
+struct SymbolAt<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    module_record: &'s ModuleRecord<'a>,
+    symbol_id: SymbolId,
+    /// Span of the identifier at the cursor.
+    span: Span,
+    /// Offset of the `<script>` block in the file.
+    start: u32,
+}
+
+/// Where an imported binding is defined in another module.
+struct ImportTarget {
+    specifier: String,
+    name: ImportName,
+    /// Span of the local binding, used when the module cannot be resolved.
+    local_span: Span,
+}
+
+enum ImportName {
+    Name(String),
+    Default,
+    Namespace,
+}
+
+impl Document {
+    /// Parses the file at `uri`.
+    /// Returns [`None`] for files which are not JavaScript, TypeScript or a supported framework file.
+    pub fn new(uri: Uri, source_text: String) -> Option<Self> {
+        let path = uri.to_file_path()?.into_owned();
+        let ext = path.extension()?.to_str()?;
+        if !VALID_EXTENSIONS.contains(&ext) && PartialLoader::parse(ext, "").is_none() {
+            return None;
+        }
+        let rope = Rope::from_str(&source_text);
+        let scripts = ParsedScripts::new(Allocator::default(), |allocator| {
+            sources(&path, allocator.alloc_str(&source_text))
+                .into_iter()
+                .map(|script| {
+                    let ret = Parser::new(allocator, script.source_text, script.source_type)
+                        .with_options(parse_options())
+                        .parse();
+                    Script::new(allocator, script, ret)
+                })
+                .collect()
+        });
+        let parsed_bytes = scripts.borrow_owner().used_bytes();
+        Some(Self { uri, path, source_text, rope, scripts, parsed_bytes })
+    }
+
+    /// Updates the document to its new content.
+    ///
+    /// JavaScript and TypeScript files are reparsed with [`Parser::reparse`], which reuses the
+    /// statements outside of the edit. Framework files, and files whose arena has grown by
+    /// [`MAX_ARENA_GROWTH`] since the last full parse, are parsed again from scratch.
+    pub fn update(&mut self, source_text: String) {
+        if source_text == self.source_text {
+            return;
+        }
+        let is_script_file = self
+            .path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| VALID_EXTENSIONS.contains(&ext));
+        if !is_script_file
+            || self.scripts.borrow_owner().used_bytes() > self.parsed_bytes * MAX_ARENA_GROWTH
+        {
+            // The file type is unchanged, so it is still supported
+            if let Some(document) = Self::new(self.uri.clone(), source_text) {
+                *self = document;
+            }
+            return;
+        }
+
+        let edit = edit_span(&self.source_text, &source_text);
+        self.scripts.with_dependent_mut(|allocator, scripts| {
+            let Some(Script { source, program, mut parsed, .. }) = scripts.pop() else { return };
+            let source_text = allocator.alloc_str(&source_text);
+            // The previous program is referenced by the previous semantic, so a copy is reparsed
+            parsed.program = program.clone_in(allocator);
+            let ret = Parser::new(allocator, source_text, source.source_type)
+                .with_options(parse_options())
+                .reparse(parsed, edit);
+            let source = JavaScriptSource::new(source_text, source.source_type);
+            scripts.push(Script::new(allocator, source, ret));
+        });
+        self.rope = Rope::from_str(&source_text);
+        self.source_text = source_text;
+    }
+
+    fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    /// Location of the definition of the symbol at `position`.
+    /// Imported bindings are followed to the export of the module they are imported from,
+    /// reading the module with `read_file`.
+    pub fn definition(
+        &self,
+        position: Position,
+        read_file: impl Fn(&Path) -> Option<String>,
+    ) -> Option<Location> {
+        let (span, import) = self.with_symbol_at(position, |symbol| {
+            let span = binding_span(symbol.semantic, symbol.symbol_id);
+            let import = symbol
+                .semantic
+                .scoping()
+                .symbol_flags(symbol.symbol_id)
+                .is_import()
+                .then(|| symbol.import_target(span))
+                .flatten();
+            (symbol.offset(span), import)
+        })?;
+
+        if let Some(import) = import
+            && let Some(location) = self.import_definition(&import, read_file)
+        {
+            return Some(location);
+        }
+
+        Some(Location::new(self.uri.clone(), self.range(span)))
+    }
+
+    /// Locations of all references to the symbol at `position`.
+    pub fn references(
+        &self,
+        position: Position,
+        include_declaration: bool,
+    ) -> Option<Vec<Location>> {
+        let spans = self.with_symbol_at(position, |symbol| {
+            let declarations = symbol.declarations().map(|(_, span)| span);
+            let references = symbol.references().map(|(_, span, _)| span);
+            let spans: Vec<Span> = if include_declaration {
+                declarations.chain(references).collect()
+            } else {
+                references.collect()
+            };
+            spans.into_iter().map(|span| symbol.offset(span)).collect::<Vec<_>>()
+        })?;
+
+        Some(
+            spans
+                .into_iter()
+                .map(|span| Location::new(self.uri.clone(), self.range(span)))
+                .collect(),
+        )
+    }
+
+    /// Highlights of all occurrences of the symbol at `position`, distinguishing reads and writes.
+    pub fn highlights(&self, position: Position) -> Option<Vec<DocumentHighlight>> {
+        let highlights = self.with_symbol_at(position, |symbol| {
+            symbol
+                .declarations()
+                .map(|(_, span)| (symbol.offset(span), DocumentHighlightKind::WRITE))
+                .chain(symbol.references().map(|(_, span, is_write)| {
+                    let kind = if is_write {
+                        DocumentHighlightKind::WRITE
+                    } else {
+                        DocumentHighlightKind::READ
+                    };
+                    (symbol.offset(span), kind)
+                }))
+                .collect::<Vec<_>>()
+        })?;
+
+        Some(
+            highlights
+                .into_iter()
+                .map(|(span, kind)| DocumentHighlight { range: self.range(span), kind: Some(kind) })
+                .collect(),
+        )
+    }
+
+    /// Range of the identifier at `position`, if it can be renamed.
+    pub fn prepare_rename(&self, position: Position) -> Option<Range> {
+        let span = self
+            .with_symbol_at(position, |symbol| {
+                (!symbol.is_exported_declaration()).then(|| symbol.offset(symbol.span))
+            })
+            .flatten()?;
+        Some(self.range(span))
+    }
+
+    /// Text edits renaming the symbol at `position` to `new_name`.
+    ///
+    /// Shorthand properties, imports and exports keep their property or module export name,
+    /// e.g. renaming `a` in `export { a }` to `b` results in `export { b as a }`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `new_name` is not a valid identifier, if it would conflict with another
+    /// binding, or if the symbol is an exported declaration, as modules importing it would break.
+    pub fn rename(
+        &self,
+        position: Position,
+        new_name: &str,
+    ) -> Result<Option<Vec<TextEdit>>, String> {
+        if !is_identifier_name(new_name) || is_reserved_keyword(new_name) {
+            return Err(format!("`{new_name}` is not a valid identifier."));
+        }
+
+        let Some(edits) = self.with_symbol_at(position, |symbol| {
+            let scoping = symbol.semantic.scoping();
+            let old_name = scoping.symbol_name(symbol.symbol_id);
+            if symbol.is_exported_declaration() {
+                return Err(format!(
+                    "Cannot rename `{old_name}`, because it is exported. Modules importing it would break."
+                ));
+            }
+            if symbol.rename_conflicts(new_name) {
+                return Err(format!(
+                    "Cannot rename `{old_name}` to `{new_name}`, because it would conflict with an existing `{new_name}`."
+                ));
+            }
+
+            let declarations = symbol.declarations().map(|(node_id, span)| {
+                let text = match symbol.parent_kind_skipping_defaults(node_id) {
+                    AstKind::BindingProperty(property) if property.shorthand => {
+                        format!("{old_name}: {new_name}")
+                    }
+                    AstKind::ImportSpecifier(specifier)
+                        if specifier.imported.span() == specifier.local.span =>
+                    {
+                        format!("{old_name} as {new_name}")
+                    }
+                    _ => new_name.to_string(),
+                };
+                (symbol.offset(span), text)
+            });
+            let references = symbol.references().map(|(node_id, span, _)| {
+                let text = match symbol.semantic.nodes().parent_kind(node_id) {
+                    AstKind::ObjectProperty(property) if property.shorthand => {
+                        format!("{old_name}: {new_name}")
+                    }
+                    AstKind::AssignmentTargetPropertyIdentifier(_) => {
+                        format!("{old_name}: {new_name}")
+                    }
+                    AstKind::ExportSpecifier(specifier)
+                        if specifier.local.span() == specifier.exported.span() =>
+                    {
+                        format!("{new_name} as {old_name}")
+                    }
+                    _ => new_name.to_string(),
+                };
+                (symbol.offset(span), text)
+            });
+            Ok(declarations.chain(references).collect::<Vec<_>>())
+        }) else {
+            return Ok(None);
+        };
+
+        Ok(Some(
+            edits?.into_iter().map(|(span, text)| TextEdit::new(self.range(span), text)).collect(),
+        ))
+    }
+
+    /// Runs `f` with the symbol of the identifier at `position`, in the `<script>` block
+    /// containing `position`.
+    fn with_symbol_at<T>(
+        &self,
+        position: Position,
+        f: impl FnOnce(&SymbolAt<'_, '_>) -> T,
+    ) -> Option<T> {
+        let offset = self.offset(position);
+        self.scripts.with_dependent(|_, scripts| {
+            let script = scripts.iter().find(|script| {
+                offset >= script.source.start
+                    && offset as usize
+                        <= script.source.start as usize + script.source.source_text.len()
+            })?;
+            let offset = offset - script.source.start;
+            let semantic = &script.semantic;
+
+            let (symbol_id, span) = semantic.nodes().iter().find_map(|node| match node.kind() {
+                AstKind::BindingIdentifier(ident) if contains(name_span(ident), offset) => {
+                    Some((ident.symbol_id.get()?, name_span(ident)))
+                }
+                AstKind::IdentifierReference(ident) if contains(ident.span, offset) => {
+                    let reference = semantic.scoping().get_reference(ident.reference_id.get()?);
+                    Some((reference.symbol_id()?, ident.span))
+                }
+                _ => None,
+            })?;
+
+            Some(f(&SymbolAt {
+                semantic,
+                module_record: &script.parsed.module_record,
+                symbol_id,
+                span,
+                start: script.source.start,
+            }))
+        })
+    }
+
+    /// Location of the export of an imported binding, in the module it is imported from.
+    fn import_definition(
+        &self,
+        import: &ImportTarget,
+        read_file: impl Fn(&Path) -> Option<String>,
+    ) -> Option<Location> {
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            main_fields: vec!["module".into(), "main".into()],
+            condition_names: vec!["module".into(), "import".into()],
+            ..ResolveOptions::default()
+        });
+        let Ok(resolution) = resolver.resolve(self.path.parent()?, &import.specifier) else {
+            return Some(Location::new(self.uri.clone(), self.range(import.local_span)));
+        };
+
+        let path = resolution.into_path_buf();
+        let uri = Uri::from_file_path(&path)?;
+        let source_text = read_file(&path)?;
+        let document = Document::new(uri, source_text)?;
+        let span = document.export_span(&import.name).unwrap_or_default();
+        Some(Location::new(document.uri.clone(), document.range(span)))
+    }
+
+    /// Span of the export with the given name. Namespace imports point to the start of the file.
+    fn export_span(&self, name: &ImportName) -> Option<Span> {
+        if matches!(name, ImportName::Namespace) {
+            return None;
+        }
+
+        self.scripts.with_dependent(|_, scripts| {
+            scripts.iter().find_map(|script| {
+                let semantic = &script.semantic;
+                let module_record = &script.parsed.module_record;
+
+                let is_export = |entry: &&ExportEntry| match (&entry.export_name, name) {
+                    (ExportExportName::Name(export_name), ImportName::Name(name)) => {
+                        export_name.name == name.as_str()
+                    }
+                    (ExportExportName::Default(_), ImportName::Default) => true,
+                    _ => false,
+                };
+
+                let span = if let Some(entry) =
+                    module_record.local_export_entries.iter().find(is_export)
+                {
+                    entry
+                        .local_name
+                        .name()
+                        .and_then(|local_name| semantic.scoping().get_root_binding(&local_name))
+                        .map_or(entry.span, |symbol_id| binding_span(semantic, symbol_id))
+                } else {
+                    module_record.indirect_export_entries.iter().find(is_export)?.span
+                };
+                let start = script.source.start;
+                Some(Span::new(span.start + start, span.end + start))
+            })
+        })
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn offset(&self, position: Position) -> u32 {
+        self.rope.char_to_byte(position_to_char(&self.rope, position)) as u32
+    }
+
+    fn range(&self, span: Span) -> Range {
+        let (start_line, start_character) =
+            get_line_column(&self.rope, span.start, self.source_text());
+        let (end_line, end_character) = get_line_column(&self.rope, span.end, self.source_text());
+        Range::new(
+            Position::new(start_line, start_character),
+            Position::new(end_line, end_character),
+        )
+    }
+}
+
+impl SymbolAt<'_, '_> {
+    /// Span in the file, from a span in the `<script>` block.
+    fn offset(&self, span: Span) -> Span {
+        Span::new(span.start + self.start, span.end + self.start)
+    }
+
+    /// The binding identifiers declaring the symbol, including redeclarations.
+    fn declarations(&self) -> impl Iterator<Item = (NodeId, Span)> + '_ {
+        self.semantic.nodes().iter().filter_map(|node| match node.kind() {
+            AstKind::BindingIdentifier(ident) if ident.symbol_id.get() == Some(self.symbol_id) => {
+                Some((node.id(), name_span(ident)))
+            }
+            _ => None,
+        })
+    }
+
+    /// The identifier references to the symbol, and whether they write to it.
+    fn references(&self) -> impl Iterator<Item = (NodeId, Span, bool)> + '_ {
+        let nodes = self.semantic.nodes();
+        self.semantic.scoping().get_resolved_references(self.symbol_id).map(move |reference| {
+            let node_id = reference.node_id();
+            (node_id, nodes.kind(node_id).span(), reference.is_write())
+        })
+    }
+
+    /// Returns `true` if renaming the symbol to `new_name` would change what a reference resolves to:
+    /// * `new_name` is declared in the same scope
+    /// * `new_name` is declared in a child scope, between a reference and the declaration
+    /// * `new_name` is an enclosing binding or a global, referenced from within the symbol's scope
+    fn rename_conflicts(&self, new_name: &str) -> bool {
+        let scoping = self.semantic.scoping();
+        let nodes = self.semantic.nodes();
+        let scope_id = scoping.symbol_scope_id(self.symbol_id);
+        let is_within_scope =
+            |inner: ScopeId| scoping.scope_ancestors(inner).any(|ancestor| ancestor == scope_id);
+        let reference_scope =
+            |reference: &Reference| nodes.get_node(reference.node_id()).scope_id();
+
+        if scoping.get_binding(scope_id, new_name).is_some() {
+            return true;
+        }
+
+        let is_shadowed = scoping.get_resolved_references(self.symbol_id).any(|reference| {
+            scoping
+                .find_binding(reference_scope(reference), new_name)
+                .is_some_and(|binding| is_within_scope(scoping.symbol_scope_id(binding)))
+        });
+        if is_shadowed {
+            return true;
+        }
+
+        let outer_references = scoping
+            .symbol_ids()
+            .filter(|&symbol_id| {
+                scoping.symbol_name(symbol_id) == new_name
+                    && !is_within_scope(scoping.symbol_scope_id(symbol_id))
+            })
+            .flat_map(|symbol_id| scoping.get_resolved_references(symbol_id))
+            .chain(
+                scoping
+                    .root_unresolved_references()
+                    .get(new_name)
+                    .into_iter()
+                    .flatten()
+                    .map(|&reference_id| scoping.get_reference(reference_id)),
+            );
+        outer_references.into_iter().any(|reference| is_within_scope(reference_scope(reference)))
+    }
+
+    /// The parent of a binding identifier, skipping default values like in `{ a = 1 }`.
+    fn parent_kind_skipping_defaults(&self, node_id: NodeId) -> AstKind<'_> {
+        let nodes = self.semantic.nodes();
+        match nodes.parent_kind(node_id) {
+            AstKind::AssignmentPattern(_) => nodes.parent_kind(nodes.parent_id(node_id)),
+            kind => kind,
+        }
+    }
+
+    /// Returns `true` for module-level declarations in `export` statements, e.g. `export const a = 1`.
+    fn is_exported_declaration(&self) -> bool {
+        let scoping = self.semantic.scoping();
+        scoping.symbol_scope_id(self.symbol_id) == scoping.root_scope_id()
+            && self
+                .semantic
+                .nodes()
+                .ancestor_kinds(scoping.symbol_declaration(self.symbol_id))
+                .any(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_)))
+    }
+
+    /// The module and export name an imported binding declared at `span` is imported from.
+    fn import_target(&self, span: Span) -> Option<ImportTarget> {
+        let entry =
+            self.module_record.import_entries.iter().find(|entry| entry.local_name.span == span)?;
+        let name = match &entry.import_name {
+            ImportImportName::Name(name) => ImportName::Name(name.name.to_string()),
+            ImportImportName::Default(_) => ImportName::Default,
+            ImportImportName::NamespaceObject => ImportName::Namespace,
+        };
+        Some(ImportTarget {
+            specifier: entry.module_request.name.to_string(),
+            name,
+            local_span: self.offset(span),
+        })
+    }
+}
+
+impl<'a> Script<'a> {
+    fn new(
+        allocator: &'a Allocator,
+        source: JavaScriptSource<'a>,
+        mut parsed: ParserReturn<'a>,
+    ) -> Self {
+        let program = allocator.alloc(parsed.program.take_in(allocator));
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        Self { source, program, semantic, parsed }
+    }
+}
+
+/// Keep navigating the rest of the file while it is being edited.
+fn parse_options() -> ParseOptions {
+    ParseOptions { error_recovery: true, ..ParseOptions::default() }
+}
+
+/// Span of the text in `old` which is replaced to get `new`, between their common prefix and suffix.
+#[expect(clippy::cast_possible_truncation)]
+fn edit_span(old: &str, new: &str) -> Span {
+    let mut prefix = old.bytes().zip(new.bytes()).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) {
+        suffix -= 1;
+    }
+    Span::new(prefix as u32, (old.len() - suffix) as u32)
+}
+
+/// The JavaScript sources of the file, the `<script>` blocks of framework files.
+fn sources<'a>(path: &Path, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+    let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+    PartialLoader::parse(ext, source_text).unwrap_or_else(|| {
+        SourceType::from_path(path)
+            .map(|source_type| vec![JavaScriptSource::new(source_text, source_type)])
+            .unwrap_or_default()
+    })
+}
+
+/// Span of the first binding identifier declaring the symbol.
+/// Unlike [`Scoping::symbol_span`](oxc_semantic::Scoping::symbol_span), excludes type annotations.
+fn binding_span(semantic: &Semantic, symbol_id: SymbolId) -> Span {
+    semantic
+        .nodes()
+        .iter()
+        .find_map(|node| match node.kind() {
+            AstKind::BindingIdentifier(ident) if ident.symbol_id.get() == Some(symbol_id) => {
+                Some(name_span(ident))
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| semantic.scoping().symbol_span(symbol_id))
+}
+
+/// Span of the name of a binding identifier. In TypeScript, the span of the binding identifier
+/// includes its type annotation.
+#[expect(clippy::cast_possible_truncation)]
+fn name_span(ident: &BindingIdentifier) -> Span {
+    Span::sized(ident.span.start, ident.name.len() as u32)
+}
+
+fn contains(span: Span, offset: u32) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+#[cfg(test)]
+mod test {
+    use std::{str::FromStr, sync::Arc};
+
+    use oxc_span::Span;
+    use tower_lsp_server::{
+        UriExt,
+        lsp_types::{DocumentHighlightKind, Position, Range, Uri},
+    };
+
+    use super::{Document, DocumentCache};
+
+    fn uri(path: &str) -> Uri {
+        Uri::from_str(&format!("file:///{path}")).unwrap()
+    }
+
+    fn range(line: u32, start: u32, end: u32) -> Range {
+        Range::new(Position::new(line, start), Position::new(line, end))
+    }
+
+    #[test]
+    fn references_and_highlights() {
+        let uri = uri("test.js");
+        let source_text = "let a = 1;\na = a + 1;\nfunction f(a) { return a; }\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        let references = document.references(Position::new(1, 4), true).unwrap();
+        let ranges: Vec<Range> = references.into_iter().map(|location| location.range).collect();
+        assert_eq!(ranges, vec![range(0, 4, 5), range(1, 0, 1), range(1, 4, 5)]);
+
+        let references = document.references(Position::new(0, 4), false).unwrap();
+        assert_eq!(references.len(), 2);
+
+        let highlights = document.highlights(Position::new(2, 11)).unwrap();
+        let highlights: Vec<_> =
+            highlights.into_iter().map(|highlight| (highlight.range, highlight.kind)).collect();
+        assert_eq!(
+            highlights,
+            vec![
+                (range(2, 11, 12), Some(DocumentHighlightKind::WRITE)),
+                (range(2, 23, 24), Some(DocumentHighlightKind::READ)),
+            ]
+        );
+
+        assert!(document.references(Position::new(0, 8), true).is_none());
+    }
+
+    #[test]
+    fn definition_of_local_binding() {
+        let uri = uri("test.ts");
+        let source_text = "const a: number = 1;\nconsole.log(a);\n";
+        let document = Document::new(uri.clone(), source_text.to_string()).unwrap();
+
+        let location = document.definition(Position::new(1, 12), |_| None).unwrap();
+        assert_eq!(location.uri, uri);
+        assert_eq!(location.range, range(0, 6, 7));
+    }
+
+    #[test]
+    fn definition_with_syntax_error() {
+        let uri = uri("test.js");
+        let source_text = "const a = 1;\nlet b = ;\nconsole.log(a);\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        let location = document.definition(Position::new(2, 12), |_| None).unwrap();
+        assert_eq!(location.range, range(0, 6, 7));
+    }
+
+    #[test]
+    fn definition_in_vue_script() {
+        let uri = uri("test.vue");
+        let source_text = "<template>{{ a }}</template>\n<script setup>\nconst a = 1;\nconsole.log(a);\n</script>\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        let location = document.definition(Position::new(3, 12), |_| None).unwrap();
+        assert_eq!(location.range, range(2, 6, 7));
+    }
+
+    #[test]
+    fn definition_of_import() {
+        let dir = std::env::temp_dir().join("oxc_language_server_navigation");
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("target.js");
+        std::fs::write(&target, "").unwrap();
+        let uri = Uri::from_file_path(dir.join("test.js")).unwrap();
+        let target_uri = Uri::from_file_path(&target).unwrap();
+
+        let source_text = "import def, { b as c } from './target';\nc(def);\n";
+        let document = Document::new(uri.clone(), source_text.to_string()).unwrap();
+        let read_file =
+            |_: &std::path::Path| Some("export function b() {}\nexport default 1;\n".to_string());
+
+        let location = document.definition(Position::new(1, 0), read_file).unwrap();
+        assert_eq!(location.uri, target_uri);
+        assert_eq!(location.range, range(0, 16, 17));
+
+        let location = document.definition(Position::new(1, 3), read_file).unwrap();
+        assert_eq!(location.uri, target_uri);
+        assert_eq!(location.range, range(1, 15, 16));
+
+        // unresolved modules fall back to the local binding
+        let source_text = "import { x } from './missing';\nx;\n";
+        let document = Document::new(uri.clone(), source_text.to_string()).unwrap();
+        let location = document.definition(Position::new(1, 0), read_file).unwrap();
+        assert_eq!(location.uri, uri);
+        assert_eq!(location.range, range(0, 9, 10));
+    }
+
+    #[test]
+    fn rename_shorthands() {
+        let uri = uri("test.js");
+        let source_text = "import { a } from 'mod';\nconst { b } = a;\nconst o = { a, b };\n({ b } = o);\nexport { b };\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        let edits = document.rename(Position::new(0, 9), "x").unwrap().unwrap();
+        let edits: Vec<_> = edits.into_iter().map(|edit| (edit.range, edit.new_text)).collect();
+        assert_eq!(
+            edits,
+            vec![
+                (range(0, 9, 10), "a as x".to_string()),
+                (range(1, 14, 15), "x".to_string()),
+                (range(2, 12, 13), "a: x".to_string()),
+            ]
+        );
+
+        let edits = document.rename(Position::new(1, 8), "y").unwrap().unwrap();
+        let edits: Vec<_> = edits.into_iter().map(|edit| (edit.range, edit.new_text)).collect();
+        assert_eq!(
+            edits,
+            vec![
+                (range(1, 8, 9), "b: y".to_string()),
+                (range(2, 15, 16), "b: y".to_string()),
+                (range(3, 3, 4), "b: y".to_string()),
+                (range(4, 9, 10), "y as b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rename_invalid() {
+        let uri = uri("test.js");
+        let source_text = "export const a = 1;\nlet b = a;\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        assert!(document.prepare_rename(Position::new(0, 13)).is_none());
+        assert!(document.rename(Position::new(1, 8), "c").is_err());
+        assert_eq!(document.prepare_rename(Position::new(1, 4)), Some(range(1, 4, 5)));
+        assert!(document.rename(Position::new(1, 4), "class").is_err());
+        assert!(document.rename(Position::new(1, 4), "1c").is_err());
+        assert!(document.rename(Position::new(0, 0), "c").unwrap().is_none());
+    }
+
+    #[test]
+    fn rename_conflicts() {
+        let uri = uri("test.js");
+        let source_text = "let a = 1, b = 2;\nfunction f(c) {\n  let d = a;\n  return c + e;\n}\n{ let g = a; }\n";
+        let document = Document::new(uri, source_text.to_string()).unwrap();
+
+        // declared in the same scope
+        assert!(document.rename(Position::new(0, 4), "b").is_err());
+        // declared in a child scope which a reference resolves through
+        assert!(document.rename(Position::new(0, 4), "d").is_err());
+        assert!(document.rename(Position::new(0, 4), "g").is_err());
+        // a global referenced from within the symbol's scope
+        assert!(document.rename(Position::new(1, 11), "e").is_err());
+        // an enclosing binding referenced from within the symbol's scope
+        assert!(document.rename(Position::new(2, 6), "a").is_err());
+
+        // bindings in unrelated scopes
+        assert!(document.rename(Position::new(1, 11), "g").unwrap().is_some());
+        assert!(document.rename(Position::new(2, 6), "b").unwrap().is_some());
+        assert!(document.rename(Position::new(0, 11), "c").unwrap().is_some());
+    }
+
+    #[test]
+    fn document_cache() {
+        let cache = DocumentCache::default();
+        let uri = uri("test.js");
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            cache.update(&uri, 1, "let a;".to_string()).await.unwrap();
+            assert!(cache.get(&uri, 1).is_some());
+            assert!(cache.get(&uri, 2).is_none());
+
+            // the previous version is updated
+            let document = cache.update(&uri, 2, "let a;\nlet b = a;".to_string()).await.unwrap();
+            assert!(Arc::ptr_eq(&document, &cache.get(&uri, 2).unwrap()));
+            assert!(cache.get(&uri, 1).is_none());
+            let references = document.lock().await.references(Position::new(0, 4), true).unwrap();
+            assert_eq!(references.len(), 2);
+        });
+        cache.remove(&uri);
+        assert!(cache.get(&uri, 2).is_none());
+    }
+
+    #[test]
+    #[expect(clippy::disallowed_methods)]
+    fn update() {
+        let uri = uri("test.ts");
+        let source_text = "import { c } from 'c';\nconst a = 1;\nfunction f() {\n  return a;\n}\nconst b = f();\nexport { b, c };\n";
+        let mut document = Document::new(uri.clone(), source_text.to_string()).unwrap();
+
+        // Edits which are reparsed incrementally, or in full, give the same result as a new document
+        let edits = [
+            source_text.replace("return a;", "return a + a;"),
+            source_text.replace("const b = f();", "const b = f();\nconst c = b;"),
+            source_text.replace("function f() {", "function f( {"),
+            "let x = 'é';\nlet y = x;\nlet z = 'è';\n".to_string(),
+            String::new(),
+            source_text.to_string(),
+        ];
+        for source_text in edits {
+            document.update(source_text.clone());
+            let expected = Document::new(uri.clone(), source_text).unwrap();
+            for line in 0..7 {
+                for character in 0..16 {
+                    let position = Position::new(line, character);
+                    assert_eq!(
+                        document.references(position, true),
+                        expected.references(position, true)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edit_span() {
+        assert_eq!(super::edit_span("let a = 1;", "let a = 12;"), Span::new(9, 9));
+        assert_eq!(super::edit_span("let a = 1;", "let b = 1;"), Span::new(4, 5));
+        assert_eq!(super::edit_span("aaa", "aa"), Span::new(2, 3));
+        // never splits a character
+        assert_eq!(super::edit_span("'é'", "'è'"), Span::new(1, 3));
+    }
+
+    #[test]
+    fn unsupported_files() {
+        assert!(Document::new(uri("test.css"), String::new()).is_none());
+        assert!(Document::new(uri("test.svelte"), String::new()).is_some());
+    }
+}
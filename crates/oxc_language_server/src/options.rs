@@ -1,9 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use tower_lsp_server::lsp_types::Uri;
 
 use crate::{formatter::options::FormatOptions, linter::options::LintOptions};
 
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Options {
     #[serde(flatten)]
@@ -12,7 +12,7 @@ pub struct Options {
     pub format: FormatOptions,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspaceOption {
     pub workspace_uri: Uri,
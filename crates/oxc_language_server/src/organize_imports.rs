@@ -0,0 +1,475 @@
+//! Organize the imports of a file: remove unused imports, then sort and group the remaining ones.
+//!
+//! Every block of consecutive import declarations is organized on its own.
+//! Side-effect imports like `import "./polyfill"` or `import {} from "./polyfill"` are never moved
+//! or removed, because the order they are evaluated in matters. Imports are only sorted between them.
+
+use std::cmp::Ordering;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstKind, Comment,
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, Statement},
+};
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_linter::ImportGroup;
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder};
+use oxc_span::{SourceType, Span};
+use oxc_syntax::{import_source::compare_names, module_record::ModuleRecord};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tower_lsp_server::{
+    UriExt,
+    lsp_types::{Position, Range, TextEdit, Uri},
+};
+
+/// An import declaration of a block, with its comments.
+struct Import<'a> {
+    /// The import declaration with its leading comments and the comments on the same line after it.
+    text: String,
+    kind: ImportKind<'a>,
+}
+
+enum ImportKind<'a> {
+    /// `import "./polyfill"`, it keeps the empty lines around it.
+    SideEffect {
+        blank_line_before: bool,
+        blank_line_after: bool,
+    },
+    Sorted {
+        group: ImportGroup,
+        specifier: &'a str,
+        is_type: bool,
+    },
+}
+
+/// What happens to an import declaration with unused bindings.
+enum Organized {
+    Keep,
+    Remove,
+    Rewrite(String),
+}
+
+/// Text edits organizing the imports of the file, empty when the imports are organized already.
+/// Returns [`None`] for files which are not JavaScript or TypeScript, or which can not be parsed.
+///
+/// `<script>` blocks of Vue, Svelte and Astro files are not supported,
+/// because imports used in the template are not known.
+pub fn organize_imports(uri: &Uri, source_text: &str) -> Option<Vec<TextEdit>> {
+    let path = uri.to_file_path()?;
+    let source_type = SourceType::from_path(&path).ok()?;
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked || !ret.errors.is_empty() {
+        return None;
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+
+    let organizer = ImportOrganizer {
+        source_text,
+        comments: &ret.program.comments,
+        unused: unused_imports(&semantic, &ret.module_record),
+        requests: import_requests(&ret.module_record),
+        line_break: if source_text.contains("\r\n") { "\r\n" } else { "\n" },
+    };
+
+    let rope = Rope::from_str(source_text);
+    let range = |span: Span| {
+        let (start_line, start_character) = get_line_column(&rope, span.start, source_text);
+        let (end_line, end_character) = get_line_column(&rope, span.end, source_text);
+        Range::new(
+            Position::new(start_line, start_character),
+            Position::new(end_line, end_character),
+        )
+    };
+
+    let mut text_edits = vec![];
+    let mut block = vec![];
+    for statement in &ret.program.body {
+        if let Statement::ImportDeclaration(declaration) = statement {
+            block.push(&**declaration);
+            continue;
+        }
+        if let Some((span, new_text)) = organizer.organize_block(&block) {
+            text_edits.push(TextEdit { range: range(span), new_text });
+        }
+        block.clear();
+    }
+    if let Some((span, new_text)) = organizer.organize_block(&block) {
+        text_edits.push(TextEdit { range: range(span), new_text });
+    }
+
+    Some(text_edits)
+}
+
+/// Spans of the local names of imported bindings, which are never referenced.
+fn unused_imports(semantic: &Semantic, module_record: &ModuleRecord) -> FxHashSet<Span> {
+    let scoping = semantic.scoping();
+    // `React` is used by JSX elements with the classic runtime, without being referenced
+    let has_jsx = semantic
+        .nodes()
+        .iter()
+        .any(|node| matches!(node.kind(), AstKind::JSXElement(_) | AstKind::JSXFragment(_)));
+
+    module_record
+        .import_entries
+        .iter()
+        .filter(|entry| !(has_jsx && entry.local_name.name == "React"))
+        .filter(|entry| {
+            scoping
+                .get_root_binding(&entry.local_name.name)
+                .is_some_and(|symbol_id| scoping.symbol_is_unused(symbol_id))
+        })
+        .map(|entry| entry.local_name.span)
+        .collect()
+}
+
+/// The module specifier of every import declaration, and if it is a type-only import.
+fn import_requests<'a>(module_record: &ModuleRecord<'a>) -> FxHashMap<Span, (&'a str, bool)> {
+    module_record
+        .requested_modules
+        .iter()
+        .flat_map(|(specifier, requests)| {
+            requests
+                .iter()
+                .filter(|request| request.is_import)
+                .map(|request| (request.statement_span, (specifier.as_str(), request.is_type)))
+        })
+        .collect()
+}
+
+struct ImportOrganizer<'s, 'a> {
+    source_text: &'a str,
+    comments: &'s [Comment],
+    unused: FxHashSet<Span>,
+    requests: FxHashMap<Span, (&'a str, bool)>,
+    line_break: &'static str,
+}
+
+impl<'a> ImportOrganizer<'_, 'a> {
+    /// The span of the block and its organized text, or [`None`] when nothing changes.
+    /// Comments before the first import stay in place, because they often belong to the file.
+    fn organize_block(&self, block: &[&ImportDeclaration<'a>]) -> Option<(Span, String)> {
+        let first = block.first()?;
+
+        let mut imports = vec![];
+        let mut blank_lines = vec![];
+        let mut end = first.span.start;
+        for (index, declaration) in block.iter().enumerate() {
+            let start = if index == 0 {
+                declaration.span.start
+            } else {
+                self.leading_comments_start(end, declaration.span.start)
+            };
+            blank_lines
+                .push(index > 0 && has_blank_line(&self.source_text[end as usize..start as usize]));
+            let next_start = block.get(index + 1).map(|next| next.span.start);
+            end = self.trailing_comments_end(declaration.span.end, next_start);
+
+            let declaration_text = match self.organize_declaration(declaration) {
+                Organized::Keep => self.slice(declaration.span).to_string(),
+                Organized::Remove => continue,
+                Organized::Rewrite(text) => text,
+            };
+            let text = format!(
+                "{}{declaration_text}{}",
+                &self.source_text[start as usize..declaration.span.start as usize],
+                &self.source_text[declaration.span.end as usize..end as usize]
+            );
+
+            let (specifier, is_type) =
+                self.requests.get(&declaration.span).copied().unwrap_or_else(|| {
+                    (declaration.source.value.as_str(), declaration.import_kind.is_type())
+                });
+            // `import {} from "x"` only has side effects too, the same as in the formatter's sorting
+            let is_side_effect =
+                declaration.specifiers.as_ref().is_none_or(|specifiers| specifiers.is_empty());
+            let kind = if is_side_effect {
+                ImportKind::SideEffect {
+                    blank_line_before: blank_lines[index],
+                    blank_line_after: false,
+                }
+            } else {
+                ImportKind::Sorted { group: ImportGroup::new(specifier), specifier, is_type }
+            };
+            imports.push((index, Import { text, kind }));
+        }
+        blank_lines.push(false);
+        for (index, import) in &mut imports {
+            if let ImportKind::SideEffect { blank_line_after, .. } = &mut import.kind {
+                *blank_line_after = blank_lines[*index + 1];
+            }
+        }
+        let mut imports = imports.into_iter().map(|(_, import)| import).collect::<Vec<_>>();
+
+        // sort the imports between side-effect imports
+        for sorted in
+            imports.split_mut(|import| matches!(import.kind, ImportKind::SideEffect { .. }))
+        {
+            sorted.sort_by(|a, b| compare_imports(&a.kind, &b.kind));
+        }
+
+        let mut text = String::new();
+        for (index, import) in imports.iter().enumerate() {
+            if index > 0 {
+                text.push_str(self.line_break);
+                if has_blank_line_between(&imports[index - 1].kind, &import.kind) {
+                    text.push_str(self.line_break);
+                }
+            }
+            text.push_str(&import.text);
+        }
+
+        let span = Span::new(first.span.start, end);
+        if text == self.slice(span) { None } else { Some((span, text)) }
+    }
+
+    /// Remove the unused bindings of an import declaration.
+    /// Declarations without any used binding are removed completely.
+    fn organize_declaration(&self, declaration: &ImportDeclaration<'a>) -> Organized {
+        let Some(specifiers) = &declaration.specifiers else {
+            return Organized::Keep;
+        };
+        let used = specifiers
+            .iter()
+            .filter(|specifier| !self.unused.contains(&specifier.local().span))
+            .collect::<Vec<_>>();
+
+        if used.len() == specifiers.len() {
+            return Organized::Keep;
+        }
+        if used.is_empty() {
+            return Organized::Remove;
+        }
+        // comments inside the declaration would be lost when rewriting it
+        if declaration.phase.is_some() || self.has_comments_inside(declaration.span) {
+            return Organized::Keep;
+        }
+
+        let mut clauses = vec![];
+        let mut named = vec![];
+        for specifier in used {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    named.push(self.slice(specifier.span));
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    clauses.push(self.slice(specifier.span).to_string());
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    clauses.push(self.slice(specifier.span).to_string());
+                }
+            }
+        }
+        if !named.is_empty() {
+            clauses.push(format!("{{ {} }}", named.join(", ")));
+        }
+
+        let mut text = String::from("import ");
+        if declaration.import_kind.is_type() {
+            text.push_str("type ");
+        }
+        text.push_str(&clauses.join(", "));
+        text.push_str(" from ");
+        // the module specifier, followed by the import attributes and the semicolon
+        text.push_str(self.slice(Span::new(declaration.source.span.start, declaration.span.end)));
+        Organized::Rewrite(text)
+    }
+
+    /// The start of the comments before an import, which are not on the line of the previous import.
+    fn leading_comments_start(&self, previous_end: u32, start: u32) -> u32 {
+        self.comments
+            .iter()
+            .find(|comment| comment.span.start >= previous_end && comment.span.end <= start)
+            .map_or(start, |comment| comment.span.start)
+    }
+
+    /// The end of the comments on the same line after an import.
+    fn trailing_comments_end(&self, declaration_end: u32, next_start: Option<u32>) -> u32 {
+        let mut end = declaration_end;
+        for comment in
+            self.comments.iter().skip_while(|comment| comment.span.start < declaration_end)
+        {
+            if next_start.is_some_and(|next_start| comment.span.end > next_start)
+                || self.source_text[end as usize..comment.span.start as usize].contains('\n')
+            {
+                break;
+            }
+            end = comment.span.end;
+        }
+        end
+    }
+
+    fn has_comments_inside(&self, span: Span) -> bool {
+        self.comments
+            .iter()
+            .any(|comment| comment.span.start >= span.start && comment.span.end <= span.end)
+    }
+
+    fn slice(&self, span: Span) -> &'a str {
+        &self.source_text[span.start as usize..span.end as usize]
+    }
+}
+
+/// Sort by group, then case-insensitive by module specifier like the formatter sorts imports,
+/// with type-only imports last.
+fn compare_imports(a: &ImportKind, b: &ImportKind) -> Ordering {
+    let (
+        ImportKind::Sorted { group: a_group, specifier: a_specifier, is_type: a_is_type },
+        ImportKind::Sorted { group: b_group, specifier: b_specifier, is_type: b_is_type },
+    ) = (a, b)
+    else {
+        return Ordering::Equal;
+    };
+
+    a_group
+        .cmp(b_group)
+        .then_with(|| compare_names(a_specifier, b_specifier, true))
+        .then_with(|| a_is_type.cmp(b_is_type))
+}
+
+fn has_blank_line_between(previous: &ImportKind, next: &ImportKind) -> bool {
+    match (previous, next) {
+        (ImportKind::SideEffect { blank_line_after, .. }, _) => *blank_line_after,
+        (_, ImportKind::SideEffect { blank_line_before, .. }) => *blank_line_before,
+        (ImportKind::Sorted { group: a, .. }, ImportKind::Sorted { group: b, .. }) => a != b,
+    }
+}
+
+fn has_blank_line(whitespace: &str) -> bool {
+    whitespace.matches('\n').count() > 1
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tower_lsp_server::lsp_types::{Position, Range, Uri};
+
+    use super::organize_imports;
+
+    fn uri(file_name: &str) -> Uri {
+        Uri::from_str(&format!("file:///{file_name}")).unwrap()
+    }
+
+    /// The organized imports, when exactly one block of imports changed.
+    fn organized(file_name: &str, source_text: &str) -> Option<String> {
+        let mut text_edits = organize_imports(&uri(file_name), source_text).unwrap();
+        assert!(text_edits.len() <= 1);
+        text_edits.pop().map(|text_edit| text_edit.new_text)
+    }
+
+    #[test]
+    fn sort_and_group() {
+        let source_text = "import b from './b';
+import fs from 'node:fs';
+import { a } from '../a';
+import React from 'react';
+import path from 'path';
+import { z } from '@scope/z';
+use(b, fs, a, React, path, z);
+";
+        let text_edits = organize_imports(&uri("test.js"), source_text).unwrap();
+        assert_eq!(text_edits.len(), 1);
+        assert_eq!(text_edits[0].range, Range::new(Position::new(0, 0), Position::new(5, 29)));
+        assert_eq!(
+            text_edits[0].new_text,
+            "import fs from 'node:fs';
+import path from 'path';
+
+import { z } from '@scope/z';
+import React from 'react';
+
+import { a } from '../a';
+
+import b from './b';"
+        );
+    }
+
+    #[test]
+    fn groups_like_import_order() {
+        let source_text = "import alias from '~/alias';\nimport index from './index';\nimport b from './b';\nimport fs from 'fs/promises';\nuse(alias, index, b, fs);\n";
+        assert_eq!(
+            organized("test.js", source_text).as_deref(),
+            Some(
+                "import fs from 'fs/promises';\n\nimport b from './b';\n\nimport index from './index';\n\nimport alias from '~/alias';"
+            )
+        );
+    }
+
+    #[test]
+    fn remove_unused() {
+        let source_text = "import a, { b, c as d, type E } from 'x' with { type: 'json' };
+import f, * as g from 'f';
+import type { H } from 'h';
+use(a, d);
+let e: E;
+export { g };
+";
+        assert_eq!(
+            organized("test.ts", source_text).as_deref(),
+            Some(
+                "import * as g from 'f';\nimport a, { c as d, type E } from 'x' with { type: 'json' };"
+            )
+        );
+    }
+
+    #[test]
+    fn type_only_imports() {
+        let source_text = "import type { T } from 'x';\nimport { v } from 'x';\nlet t: T = v;\n";
+        assert_eq!(
+            organized("test.ts", source_text).as_deref(),
+            Some("import { v } from 'x';\nimport type { T } from 'x';")
+        );
+    }
+
+    #[test]
+    fn side_effect_imports() {
+        let source_text = "import c from 'c';
+import './polyfill';
+import b from 'b';
+import a from 'a';
+
+import './styles.css';
+import unused from 'unused';
+use(a, b, c);
+";
+        assert_eq!(
+            organized("test.js", source_text).as_deref(),
+            Some(
+                "import c from 'c';\nimport './polyfill';\nimport a from 'a';\nimport b from 'b';\n\nimport './styles.css';"
+            )
+        );
+    }
+
+    #[test]
+    fn empty_import_is_side_effect() {
+        let source_text =
+            "import b from 'b';\nimport {} from './polyfill';\nimport a from 'a';\nuse(a, b);\n";
+        assert_eq!(organized("test.js", source_text), None);
+    }
+
+    #[test]
+    fn comments() {
+        let source_text = "// header
+import b from 'b'; // b
+// a
+import a from 'a';
+use(a, b);
+";
+        assert_eq!(
+            organized("test.js", source_text).as_deref(),
+            Some("// a\nimport a from 'a';\nimport b from 'b'; // b")
+        );
+    }
+
+    #[test]
+    fn organized_already() {
+        let source_text =
+            "import React from 'react';\n\nimport a from './a';\nconst b = <div>{a}</div>;\n";
+        assert_eq!(organized("test.jsx", source_text), None);
+        assert_eq!(organize_imports(&uri("test.vue"), "<script>import a from 'a';</script>"), None);
+        assert_eq!(organize_imports(&uri("test.js"), "import a from"), None);
+    }
+}
@@ -1,12 +1,13 @@
-use std::vec;
+use std::{sync::Arc, vec};
 
-use log::debug;
-use tokio::sync::{Mutex, RwLock};
+use log::{debug, warn};
+use oxc_linter::LINTABLE_EXTENSIONS;
+use tokio::sync::{Mutex, RwLock, mpsc::UnboundedSender};
 use tower_lsp_server::{
     UriExt,
     lsp_types::{
-        CodeActionOrCommand, Diagnostic, FileEvent, FileSystemWatcher, GlobPattern, OneOf, Range,
-        RelativePattern, TextEdit, Uri, WatchKind,
+        CodeActionOrCommand, Diagnostic, FileChangeType, FileEvent, FileSystemWatcher, GlobPattern,
+        Hover, OneOf, Position, Range, RelativePattern, TextEdit, Uri, WatchKind,
     },
 };
 
@@ -14,8 +15,10 @@ use crate::{
     ConcurrentHashMap,
     code_actions::{apply_all_fix_code_action, apply_fix_code_actions, fix_all_text_edit},
     formatter::server_formatter::ServerFormatter,
+    hover::rule_documentation_hover,
     linter::{
         error_with_position::DiagnosticReport,
+        isolated_lint_handler::IsolatedLintHandler,
         server_linter::{ServerLinter, ServerLinterRun, normalize_path},
     },
     options::Options,
@@ -71,12 +74,14 @@ impl WorkspaceWorker {
         *self.server_linter.write().await = Some(ServerLinter::new(&self.root_uri, &options.lint));
         if options.format.experimental {
             debug!("experimental formatter enabled");
-            *self.server_formatter.write().await = Some(ServerFormatter::new());
+            *self.server_formatter.write().await =
+                Some(ServerFormatter::new(options.format.clone()));
         }
     }
 
     /// Initialize file system watchers for the workspace.
-    /// These watchers are used to watch for changes in the lint configuration files.
+    /// These watchers are used to watch for changes in the lint configuration files,
+    /// and in the lintable files when the whole workspace is linted.
     /// The returned watchers will be registered to the client.
     pub async fn init_watchers(&self) -> Vec<FileSystemWatcher> {
         let mut watchers = Vec::new();
@@ -86,6 +91,7 @@ impl WorkspaceWorker {
         let default_options = Options::default();
         let options = options.as_ref().unwrap_or(&default_options);
         let use_nested_configs = options.lint.use_nested_configs();
+        let lint_workspace = options.lint.lint_workspace;
 
         // append the base watcher
         watchers.push(FileSystemWatcher {
@@ -101,6 +107,16 @@ impl WorkspaceWorker {
             kind: Some(WatchKind::all()), // created, deleted, changed
         });
 
+        if lint_workspace {
+            watchers.push(FileSystemWatcher {
+                glob_pattern: GlobPattern::Relative(RelativePattern {
+                    base_uri: OneOf::Right(self.root_uri.clone()),
+                    pattern: format!("**/*.{{{}}}", LINTABLE_EXTENSIONS.join(",")),
+                }),
+                kind: Some(WatchKind::all()), // created, deleted, changed
+            });
+        }
+
         let Some(root_path) = &self.root_uri.to_file_path() else {
             return watchers;
         };
@@ -141,6 +157,20 @@ impl WorkspaceWorker {
         self.server_formatter.read().await.is_some()
     }
 
+    /// Check if the whole workspace folder is linted, not only the opened files
+    pub async fn lints_workspace(&self) -> bool {
+        self.options.lock().await.as_ref().is_some_and(|options| options.lint.lint_workspace)
+    }
+
+    /// Get the lintable files of the workspace folder, which are not ignored
+    pub async fn workspace_files(&self) -> Vec<Uri> {
+        let Some(server_linter) = &*self.server_linter.read().await else {
+            return vec![];
+        };
+
+        server_linter.workspace_files(&self.root_uri)
+    }
+
     /// Remove all diagnostics for the given URI
     pub async fn remove_diagnostics(&self, uri: &Uri) {
         let server_linter_guard = self.server_linter.read().await;
@@ -178,6 +208,79 @@ impl WorkspaceWorker {
         server_linter.run_single(uri, content, run_type).await
     }
 
+    /// Lint the files of the workspace on a blocking task, sending to `linted` after each file.
+    /// Files are linted with their `content` when they are open, or else as saved on disk.
+    pub async fn lint_workspace(
+        self: Arc<Self>,
+        files: Vec<(Uri, Option<String>)>,
+        linted: UnboundedSender<()>,
+    ) -> Vec<(Uri, Vec<DiagnosticReport>)> {
+        let lint = tokio::task::spawn_blocking(move || {
+            let Some(server_linter) = &*self.server_linter.blocking_read() else {
+                return vec![];
+            };
+            server_linter.lint_workspace(files, || {
+                // the receiver is only gone when the server shuts down
+                let _ = linted.send(());
+            })
+        });
+        lint.await.unwrap_or_else(|err| {
+            warn!("Failed to lint the workspace: {err}");
+            vec![]
+        })
+    }
+
+    /// Lint a file for a `textDocument/diagnostic` request of the client.
+    /// The file is linted like it was changed, falling back to the cached diagnostics when
+    /// the configured `run` does not lint on type, and to a full lint when nothing is cached yet.
+    /// - If the file is not lintable, [`None`] is returned
+    pub async fn pull_diagnostics(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+    ) -> Option<Vec<DiagnosticReport>> {
+        let Some(server_linter) = &*self.server_linter.read().await else {
+            return None;
+        };
+
+        if let Some(diagnostics) =
+            server_linter.run_single(uri, content.clone(), ServerLinterRun::OnType).await
+        {
+            return Some(diagnostics);
+        }
+        if let Some(diagnostics) = server_linter.get_cached_diagnostics(uri) {
+            return Some(diagnostics);
+        }
+        server_linter.run_single(uri, content, ServerLinterRun::Always).await
+    }
+
+    /// Get the cached diagnostics of all linted files in the workspace
+    pub async fn get_all_cached_diagnostics(&self) -> Vec<(Uri, Vec<DiagnosticReport>)> {
+        let Some(server_linter) = &*self.server_linter.read().await else {
+            return vec![];
+        };
+
+        server_linter
+            .get_cached_files_of_diagnostics()
+            .into_iter()
+            .filter_map(|uri| {
+                let diagnostics = server_linter.get_cached_diagnostics(&uri)?;
+                Some((uri, diagnostics))
+            })
+            .collect()
+    }
+
+    /// Get a hover with the documentation of the rules reporting diagnostics at the given position
+    /// It only uses the [`ServerLinter`] cached diagnostics, hovering will not lint the file
+    pub async fn get_hover(&self, uri: &Uri, position: Position) -> Option<Hover> {
+        let Some(server_linter) = &*self.server_linter.read().await else {
+            return None;
+        };
+        let diagnostics = server_linter.get_cached_diagnostics(uri)?;
+
+        rule_documentation_hover(diagnostics.iter(), position)
+    }
+
     /// Format a file with the current formatter
     /// - If no formatter is active, [`None`] is returned
     /// - If the formatter is active, but no changes are made, an empty vector is returned
@@ -189,6 +292,38 @@ impl WorkspaceWorker {
         server_formatter.run_single(uri, content)
     }
 
+    /// Format the statements enclosing the given range with the current formatter
+    /// - If no formatter is active, [`None`] is returned
+    /// - If the formatter is active, but no changes are made, an empty vector is returned
+    pub async fn format_range(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        range: Range,
+    ) -> Option<Vec<TextEdit>> {
+        let Some(server_formatter) = &*self.server_formatter.read().await else {
+            return None;
+        };
+
+        server_formatter.run_range(uri, content, range)
+    }
+
+    /// Format the statement ended by the character typed before the given position
+    /// - If no formatter is active, [`None`] is returned
+    /// - If the formatter is active, but no changes are made, an empty vector is returned
+    pub async fn format_on_type(
+        &self,
+        uri: &Uri,
+        content: Option<String>,
+        position: Position,
+    ) -> Option<Vec<TextEdit>> {
+        let Some(server_formatter) = &*self.server_formatter.read().await else {
+            return None;
+        };
+
+        server_formatter.run_on_type(uri, content, position)
+    }
+
     /// Revalidate diagnostics for the given URIs
     /// This will re-lint all opened files and return the new diagnostics
     async fn revalidate_diagnostics(
@@ -290,12 +425,38 @@ impl WorkspaceWorker {
     }
 
     /// Handle file changes that are watched by the client
-    /// At the moment, this only handles changes to lint configuration files
-    /// When a change is detected, the linter is refreshed and all diagnostics are revalidated
+    /// When a lint configuration file changes, the linter is refreshed and all diagnostics are revalidated.
+    /// When a lintable file changes and the whole workspace is linted, only this file is linted again.
+    /// `content` is the unsaved content of the file if it is open in the editor, which is linted instead of the file on disk.
     pub async fn did_change_watched_files(
         &self,
-        _file_event: &FileEvent,
+        file_event: &FileEvent,
+        content: Option<String>,
     ) -> Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>> {
+        if file_event
+            .uri
+            .to_file_path()
+            .is_some_and(|path| IsolatedLintHandler::should_lint_path(&path))
+        {
+            if !self.lints_workspace().await {
+                return None;
+            }
+            let server_linter_guard = self.server_linter.read().await;
+            let server_linter = server_linter_guard.as_ref()?;
+            let diagnostics = if file_event.typ == FileChangeType::DELETED && content.is_none() {
+                server_linter.remove_diagnostics(&file_event.uri);
+                vec![]
+            } else {
+                server_linter
+                    .run_single(&file_event.uri, content, ServerLinterRun::Always)
+                    .await
+                    .unwrap_or_default()
+            };
+            let map = ConcurrentHashMap::default();
+            map.pin().insert(file_event.uri.to_string(), diagnostics);
+            return Some(map);
+        }
+
         let files = {
             let server_linter_guard = self.server_linter.read().await;
             let server_linter = server_linter_guard.as_ref()?;
@@ -312,8 +473,8 @@ impl WorkspaceWorker {
     ) -> (
         // Diagnostic reports that need to be revalidated
         Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>>,
-        // File system watcher for lint config changes
-        Option<FileSystemWatcher>,
+        // File system watchers, replacing the current ones of the workspace
+        Option<Vec<FileSystemWatcher>>,
         // Is true, when the formatter was added to the workspace worker
         bool,
     ) {
@@ -341,14 +502,22 @@ impl WorkspaceWorker {
         if current_option.format.experimental != changed_options.format.experimental {
             if changed_options.format.experimental {
                 debug!("experimental formatter enabled");
-                *self.server_formatter.write().await = Some(ServerFormatter::new());
+                *self.server_formatter.write().await =
+                    Some(ServerFormatter::new(changed_options.format.clone()));
                 formatting = true;
             } else {
                 debug!("experimental formatter disabled");
                 *self.server_formatter.write().await = None;
             }
+        } else if changed_options.format.experimental
+            && current_option.format != changed_options.format
+        {
+            debug!("formatter options changed");
+            *self.server_formatter.write().await =
+                Some(ServerFormatter::new(changed_options.format.clone()));
         }
 
+        let mut diagnostics = None;
         if ServerLinter::needs_restart(&current_option.lint, &changed_options.lint) {
             let files = {
                 let server_linter_guard = self.server_linter.read().await;
@@ -360,30 +529,18 @@ impl WorkspaceWorker {
                 }
             };
             self.refresh_server_linter().await;
-
-            if current_option.lint.config_path != changed_options.lint.config_path {
-                return (
-                    Some(self.revalidate_diagnostics(files).await),
-                    Some(FileSystemWatcher {
-                        glob_pattern: GlobPattern::Relative(RelativePattern {
-                            base_uri: OneOf::Right(self.root_uri.clone()),
-                            pattern: changed_options
-                                .lint
-                                .config_path
-                                .as_ref()
-                                .unwrap_or(&"**/.oxlintrc.json".to_string())
-                                .to_owned(),
-                        }),
-                        kind: Some(WatchKind::all()), // created, deleted, changed
-                    }),
-                    formatting,
-                );
-            }
-
-            return (Some(self.revalidate_diagnostics(files).await), None, formatting);
+            diagnostics = Some(self.revalidate_diagnostics(files).await);
         }
 
-        (None, None, formatting)
+        let watchers = if current_option.lint.config_path != changed_options.lint.config_path
+            || current_option.lint.lint_workspace != changed_options.lint.lint_workspace
+        {
+            Some(self.init_watchers().await)
+        } else {
+            None
+        };
+
+        (diagnostics, watchers, formatting)
     }
 }
 
@@ -396,6 +553,7 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::tester::get_file_uri;
 
     #[test]
     fn test_get_root_uri() {
@@ -419,4 +577,30 @@ mod tests {
                 .is_responsible_for_uri(&Uri::from_str("file:///path/to/other/file.js").unwrap())
         );
     }
+
+    #[test]
+    fn test_did_change_watched_files_lints_open_content() {
+        let uri = get_file_uri("fixtures/linter/watched_files/debugger.js");
+        let changed = FileEvent { uri: uri.clone(), typ: FileChangeType::CHANGED };
+        let deleted = FileEvent { uri: uri.clone(), typ: FileChangeType::DELETED };
+        let diagnostic_count = |file_event: &FileEvent, content: Option<&str>| {
+            tokio::runtime::Runtime::new().unwrap().block_on(async {
+                let worker = WorkspaceWorker::new(get_file_uri("fixtures/linter/watched_files"));
+                let mut options = Options::default();
+                options.lint.lint_workspace = true;
+                worker.start_worker(&options).await;
+                let diagnostics = worker
+                    .did_change_watched_files(file_event, content.map(ToString::to_string))
+                    .await
+                    .unwrap();
+                diagnostics.pin().get(&uri.to_string()).unwrap().len()
+            })
+        };
+
+        assert_eq!(diagnostic_count(&changed, None), 1);
+        // an open file is linted with its unsaved content
+        assert_eq!(diagnostic_count(&changed, Some("foo();")), 0);
+        assert_eq!(diagnostic_count(&deleted, Some("debugger;")), 1);
+        assert_eq!(diagnostic_count(&deleted, None), 0);
+    }
 }
@@ -0,0 +1,482 @@
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_span::{ContentEq, GetSpan, SPAN, Span};
+
+/// Split an identifier into a metavariable name, and whether it is a `$$$NAME` list metavariable.
+///
+/// Returns `None` if `identifier` is not a metavariable.
+pub fn metavariable(identifier: &str) -> Option<(&str, bool)> {
+    let (name, is_list) = match identifier.strip_prefix("$$$") {
+        Some(name) => (name, true),
+        None => (identifier.strip_prefix('$')?, false),
+    };
+    let mut chars = name.chars();
+    let is_valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    is_valid.then_some((name, is_list))
+}
+
+/// Code captured by a metavariable.
+#[derive(Clone, Copy)]
+enum Binding<'b, 'a> {
+    /// `$NAME` in an expression position.
+    Expression(&'b Expression<'a>),
+    /// `$NAME` as a property name or assignment target, or `$$$NAME`.
+    Text(Span),
+}
+
+/// How loosely an expression binds, which decides whether it needs parentheses when it is
+/// substituted into other code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precedence {
+    /// A primary, member or call expression, which never needs parentheses.
+    Tight,
+    /// An operator expression, e.g. `a + b`, `await a` or `a => b`.
+    Operator,
+    /// A sequence expression, e.g. `a, b`.
+    Sequence,
+}
+
+impl Precedence {
+    pub fn of(expression: &Expression) -> Self {
+        match expression {
+            Expression::SequenceExpression(_) => Self::Sequence,
+            Expression::CallExpression(_) => Self::Tight,
+            expression
+                if expression.is_primary_expression() || expression.is_member_expression() =>
+            {
+                Self::Tight
+            }
+            _ => Self::Operator,
+        }
+    }
+}
+
+/// Matches a pattern against source expressions, recording what each metavariable captured.
+pub struct Matcher<'b, 'a> {
+    source_text: &'b str,
+    bindings: FxHashMap<&'a str, Binding<'b, 'a>>,
+}
+
+impl<'b, 'a> Matcher<'b, 'a> {
+    pub fn new(source_text: &'b str) -> Self {
+        Self { source_text, bindings: FxHashMap::default() }
+    }
+
+    /// Source text captured by metavariable `name`, if it was bound.
+    pub fn binding_text(&self, name: &str) -> Option<&'b str> {
+        self.bindings.get(name).map(|&binding| self.text(binding))
+    }
+
+    /// How loosely the expression captured by metavariable `name` binds.
+    ///
+    /// Returns `None` if `name` did not capture an expression.
+    pub fn binding_precedence(&self, name: &str) -> Option<Precedence> {
+        match self.bindings.get(name) {
+            Some(Binding::Expression(expression)) => Some(Precedence::of(expression)),
+            _ => None,
+        }
+    }
+
+    fn text(&self, binding: Binding<'b, 'a>) -> &'b str {
+        match binding {
+            Binding::Expression(expression) => expression.span().source_text(self.source_text),
+            Binding::Text(span) => span.source_text(self.source_text),
+        }
+    }
+
+    /// Bind metavariable `name`. If it is already bound, check that it captured the same code.
+    fn bind(&mut self, name: &'a str, binding: Binding<'b, 'a>) -> bool {
+        let Some(&existing) = self.bindings.get(name) else {
+            self.bindings.insert(name, binding);
+            return true;
+        };
+        match (existing, binding) {
+            (Binding::Expression(existing), Binding::Expression(expression)) => {
+                existing.content_eq(expression)
+            }
+            _ => self.text(existing) == self.text(binding),
+        }
+    }
+
+    pub fn match_expression(
+        &mut self,
+        pattern: &'b Expression<'a>,
+        source: &'b Expression<'a>,
+    ) -> bool {
+        if let Expression::Identifier(ident) = pattern
+            && let Some((name, _)) = metavariable(ident.name.as_str())
+        {
+            return self.bind(name, Binding::Expression(source));
+        }
+        if let (Some(pattern), Some(source)) =
+            (pattern.as_member_expression(), source.as_member_expression())
+        {
+            return self.match_member_expression(pattern, source);
+        }
+        match (pattern, source) {
+            (Expression::CallExpression(pattern), Expression::CallExpression(source)) => {
+                self.match_call_expression(pattern, source)
+            }
+            (Expression::NewExpression(pattern), Expression::NewExpression(source)) => {
+                self.match_expression(&pattern.callee, &source.callee)
+                    && pattern.type_arguments.content_eq(&source.type_arguments)
+                    && self.match_list(&pattern.arguments, &source.arguments)
+            }
+            (Expression::BinaryExpression(pattern), Expression::BinaryExpression(source)) => {
+                pattern.operator == source.operator
+                    && self.match_expression(&pattern.left, &source.left)
+                    && self.match_expression(&pattern.right, &source.right)
+            }
+            (Expression::LogicalExpression(pattern), Expression::LogicalExpression(source)) => {
+                pattern.operator == source.operator
+                    && self.match_expression(&pattern.left, &source.left)
+                    && self.match_expression(&pattern.right, &source.right)
+            }
+            (Expression::UnaryExpression(pattern), Expression::UnaryExpression(source)) => {
+                pattern.operator == source.operator
+                    && self.match_expression(&pattern.argument, &source.argument)
+            }
+            (
+                Expression::ConditionalExpression(pattern),
+                Expression::ConditionalExpression(source),
+            ) => {
+                self.match_expression(&pattern.test, &source.test)
+                    && self.match_expression(&pattern.consequent, &source.consequent)
+                    && self.match_expression(&pattern.alternate, &source.alternate)
+            }
+            (
+                Expression::AssignmentExpression(pattern),
+                Expression::AssignmentExpression(source),
+            ) => {
+                pattern.operator == source.operator
+                    && self.match_assignment_target(&pattern.left, &source.left)
+                    && self.match_expression(&pattern.right, &source.right)
+            }
+            (Expression::ArrayExpression(pattern), Expression::ArrayExpression(source)) => {
+                self.match_list(&pattern.elements, &source.elements)
+            }
+            (Expression::ObjectExpression(pattern), Expression::ObjectExpression(source)) => {
+                self.match_list(&pattern.properties, &source.properties)
+            }
+            (Expression::SequenceExpression(pattern), Expression::SequenceExpression(source)) => {
+                self.match_list(&pattern.expressions, &source.expressions)
+            }
+            (Expression::AwaitExpression(pattern), Expression::AwaitExpression(source)) => {
+                self.match_expression(&pattern.argument, &source.argument)
+            }
+            (
+                Expression::ParenthesizedExpression(pattern),
+                Expression::ParenthesizedExpression(source),
+            ) => self.match_expression(&pattern.expression, &source.expression),
+            (Expression::ChainExpression(pattern), Expression::ChainExpression(source)) => {
+                self.match_chain_element(&pattern.expression, &source.expression)
+            }
+            (Expression::TemplateLiteral(pattern), Expression::TemplateLiteral(source)) => {
+                self.match_template_literal(pattern, source)
+            }
+            (
+                Expression::TaggedTemplateExpression(pattern),
+                Expression::TaggedTemplateExpression(source),
+            ) => {
+                self.match_expression(&pattern.tag, &source.tag)
+                    && pattern.type_arguments.content_eq(&source.type_arguments)
+                    && self.match_template_literal(&pattern.quasi, &source.quasi)
+            }
+            (Expression::TSAsExpression(pattern), Expression::TSAsExpression(source)) => {
+                self.match_expression(&pattern.expression, &source.expression)
+                    && pattern.type_annotation.content_eq(&source.type_annotation)
+            }
+            (
+                Expression::TSSatisfiesExpression(pattern),
+                Expression::TSSatisfiesExpression(source),
+            ) => {
+                self.match_expression(&pattern.expression, &source.expression)
+                    && pattern.type_annotation.content_eq(&source.type_annotation)
+            }
+            (Expression::TSNonNullExpression(pattern), Expression::TSNonNullExpression(source)) => {
+                self.match_expression(&pattern.expression, &source.expression)
+            }
+            _ => pattern.content_eq(source),
+        }
+    }
+
+    fn match_member_expression(
+        &mut self,
+        pattern: &'b MemberExpression<'a>,
+        source: &'b MemberExpression<'a>,
+    ) -> bool {
+        match (pattern, source) {
+            (
+                MemberExpression::ComputedMemberExpression(pattern),
+                MemberExpression::ComputedMemberExpression(source),
+            ) => {
+                pattern.optional == source.optional
+                    && self.match_expression(&pattern.object, &source.object)
+                    && self.match_expression(&pattern.expression, &source.expression)
+            }
+            (
+                MemberExpression::StaticMemberExpression(pattern),
+                MemberExpression::StaticMemberExpression(source),
+            ) => {
+                pattern.optional == source.optional
+                    && self.match_expression(&pattern.object, &source.object)
+                    && self.match_identifier_name(&pattern.property, &source.property)
+            }
+            (
+                MemberExpression::PrivateFieldExpression(pattern),
+                MemberExpression::PrivateFieldExpression(source),
+            ) => {
+                pattern.optional == source.optional
+                    && self.match_expression(&pattern.object, &source.object)
+                    && pattern.field.content_eq(&source.field)
+            }
+            _ => false,
+        }
+    }
+
+    fn match_call_expression(
+        &mut self,
+        pattern: &'b CallExpression<'a>,
+        source: &'b CallExpression<'a>,
+    ) -> bool {
+        pattern.optional == source.optional
+            && self.match_expression(&pattern.callee, &source.callee)
+            && pattern.type_arguments.content_eq(&source.type_arguments)
+            && self.match_list(&pattern.arguments, &source.arguments)
+    }
+
+    fn match_chain_element(
+        &mut self,
+        pattern: &'b ChainElement<'a>,
+        source: &'b ChainElement<'a>,
+    ) -> bool {
+        if let (Some(pattern), Some(source)) =
+            (pattern.as_member_expression(), source.as_member_expression())
+        {
+            return self.match_member_expression(pattern, source);
+        }
+        match (pattern, source) {
+            (ChainElement::CallExpression(pattern), ChainElement::CallExpression(source)) => {
+                self.match_call_expression(pattern, source)
+            }
+            (
+                ChainElement::TSNonNullExpression(pattern),
+                ChainElement::TSNonNullExpression(source),
+            ) => self.match_expression(&pattern.expression, &source.expression),
+            _ => false,
+        }
+    }
+
+    fn match_template_literal(
+        &mut self,
+        pattern: &'b TemplateLiteral<'a>,
+        source: &'b TemplateLiteral<'a>,
+    ) -> bool {
+        pattern.quasis.content_eq(&source.quasis)
+            && pattern.expressions.len() == source.expressions.len()
+            && pattern
+                .expressions
+                .iter()
+                .zip(&source.expressions)
+                .all(|(pattern, source)| self.match_expression(pattern, source))
+    }
+
+    fn match_assignment_target(
+        &mut self,
+        pattern: &'b AssignmentTarget<'a>,
+        source: &'b AssignmentTarget<'a>,
+    ) -> bool {
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = pattern
+            && let Some((name, _)) = metavariable(ident.name.as_str())
+        {
+            return self.bind(name, Binding::Text(source.span()));
+        }
+        if let (Some(pattern), Some(source)) =
+            (pattern.as_member_expression(), source.as_member_expression())
+        {
+            return self.match_member_expression(pattern, source);
+        }
+        pattern.content_eq(source)
+    }
+
+    fn match_identifier_name(
+        &mut self,
+        pattern: &'b IdentifierName<'a>,
+        source: &'b IdentifierName<'a>,
+    ) -> bool {
+        if let Some((name, _)) = metavariable(pattern.name.as_str()) {
+            return self.bind(name, Binding::Text(source.span));
+        }
+        pattern.name == source.name
+    }
+
+    fn match_property_key(
+        &mut self,
+        pattern: &'b PropertyKey<'a>,
+        source: &'b PropertyKey<'a>,
+    ) -> bool {
+        if let PropertyKey::StaticIdentifier(pattern) = pattern
+            && let Some((name, _)) = metavariable(pattern.name.as_str())
+        {
+            return self.bind(name, Binding::Text(source.span()));
+        }
+        match (pattern.as_expression(), source.as_expression()) {
+            (Some(pattern), Some(source)) => self.match_expression(pattern, source),
+            _ => pattern.content_eq(source),
+        }
+    }
+
+    /// Match a list of elements, where each `$$$NAME` in `pattern` can match any number of elements.
+    ///
+    /// `$$$NAME` metavariables are matched lazily, backtracking when the rest of the list fails to match.
+    fn match_list<T: ListElement<'a>>(&mut self, pattern: &'b [T], source: &'b [T]) -> bool {
+        let Some((first, pattern_rest)) = pattern.split_first() else {
+            return source.is_empty();
+        };
+        if let Some(name) = first.list_metavariable() {
+            for len in 0..=source.len() {
+                let (captured, source_rest) = source.split_at(len);
+                let bindings = self.bindings.clone();
+                if self.bind(name, Binding::Text(list_span(captured)))
+                    && self.match_list(pattern_rest, source_rest)
+                {
+                    return true;
+                }
+                self.bindings = bindings;
+            }
+            return false;
+        }
+        let Some((head, source_rest)) = source.split_first() else {
+            return false;
+        };
+        T::match_element(self, first, head) && self.match_list(pattern_rest, source_rest)
+    }
+}
+
+/// Span covering all of `elements`, including the separators between them.
+fn list_span<T: GetSpan>(elements: &[T]) -> Span {
+    match (elements.first(), elements.last()) {
+        (Some(first), Some(last)) => Span::new(first.span().start, last.span().end),
+        _ => SPAN,
+    }
+}
+
+/// An element of a list which `$$$NAME` metavariables can match a run of.
+trait ListElement<'a>: GetSpan {
+    /// The name of the `$$$NAME` metavariable which this element consists of, if any.
+    fn list_metavariable(&self) -> Option<&'a str>;
+
+    fn match_element<'b>(
+        matcher: &mut Matcher<'b, 'a>,
+        pattern: &'b Self,
+        source: &'b Self,
+    ) -> bool;
+}
+
+fn list_metavariable<'a>(expression: Option<&Expression<'a>>) -> Option<&'a str> {
+    if let Some(Expression::Identifier(ident)) = expression
+        && let Some((name, true)) = metavariable(ident.name.as_str())
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+impl<'a> ListElement<'a> for Expression<'a> {
+    fn list_metavariable(&self) -> Option<&'a str> {
+        list_metavariable(Some(self))
+    }
+
+    fn match_element<'b>(
+        matcher: &mut Matcher<'b, 'a>,
+        pattern: &'b Self,
+        source: &'b Self,
+    ) -> bool {
+        matcher.match_expression(pattern, source)
+    }
+}
+
+impl<'a> ListElement<'a> for Argument<'a> {
+    fn list_metavariable(&self) -> Option<&'a str> {
+        list_metavariable(self.as_expression())
+    }
+
+    fn match_element<'b>(
+        matcher: &mut Matcher<'b, 'a>,
+        pattern: &'b Self,
+        source: &'b Self,
+    ) -> bool {
+        match (pattern, source) {
+            (Argument::SpreadElement(pattern), Argument::SpreadElement(source)) => {
+                matcher.match_expression(&pattern.argument, &source.argument)
+            }
+            _ => match (pattern.as_expression(), source.as_expression()) {
+                (Some(pattern), Some(source)) => matcher.match_expression(pattern, source),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl<'a> ListElement<'a> for ArrayExpressionElement<'a> {
+    fn list_metavariable(&self) -> Option<&'a str> {
+        list_metavariable(self.as_expression())
+    }
+
+    fn match_element<'b>(
+        matcher: &mut Matcher<'b, 'a>,
+        pattern: &'b Self,
+        source: &'b Self,
+    ) -> bool {
+        match (pattern, source) {
+            (
+                ArrayExpressionElement::SpreadElement(pattern),
+                ArrayExpressionElement::SpreadElement(source),
+            ) => matcher.match_expression(&pattern.argument, &source.argument),
+            (ArrayExpressionElement::Elision(_), ArrayExpressionElement::Elision(_)) => true,
+            _ => match (pattern.as_expression(), source.as_expression()) {
+                (Some(pattern), Some(source)) => matcher.match_expression(pattern, source),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl<'a> ListElement<'a> for ObjectPropertyKind<'a> {
+    fn list_metavariable(&self) -> Option<&'a str> {
+        match self {
+            // `{ $$$props }` is a shorthand property
+            ObjectPropertyKind::ObjectProperty(property) if property.shorthand => {
+                list_metavariable(Some(&property.value))
+            }
+            _ => None,
+        }
+    }
+
+    fn match_element<'b>(
+        matcher: &mut Matcher<'b, 'a>,
+        pattern: &'b Self,
+        source: &'b Self,
+    ) -> bool {
+        match (pattern, source) {
+            (
+                ObjectPropertyKind::ObjectProperty(pattern),
+                ObjectPropertyKind::ObjectProperty(source),
+            ) => {
+                pattern.kind == source.kind
+                    && pattern.method == source.method
+                    && pattern.computed == source.computed
+                    && matcher.match_property_key(&pattern.key, &source.key)
+                    && matcher.match_expression(&pattern.value, &source.value)
+            }
+            (
+                ObjectPropertyKind::SpreadProperty(pattern),
+                ObjectPropertyKind::SpreadProperty(source),
+            ) => matcher.match_expression(&pattern.argument, &source.argument),
+            _ => false,
+        }
+    }
+}
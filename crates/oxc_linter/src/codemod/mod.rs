@@ -0,0 +1,446 @@
+//! Structural search and replace.
+//!
+//! A [`Codemod`] rewrites every expression which matches a pattern. Patterns and rewrites are
+//! JavaScript expressions, which may contain metavariables:
+//! * `$NAME` matches any expression, property name (`$obj.$prop`) or assignment target
+//! * `$$$NAME` matches any number of arguments, array elements, object properties or sequence
+//!   expressions, e.g. `foo($first, $$$rest)`
+//!
+//! A metavariable which occurs more than once in a pattern must match the same code each time.
+//! Other nodes are compared with [`ContentEq`](oxc_span::ContentEq), so formatting and comments
+//! are ignored. Metavariables are only recognised within expressions, so e.g. `$x` in a pattern's
+//! function body only matches `$x`.
+//!
+//! Each match is replaced with the rewrite, with metavariables substituted by the source text which
+//! they matched. Captured expressions are wrapped in parentheses where they could bind less tightly
+//! than the surrounding rewrite, e.g. `a + b` substituted into `$x * 2` becomes `(a + b) * 2`, and
+//! so is the rewrite where it could bind less tightly than the code around the match.
+//! Only the outermost of nested matches is rewritten.
+
+mod matcher;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IdentifierName, IdentifierReference},
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use rustc_hash::FxHashSet;
+
+use crate::{Fix, Fixer, Message, PossibleFixes};
+
+use matcher::{Matcher, Precedence, metavariable};
+
+/// A structural search-and-replace, e.g. `$obj.foo($$$args)` to `$obj.bar($$$args)`.
+#[derive(Debug, Clone)]
+pub struct Codemod {
+    pattern: String,
+    rewrite: Vec<Segment>,
+    /// How loosely the rewrite binds, before metavariables are substituted.
+    precedence: Precedence,
+}
+
+/// Where code is substituted, which decides whether it needs parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    /// A whole expression on its own, e.g. an argument, an array element or a statement.
+    Element,
+    /// An operand of an operator, the object of a member expression or a callee.
+    Operand,
+}
+
+impl Slot {
+    fn needs_parentheses(self, precedence: Precedence) -> bool {
+        match precedence {
+            Precedence::Tight => false,
+            Precedence::Operator => self == Self::Operand,
+            Precedence::Sequence => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Text(String),
+    /// A metavariable, including its `$` or `$$$` prefix.
+    Metavariable(String),
+}
+
+/// The result of applying a [`Codemod`] to a file.
+#[derive(Debug)]
+pub struct CodemodResult {
+    /// The rewritten source text.
+    pub code: String,
+    /// The number of expressions which were rewritten.
+    pub matches: usize,
+}
+
+impl Codemod {
+    /// Create a codemod which replaces expressions matching `pattern` with `rewrite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` or `rewrite` is not a valid expression, or if `rewrite` uses a
+    /// metavariable which does not occur in `pattern`.
+    pub fn new(pattern: &str, rewrite: &str) -> Result<Self, OxcDiagnostic> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let pattern_expression = parse_template(&allocator, pattern, source_type, "pattern")?;
+        let rewrite_expression = parse_template(&allocator, rewrite, source_type, "rewrite")?;
+        let precedence = Precedence::of(&rewrite_expression);
+
+        let mut collector = MetavariableCollector::default();
+        collector.visit_expression(&pattern_expression);
+
+        let rewrite = split_rewrite(rewrite);
+        for segment in &rewrite {
+            if let Segment::Metavariable(token) = segment
+                && let Some((name, _)) = metavariable(token)
+                && !collector.names.contains(name)
+            {
+                return Err(OxcDiagnostic::error(format!(
+                    "Metavariable `{token}` is used in the rewrite but does not occur in the pattern"
+                )));
+            }
+        }
+        Ok(Self { pattern: pattern.to_string(), rewrite, precedence })
+    }
+
+    /// Rewrite all matches in `source_text`.
+    ///
+    /// Returns `None` if there are no matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns the syntax errors in `source_text` if it cannot be parsed, or an error if the
+    /// rewritten code is not valid.
+    pub fn apply(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Result<Option<CodemodResult>, Vec<OxcDiagnostic>> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        if ret.panicked || !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        // The pattern is parsed with the file's source type, so that it can use TypeScript syntax.
+        // A JSX pattern cannot match a `.ts` file, and vice versa.
+        let Ok(pattern) = Parser::new(&allocator, &self.pattern, source_type).parse_expression()
+        else {
+            return Ok(None);
+        };
+
+        let mut finder = MatchFinder {
+            codemod: self,
+            pattern: &pattern,
+            source_text,
+            parents: vec![],
+            fixes: vec![],
+        };
+        finder.visit_program(&ret.program);
+        if finder.fixes.is_empty() {
+            return Ok(None);
+        }
+
+        let matches = finder.fixes.len();
+        let messages = finder
+            .fixes
+            .into_iter()
+            .map(|fix| {
+                let diagnostic = OxcDiagnostic::warn("Codemod match").with_label(fix.span);
+                Message::new(diagnostic, PossibleFixes::Single(fix))
+            })
+            .collect();
+        let code = Fixer::new(source_text, messages).fix().fixed_code.into_owned();
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &code, source_type).parse();
+        if let Some(error) = ret.errors.into_iter().next() {
+            return Err(vec![
+                OxcDiagnostic::error("Applying the codemod produced invalid code")
+                    .with_help(error.message.clone()),
+            ]);
+        }
+        Ok(Some(CodemodResult { code, matches }))
+    }
+
+    /// The code which replaces a match in `slot`.
+    fn rewrite(&self, matcher: &Matcher, slot: Slot) -> String {
+        // A rewrite which is just a metavariable takes the place of the match
+        if let [Segment::Metavariable(token)] = self.rewrite.as_slice() {
+            return Self::substitute(matcher, token, slot);
+        }
+        let mut code = String::new();
+        for (index, segment) in self.rewrite.iter().enumerate() {
+            match segment {
+                Segment::Text(text) => code.push_str(text),
+                Segment::Metavariable(token) => {
+                    let slot =
+                        if self.is_standalone(index) { Slot::Element } else { Slot::Operand };
+                    code.push_str(&Self::substitute(matcher, token, slot));
+                }
+            }
+        }
+        if slot.needs_parentheses(self.precedence) { format!("({code})") } else { code }
+    }
+
+    /// The code captured by metavariable `token`, to be placed in `slot`.
+    fn substitute(matcher: &Matcher, token: &str, slot: Slot) -> String {
+        let Some((name, _)) = metavariable(token) else {
+            return token.to_string();
+        };
+        // A metavariable in the pattern which was never compared stays as it is
+        let Some(binding) = matcher.binding_text(name) else {
+            return token.to_string();
+        };
+        match matcher.binding_precedence(name) {
+            Some(precedence) if slot.needs_parentheses(precedence) => format!("({binding})"),
+            _ => binding.to_string(),
+        }
+    }
+
+    /// Whether the metavariable at `index` in the rewrite is a whole expression on its own, e.g. an
+    /// argument or an array element.
+    fn is_standalone(&self, index: usize) -> bool {
+        let before = match index.checked_sub(1).map(|index| &self.rewrite[index]) {
+            None => None,
+            Some(Segment::Text(text)) => text.trim_end().chars().next_back(),
+            Some(Segment::Metavariable(_)) => return false,
+        };
+        let after = match self.rewrite.get(index + 1) {
+            None => None,
+            Some(Segment::Text(text)) => text.trim_start().chars().next(),
+            Some(Segment::Metavariable(_)) => return false,
+        };
+        matches!(before, None | Some('(' | '[' | ','))
+            && matches!(after, None | Some(')' | ']' | ','))
+    }
+}
+
+fn parse_template<'a>(
+    allocator: &'a Allocator,
+    template: &'a str,
+    source_type: SourceType,
+    kind: &str,
+) -> Result<Expression<'a>, OxcDiagnostic> {
+    Parser::new(allocator, template, source_type).parse_expression().map_err(|errors| {
+        let diagnostic = OxcDiagnostic::error(format!("Invalid codemod {kind} `{template}`"));
+        match errors.into_iter().next() {
+            Some(error) => diagnostic.with_help(error.message.clone()),
+            None => diagnostic,
+        }
+    })
+}
+
+/// Split `rewrite` into text and the metavariables to substitute.
+fn split_rewrite(rewrite: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut rest = rewrite;
+    while let Some(index) = rest.find('$') {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let dollars = rest.len() - rest.trim_start_matches('$').len();
+        let name_len = rest[dollars..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len() - dollars);
+        let (token, after) = rest.split_at(dollars + name_len);
+        if metavariable(token).is_some() {
+            if !text.is_empty() {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(Segment::Metavariable(token.to_string()));
+        } else {
+            text.push_str(token);
+        }
+        rest = after;
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    segments
+}
+
+/// Collects the names of the metavariables in a pattern.
+#[derive(Default)]
+struct MetavariableCollector<'a> {
+    names: FxHashSet<&'a str>,
+}
+
+impl<'a> Visit<'a> for MetavariableCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if let Some((name, _)) = metavariable(it.name.as_str()) {
+            self.names.insert(name);
+        }
+    }
+
+    fn visit_identifier_name(&mut self, it: &IdentifierName<'a>) {
+        if let Some((name, _)) = metavariable(it.name.as_str()) {
+            self.names.insert(name);
+        }
+    }
+}
+
+/// Finds the outermost expressions which match the pattern, and creates a fix to rewrite each one.
+struct MatchFinder<'c, 'a> {
+    codemod: &'c Codemod,
+    pattern: &'c Expression<'a>,
+    source_text: &'c str,
+    /// The nodes enclosing the current expression, innermost last.
+    parents: Vec<AstKind<'a>>,
+    fixes: Vec<Fix<'a>>,
+}
+
+impl<'a> MatchFinder<'_, 'a> {
+    /// Where `expression` is placed in its parent.
+    fn slot(&self, expression: &Expression<'a>) -> Slot {
+        let is_operand = match self.parents.last() {
+            Some(AstKind::ComputedMemberExpression(member)) => {
+                member.object.span() == expression.span()
+            }
+            Some(AstKind::ConditionalExpression(conditional)) => {
+                conditional.test.span() == expression.span()
+            }
+            // Arguments have their own `AstKind`, so an expression directly inside a call is its
+            // callee
+            Some(
+                AstKind::StaticMemberExpression(_)
+                | AstKind::PrivateFieldExpression(_)
+                | AstKind::CallExpression(_)
+                | AstKind::NewExpression(_)
+                | AstKind::TaggedTemplateExpression(_)
+                | AstKind::UnaryExpression(_)
+                | AstKind::AwaitExpression(_)
+                | AstKind::BinaryExpression(_)
+                | AstKind::LogicalExpression(_)
+                | AstKind::PrivateInExpression(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSSatisfiesExpression(_)
+                | AstKind::TSTypeAssertion(_)
+                | AstKind::TSNonNullExpression(_)
+                | AstKind::TSInstantiationExpression(_),
+            ) => true,
+            _ => false,
+        };
+        if is_operand { Slot::Operand } else { Slot::Element }
+    }
+}
+
+impl<'a> Visit<'a> for MatchFinder<'_, 'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        self.parents.push(kind);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.parents.pop();
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        let mut matcher = Matcher::new(self.source_text);
+        if matcher.match_expression(self.pattern, it) {
+            let code = self.codemod.rewrite(&matcher, self.slot(it));
+            self.fixes.push(Fix::new(code, it.span()));
+            return;
+        }
+        walk::walk_expression(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::Codemod;
+
+    #[track_caller]
+    fn rewrite(pattern: &str, rewrite: &str, source_text: &str) -> Option<String> {
+        let codemod = Codemod::new(pattern, rewrite).unwrap();
+        codemod.apply(source_text, SourceType::mjs()).unwrap().map(|result| result.code)
+    }
+
+    #[test]
+    fn rewrites_matches() {
+        let pass = [
+            (
+                "$obj.foo($$$args)",
+                "$obj.bar($$$args)",
+                "a.b.foo(1, 2); foo(3); x.foo();",
+                "a.b.bar(1, 2); foo(3); x.bar();",
+            ),
+            ("foo($first, $$$rest)", "bar($$$rest, $first)", "foo(1, 2, 3)", "bar(2, 3, 1)"),
+            ("foo($$$args, last)", "foo(last, $$$args)", "foo(a, b, last)", "foo(last, a, b)"),
+            ("$a === $a", "Number.isNaN($a)", "x === x; x === y;", "Number.isNaN(x); x === y;"),
+            ("$obj.$prop", "$obj[\"$prop\"]", "if (a.b) {}", "if (a[\"b\"]) {}"),
+            ("$x = $x + 1", "$x++", "a.b = a.b + 1; c = d + 1;", "a.b++; c = d + 1;"),
+            ("[$$$items]", "[...new Set([$$$items])]", "f([1, 2])", "f([...new Set([1, 2])])"),
+            ("{ a: $a, $$$rest }", "$a", "f({ a: 1, b, ...c })", "f(1)"),
+            ("`x${$a}`", "'x' + $a", "f(`x${y}`)", "f('x' + y)"),
+            ("$a?.b()", "$a?.c()", "x?.b(); x.b();", "x?.c(); x.b();"),
+            // Captured expressions are parenthesized where precedence matters
+            ("double($x)", "$x * 2", "double(a + b)", "(a + b) * 2"),
+            ("double($x)", "$x * 2", "double(a.b)", "a.b * 2"),
+            ("double($x)", "twice($x)", "double(a + b)", "twice(a + b)"),
+            ("double($x)", "twice($x)", "double((a, b))", "twice((a, b))"),
+            ("id($x)", "$x", "id(a + b) * 2", "(a + b) * 2"),
+            ("id($x)", "$x", "f(id(a + b))", "f(a + b)"),
+            // The rewrite is parenthesized where it is an operand, a member object or a callee
+            ("$a.add($b)", "$a + $b", "x.add(y) * 2", "(x + y) * 2"),
+            ("$a.add($b)", "$a + $b", "x.add(y).z", "(x + y).z"),
+            ("make($a)", "$a || noop", "make(f)()", "(f || noop)()"),
+            ("$a.add($b)", "$a + $b", "f(x.add(y), z)", "f(x + y, z)"),
+            // Only the outermost match is rewritten
+            ("foo($a)", "bar($a)", "foo(foo(1))", "bar(foo(1))"),
+            // Comments and formatting are ignored
+            ("foo(1, 2)", "bar()", "foo(1, /* two */\n 2)", "bar()"),
+        ];
+        for (pattern, rewrite_template, source_text, expected) in pass {
+            assert_eq!(
+                rewrite(pattern, rewrite_template, source_text).as_deref(),
+                Some(expected),
+                "pattern: {pattern}"
+            );
+        }
+
+        let no_match = [
+            ("$a === $a", "x === y"),
+            ("foo($a)", "foo(1, 2)"),
+            ("foo($$$args, last)", "foo(a, b)"),
+            ("a.b", "a[b]"),
+            ("$a?.b()", "x.b()"),
+        ];
+        for (pattern, source_text) in no_match {
+            assert_eq!(rewrite(pattern, "x", source_text), None, "pattern: {pattern}");
+        }
+    }
+
+    #[test]
+    fn typescript() {
+        let codemod = Codemod::new("$a as any", "$a as unknown").unwrap();
+        let result = codemod.apply("let x = (y as any).z;", SourceType::ts()).unwrap().unwrap();
+        assert_eq!(result.code, "let x = (y as unknown).z;");
+        assert_eq!(result.matches, 1);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Codemod::new("foo(", "bar").is_err());
+        assert!(Codemod::new("foo", "bar(").is_err());
+        let err = Codemod::new("foo($a)", "bar($b)").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Metavariable `$b` is used in the rewrite but does not occur in the pattern"
+        );
+
+        let codemod = Codemod::new("foo($a)", "bar($a)").unwrap();
+        assert!(codemod.apply("foo(", SourceType::mjs()).is_err());
+
+        // `b + 1 = a` is not a valid assignment
+        let codemod = Codemod::new("$x = $y", "$y = $x").unwrap();
+        assert!(codemod.apply("a = b + 1", SourceType::mjs()).is_err());
+    }
+}
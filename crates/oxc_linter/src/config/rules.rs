@@ -40,6 +40,11 @@ impl OxlintRules {
     pub fn is_empty(&self) -> bool {
         self.rules.is_empty()
     }
+
+    /// Returns an iterator over the configured rules.
+    pub fn iter(&self) -> impl Iterator<Item = &ESLintRule> {
+        self.rules.iter()
+    }
 }
 
 /// A fully qualified rule name.
@@ -305,6 +310,13 @@ impl ESLintRule {
             Cow::Owned(format!("{}/{}", self.plugin_name, self.rule_name))
         }
     }
+
+    /// Returns `true` if the rule is implemented by oxlint, rather than by a JS plugin or not at all.
+    pub fn is_builtin(&self) -> bool {
+        let (rule_name, plugin_name) =
+            transform_rule_and_plugin_name(&self.rule_name, &self.plugin_name);
+        RULES.iter().any(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+    }
 }
 
 #[cfg(test)]
@@ -456,4 +468,19 @@ mod test {
             assert_eq!(severity, &AllowWarnDeny::Deny, "{config:?}");
         }
     }
+
+    #[test]
+    fn test_is_builtin() {
+        let rules = OxlintRules::deserialize(&json!({
+            "no-console": "error",
+            "@typescript-eslint/no-unused-vars": "error",
+            "vitest/no-focused-tests": "error",
+            "react-hooks/rules-of-hooks": "error",
+            "not-a-rule": "error",
+            "foo/bar": "error",
+        }))
+        .unwrap();
+        let builtin = rules.iter().map(super::ESLintRule::is_builtin).collect::<Vec<_>>();
+        assert_eq!(builtin, [true, true, true, true, false, false]);
+    }
 }
@@ -1,6 +1,6 @@
 use std::{
     borrow::Cow,
-    cell::{Cell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     path::Path,
     rc::Rc,
     sync::Arc,
@@ -14,6 +14,7 @@ use crate::{
     AllowWarnDeny, FrameworkFlags,
     config::{LintConfig, LintPlugins},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
+    esquery::{EstreeProgram, EstreeTree},
     fixer::{Fix, FixKind, Message, PossibleFixes},
     frameworks::{self, FrameworkOptions},
     module_record::ModuleRecord,
@@ -37,6 +38,8 @@ pub struct ContextSubHost<'a> {
     pub(super) framework_options: FrameworkOptions,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// ESTree serialization of the program, built on first use by selector based rules.
+    pub(super) estree: OnceCell<EstreeProgram>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -77,6 +80,7 @@ impl<'a> ContextSubHost<'a> {
             source_text_offset,
             disable_directives,
             framework_options: frameworks_options,
+            estree: OnceCell::new(),
         }
     }
 
@@ -96,6 +100,13 @@ impl<'a> ContextSubHost<'a> {
     pub fn disable_directives(&self) -> &DisableDirectives {
         &self.disable_directives
     }
+
+    /// The ESTree serialization of the program, built on first use.
+    pub(crate) fn estree(&self) -> &EstreeTree<'_> {
+        self.estree
+            .get_or_init(|| EstreeProgram::from_program(self.semantic.nodes().program()))
+            .tree()
+    }
 }
 
 /// Stores shared information about a file being linted.
@@ -195,6 +206,12 @@ impl<'a> ContextHost<'a> {
         &self.current_sub_host().semantic
     }
 
+    /// ESTree serialization of the current script block, shared between rules.
+    #[inline]
+    pub(crate) fn estree(&self) -> &EstreeTree<'_> {
+        self.current_sub_host().estree()
+    }
+
     /// Mutable reference to the [`Semantic`] analysis of current script block.
     #[inline]
     pub fn semantic_mut(&mut self) -> &mut Semantic<'a> {
@@ -16,6 +16,7 @@ use crate::{
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
     config::GlobalValue,
     disable_directives::DisableDirectives,
+    esquery::EstreeTree,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
     frameworks::FrameworkOptions,
 };
@@ -115,6 +116,15 @@ impl<'a> LintContext<'a> {
         self.parent.module_record()
     }
 
+    /// Get the ESTree serialization of the program, for matching [esquery](crate::esquery)
+    /// selectors.
+    ///
+    /// It is built the first time a rule asks for it, and shared by all rules linting the file.
+    #[inline]
+    pub(crate) fn estree(&self) -> &EstreeTree<'_> {
+        self.parent.estree()
+    }
+
     /// Get the control flow graph for the current program.
     #[inline]
     pub fn cfg(&self) -> &ControlFlowGraph {
@@ -484,7 +494,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
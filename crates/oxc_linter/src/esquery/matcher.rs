@@ -0,0 +1,424 @@
+use std::borrow::Cow;
+
+use serde_json::{Map, Value};
+
+use oxc_span::{CompactStr, Span};
+
+use super::parser::{
+    AttributeOperator, AttributeValue, Combinator, ComplexSelector, CompoundSelector, NodeClass,
+    Selector, SimpleSelector, number_to_js_string,
+};
+
+/// An ESTree AST, flattened for matching selectors against it.
+///
+/// Nodes are stored in depth-first pre-order, so the descendants of a node
+/// are the nodes directly following it up to [`EstreeNode::subtree_end`].
+pub struct EstreeTree<'v> {
+    nodes: Vec<EstreeNode<'v>>,
+    /// Node indices of every array of nodes in the AST, e.g. `BlockStatement.body`.
+    lists: Vec<Vec<usize>>,
+}
+
+struct EstreeNode<'v> {
+    value: &'v Map<String, Value>,
+    node_type: &'v str,
+    parent: Option<usize>,
+    /// The list this node is part of, and its position in it.
+    list: Option<(usize, usize)>,
+    /// Index after the last descendant of this node.
+    subtree_end: usize,
+}
+
+/// A node in an [`EstreeTree`] which matched a selector.
+pub struct EstreeMatch<'t, 'v> {
+    tree: &'t EstreeTree<'v>,
+    node: usize,
+}
+
+impl<'v> EstreeMatch<'_, 'v> {
+    /// Span of the node, from its ESTree `start` and `end` fields.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn span(&self) -> Span {
+        let offset =
+            |key| self.get(key).and_then(|value| value.as_u64()).unwrap_or_default() as u32;
+        Span::new(offset("start"), offset("end"))
+    }
+
+    /// Value of a field of the node, e.g. `callee.name`.
+    pub fn get(&self, path: &str) -> Option<Cow<'v, Value>> {
+        let path = path.split('.').map(CompactStr::from).collect::<Vec<_>>();
+        get_path(self.tree.nodes[self.node].value, &path)
+    }
+}
+
+impl<'v> EstreeTree<'v> {
+    /// Build a tree from an ESTree `Program` deserialized from JSON.
+    pub fn new(program: &'v Value) -> Self {
+        let mut tree = Self { nodes: vec![], lists: vec![] };
+        if let Value::Object(object) = program {
+            tree.add_node(object, None, None);
+        }
+        tree
+    }
+
+    fn add_node(
+        &mut self,
+        value: &'v Map<String, Value>,
+        parent: Option<usize>,
+        list: Option<(usize, usize)>,
+    ) {
+        let Some(node_type) = value.get("type").and_then(Value::as_str) else {
+            return;
+        };
+        let index = self.nodes.len();
+        self.nodes.push(EstreeNode { value, node_type, parent, list, subtree_end: 0 });
+
+        for (key, child) in value {
+            match child {
+                Value::Object(object) if key != "type" => self.add_node(object, Some(index), None),
+                Value::Array(array) => {
+                    let list_id = self.lists.len();
+                    self.lists.push(vec![]);
+                    for element in array {
+                        if let Value::Object(object) = element
+                            && object.get("type").is_some_and(Value::is_string)
+                        {
+                            let position = self.lists[list_id].len();
+                            self.lists[list_id].push(self.nodes.len());
+                            self.add_node(object, Some(index), Some((list_id, position)));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.nodes[index].subtree_end = self.nodes.len();
+    }
+
+    /// All nodes matching `selector`, in depth-first pre-order.
+    pub fn matches<'t>(&'t self, selector: &Selector) -> Vec<EstreeMatch<'t, 'v>> {
+        (0..self.nodes.len())
+            .filter(|&node| self.matches_selector(selector, node))
+            .map(|node| EstreeMatch { tree: self, node })
+            .collect()
+    }
+
+    fn matches_selector(&self, selector: &Selector, node: usize) -> bool {
+        selector.0.iter().any(|complex| self.matches_complex(complex, node, None))
+    }
+
+    fn matches_complex(
+        &self,
+        complex: &ComplexSelector,
+        node: usize,
+        scope: Option<usize>,
+    ) -> bool {
+        self.matches_from(complex, complex.compounds.len() - 1, node, scope)
+    }
+
+    /// Whether `complex.compounds[..=index]` matches with `compounds[index]` matching `node`.
+    fn matches_from(
+        &self,
+        complex: &ComplexSelector,
+        index: usize,
+        node: usize,
+        scope: Option<usize>,
+    ) -> bool {
+        if !self.matches_compound(&complex.compounds[index], node) {
+            return false;
+        }
+        if index == 0 {
+            return match (scope, complex.leading) {
+                (Some(scope), Some(combinator)) => self.is_related(scope, node, combinator),
+                _ => true,
+            };
+        }
+        let previous = index - 1;
+        match complex.combinators[previous] {
+            Combinator::Child => self.nodes[node]
+                .parent
+                .is_some_and(|parent| self.matches_from(complex, previous, parent, scope)),
+            Combinator::Descendant => self
+                .ancestors(node)
+                .any(|ancestor| self.matches_from(complex, previous, ancestor, scope)),
+            Combinator::Adjacent => self
+                .previous_siblings(node)
+                .next()
+                .is_some_and(|sibling| self.matches_from(complex, previous, sibling, scope)),
+            Combinator::Sibling => self
+                .previous_siblings(node)
+                .any(|sibling| self.matches_from(complex, previous, sibling, scope)),
+        }
+    }
+
+    /// Whether `node` is related to `scope` through `combinator`, i.e. `scope <combinator> node`.
+    fn is_related(&self, scope: usize, node: usize, combinator: Combinator) -> bool {
+        match combinator {
+            Combinator::Descendant => scope < node && node < self.nodes[scope].subtree_end,
+            Combinator::Child => self.nodes[node].parent == Some(scope),
+            Combinator::Adjacent => self.previous_siblings(node).next() == Some(scope),
+            Combinator::Sibling => self.previous_siblings(node).any(|sibling| sibling == scope),
+        }
+    }
+
+    fn ancestors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.nodes[node].parent, |&node| self.nodes[node].parent)
+    }
+
+    /// Siblings before `node` in the same list, closest first.
+    fn previous_siblings(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let siblings = match self.nodes[node].list {
+            Some((list, position)) => &self.lists[list][..position],
+            None => &[],
+        };
+        siblings.iter().rev().copied()
+    }
+
+    fn matches_compound(&self, compound: &CompoundSelector, node: usize) -> bool {
+        compound.0.iter().all(|simple| self.matches_simple(simple, node))
+    }
+
+    fn matches_simple(&self, simple: &SimpleSelector, node: usize) -> bool {
+        let entry = &self.nodes[node];
+        match simple {
+            SimpleSelector::Wildcard => true,
+            SimpleSelector::Type(name) => entry.node_type.eq_ignore_ascii_case(name),
+            SimpleSelector::Attribute { path, test } => {
+                let value = get_path(entry.value, path);
+                match test {
+                    None => value.is_some_and(|value| !value.is_null()),
+                    Some((operator, expected)) => {
+                        matches_attribute(value.as_deref(), *operator, expected)
+                    }
+                }
+            }
+            SimpleSelector::Field(path) => self.matches_field(path, node),
+            SimpleSelector::Not(selectors) => {
+                !selectors.iter().any(|complex| self.matches_complex(complex, node, None))
+            }
+            SimpleSelector::Matches(selectors) => {
+                selectors.iter().any(|complex| self.matches_complex(complex, node, None))
+            }
+            SimpleSelector::Has(selectors) => (node + 1..entry.subtree_end).any(|descendant| {
+                selectors
+                    .iter()
+                    .any(|complex| self.matches_complex(complex, descendant, Some(node)))
+            }),
+            SimpleSelector::NthChild(n) => {
+                entry.list.is_some_and(|(_, position)| position + 1 == *n)
+            }
+            SimpleSelector::NthLastChild(n) => {
+                entry.list.is_some_and(|(list, position)| self.lists[list].len() - position == *n)
+            }
+            SimpleSelector::Class(class) => self.matches_class(*class, node),
+        }
+    }
+
+    /// `.a.b` matches a node found at path `a.b` from its ancestor two levels up.
+    fn matches_field(&self, path: &[CompactStr], node: usize) -> bool {
+        let Some(ancestor) = self.ancestors(node).nth(path.len() - 1) else {
+            return false;
+        };
+        let target = self.nodes[node].value;
+        let mut current: Vec<&Value> = vec![];
+        if let Some(value) = self.nodes[ancestor].value.get(path[0].as_str()) {
+            current.push(value);
+        }
+        for key in &path[1..] {
+            current = current
+                .into_iter()
+                .flat_map(|value| match value {
+                    Value::Array(array) => array.iter().collect::<Vec<_>>(),
+                    value => vec![value],
+                })
+                .filter_map(|value| value.get(key.as_str()))
+                .collect();
+        }
+        current.into_iter().any(|value| match value {
+            Value::Object(object) => std::ptr::eq(object, target),
+            Value::Array(array) => array.iter().any(
+                |value| matches!(value, Value::Object(object) if std::ptr::eq(object, target)),
+            ),
+            _ => false,
+        })
+    }
+
+    fn matches_class(&self, class: NodeClass, node: usize) -> bool {
+        let node_type = self.nodes[node].node_type;
+        let is_declaration = node_type.ends_with("Declaration");
+        let is_expression = node_type.ends_with("Expression")
+            || node_type.ends_with("Literal")
+            || node_type == "MetaProperty"
+            || (node_type == "Identifier"
+                && self.nodes[node]
+                    .parent
+                    .is_none_or(|parent| self.nodes[parent].node_type != "MetaProperty"));
+        match class {
+            NodeClass::Statement => node_type.ends_with("Statement") || is_declaration,
+            NodeClass::Declaration => is_declaration,
+            NodeClass::Pattern => node_type.ends_with("Pattern") || is_expression,
+            NodeClass::Expression => is_expression,
+            NodeClass::Function => matches!(
+                node_type,
+                "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+            ),
+        }
+    }
+}
+
+/// Value at `path` in `object`. Array elements are accessed by index, and arrays have a `length`.
+fn get_path<'v>(object: &'v Map<String, Value>, path: &[CompactStr]) -> Option<Cow<'v, Value>> {
+    let mut value = object.get(path[0].as_str())?;
+    for key in &path[1..] {
+        value = match value {
+            Value::Object(object) => object.get(key.as_str())?,
+            Value::Array(array) if key == "length" => {
+                return Some(Cow::Owned(Value::from(array.len())));
+            }
+            Value::Array(array) => array.get(key.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(Cow::Borrowed(value))
+}
+
+fn matches_attribute(
+    value: Option<&Value>,
+    operator: AttributeOperator,
+    expected: &AttributeValue,
+) -> bool {
+    let is_equal = || match expected {
+        AttributeValue::Literal(literal) => {
+            value.is_some_and(|value| js_to_string(value) == literal.as_str())
+        }
+        AttributeValue::Regex(regex) => {
+            value.and_then(Value::as_str).is_some_and(|value| regex.is_match(value))
+        }
+        AttributeValue::Type(type_name) => js_type_of(value) == type_name.as_str(),
+    };
+    let compare = |predicate: fn(f64, f64) -> bool| {
+        let (Some(value), AttributeValue::Literal(literal)) = (value, expected) else {
+            return false;
+        };
+        let value = match value {
+            Value::Number(number) => number.as_f64(),
+            Value::String(string) => string.parse::<f64>().ok(),
+            _ => None,
+        };
+        value.zip(literal.parse::<f64>().ok()).is_some_and(|(a, b)| predicate(a, b))
+    };
+    match operator {
+        AttributeOperator::Equal => is_equal(),
+        AttributeOperator::NotEqual => !is_equal(),
+        AttributeOperator::Less => compare(|a, b| a < b),
+        AttributeOperator::LessEqual => compare(|a, b| a <= b),
+        AttributeOperator::Greater => compare(|a, b| a > b),
+        AttributeOperator::GreaterEqual => compare(|a, b| a >= b),
+    }
+}
+
+/// `String(value)` in JavaScript.
+fn js_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(boolean) => boolean.to_string(),
+        Value::Number(number) => number.as_f64().map_or_else(String::new, number_to_js_string),
+        Value::String(string) => string.clone(),
+        Value::Array(_) => String::new(),
+        Value::Object(_) => "[object Object]".to_string(),
+    }
+}
+
+/// `typeof value` in JavaScript.
+fn js_type_of(value: Option<&Value>) -> &'static str {
+    match value {
+        None => "undefined",
+        Some(Value::Null | Value::Array(_) | Value::Object(_)) => "object",
+        Some(Value::Bool(_)) => "boolean",
+        Some(Value::Number(_)) => "number",
+        Some(Value::String(_)) => "string",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::*;
+
+    fn query(source_text: &str, selector: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::tsx()).parse();
+        let json: Value = serde_json::from_str(&ret.program.to_estree_ts_json(false)).unwrap();
+        let tree = EstreeTree::new(&json);
+        let selector = Selector::parse(selector).unwrap();
+        tree.matches(&selector)
+            .into_iter()
+            .map(|node| node.span().source_text(source_text).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn types_and_attributes() {
+        let source = "foo(1); bar('x'); foo.bar(2.5, true);";
+        assert_eq!(query(source, "CallExpression[callee.name='foo']"), ["foo(1)"]);
+        assert_eq!(query(source, "callexpression[callee.name=foo]"), ["foo(1)"]);
+        assert_eq!(query(source, "Literal[value=1]"), ["1"]);
+        assert_eq!(query(source, "Literal[value>2]"), ["2.5"]);
+        assert_eq!(query(source, "Literal[value=/^x$/]"), ["'x'"]);
+        assert_eq!(query(source, "Literal[value=type(boolean)]"), ["true"]);
+        assert_eq!(query(source, "MemberExpression[computed!=true]"), ["foo.bar"]);
+        assert_eq!(query(source, "CallExpression[arguments.1]"), ["foo.bar(2.5, true)"]);
+        assert_eq!(query(source, "CallExpression[arguments.0.value=1]"), ["foo(1)"]);
+    }
+
+    #[test]
+    fn combinators() {
+        let source = "function f(a) { return a; } const b = 1; let c; f(b);";
+        assert_eq!(query(source, "FunctionDeclaration Identifier[name='a']"), ["a", "a"]);
+        assert_eq!(query(source, "FunctionDeclaration > Identifier"), ["f", "a"]);
+        assert_eq!(
+            query(source, "FunctionDeclaration ~ VariableDeclaration"),
+            ["const b = 1;", "let c;"]
+        );
+        assert_eq!(query(source, "FunctionDeclaration + VariableDeclaration"), ["const b = 1;"]);
+        assert_eq!(query(source, "FunctionDeclaration > Identifier.id"), ["f"]);
+        assert_eq!(query(source, "FunctionDeclaration > Identifier.params"), ["a"]);
+        assert_eq!(
+            query(source, "FunctionDeclaration > BlockStatement.body > ReturnStatement"),
+            ["return a;"]
+        );
+    }
+
+    #[test]
+    fn pseudo_classes() {
+        let source = "for (;;) { break; } for (;;) {} while (x) { if (y) break; }";
+        assert_eq!(query(source, "ForStatement:has(BreakStatement)"), ["for (;;) { break; }"]);
+        assert_eq!(query(source, "ForStatement:not(:has(BreakStatement))"), ["for (;;) {}"]);
+        assert_eq!(query(source, "WhileStatement:has(> BlockStatement > BreakStatement)").len(), 0);
+        assert_eq!(
+            query(source, ":matches(ForStatement, WhileStatement):has(IfStatement)").len(),
+            1
+        );
+        assert_eq!(query(source, "Program > :first-child"), ["for (;;) { break; }"]);
+        assert_eq!(query(source, "Program > :last-child").len(), 1);
+        assert_eq!(query(source, "Program > :nth-child(2)"), ["for (;;) {}"]);
+        assert_eq!(query(source, "Program > :nth-last-child(3)"), ["for (;;) { break; }"]);
+        assert_eq!(
+            query("const f = () => {}; function g() {}", ":function"),
+            ["() => {}", "function g() {}"]
+        );
+        assert_eq!(query("x; y = 1;", "ExpressionStatement > :expression"), ["x", "y = 1"]);
+        assert_eq!(query("let x;", ":statement"), ["let x;"]);
+    }
+
+    #[test]
+    fn typescript_nodes() {
+        let source = "let x = y as any; enum E {}";
+        assert_eq!(query(source, "TSAsExpression > TSAnyKeyword"), ["any"]);
+        assert_eq!(query(source, "TSEnumDeclaration"), ["enum E {}"]);
+    }
+}
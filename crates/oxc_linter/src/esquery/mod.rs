@@ -0,0 +1,61 @@
+//! Selectors for matching AST nodes, compatible with [esquery](https://github.com/estools/esquery).
+//!
+//! ESLint rules such as `no-restricted-syntax` are configured with esquery selectors, which refer to
+//! ESTree node types and fields. Selectors are therefore matched against the ESTree serialization of
+//! the AST produced by `oxc_estree`, rather than against [`oxc_ast::AstKind`] directly.
+//!
+//! Supported syntax:
+//! * node types (`Identifier`) and the wildcard (`*`)
+//! * attributes: `[attr]`, `[attr="foo"]`, `[attr!=foo]`, `[attr=/regex/i]`, `[attr=type(string)]`,
+//!   `[attr>1]`, `[attr<=1]`, with nested paths such as `[callee.object.name="foo"]`
+//! * fields: `FunctionDeclaration > Identifier.id`
+//! * combinators: descendant (`A B`), child (`A > B`), sibling (`A ~ B`) and adjacent (`A + B`)
+//! * `:not(...)`, `:matches(...)`, `:is(...)` and `:has(...)` (including `:has(> A)`)
+//! * `:first-child`, `:last-child`, `:nth-child(n)` and `:nth-last-child(n)`
+//! * `:statement`, `:expression`, `:declaration`, `:function` and `:pattern`
+
+mod matcher;
+mod parser;
+
+use oxc_ast::ast::Program;
+use self_cell::self_cell;
+use serde_json::Value;
+
+pub use matcher::{EstreeMatch, EstreeTree};
+pub use parser::Selector;
+pub use parser::{ComplexSelector, SimpleSelector};
+
+self_cell! {
+    /// The ESTree serialization of a program together with its [`EstreeTree`].
+    ///
+    /// Serializing the program is expensive, so it is built once per script block and shared by all
+    /// rules which match selectors (see [`LintContext::estree`](crate::LintContext::estree)).
+    pub struct EstreeProgram {
+        owner: Value,
+
+        #[covariant]
+        dependent: EstreeTree,
+    }
+}
+
+impl EstreeProgram {
+    pub fn from_program(program: &Program) -> Self {
+        Self::new(program_to_estree(program), |value| EstreeTree::new(value))
+    }
+
+    pub fn tree(&self) -> &EstreeTree<'_> {
+        self.borrow_dependent()
+    }
+}
+
+/// Serialize `program` to an ESTree JSON value which can be wrapped in an [`EstreeTree`].
+///
+/// Node `start` and `end` offsets are UTF-8 byte offsets, the same as [`oxc_span::Span`].
+fn program_to_estree(program: &Program) -> Value {
+    let json = if program.source_type.is_typescript() {
+        program.to_estree_ts_json(false)
+    } else {
+        program.to_estree_js_json(false)
+    };
+    serde_json::from_str(&json).unwrap_or(Value::Null)
+}
@@ -0,0 +1,573 @@
+use std::fmt;
+
+use cow_utils::CowUtils;
+use lazy_regex::{Regex, RegexBuilder};
+use oxc_span::CompactStr;
+
+/// A parsed selector, e.g. `CallExpression[callee.name="eval"], WithStatement`.
+///
+/// A list of complex selectors. It matches a node if any of them does.
+#[derive(Debug, Clone)]
+pub struct Selector(pub(super) Vec<ComplexSelector>);
+
+/// Compound selectors joined by combinators, e.g. `FunctionDeclaration > Identifier.id`.
+#[derive(Debug, Clone)]
+pub struct ComplexSelector {
+    /// Combinator relating the first compound to the node a `:has` selector is applied to.
+    /// `None` outside of `:has`.
+    pub leading: Option<Combinator>,
+    pub compounds: Vec<CompoundSelector>,
+    /// `combinators[i]` sits between `compounds[i]` and `compounds[i + 1]`.
+    pub combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+    /// `A ~ B`
+    Sibling,
+    /// `A + B`
+    Adjacent,
+}
+
+/// Simple selectors which must all match the same node, e.g. `Identifier[name="foo"]:first-child`.
+#[derive(Debug, Clone)]
+pub struct CompoundSelector(pub Vec<SimpleSelector>);
+
+#[derive(Debug, Clone)]
+pub enum SimpleSelector {
+    /// `*`
+    Wildcard,
+    /// `Identifier`
+    Type(CompactStr),
+    /// `[name]`, `[name="foo"]`, `[value>1]`
+    Attribute { path: Vec<CompactStr>, test: Option<(AttributeOperator, AttributeValue)> },
+    /// `.id`, `.body.expression`
+    Field(Vec<CompactStr>),
+    /// `:not(A, B)`
+    Not(Vec<ComplexSelector>),
+    /// `:matches(A, B)` or `:is(A, B)`
+    Matches(Vec<ComplexSelector>),
+    /// `:has(A)`, `:has(> A)`
+    Has(Vec<ComplexSelector>),
+    /// `:nth-child(n)` (`:first-child` is `:nth-child(1)`)
+    NthChild(usize),
+    /// `:nth-last-child(n)` (`:last-child` is `:nth-last-child(1)`)
+    NthLastChild(usize),
+    /// `:statement`, `:expression`, `:declaration`, `:function` or `:pattern`
+    Class(NodeClass),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    /// A quoted string, a number or a bare name. Compared by its string form.
+    Literal(CompactStr),
+    Regex(Regex),
+    /// `type(string)`
+    Type(CompactStr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorParseError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl Selector {
+    /// Parse an [esquery](https://github.com/estools/esquery) selector.
+    ///
+    /// # Errors
+    /// Returns an error if `source` is not a valid selector.
+    pub fn parse(source: &str) -> Result<Self, SelectorParseError> {
+        let mut parser = SelectorParser { source, pos: 0 };
+        let selectors = parser.parse_selector_list(false)?;
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(parser.error("Unexpected character"));
+        }
+        Ok(Self(selectors))
+    }
+}
+
+// Constructors for rules which build their selectors from options, rather than parsing them.
+
+impl Selector {
+    /// `A, B`
+    pub fn new(selectors: Vec<ComplexSelector>) -> Self {
+        Self(selectors)
+    }
+}
+
+impl ComplexSelector {
+    /// `A`
+    pub fn compound(selector: Vec<SimpleSelector>) -> Self {
+        Self { leading: None, compounds: vec![CompoundSelector(selector)], combinators: vec![] }
+    }
+
+    /// `A > B`
+    pub fn child(parent: Vec<SimpleSelector>, child: Vec<SimpleSelector>) -> Self {
+        Self {
+            leading: None,
+            compounds: vec![CompoundSelector(parent), CompoundSelector(child)],
+            combinators: vec![Combinator::Child],
+        }
+    }
+}
+
+impl SimpleSelector {
+    /// `Identifier`
+    pub fn node_type(name: &str) -> Self {
+        Self::Type(CompactStr::from(name))
+    }
+
+    /// `.body.expression`
+    pub fn field(path: &str) -> Self {
+        Self::Field(split_path(path))
+    }
+
+    /// `[callee.name]`
+    pub fn attribute_exists(path: &str) -> Self {
+        Self::Attribute { path: split_path(path), test: None }
+    }
+
+    /// `[callee.name="foo"]`
+    pub fn attribute_equals(path: &str, value: &str) -> Self {
+        Self::Attribute {
+            path: split_path(path),
+            test: Some((
+                AttributeOperator::Equal,
+                AttributeValue::Literal(CompactStr::from(value)),
+            )),
+        }
+    }
+}
+
+fn split_path(path: &str) -> Vec<CompactStr> {
+    path.split('.').map(CompactStr::from).collect()
+}
+
+struct SelectorParser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    fn error(&self, message: &str) -> SelectorParseError {
+        SelectorParseError { message: message.to_string(), offset: self.pos }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorParseError> {
+        if self.eat(c) { Ok(()) } else { Err(self.error(&format!("Expected `{c}`"))) }
+    }
+
+    /// Returns `true` if any whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn parse_selector_list(
+        &mut self,
+        relative: bool,
+    ) -> Result<Vec<ComplexSelector>, SelectorParseError> {
+        let mut selectors = vec![self.parse_complex(relative)?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(selectors);
+            }
+            selectors.push(self.parse_complex(relative)?);
+        }
+    }
+
+    fn parse_complex(&mut self, relative: bool) -> Result<ComplexSelector, SelectorParseError> {
+        self.skip_whitespace();
+        let leading = if relative {
+            Some(self.parse_combinator_symbol().unwrap_or(Combinator::Descendant))
+        } else {
+            None
+        };
+        self.skip_whitespace();
+
+        let mut compounds = vec![self.parse_compound()?];
+        let mut combinators = vec![];
+        loop {
+            let had_whitespace = self.skip_whitespace();
+            let combinator = match self.parse_combinator_symbol() {
+                Some(combinator) => combinator,
+                None if had_whitespace && self.starts_compound() => Combinator::Descendant,
+                None => break,
+            };
+            self.skip_whitespace();
+            combinators.push(combinator);
+            compounds.push(self.parse_compound()?);
+        }
+        Ok(ComplexSelector { leading, compounds, combinators })
+    }
+
+    fn parse_combinator_symbol(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            '>' => Combinator::Child,
+            '~' => Combinator::Sibling,
+            '+' => Combinator::Adjacent,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(combinator)
+    }
+
+    fn starts_compound(&self) -> bool {
+        self.peek().is_some_and(|c| matches!(c, '*' | '[' | '.' | ':') || is_identifier_char(c))
+    }
+
+    fn parse_compound(&mut self) -> Result<CompoundSelector, SelectorParseError> {
+        let mut simple = vec![];
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    simple.push(SimpleSelector::Wildcard);
+                }
+                Some('[') => simple.push(self.parse_attribute()?),
+                Some('.') => {
+                    self.pos += 1;
+                    simple.push(SimpleSelector::Field(self.parse_path()?));
+                }
+                Some(':') => simple.push(self.parse_pseudo()?),
+                Some(c) if is_identifier_char(c) => {
+                    simple.push(SimpleSelector::Type(CompactStr::from(self.parse_identifier()?)));
+                }
+                _ => break,
+            }
+        }
+        if simple.is_empty() {
+            return Err(self.error("Expected a selector"));
+        }
+        Ok(CompoundSelector(simple))
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'s str, SelectorParseError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.pos += self.peek().map_or(1, char::len_utf8);
+        }
+        if start == self.pos {
+            return Err(self.error("Expected an identifier"));
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// `a.b.c`
+    fn parse_path(&mut self) -> Result<Vec<CompactStr>, SelectorParseError> {
+        let mut path = vec![CompactStr::from(self.parse_identifier()?)];
+        while self.eat('.') {
+            path.push(CompactStr::from(self.parse_identifier()?));
+        }
+        Ok(path)
+    }
+
+    fn parse_attribute(&mut self) -> Result<SimpleSelector, SelectorParseError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+
+        let operator = if self.eat('=') {
+            Some(AttributeOperator::Equal)
+        } else if self.eat('!') {
+            self.expect('=')?;
+            Some(AttributeOperator::NotEqual)
+        } else if self.eat('<') {
+            Some(if self.eat('=') { AttributeOperator::LessEqual } else { AttributeOperator::Less })
+        } else if self.eat('>') {
+            Some(if self.eat('=') {
+                AttributeOperator::GreaterEqual
+            } else {
+                AttributeOperator::Greater
+            })
+        } else {
+            None
+        };
+
+        let test = match operator {
+            Some(operator) => {
+                self.skip_whitespace();
+                let value = self.parse_attribute_value()?;
+                if matches!(value, AttributeValue::Regex(_) | AttributeValue::Type(_))
+                    && !matches!(operator, AttributeOperator::Equal | AttributeOperator::NotEqual)
+                {
+                    return Err(self.error("Regex and type values only support `=` and `!=`"));
+                }
+                Some((operator, value))
+            }
+            None => None,
+        };
+
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(SimpleSelector::Attribute { path, test })
+    }
+
+    fn parse_attribute_value(&mut self) -> Result<AttributeValue, SelectorParseError> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let mut value = String::new();
+                loop {
+                    let Some(c) = self.peek() else {
+                        return Err(self.error("Unterminated string"));
+                    };
+                    self.pos += c.len_utf8();
+                    match c {
+                        '\\' => {
+                            let Some(escaped) = self.peek() else {
+                                return Err(self.error("Unterminated string"));
+                            };
+                            self.pos += escaped.len_utf8();
+                            value.push(match escaped {
+                                'n' => '\n',
+                                'r' => '\r',
+                                't' => '\t',
+                                c => c,
+                            });
+                        }
+                        c if c == quote => break,
+                        c => value.push(c),
+                    }
+                }
+                Ok(AttributeValue::Literal(CompactStr::from(value)))
+            }
+            Some('/') => {
+                self.pos += 1;
+                let start = self.pos;
+                let mut in_class = false;
+                loop {
+                    match self.peek() {
+                        None => return Err(self.error("Unterminated regex")),
+                        Some('\\') => self.pos += 1,
+                        Some('[') => in_class = true,
+                        Some(']') => in_class = false,
+                        Some('/') if !in_class => break,
+                        _ => {}
+                    }
+                    self.pos += self.peek().map_or(1, char::len_utf8);
+                }
+                let pattern = &self.source[start..self.pos];
+                self.pos += 1;
+                let mut builder = RegexBuilder::new(pattern);
+                while let Some(flag) = self.peek().filter(char::is_ascii_alphabetic) {
+                    self.pos += 1;
+                    match flag {
+                        'i' => builder.case_insensitive(true),
+                        'm' => builder.multi_line(true),
+                        's' => builder.dot_matches_new_line(true),
+                        'u' => builder.unicode(true),
+                        _ => return Err(self.error("Unsupported regex flag")),
+                    };
+                }
+                builder.build().map(AttributeValue::Regex).map_err(|_| self.error("Invalid regex"))
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.pos += 1;
+                }
+                let number = &self.source[start..self.pos];
+                let number = number.parse::<f64>().map_err(|_| self.error("Invalid number"))?;
+                Ok(AttributeValue::Literal(CompactStr::from(number_to_js_string(number))))
+            }
+            _ => {
+                let name = self.parse_identifier()?;
+                if name == "type" && self.eat('(') {
+                    self.skip_whitespace();
+                    let type_name = CompactStr::from(self.parse_identifier()?);
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    return Ok(AttributeValue::Type(type_name));
+                }
+                let mut value = name.to_string();
+                while self.eat('.') {
+                    value.push('.');
+                    value.push_str(self.parse_identifier()?);
+                }
+                Ok(AttributeValue::Literal(CompactStr::from(value)))
+            }
+        }
+    }
+
+    fn parse_pseudo(&mut self) -> Result<SimpleSelector, SelectorParseError> {
+        self.expect(':')?;
+        let start = self.pos;
+        let name = self.parse_identifier()?.cow_to_ascii_lowercase();
+        let selector = match name.as_ref() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let selectors = self.parse_selector_list(name == "has")?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                match name.as_ref() {
+                    "not" => SimpleSelector::Not(selectors),
+                    "has" => SimpleSelector::Has(selectors),
+                    _ => SimpleSelector::Matches(selectors),
+                }
+            }
+            "first-child" => SimpleSelector::NthChild(1),
+            "last-child" => SimpleSelector::NthLastChild(1),
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let digits_start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let n = self.source[digits_start..self.pos]
+                    .parse::<usize>()
+                    .map_err(|_| self.error("Expected a number"))?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                if name == "nth-child" {
+                    SimpleSelector::NthChild(n)
+                } else {
+                    SimpleSelector::NthLastChild(n)
+                }
+            }
+            "statement" => SimpleSelector::Class(NodeClass::Statement),
+            "expression" => SimpleSelector::Class(NodeClass::Expression),
+            "declaration" => SimpleSelector::Class(NodeClass::Declaration),
+            "function" => SimpleSelector::Class(NodeClass::Function),
+            "pattern" => SimpleSelector::Class(NodeClass::Pattern),
+            _ => {
+                self.pos = start;
+                return Err(self.error("Unknown pseudo-class"));
+            }
+        };
+        Ok(selector)
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '-' | '@')
+}
+
+/// Format a number the way JavaScript's `String(number)` does for common values.
+pub(super) fn number_to_js_string(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e21 {
+        format!("{number:.0}")
+    } else {
+        number.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_valid() {
+        let cases = [
+            "Identifier",
+            "*",
+            "CallExpression[callee.name='eval']",
+            "Literal[value=/^foo/i]",
+            "Literal[value=type(string)]",
+            "Literal[value>=1]",
+            "[optional!=true]",
+            "FunctionDeclaration > Identifier.id",
+            "Program  VariableDeclaration ~ ExpressionStatement",
+            "VariableDeclaration + ExpressionStatement",
+            "CallExpression:has(> MemberExpression.callee)",
+            "ForStatement:not(:has(BreakStatement), [init=null])",
+            ":matches(FunctionDeclaration, ArrowFunctionExpression):first-child",
+            "Property:nth-child(2), :statement, :nth-last-child(1)",
+        ];
+        for case in cases {
+            assert!(Selector::parse(case).is_ok(), "{case}");
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let cases = [
+            "",
+            "Identifier[",
+            "[name='foo]",
+            "Literal[value>/foo/]",
+            ":unknown",
+            "A >",
+            "A, ",
+            "Literal[value=/(/]",
+            "Identifier)",
+        ];
+        for case in cases {
+            assert!(Selector::parse(case).is_err(), "{case}");
+        }
+    }
+
+    #[test]
+    fn parse_structure() {
+        let Selector(selectors) = Selector::parse("A > B C, D").unwrap();
+        assert_eq!(selectors.len(), 2);
+        assert_eq!(selectors[0].compounds.len(), 3);
+        assert_eq!(selectors[0].combinators, vec![Combinator::Child, Combinator::Descendant]);
+        assert!(selectors[0].leading.is_none());
+
+        let Selector(selectors) = Selector::parse("A:has(> B, C)").unwrap();
+        let SimpleSelector::Has(has) = &selectors[0].compounds[0].0[1] else {
+            panic!("expected `:has`");
+        };
+        assert_eq!(has[0].leading, Some(Combinator::Child));
+        assert_eq!(has[1].leading, Some(Combinator::Descendant));
+    }
+
+    #[test]
+    fn number_strings() {
+        assert_eq!(number_to_js_string(1.0), "1");
+        assert_eq!(number_to_js_string(-3.0), "-3");
+        assert_eq!(number_to_js_string(1.5), "1.5");
+    }
+}
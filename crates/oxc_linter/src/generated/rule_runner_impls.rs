@@ -460,6 +460,14 @@ impl RuleRunner for crate::rules::eslint::no_restricted_imports::NoRestrictedImp
         Some(&AstTypesBitset::from_types(&[AstType::TSImportEqualsDeclaration]));
 }
 
+impl RuleRunner for crate::rules::eslint::no_restricted_properties::NoRestrictedProperties {
+    const NODE_TYPES: Option<&AstTypesBitset> = None;
+}
+
+impl RuleRunner for crate::rules::eslint::no_restricted_syntax::NoRestrictedSyntax {
+    const NODE_TYPES: Option<&AstTypesBitset> = None;
+}
+
 impl RuleRunner for crate::rules::eslint::no_return_assign::NoReturnAssign {
     const NODE_TYPES: Option<&AstTypesBitset> =
         Some(&AstTypesBitset::from_types(&[AstType::AssignmentExpression]));
@@ -873,6 +881,10 @@ impl RuleRunner for crate::rules::import::no_webpack_loader_syntax::NoWebpackLoa
     const NODE_TYPES: Option<&AstTypesBitset> = None;
 }
 
+impl RuleRunner for crate::rules::import::order::Order {
+    const NODE_TYPES: Option<&AstTypesBitset> = None;
+}
+
 impl RuleRunner for crate::rules::import::prefer_default_export::PreferDefaultExport {
     const NODE_TYPES: Option<&AstTypesBitset> = None;
 }
@@ -5,6 +5,8 @@ use std::{
     path::Path,
     ptr::{self, NonNull},
     rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use oxc_allocator::Allocator;
@@ -13,13 +15,16 @@ use oxc_ast_macros::ast;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_data_structures::box_macros::boxed_array;
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_partial_loader::PARTIAL_LOADER_EXTENSIONS;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
 
 mod ast_util;
+mod codemod;
 mod config;
 mod context;
 mod disable_directives;
+mod esquery;
 mod external_linter;
 mod external_plugin_store;
 mod fixer;
@@ -32,8 +37,10 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod timing;
 mod tsgolint;
 mod utils;
+mod walk;
 
 pub mod loader;
 pub mod rules;
@@ -55,9 +62,10 @@ pub use crate::disable_directives::{
     create_unused_directives_diagnostics,
 };
 pub use crate::{
+    codemod::{Codemod, CodemodResult},
     config::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
-        LintPlugins, Oxlintrc, ResolvedLinterState,
+        LintPlugins, OxlintRules, Oxlintrc, ResolvedLinterState,
     },
     context::{ContextSubHost, LintContext},
     external_linter::{
@@ -74,21 +82,22 @@ pub use crate::{
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunner},
     service::{LintService, LintServiceOptions, RuntimeFileSystem},
+    timing::{JS_PLUGINS_TIMING, RuleTiming, RuleTimings, TSGOLINT_TIMING},
     tsgolint::TsGoLintState,
-    utils::{read_to_arena_str, read_to_string},
+    utils::{ImportGroup, read_to_arena_str, read_to_string},
+    walk::{Extensions, Walk},
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings},
     context::ContextHost,
     fixer::{CompositeFix, Fix, Fixer, Message, PossibleFixes},
-    loader::LINT_PARTIAL_LOADER_EXTENSIONS,
     rules::RuleEnum,
     utils::iter_possible_jest_call_node,
 };
 
 #[cfg(feature = "language_server")]
 pub use crate::lsp::{
-    FixWithPosition, MessageWithPosition, PossibleFixesWithPosition,
+    FixWithPosition, MessageWithPosition, PossibleFixesWithPosition, find_rule_by_code,
     oxc_diagnostic_to_message_with_position,
 };
 
@@ -107,6 +116,7 @@ pub struct Linter {
     options: LintOptions,
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
+    rule_timings: Option<Arc<RuleTimings>>,
 }
 
 impl Linter {
@@ -115,7 +125,7 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter }
+        Self { options, config, external_linter, rule_timings: None }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -131,6 +141,17 @@ impl Linter {
         self
     }
 
+    /// Measure the time spent in each rule, and record it in `rule_timings`.
+    #[must_use]
+    pub fn with_rule_timings(mut self, rule_timings: Arc<RuleTimings>) -> Self {
+        self.rule_timings = Some(rule_timings);
+        self
+    }
+
+    pub(crate) fn rule_timings(&self) -> Option<&Arc<RuleTimings>> {
+        self.rule_timings.as_ref()
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -178,7 +199,10 @@ impl Linter {
         let is_partial_loader_file = path
             .extension()
             .and_then(|ext| ext.to_str())
-            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext));
+            .is_some_and(|ext| PARTIAL_LOADER_EXTENSIONS.contains(&ext));
+
+        // Time spent in each rule on this file, recorded in `rule_timings` once the file is done.
+        let mut rule_durations: Vec<(&'static str, &'static str, Duration)> = vec![];
 
         loop {
             let rules = rules
@@ -192,7 +216,11 @@ impl Linter {
             let should_run_on_jest_node =
                 ctx_host.plugins().has_test() && ctx_host.frameworks().is_test();
 
-            let execute_rules = |with_ast_kind_filtering: bool| {
+            let mut execute_rules = |with_ast_kind_filtering: bool| {
+                // Only measure the first run. In debug builds, rules are run a second time without
+                // AST kind filtering to check the results are the same.
+                let measure = with_ast_kind_filtering && self.rule_timings.is_some();
+
                 // IMPORTANT: We have two branches here for performance reasons:
                 //
                 // 1) Branch where we iterate over each node, then each rule
@@ -211,7 +239,10 @@ impl Linter {
                 // don't thrash the cache too much. Feel free to tweak based on benchmarking.
                 //
                 // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-                if semantic.nodes().len() > 200_000 {
+                //
+                // When measuring rules, always run each rule to completion before the next one,
+                // so its time can be measured as a whole.
+                if semantic.nodes().len() > 200_000 && !measure {
                     // TODO: It seems like there is probably a more intelligent way to preallocate space here. This will
                     // likely incur quite a few unnecessary reallocs currently. We theoretically could compute this at
                     // compile-time since we know all of the rules and their AST node type information ahead of time.
@@ -263,6 +294,8 @@ impl Linter {
                     }
                 } else {
                     for (rule, ctx) in &rules {
+                        let start = measure.then(Instant::now);
+
                         rule.run_once(ctx);
 
                         for symbol in semantic.scoping().symbol_ids() {
@@ -288,6 +321,10 @@ impl Linter {
                                 rule.run_on_jest_node(&jest_node, ctx);
                             }
                         }
+
+                        if let Some(start) = start {
+                            rule_durations.push((rule.plugin_name(), rule.name(), start.elapsed()));
+                        }
                     }
                 }
             };
@@ -335,7 +372,12 @@ impl Linter {
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
+            let start =
+                (self.rule_timings.is_some() && !external_rules.is_empty()).then(Instant::now);
             self.run_external_rules(&external_rules, path, &mut ctx_host, allocator);
+            if let Some(start) = start {
+                rule_durations.push((JS_PLUGINS_TIMING, "", start.elapsed()));
+            }
 
             // Report unused directives is now handled differently with type-aware linting
 
@@ -357,6 +399,10 @@ impl Linter {
             }
         }
 
+        if let Some(rule_timings) = &self.rule_timings {
+            rule_timings.record_all(rule_durations);
+        }
+
         let diagnostics = ctx_host.take_diagnostics();
         let disable_directives = if is_partial_loader_file {
             None
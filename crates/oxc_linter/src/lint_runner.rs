@@ -2,6 +2,7 @@ use std::{
     ffi::OsStr,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
+    time::Instant,
 };
 
 use rustc_hash::FxHashMap;
@@ -11,7 +12,7 @@ use oxc_span::Span;
 
 use crate::{
     AllowWarnDeny, ConfigStore, DisableDirectives, LintService, LintServiceOptions, Linter,
-    TsGoLintState,
+    RuleTimings, TSGOLINT_TIMING, TsGoLintState,
 };
 
 /// Unified runner that orchestrates both regular (oxc) and type-aware (tsgolint) linting
@@ -25,6 +26,8 @@ pub struct LintRunner {
     directives_store: DirectivesStore,
     /// Lint service options
     lint_service_options: LintServiceOptions,
+    /// Per-rule timings, shared with the regular linter
+    rule_timings: Option<Arc<RuleTimings>>,
 }
 
 /// Manages disable directives across all linting engines.
@@ -177,7 +180,10 @@ impl LintRunnerBuilder {
             None
         };
 
+        let rule_timings = self.regular_linter.as_ref().and_then(Linter::rule_timings).cloned();
+
         Ok(LintRunner {
+            rule_timings,
             regular_linter: self.regular_linter,
             type_aware_linter,
             directives_store: directives_coordinator,
@@ -223,7 +229,11 @@ impl LintRunner {
         }
 
         if let Some(type_aware_linter) = self.type_aware_linter.take() {
+            let start = self.rule_timings.is_some().then(Instant::now);
             type_aware_linter.lint(files, self.directives_store.map(), tx_error)?;
+            if let (Some(rule_timings), Some(start)) = (&self.rule_timings, start) {
+                rule_timings.record(TSGOLINT_TIMING, "", start.elapsed());
+            }
         } else {
             drop(tx_error);
         }
@@ -1,11 +1,12 @@
 use std::{error::Error, fmt, path::Path};
 
-use oxc_span::SourceType;
+use oxc_partial_loader::PARTIAL_LOADER_EXTENSIONS;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
-mod partial_loader;
-mod source;
-pub use partial_loader::{LINT_PARTIAL_LOADER_EXTENSIONS, LINTABLE_EXTENSIONS, PartialLoader};
-pub use source::JavaScriptSource;
+/// All valid JavaScript/TypeScript extensions, plus additional framework files that
+/// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
+pub const LINTABLE_EXTENSIONS: &[&str] =
+    constcat::concat_slices!([&str]: VALID_EXTENSIONS, PARTIAL_LOADER_EXTENSIONS);
 
 // TODO: use oxc_resolver::FileSystem. We can't do so until that crate exposes FileSystemOs
 // externally.
@@ -19,7 +20,7 @@ impl Loader {
             || path
                 .extension()
                 .and_then(std::ffi::OsStr::to_str)
-                .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext))
+                .is_some_and(|ext| PARTIAL_LOADER_EXTENSIONS.contains(&ext))
     }
 }
 
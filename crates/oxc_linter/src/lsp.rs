@@ -2,7 +2,11 @@ use oxc_data_structures::rope::{Rope, get_line_column};
 use oxc_span::GetSpan;
 use std::borrow::Cow;
 
-use crate::fixer::{Fix, Message, PossibleFixes};
+use crate::{
+    context::plugin_name_to_prefix,
+    fixer::{Fix, Message, PossibleFixes},
+    rules::{RULES, RuleEnum},
+};
 use oxc_diagnostics::{OxcCode, OxcDiagnostic, Severity};
 
 #[derive(Clone, Debug)]
@@ -50,6 +54,14 @@ impl SpanPosition {
     }
 }
 
+/// Find the rule reporting diagnostics with the given code, e.g. `eslint-plugin-react(jsx-key)`.
+pub fn find_rule_by_code(code: &str) -> Option<&'static RuleEnum> {
+    let (prefix, rule_name) = code.strip_suffix(')')?.split_once('(')?;
+    RULES.iter().find(|rule| {
+        rule.name() == rule_name && plugin_name_to_prefix(rule.plugin_name()) == prefix
+    })
+}
+
 pub fn offset_to_position(rope: &Rope, offset: u32, source_text: &str) -> SpanPosition {
     let (line, column) = get_line_column(rope, offset, source_text);
     SpanPosition::new(line, column)
@@ -263,7 +275,19 @@ fn disable_for_this_section<'a>(
 mod test {
     use oxc_data_structures::rope::Rope;
 
-    use super::offset_to_position;
+    use super::{find_rule_by_code, offset_to_position};
+
+    #[test]
+    fn find_rule() {
+        let rule = find_rule_by_code("eslint(no-debugger)").unwrap();
+        assert_eq!((rule.plugin_name(), rule.name()), ("eslint", "no-debugger"));
+        let rule = find_rule_by_code("typescript-eslint(no-explicit-any)").unwrap();
+        assert_eq!((rule.plugin_name(), rule.name()), ("typescript", "no-explicit-any"));
+
+        assert!(find_rule_by_code("typescript(no-explicit-any)").is_none());
+        assert!(find_rule_by_code("eslint(unknown)").is_none());
+        assert!(find_rule_by_code("no-debugger").is_none());
+    }
 
     #[test]
     fn single_line() {
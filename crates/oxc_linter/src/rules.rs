@@ -35,6 +35,7 @@ pub(crate) mod import {
     pub mod no_self_import;
     pub mod no_unassigned_import;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
     pub mod prefer_default_export;
     pub mod unambiguous;
 }
@@ -136,6 +137,8 @@ pub(crate) mod eslint {
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
+    pub mod no_restricted_properties;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -756,6 +759,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_redeclare,
     eslint::no_regex_spaces,
     eslint::no_restricted_globals,
+    eslint::no_restricted_properties,
+    eslint::no_restricted_syntax,
     eslint::no_return_assign,
     eslint::no_script_url,
     eslint::no_self_assign,
@@ -837,6 +842,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default_member,
     import::no_self_import,
     import::no_webpack_loader_syntax,
+    import::order,
     import::prefer_default_export,
     import::unambiguous,
     jest::consistent_test_it,
@@ -0,0 +1,448 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    esquery::{ComplexSelector, EstreeMatch, Selector, SimpleSelector},
+    rule::Rule,
+};
+
+fn restricted_object_property_diagnostic(
+    object: &str,
+    property: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = message.map(|message| format!(" {message}")).unwrap_or_default();
+    OxcDiagnostic::warn(format!("'{object}.{property}' is restricted from being used.{message}"))
+        .with_label(span)
+}
+
+fn restricted_property_diagnostic(
+    property: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = message.map(|message| format!(" {message}")).unwrap_or_default();
+    OxcDiagnostic::warn(format!("'{property}' is restricted from being used.{message}"))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedProperties(Box<NoRestrictedPropertiesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPropertiesConfig {
+    restrictions: Vec<RestrictedProperty>,
+}
+
+impl std::ops::Deref for NoRestrictedProperties {
+    type Target = NoRestrictedPropertiesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedProperty {
+    object: Option<CompactStr>,
+    message: Option<CompactStr>,
+    /// Matches the restricted member expressions and destructured properties.
+    selector: Selector,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows certain properties on certain objects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Certain properties on objects may be disallowed in a codebase. This is useful for
+    /// deprecating an API or restricting usage of a module's methods. For example, you may want to
+    /// disallow using `describe.only` when using Mocha or telling people to use `Object.assign`
+    /// instead of `_.extend`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `[{ "object": "disallowedObjectName", "property": "disallowedPropertyName" }]`:
+    /// ```js
+    /// const example = disallowedObjectName.disallowedPropertyName;
+    ///
+    /// disallowedObjectName.disallowedPropertyName();
+    ///
+    /// const { disallowedPropertyName } = disallowedObjectName;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with
+    /// `[{ "object": "disallowedObjectName", "property": "disallowedPropertyName" }]`:
+    /// ```js
+    /// const example = disallowedObjectName.somePropertyName;
+    ///
+    /// allowedObjectName.disallowedPropertyName();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// A list of objects, each with an `object` name, a `property` name, or both, and an optional
+    /// custom `message`:
+    ///
+    /// ```json
+    /// {
+    ///   "no-restricted-properties": [
+    ///     "error",
+    ///     { "object": "JSON", "property": "parse", "message": "Use safeParse instead." },
+    ///     { "object": "require", "allowProperties": ["resolve"] },
+    ///     { "property": "__defineGetter__", "allowObjects": ["legacy"] }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// `allowProperties` can only be used together with `object`, and `allowObjects` only
+    /// together with `property`.
+    NoRestrictedProperties,
+    eslint,
+    restriction
+);
+
+impl Rule for NoRestrictedProperties {
+    fn from_configuration(value: Value) -> Self {
+        let Value::Array(items) = value else {
+            return Self::default();
+        };
+        let restrictions = items
+            .iter()
+            .filter_map(|item| {
+                let object = item.get("object").and_then(Value::as_str).map(CompactStr::from);
+                let property = item.get("property").and_then(Value::as_str).map(CompactStr::from);
+                let message = item.get("message").and_then(Value::as_str).map(CompactStr::from);
+                let allow_objects = string_list(item.get("allowObjects"));
+                let allow_properties = string_list(item.get("allowProperties"));
+                let selector = restriction_selector(
+                    object.as_deref(),
+                    property.as_deref(),
+                    &allow_objects,
+                    &allow_properties,
+                )?;
+                Some(RestrictedProperty { object, message, selector })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedPropertiesConfig { restrictions }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.restrictions.is_empty() {
+            return;
+        }
+        let tree = ctx.estree();
+        for restriction in &self.restrictions {
+            for node in tree.matches(&restriction.selector) {
+                let Some(property) = static_property_name(&node) else {
+                    continue;
+                };
+                let message = restriction.message.as_deref();
+                ctx.diagnostic(match &restriction.object {
+                    Some(object) => restricted_object_property_diagnostic(
+                        object,
+                        &property,
+                        message,
+                        node.span(),
+                    ),
+                    None => restricted_property_diagnostic(&property, message, node.span()),
+                });
+            }
+        }
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<&str> {
+    value
+        .and_then(Value::as_array)
+        .map_or_else(Vec::new, |values| values.iter().filter_map(Value::as_str).collect())
+}
+
+/// Builds the selector matching a restriction, or `None` if the restriction is invalid.
+///
+/// Restricted properties are either accessed on a member expression, e.g. `JSON.parse`, or
+/// destructured from the object, e.g. `const { parse } = JSON`.
+fn restriction_selector(
+    object: Option<&str>,
+    property: Option<&str>,
+    allow_objects: &[&str],
+    allow_properties: &[&str],
+) -> Option<Selector> {
+    let (member, destructuring) = match (object, property) {
+        (Some(object), Some(property)) => {
+            let mut member = vec![SimpleSelector::node_type("MemberExpression")];
+            member.extend(identifier_test("object", object));
+            member.push(member_property_test(property));
+            (member, destructured_from(object, vec![key_test(property)]))
+        }
+        (Some(object), None) => {
+            if !allow_objects.is_empty() {
+                return None;
+            }
+            let mut member = vec![SimpleSelector::node_type("MemberExpression")];
+            member.extend(identifier_test("object", object));
+            member.push(SimpleSelector::Matches(vec![
+                ComplexSelector::compound(vec![
+                    SimpleSelector::attribute_equals("computed", "false"),
+                    SimpleSelector::attribute_equals("property.type", "Identifier"),
+                ]),
+                ComplexSelector::compound(vec![
+                    SimpleSelector::attribute_equals("computed", "true"),
+                    SimpleSelector::attribute_equals("property.type", "Literal"),
+                ]),
+            ]));
+            member.extend(not(allow_properties
+                .iter()
+                .map(|property| ComplexSelector::compound(vec![member_property_test(property)]))));
+
+            let mut key = vec![SimpleSelector::Matches(vec![
+                ComplexSelector::compound(vec![
+                    SimpleSelector::attribute_equals("computed", "false"),
+                    SimpleSelector::attribute_equals("key.type", "Identifier"),
+                ]),
+                ComplexSelector::compound(vec![SimpleSelector::attribute_equals(
+                    "key.type", "Literal",
+                )]),
+            ])];
+            key.extend(not(allow_properties
+                .iter()
+                .map(|property| ComplexSelector::compound(vec![key_test(property)]))));
+            (member, destructured_from(object, key))
+        }
+        (None, Some(property)) => {
+            if !allow_properties.is_empty() {
+                return None;
+            }
+            let mut member =
+                vec![SimpleSelector::node_type("MemberExpression"), member_property_test(property)];
+            member.extend(not(allow_objects.iter().map(|object| {
+                ComplexSelector::compound(identifier_test("object", object).into())
+            })));
+
+            let mut key = vec![
+                SimpleSelector::node_type("Property"),
+                SimpleSelector::field("properties"),
+                key_test(property),
+            ];
+            key.extend(not(allow_objects.iter().map(|object| {
+                ComplexSelector::child(
+                    vec![object_pattern_from(object)],
+                    vec![SimpleSelector::Wildcard],
+                )
+            })));
+            let destructuring =
+                ComplexSelector::child(vec![SimpleSelector::node_type("ObjectPattern")], key);
+            (member, destructuring)
+        }
+        (None, None) => return None,
+    };
+    Some(Selector::new(vec![ComplexSelector::compound(member), destructuring]))
+}
+
+/// Matches a `Property` with `tests`, destructured from the identifier `object`.
+fn destructured_from(object: &str, tests: Vec<SimpleSelector>) -> ComplexSelector {
+    let mut property =
+        vec![SimpleSelector::node_type("Property"), SimpleSelector::field("properties")];
+    property.extend(tests);
+    ComplexSelector::child(vec![object_pattern_from(object)], property)
+}
+
+/// Matches an `ObjectPattern` whose value comes from the identifier `object`.
+fn object_pattern_from(object: &str) -> SimpleSelector {
+    let pattern = |parent: &str, value: &str, field: &str| {
+        let mut parent = vec![SimpleSelector::node_type(parent)];
+        parent.extend(identifier_test(value, object));
+        ComplexSelector::child(
+            parent,
+            vec![SimpleSelector::node_type("ObjectPattern"), SimpleSelector::field(field)],
+        )
+    };
+    SimpleSelector::Matches(vec![
+        pattern("VariableDeclarator", "init", "id"),
+        pattern("AssignmentExpression", "right", "left"),
+        pattern("AssignmentPattern", "right", "left"),
+    ])
+}
+
+/// `[field.type="Identifier"][field.name="name"]`
+fn identifier_test(field: &str, name: &str) -> [SimpleSelector; 2] {
+    [
+        SimpleSelector::attribute_equals(&format!("{field}.type"), "Identifier"),
+        SimpleSelector::attribute_equals(&format!("{field}.name"), name),
+    ]
+}
+
+fn member_property_test(property: &str) -> SimpleSelector {
+    static_name_test("property", property)
+}
+
+fn key_test(property: &str) -> SimpleSelector {
+    static_name_test("key", property)
+}
+
+/// Matches `obj.name`, `obj["name"]`, `{ name }` and `{ "name": value }`.
+fn static_name_test(field: &str, name: &str) -> SimpleSelector {
+    let mut identifier = vec![SimpleSelector::attribute_equals("computed", "false")];
+    identifier.extend(identifier_test(field, name));
+    // Computed keys in object patterns are matched by literal value, the same as ESLint.
+    let mut literal = if field == "property" {
+        vec![SimpleSelector::attribute_equals("computed", "true")]
+    } else {
+        vec![]
+    };
+    literal.extend([
+        SimpleSelector::attribute_equals(&format!("{field}.type"), "Literal"),
+        SimpleSelector::Not(vec![ComplexSelector::compound(vec![
+            SimpleSelector::attribute_exists(&format!("{field}.regex")),
+        ])]),
+        SimpleSelector::attribute_equals(&format!("{field}.value"), name),
+    ]);
+    SimpleSelector::Matches(vec![
+        ComplexSelector::compound(identifier),
+        ComplexSelector::compound(literal),
+    ])
+}
+
+fn not(selectors: impl Iterator<Item = ComplexSelector>) -> Option<SimpleSelector> {
+    let selectors = selectors.collect::<Vec<_>>();
+    (!selectors.is_empty()).then(|| SimpleSelector::Not(selectors))
+}
+
+/// Name of the property accessed by a matched `MemberExpression` or destructured by a `Property`.
+fn static_property_name(node: &EstreeMatch) -> Option<String> {
+    let field = if node.get("type")?.as_str()? == "MemberExpression" { "property" } else { "key" };
+    let name = match node.get(&format!("{field}.type"))?.as_str()? {
+        "Identifier" => node.get(&format!("{field}.name"))?,
+        _ => node.get(&format!("{field}.value"))?,
+    };
+    Some(match name.as_ref() {
+        Value::String(name) => name.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "someObject.someProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "someObject.someProperty()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty()",
+            Some(
+                json!([{ "object": "someObject", "property": "disallowedProperty", "message": "Please use someObject.allowedProperty instead." }]),
+            ),
+        ),
+        (
+            "anotherObject['disallowedProperty']()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        ("obj.toString", Some(json!([{ "object": "obj", "property": "__proto__" }]))),
+        ("toString.toString", Some(json!([{ "object": "obj", "property": "foo" }]))),
+        ("obj.toString", Some(json!([{ "object": "obj", "property": "foo" }]))),
+        ("foo.bar", Some(json!([{ "property": "baz" }]))),
+        ("foo.bar", Some(json!([{ "object": "baz" }]))),
+        ("foo()", Some(json!([{ "object": "foo" }]))),
+        ("foo;", Some(json!([{ "object": "foo" }]))),
+        ("foo[/(?<zero>0)/]", Some(json!([{ "property": "null" }]))),
+        ("let bar = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {baz} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {baz: bar} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar} = baz;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {['foo']: bar} = baz;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("function f({ bar }) {}", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("[bar] = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("foo.bar", Some(json!([{ "object": "foo", "allowProperties": ["bar"] }]))),
+        ("let { bar } = foo;", Some(json!([{ "object": "foo", "allowProperties": ["bar"] }]))),
+        ("foo.bar", Some(json!([{ "property": "bar", "allowObjects": ["foo"] }]))),
+        ("let { bar } = foo;", Some(json!([{ "property": "bar", "allowObjects": ["foo"] }]))),
+        // `allowObjects` is only allowed together with `property`
+        ("foo.bar", Some(json!([{ "object": "foo", "allowObjects": ["foo"] }]))),
+    ];
+
+    let fail = vec![
+        (
+            "someObject.disallowedProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "someObject.disallowedProperty",
+            Some(
+                json!([{ "object": "someObject", "property": "disallowedProperty", "message": "Please use someObject.allowedProperty instead." }]),
+            ),
+        ),
+        (
+            "someObject.disallowedProperty; anotherObject.anotherDisallowedProperty()",
+            Some(json!([
+                { "object": "someObject", "property": "disallowedProperty" },
+                { "object": "anotherObject", "property": "anotherDisallowedProperty" }
+            ])),
+        ),
+        (
+            "foo.__proto__",
+            Some(
+                json!([{ "property": "__proto__", "message": "Please use Object.getPrototypeOf instead." }]),
+            ),
+        ),
+        (
+            "foo['__proto__']",
+            Some(
+                json!([{ "property": "__proto__", "message": "Please use Object.getPrototypeOf instead." }]),
+            ),
+        ),
+        ("foo.bar.baz;", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar();", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar.baz();", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar.baz;", Some(json!([{ "property": "bar" }]))),
+        ("foo.bar();", Some(json!([{ "property": "bar" }]))),
+        ("foo.bar.baz();", Some(json!([{ "property": "bar" }]))),
+        ("foo['bar'] = 1; foo.bar", Some(json!([{ "property": "bar" }]))),
+        (
+            "require.call({}, 'foo')",
+            Some(json!([{ "object": "require", "message": "Please call require() directly." }])),
+        ),
+        ("require['resolve']", Some(json!([{ "object": "require" }]))),
+        ("let {bar} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar: baz} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {'bar': baz} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar: {baz: qux}} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar} = foo;", Some(json!([{ "object": "foo" }]))),
+        ("let {bar: baz} = foo;", Some(json!([{ "object": "foo" }]))),
+        ("let {bar} = foo;", Some(json!([{ "property": "bar" }]))),
+        ("let {bar: baz} = foo;", Some(json!([{ "property": "bar" }]))),
+        ("({bar: baz} = foo);", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("function qux({ bar } = foo) {}", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("function qux({ bar }) {}", Some(json!([{ "property": "bar" }]))),
+        ("foo.baz; foo.bar;", Some(json!([{ "object": "foo", "allowProperties": ["baz"] }]))),
+        ("let { baz, bar } = foo;", Some(json!([{ "object": "foo", "allowProperties": ["baz"] }]))),
+        ("foo.bar; baz.bar;", Some(json!([{ "property": "bar", "allowObjects": ["foo"] }]))),
+        ("let { bar } = baz;", Some(json!([{ "property": "bar", "allowObjects": ["foo"] }]))),
+    ];
+
+    Tester::new(NoRestrictedProperties::NAME, NoRestrictedProperties::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,237 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, esquery::Selector, rule::Rule};
+
+fn no_restricted_syntax_diagnostic(
+    selector: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let message = message.map_or_else(
+        || format!("Using '{selector}' is not allowed."),
+        std::string::ToString::to_string,
+    );
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    /// The selector as written in the configuration, for the default message.
+    source: CompactStr,
+    selector: Selector,
+    message: Option<CompactStr>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching the configured selectors.
+    ///
+    /// Selectors use the [esquery](https://github.com/estools/esquery) syntax supported by ESLint,
+    /// and refer to ESTree node types and fields, e.g. `CallExpression[callee.name="eval"]`.
+    /// TypeScript files use the TS-ESTree node types, e.g. `TSEnumDeclaration`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. As a result,
+    /// some projects choose to disallow the use of certain language features altogether, or
+    /// patterns which are specific to the project and have no dedicated rule.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `["FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"]`:
+    /// ```js
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// const doSomething = function () {};
+    ///
+    /// foo in bar;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with
+    /// `["FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"]`:
+    /// ```js
+    /// me.dontMess();
+    ///
+    /// function doSomething() {};
+    ///
+    /// foo instanceof bar;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// A list of selectors, each either a string or an object with a `selector` and a custom `message`:
+    ///
+    /// ```json
+    /// {
+    ///   "no-restricted-syntax": [
+    ///     "error",
+    ///     "FunctionExpression",
+    ///     { "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]", "message": "setTimeout must always be invoked with two arguments." }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// An invalid selector is reported as a configuration error.
+    NoRestrictedSyntax,
+    eslint,
+    restriction
+);
+
+impl TryFrom<Value> for NoRestrictedSyntaxConfig {
+    type Error = OxcDiagnostic;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Array(items) = value else {
+            return Ok(Self::default());
+        };
+        let restrictions = items
+            .iter()
+            .map(|item| {
+                let (source, message) = match item {
+                    Value::String(selector) => (selector.as_str(), None),
+                    Value::Object(object) => (
+                        object.get("selector").and_then(Value::as_str).ok_or_else(|| {
+                            invalid_option(&format!("Expected a string 'selector', got {item}"))
+                        })?,
+                        object.get("message").and_then(Value::as_str).map(CompactStr::from),
+                    ),
+                    _ => {
+                        return Err(invalid_option(&format!(
+                            "Expected a string or an object, got {item}"
+                        )));
+                    }
+                };
+                let selector = Selector::parse(source).map_err(|err| {
+                    invalid_option(&format!("Invalid selector '{source}': {err}"))
+                })?;
+                Ok(RestrictedSyntax { source: CompactStr::from(source), selector, message })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { restrictions })
+    }
+}
+
+fn invalid_option(message: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid option for no-restricted-syntax: {message}"))
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(NoRestrictedSyntaxConfig::try_from(value).unwrap()))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.restrictions.is_empty() {
+            return;
+        }
+        let tree = ctx.estree();
+        for restriction in &self.restrictions {
+            for node in tree.matches(&restriction.selector) {
+                ctx.diagnostic(no_restricted_syntax_diagnostic(
+                    &restriction.source,
+                    restriction.message.as_deref(),
+                    node.span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "Property > Literal.key", "message": "custom" }])),
+        ),
+        ("for (;;) { break; }", Some(json!(["ForStatement:not(:has(BreakStatement))"]))),
+        ("let x: number = 1;", Some(json!(["TSAnyKeyword"]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (err) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(
+                json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom error message." }]),
+            ),
+        ),
+        (
+            "setTimeout(() => {})",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+                "message": "setTimeout must always be invoked with two arguments."
+            }])),
+        ),
+        ("for (;;) {}", Some(json!(["ForStatement:not(:has(BreakStatement))"]))),
+        ("foo?.bar", Some(json!(["ChainExpression > MemberExpression[optional=true]"]))),
+        ("let x: any = 1;", Some(json!(["TSAnyKeyword"]))),
+        ("enum E { A }", Some(json!(["TSEnumDeclaration"]))),
+        ("a = b; if (c) d();", Some(json!([":statement:not(:has(CallExpression))"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_configuration() {
+    use serde_json::json;
+
+    for config in [
+        json!(["Identifier["]),
+        json!([{ "selector": "CallExpression[" }]),
+        json!([{ "message": "no selector" }]),
+        json!([42]),
+    ] {
+        assert!(NoRestrictedSyntaxConfig::try_from(config).is_err());
+    }
+}
@@ -153,6 +153,7 @@ impl NoUnusedExpressions {
             | Expression::Super(_)
             | Expression::CallExpression(_)
             | Expression::V8IntrinsicExpression(_)
+            | Expression::ErrorExpression(_)
             | Expression::UpdateExpression(_)
             | Expression::TSSatisfiesExpression(_)
             | Expression::YieldExpression(_) => false,
@@ -0,0 +1,915 @@
+use std::{cmp::Ordering, path::Path};
+
+use cow_utils::CowUtils;
+use oxc_ast::ast::{Statement, TSModuleReference};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::import_source::matches_pattern;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, utils::ImportGroup};
+
+fn out_of_order_diagnostic(span: Span, name: &str, other: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` import should occur before import of `{other}`"))
+        .with_label(span)
+}
+
+fn missing_empty_line_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn empty_line_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+fn empty_line_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Rank of each [`ImportType`], indexed by `ImportType as usize`.
+    group_ranks: [usize; ImportType::COUNT],
+    /// Whether `type` is listed in `groups`. Otherwise type imports are ranked by their module.
+    has_type_group: bool,
+    path_groups: Vec<PathGroup>,
+    path_groups_excluded_import_types: Vec<ImportType>,
+    newlines_between: NewlinesBetween,
+    alphabetize: Alphabetize,
+    distinct_group: bool,
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            group_ranks: group_ranks_from_config(&[
+                vec![ImportType::Builtin],
+                vec![ImportType::External],
+                vec![ImportType::Parent],
+                vec![ImportType::Sibling],
+                vec![ImportType::Index],
+            ]),
+            has_type_group: false,
+            path_groups: vec![],
+            path_groups_excluded_import_types: vec![
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Object,
+            ],
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+            distinct_group: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` statements.
+    ///
+    /// Imports are grouped by the kind of module they refer to, and groups are ordered as
+    /// configured by the `groups` option. The kinds are:
+    ///
+    /// - `builtin`: Node.js builtin modules, e.g. `fs` or `node:path`
+    /// - `external`: modules resolved into `node_modules`, or bare module names that could not be resolved
+    /// - `internal`: bare module names resolved outside of `node_modules`, e.g. workspace packages or path aliases
+    /// - `parent`: modules in a parent directory, e.g. `../foo`
+    /// - `sibling`: modules in the same or a child directory, e.g. `./foo`
+    /// - `index`: the index of the current directory, e.g. `./`
+    /// - `object`: TypeScript object imports, e.g. `import log = console.log`
+    /// - `type`: `import type` declarations, only when `type` is listed in `groups`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An inconsistent order of imports makes it harder to see at a glance what a module depends on,
+    /// and leads to noisy diffs when imports are added in different places by different people.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import _ from 'lodash';
+    /// import path from 'path'; // `path` import should occur before import of `lodash`
+    ///
+    /// import foo from './foo';
+    /// import bar from '../bar'; // `../bar` import should occur before import of `./foo`
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import path from 'path';
+    /// import _ from 'lodash';
+    /// import bar from '../bar';
+    /// import foo from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "import/order": ["error", {
+    ///     "groups": ["builtin", "external", ["parent", "sibling"], "index"],
+    ///     "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }],
+    ///     "pathGroupsExcludedImportTypes": ["builtin"],
+    ///     "newlines-between": "always",
+    ///     "distinctGroup": true,
+    ///     "alphabetize": { "order": "asc", "caseInsensitive": true }
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// - `groups`: The order of the import kinds. Kinds nested in an array share the same rank.
+    ///   Omitted kinds are grouped together after all listed kinds.
+    ///   Default: `["builtin", "external", "parent", "sibling", "index"]`.
+    /// - `pathGroups`: `pattern`s that place matching imports relative to a `group`, where `*` matches
+    ///   any sequence of characters, including `/`.
+    ///   `position` is one of `"before"` or `"after"`; when omitted, the import is ranked the same as `group`.
+    /// - `pathGroupsExcludedImportTypes`: Import kinds that are never matched against `pathGroups`.
+    ///   Default: `["builtin", "external", "object"]`.
+    /// - `newlines-between`: One of `"ignore"` (default), `"always"`, `"always-and-inside-groups"` or `"never"`.
+    /// - `distinctGroup`: Whether path groups with a `position` are treated as separate groups
+    ///   when checking `newlines-between`. Default: `true`.
+    /// - `alphabetize`: `order` is one of `"ignore"` (default), `"asc"` or `"desc"`, and sorts imports
+    ///   within a group by module name. `caseInsensitive` defaults to `false`.
+    ///
+    /// The fix reorders whole import statements, moving comments on the lines directly above an
+    /// import and at the end of its line along with it. No fix is offered when other statements or
+    /// detached comments sit between the imports.
+    Order,
+    import,
+    style,
+    fix
+);
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let mut options = OrderConfig::default();
+
+        if let Some(groups) = config.get("groups").and_then(Value::as_array) {
+            let groups = groups
+                .iter()
+                .map(|group| match group {
+                    Value::Array(types) => types
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(ImportType::from_str)
+                        .collect::<Vec<_>>(),
+                    Value::String(name) => ImportType::from_str(name).into_iter().collect(),
+                    _ => vec![],
+                })
+                .collect::<Vec<_>>();
+            options.group_ranks = group_ranks_from_config(&groups);
+            options.has_type_group = groups.iter().flatten().any(|t| *t == ImportType::Type);
+        }
+
+        if let Some(excluded) =
+            config.get("pathGroupsExcludedImportTypes").and_then(Value::as_array)
+        {
+            options.path_groups_excluded_import_types = excluded
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(ImportType::from_str)
+                .collect();
+        }
+
+        if let Some(path_groups) = config.get("pathGroups").and_then(Value::as_array) {
+            options.path_groups = path_groups_from_config(path_groups);
+        }
+
+        if let Some(newlines_between) = config.get("newlines-between").and_then(Value::as_str) {
+            options.newlines_between = NewlinesBetween::from(newlines_between);
+        }
+
+        if let Some(distinct_group) = config.get("distinctGroup").and_then(Value::as_bool) {
+            options.distinct_group = distinct_group;
+        }
+
+        if let Some(alphabetize) = config.get("alphabetize") {
+            options.alphabetize = Alphabetize {
+                order: alphabetize
+                    .get("order")
+                    .and_then(Value::as_str)
+                    .map(AlphabetizeOrder::from)
+                    .unwrap_or_default(),
+                case_insensitive: alphabetize
+                    .get("caseInsensitive")
+                    .and_then(Value::as_bool)
+                    .unwrap_or_default(),
+            };
+        }
+
+        Self(Box::new(options))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let program = ctx.nodes().program();
+
+        let mut imports = vec![];
+        // Whether all imports are adjacent statements, which is required for reordering them.
+        let mut is_contiguous = true;
+        let mut last_import_index = None;
+
+        for (index, statement) in program.body.iter().enumerate() {
+            let Some(import) = self.import_item(statement, ctx) else {
+                continue;
+            };
+            if last_import_index.is_some_and(|last| last + 1 != index) {
+                is_contiguous = false;
+            }
+            last_import_index = Some(index);
+            imports.push(import);
+        }
+
+        if imports.len() < 2 {
+            return;
+        }
+
+        let fix = if is_contiguous { self.build_fix(&imports, ctx) } else { None };
+        let report = |diagnostic: OxcDiagnostic| match &fix {
+            Some((span, text)) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(*span, text.clone()));
+            }
+            None => ctx.diagnostic(diagnostic),
+        };
+
+        for (index, current) in imports.iter().enumerate() {
+            if let Some(previous) =
+                imports[..index].iter().find(|previous| self.compare(previous, current).is_gt())
+            {
+                report(out_of_order_diagnostic(current.span, current.name, previous.name));
+            }
+        }
+
+        if self.newlines_between == NewlinesBetween::Ignore {
+            return;
+        }
+
+        for pair in imports.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+            let empty_lines = count_empty_lines_between(previous.span, current.span, ctx);
+            let is_same_group = self.newline_group(previous) == self.newline_group(current);
+            match self.newlines_between {
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    if !is_same_group && empty_lines == 0 =>
+                {
+                    report(missing_empty_line_diagnostic(previous.span));
+                }
+                NewlinesBetween::Always if is_same_group && empty_lines > 0 => {
+                    report(empty_line_within_group_diagnostic(previous.span));
+                }
+                NewlinesBetween::Never if empty_lines > 0 => {
+                    report(empty_line_between_groups_diagnostic(previous.span));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Order {
+    fn import_item<'a>(
+        &self,
+        statement: &Statement<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Option<ImportItem<'a>> {
+        let (name, import_type) = match statement {
+            Statement::ImportDeclaration(decl) => {
+                let name = decl.source.value.as_str();
+                let import_type = if decl.import_kind.is_type() && self.has_type_group {
+                    ImportType::Type
+                } else {
+                    classify_import(name, ctx)
+                };
+                (name, import_type)
+            }
+            Statement::TSImportEqualsDeclaration(decl) => match &decl.module_reference {
+                TSModuleReference::ExternalModuleReference(reference) => {
+                    let name = reference.expression.value.as_str();
+                    let import_type = if decl.import_kind.is_type() && self.has_type_group {
+                        ImportType::Type
+                    } else {
+                        classify_import(name, ctx)
+                    };
+                    (name, import_type)
+                }
+                reference => (ctx.source_range(reference.span()), ImportType::Object),
+            },
+            _ => return None,
+        };
+
+        let (rank, offset) = self.rank(name, import_type);
+        Some(ImportItem { span: statement.span(), name, rank, offset })
+    }
+
+    fn rank(&self, name: &str, import_type: ImportType) -> (usize, i32) {
+        if !self.path_groups_excluded_import_types.contains(&import_type)
+            && let Some(path_group) = self
+                .path_groups
+                .iter()
+                .find(|path_group| matches_pattern(path_group.pattern.as_str(), name))
+        {
+            return (self.group_ranks[path_group.group as usize], path_group.offset);
+        }
+        (self.group_ranks[import_type as usize], 0)
+    }
+
+    fn newline_group(&self, import: &ImportItem) -> (usize, i32) {
+        if self.distinct_group { (import.rank, import.offset) } else { (import.rank, 0) }
+    }
+
+    fn compare(&self, a: &ImportItem, b: &ImportItem) -> Ordering {
+        (a.rank, a.offset).cmp(&(b.rank, b.offset)).then_with(|| {
+            let ordering = if self.alphabetize.case_insensitive {
+                a.name.cow_to_ascii_lowercase().cmp(&b.name.cow_to_ascii_lowercase())
+            } else {
+                a.name.cmp(b.name)
+            };
+            match self.alphabetize.order {
+                AlphabetizeOrder::Ignore => Ordering::Equal,
+                AlphabetizeOrder::Asc => ordering,
+                AlphabetizeOrder::Desc => ordering.reverse(),
+            }
+        })
+    }
+
+    /// Builds a replacement for all imports sorted into the configured order.
+    ///
+    /// Returns `None` when the imports can not be moved around safely, i.e. when
+    /// there are comments between them which do not belong to any import.
+    fn build_fix(&self, imports: &[ImportItem], ctx: &LintContext) -> Option<(Span, String)> {
+        let source_text = ctx.source_text();
+
+        let mut ranges = Vec::with_capacity(imports.len());
+        for (index, import) in imports.iter().enumerate() {
+            let prev_end = ranges.last().map_or(0, |range: &Span| range.end);
+            let next_start = imports
+                .get(index + 1)
+                .map_or(ctx.nodes().program().span.end, |next| next.span.start);
+            ranges.push(attached_range(import.span, prev_end, next_start, ctx));
+        }
+
+        let separators = ranges
+            .windows(2)
+            .map(|pair| Span::new(pair[0].end, pair[1].start).source_text(source_text))
+            .collect::<Vec<_>>();
+        if separators.iter().any(|separator| !separator.trim().is_empty()) {
+            return None;
+        }
+
+        let mut order = (0..imports.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| self.compare(&imports[*a], &imports[*b]));
+
+        let mut text = String::new();
+        for (position, pair) in order.windows(2).enumerate() {
+            let (previous, current) = (&imports[pair[0]], &imports[pair[1]]);
+            if position == 0 {
+                text.push_str(ranges[pair[0]].source_text(source_text));
+            }
+            let is_same_group = self.newline_group(previous) == self.newline_group(current);
+            let separator = separators[position];
+            text.push_str(match self.newlines_between {
+                NewlinesBetween::Ignore => separator,
+                NewlinesBetween::AlwaysAndInsideGroups
+                    if is_same_group || separator.matches('\n').count() > 1 =>
+                {
+                    separator
+                }
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    if !is_same_group =>
+                {
+                    "\n\n"
+                }
+                _ => "\n",
+            });
+            text.push_str(ranges[pair[1]].source_text(source_text));
+        }
+
+        let span = Span::new(ranges[0].start, ranges[ranges.len() - 1].end);
+        (span.source_text(source_text) != text).then_some((span, text))
+    }
+}
+
+struct ImportItem<'a> {
+    span: Span,
+    name: &'a str,
+    rank: usize,
+    offset: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportType {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    Object,
+    Type,
+    Unknown,
+}
+
+impl ImportType {
+    const COUNT: usize = 9;
+
+    fn from_str(name: &str) -> Option<Self> {
+        match name {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "object" => Some(Self::Object),
+            "type" => Some(Self::Type),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    fn all() -> [Self; Self::COUNT] {
+        [
+            Self::Builtin,
+            Self::External,
+            Self::Internal,
+            Self::Parent,
+            Self::Sibling,
+            Self::Index,
+            Self::Object,
+            Self::Type,
+            Self::Unknown,
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PathGroup {
+    pattern: CompactStr,
+    group: ImportType,
+    /// Position relative to `group`: negative for `before`, positive for `after`.
+    offset: i32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+impl From<&str> for NewlinesBetween {
+    fn from(raw: &str) -> Self {
+        match raw {
+            "always" => Self::Always,
+            "always-and-inside-groups" => Self::AlwaysAndInsideGroups,
+            "never" => Self::Never,
+            _ => Self::Ignore,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Alphabetize {
+    order: AlphabetizeOrder,
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+impl From<&str> for AlphabetizeOrder {
+    fn from(raw: &str) -> Self {
+        match raw {
+            "asc" => Self::Asc,
+            "desc" => Self::Desc,
+            _ => Self::Ignore,
+        }
+    }
+}
+
+/// Import kinds omitted from `groups` share a rank after all listed groups.
+fn group_ranks_from_config(groups: &[Vec<ImportType>]) -> [usize; ImportType::COUNT] {
+    let mut ranks = [groups.len(); ImportType::COUNT];
+    for import_type in ImportType::all() {
+        if let Some(rank) = groups.iter().position(|group| group.contains(&import_type)) {
+            ranks[import_type as usize] = rank;
+        }
+    }
+    ranks
+}
+
+#[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // for `as i32`
+fn path_groups_from_config(path_groups: &[Value]) -> Vec<PathGroup> {
+    let mut result: Vec<PathGroup> = vec![];
+    for path_group in path_groups {
+        let Some(pattern) = path_group.get("pattern").and_then(Value::as_str) else {
+            continue;
+        };
+        let Some(group) =
+            path_group.get("group").and_then(Value::as_str).and_then(ImportType::from_str)
+        else {
+            continue;
+        };
+        let position = path_group.get("position").and_then(Value::as_str);
+        let offset = match position {
+            Some("before") => -1,
+            Some("after") => 1,
+            _ => 0,
+        };
+        result.push(PathGroup { pattern: CompactStr::from(pattern), group, offset });
+    }
+
+    // Path groups listed first sit closer to the start, for both `before` and `after` positions.
+    for group in ImportType::all() {
+        let before = result.iter().filter(|p| p.group == group && p.offset < 0).count() as i32;
+        let mut before_index = 0;
+        let mut after_index = 0;
+        for path_group in result.iter_mut().filter(|p| p.group == group) {
+            match path_group.offset.cmp(&0) {
+                Ordering::Less => {
+                    path_group.offset = before_index - before;
+                    before_index += 1;
+                }
+                Ordering::Greater => {
+                    after_index += 1;
+                    path_group.offset = after_index;
+                }
+                Ordering::Equal => {}
+            }
+        }
+    }
+    result
+}
+
+fn classify_import(name: &str, ctx: &LintContext) -> ImportType {
+    match ImportGroup::new(name) {
+        ImportGroup::Builtin => ImportType::Builtin,
+        ImportGroup::Parent => ImportType::Parent,
+        ImportGroup::Index => ImportType::Index,
+        ImportGroup::Sibling => ImportType::Sibling,
+        group @ (ImportGroup::External | ImportGroup::Unknown) => {
+            if let Some(module) = ctx.module_record().get_loaded_module(name) {
+                if is_in_node_modules(&module.resolved_absolute_path) {
+                    ImportType::External
+                } else {
+                    ImportType::Internal
+                }
+            } else if group == ImportGroup::External {
+                ImportType::External
+            } else {
+                ImportType::Unknown
+            }
+        }
+    }
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|component| component.as_os_str() == "node_modules")
+}
+
+/// Counts the lines between `previous` and `current` that contain only whitespace.
+fn count_empty_lines_between(previous: Span, current: Span, ctx: &LintContext) -> usize {
+    let text = ctx.source_range(Span::new(previous.end, current.start));
+    let lines = text.split('\n').collect::<Vec<_>>();
+    if lines.len() <= 2 {
+        return 0;
+    }
+    lines[1..lines.len() - 1].iter().filter(|line| line.trim().is_empty()).count()
+}
+
+/// Extends the span of an import statement to cover the comments attached to it:
+/// comments on the lines directly above it, and comments after it on the same line.
+#[expect(clippy::cast_possible_truncation)] // for `as u32`
+fn attached_range(span: Span, prev_end: u32, next_start: u32, ctx: &LintContext) -> Span {
+    let source_text = ctx.source_text();
+
+    let mut end = span.end;
+    let line_end = source_text[span.end as usize..]
+        .find('\n')
+        .map_or(source_text.len() as u32, |offset| span.end + offset as u32);
+    if line_end <= next_start {
+        let mut cursor = span.end;
+        for comment in ctx.comments_range(span.end..line_end) {
+            if comment.span.end > line_end
+                || !Span::new(cursor, comment.span.start).source_text(source_text).trim().is_empty()
+            {
+                break;
+            }
+            cursor = comment.span.end;
+        }
+        if Span::new(cursor, line_end).source_text(source_text).trim().is_empty() {
+            end = cursor;
+        }
+    }
+
+    let mut start = span.start;
+    for comment in ctx.comments_range(prev_end..span.start).rev() {
+        let between = Span::new(comment.span.end, start).source_text(source_text);
+        if !between.trim().is_empty() || between.matches('\n').count() > 1 {
+            break;
+        }
+        let line_start = source_text[..comment.span.start as usize]
+            .rfind('\n')
+            .map_or(0, |offset| offset as u32 + 1);
+        if line_start < prev_end
+            || !Span::new(line_start, comment.span.start).source_text(source_text).trim().is_empty()
+        {
+            break;
+        }
+        start = comment.span.start;
+    }
+
+    Span::new(start, end)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"import fs from 'fs';
+            import chai from 'chai';
+            import foo from '../foo';
+            import bar from './bar';
+            import index from './';",
+            None,
+        ),
+        (
+            r"import fs from 'node:fs';
+            import path from 'path';
+            import a from 'a';",
+            None,
+        ),
+        // Omitted groups go last
+        (
+            r"import chai from 'chai';
+            import internal from '@my-alias/internal';",
+            Some(json!([{ "groups": ["external"] }])),
+        ),
+        // Groups sharing a rank
+        (
+            r"import bar from './bar';
+            import foo from '../foo';",
+            Some(json!([{ "groups": [["parent", "sibling"]] }])),
+        ),
+        // Type imports only use the `type` group when it is configured
+        (
+            r"import type { Foo } from './foo';
+            import chai from 'chai';",
+            Some(json!([{ "groups": ["type", "external", "sibling"] }])),
+        ),
+        (
+            r"import chai from 'chai';
+            import type { Foo } from './foo';",
+            None,
+        ),
+        // Imports separated by other statements are still checked, but order is respected here
+        (
+            r"import fs from 'fs';
+            const x = 1;
+            import foo from './foo';",
+            None,
+        ),
+        // pathGroups
+        (
+            r"import chai from 'chai';
+            import app from '@app/foo';
+            import foo from './foo';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+            }])),
+        ),
+        (
+            r"import app from '@app/foo';
+            import fs from 'fs';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        // External imports are excluded from path groups by default
+        (
+            r"import app from '@app/foo';
+            import chai from 'chai';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }]
+            }])),
+        ),
+        // newlines-between
+        (
+            r"import fs from 'fs';
+
+            import chai from 'chai';
+            import _ from 'lodash';
+
+            import foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import chai from 'chai';
+
+            import _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r"import fs from 'fs';
+            import chai from 'chai';
+            import foo from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        // alphabetize
+        (
+            r"import a from 'a';
+            import B from 'B';
+            import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"import c from 'c';
+            import b from 'b';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        // TypeScript import equals
+        (
+            r"import fs = require('fs');
+            import foo = require('./foo');
+            import log = console.log;",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r"import foo from './foo';
+            import fs from 'fs';",
+            None,
+        ),
+        (
+            r"import bar from '../bar';
+            import chai from 'chai';
+            import path from 'node:path';",
+            None,
+        ),
+        (
+            r"import index from './';
+            import sibling from './sibling';",
+            None,
+        ),
+        (
+            r"import chai from 'chai';
+            import type { Foo } from './foo';
+            import fs from 'fs';",
+            Some(json!([{ "groups": ["builtin", "external", "sibling", "type"] }])),
+        ),
+        (
+            r"import app from '@app/foo';
+            import chai from 'chai';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+            }])),
+        ),
+        (
+            r"import fs from 'fs';
+            import chai from 'chai';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import chai from 'chai';
+
+            import _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+            import chai from 'chai';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"import b from 'b';
+            import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import foo from './foo';
+            const x = 1;
+            import fs from 'fs';",
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import foo from './foo';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport foo from './foo';",
+            None,
+        ),
+        (
+            "// header\n\n// attached to foo\nimport foo from './foo'; // trailing foo\nimport fs from 'fs'; /* trailing fs */\n\nfoo(fs);",
+            "// header\n\nimport fs from 'fs'; /* trailing fs */\n// attached to foo\nimport foo from './foo'; // trailing foo\n\nfoo(fs);",
+            None,
+        ),
+        (
+            "import foo from './foo';\nimport chai from 'chai';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport chai from 'chai';\n\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';\nimport foo from './foo';",
+            "import fs from 'fs';\nimport path from 'path';\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import c from 'c';\nimport a from 'a';\nimport b from 'b';",
+            "import a from 'a';\nimport b from 'b';\nimport c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        // Detached comments between imports are not moved
+        (
+            "import foo from './foo';\n\n// detached\n\nimport fs from 'fs';",
+            "import foo from './foo';\n\n// detached\n\nimport fs from 'fs';",
+            None,
+        ),
+        // Imports separated by other statements are not moved
+        (
+            "import foo from './foo';\nconst x = 1;\nimport fs from 'fs';",
+            "import foo from './foo';\nconst x = 1;\nimport fs from 'fs';",
+            None,
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
+
+#[test]
+fn test_resolved_import_types() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    // `order-workspace-package` refers to the package the linted file belongs to, so it resolves
+    // outside of `node_modules` and is `internal`, while `chai` is resolved inside `node_modules`.
+    let pass = vec![
+        (
+            r"import chai from 'chai';
+            import foo from 'order-workspace-package/foo';",
+            Some(json!([{ "groups": ["external", "internal"] }])),
+        ),
+        // Unresolved bare module names are `external`
+        (
+            r"import chai from 'chai';
+            import missing from 'missing-package';
+            import foo from 'order-workspace-package/foo';",
+            Some(json!([{ "groups": ["external", "internal"] }])),
+        ),
+    ];
+
+    let fail = vec![(
+        r"import foo from 'order-workspace-package/foo';
+        import chai from 'chai';",
+        Some(json!([{ "groups": ["external", "internal"] }])),
+    )];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail)
+        .change_rule_path("order/index.ts")
+        .with_import_plugin(true)
+        .intentionally_allow_no_fix_tests()
+        .with_snapshot_suffix("resolved")
+        .test_and_snapshot();
+}
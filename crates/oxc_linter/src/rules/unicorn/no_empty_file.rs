@@ -1,10 +1,10 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_partial_loader::PARTIAL_LOADER_EXTENSIONS;
 use oxc_span::Span;
 
 use crate::{
     context::{ContextHost, LintContext},
-    loader::LINT_PARTIAL_LOADER_EXTENSIONS,
     rule::Rule,
     utils::is_empty_stmt,
 };
@@ -62,9 +62,9 @@ impl Rule for NoEmptyFile {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.file_path().extension().is_some_and(|ext| {
-            !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext.to_string_lossy().as_ref())
-        })
+        ctx.file_path()
+            .extension()
+            .is_some_and(|ext| !PARTIAL_LOADER_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
     }
 }
 
@@ -18,6 +18,7 @@ use smallvec::SmallVec;
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
+use oxc_partial_loader::{JavaScriptSource, PARTIAL_LOADER_EXTENSIONS, PartialLoader};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
@@ -28,12 +29,8 @@ use crate::lsp::MessageWithPosition;
 #[cfg(test)]
 use crate::fixer::{Message, PossibleFixes};
 use crate::{
-    Fixer, Linter,
-    context::ContextSubHost,
-    disable_directives::DisableDirectives,
-    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
-    module_record::ModuleRecord,
-    utils::read_to_arena_str,
+    Fixer, Linter, context::ContextSubHost, disable_directives::DisableDirectives,
+    module_record::ModuleRecord, utils::read_to_arena_str,
 };
 
 use super::LintServiceOptions;
@@ -77,7 +74,7 @@ struct ModuleProcessOutput<'alloc_pool> {
 struct ProcessedModule<'alloc_pool> {
     /// Module records of source sections, or diagnostics if parsing failed on that section.
     ///
-    /// Modules with special extensions such as .vue could contain multiple source sections (see `PartialLoader::PartialLoader`).
+    /// Modules with special extensions such as .vue could contain multiple source sections (see [`PartialLoader`]).
     /// Plain ts/js modules have one section. Using `SmallVec` to avoid allocations for plain modules.
     section_module_records: SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]>,
 
@@ -125,6 +122,8 @@ struct SectionContent<'a> {
     /// None if section parsing failed. The corresponding item with the same index in
     /// `ProcessedModule.section_module_records` would be `Err(Vec<OxcDiagnostic>)`.
     semantic: Option<Semantic<'a>>,
+    /// Syntax errors the parser recovered from. They are reported along with the lint diagnostics.
+    recovered_errors: Vec<OxcDiagnostic>,
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -363,7 +362,7 @@ impl Runtime {
     ) -> Option<Result<(SourceType, &'a str), Error>> {
         let source_type = SourceType::from_path(path);
         let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext));
+            source_type.as_ref().is_err_and(|_| !PARTIAL_LOADER_EXTENSIONS.contains(&ext));
         if not_supported_yet {
             return None;
         }
@@ -625,17 +624,21 @@ impl Runtime {
                         dep.section_contents.len()
                     );
 
+                    let mut recovered_errors = vec![];
                     let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                         .section_module_records
                         .into_iter()
                         .zip(dep.section_contents.drain(..))
                         .filter_map(|(record_result, section)| match record_result {
-                            Ok(module_record) => Some(ContextSubHost::new_with_framework_options(
-                                section.semantic.unwrap(),
-                                Arc::clone(&module_record),
-                                section.source.start,
-                                section.source.framework_options,
-                            )),
+                            Ok(module_record) => {
+                                recovered_errors.extend(section.recovered_errors);
+                                Some(ContextSubHost::new_with_framework_options(
+                                    section.semantic.unwrap(),
+                                    Arc::clone(&module_record),
+                                    section.source.start,
+                                    section.source.framework_options,
+                                ))
+                            }
                             Err(messages) => {
                                 if !messages.is_empty() {
                                     let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -651,6 +654,17 @@ impl Runtime {
                         })
                         .collect();
 
+                    let has_syntax_errors = !recovered_errors.is_empty();
+                    if has_syntax_errors {
+                        let diagnostics = DiagnosticService::wrap_diagnostics(
+                            &me.cwd,
+                            path,
+                            dep.source_text,
+                            recovered_errors,
+                        );
+                        tx_error.send((path.to_path_buf(), diagnostics)).unwrap();
+                    }
+
                     if context_sub_hosts.is_empty() {
                         return;
                     }
@@ -669,7 +683,8 @@ impl Runtime {
                             .insert(path.to_path_buf(), disable_directives);
                     }
 
-                    if me.linter.options().fix.is_some() {
+                    // Fixes are not applied to files with syntax errors
+                    if me.linter.options().fix.is_some() && !has_syntax_errors {
                         let fix_result = Fixer::new(dep.source_text, messages).fix();
                         if fix_result.fixed {
                             // write to file, replacing only the changed part
@@ -740,6 +755,15 @@ impl Runtime {
                             .zip(section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
                                 Ok(module_record) => {
+                                    messages.lock().unwrap().extend(
+                                        section.recovered_errors.into_iter().map(|diagnostic| {
+                                            oxc_diagnostic_to_message_with_position(
+                                                diagnostic,
+                                                source_text,
+                                                rope,
+                                            )
+                                        }),
+                                    );
                                     Some(ContextSubHost::new_with_framework_options(
                                         section.semantic.unwrap(),
                                         Arc::clone(&module_record),
@@ -816,12 +840,20 @@ impl Runtime {
                             .into_iter()
                             .zip(section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => Some(ContextSubHost::new_with_framework_options(
-                                    section.semantic.unwrap(),
-                                    Arc::clone(&module_record),
-                                    section.source.start,
-                                    section.source.framework_options
-                                )),
+                                Ok(module_record) => {
+                                    messages.lock().unwrap().extend(
+                                        section
+                                            .recovered_errors
+                                            .into_iter()
+                                            .map(|err| Message::new(err, PossibleFixes::None)),
+                                    );
+                                    Some(ContextSubHost::new_with_framework_options(
+                                        section.semantic.unwrap(),
+                                        Arc::clone(&module_record),
+                                        section.source.start,
+                                        section.source.framework_options
+                                    ))
+                                }
                                 Err(errors) => {
                                     if !errors.is_empty() {
                                         messages
@@ -879,7 +911,7 @@ impl Runtime {
 
         if SourceType::from_path(Path::new(path))
             .as_ref()
-            .is_err_and(|_| !LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext))
+            .is_err_and(|_| !PARTIAL_LOADER_EXTENSIONS.contains(&ext))
         {
             return None;
         }
@@ -978,19 +1010,24 @@ impl Runtime {
                 section_source.source_type,
                 check_syntax_errors,
             ) {
-                Ok((record, semantic)) => {
+                Ok((record, semantic, recovered_errors)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
+                            recovered_errors,
                         });
                     }
                 }
                 Err(err) => {
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
-                        sections.push(SectionContent { source: section_source, semantic: None });
+                        sections.push(SectionContent {
+                            source: section_source,
+                            semantic: None,
+                            recovered_errors: vec![],
+                        });
                     }
                 }
             }
@@ -1005,17 +1042,26 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
-    ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+    ) -> Result<(ResolvedModuleRecord, Semantic<'a>, Vec<OxcDiagnostic>), Vec<OxcDiagnostic>> {
+        let parse = |source_type| {
+            Parser::new(allocator, source_text, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    allow_return_outside_function: true,
+                    // Keep linting the rest of the file while it is being edited
+                    error_recovery: true,
+                    ..ParseOptions::default()
+                })
+                .parse()
+        };
+        let mut ret = parse(source_type);
+        // JavaScript files with a `@flow` pragma which fail to parse are parsed again as Flow
+        if ret.is_flow_language {
+            ret = parse(source_type.with_flow(true));
+        }
 
-        if !ret.errors.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.errors });
+        if ret.panicked {
+            return Err(ret.errors);
         }
 
         let semantic_ret = SemanticBuilder::new()
@@ -1051,6 +1097,6 @@ impl Runtime {
                 })
                 .collect();
         }
-        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
+        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, ret.errors))
     }
 }
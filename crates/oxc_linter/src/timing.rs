@@ -0,0 +1,155 @@
+use std::{borrow::Cow, sync::Mutex, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+/// Plugin name under which time spent running JS plugins is recorded.
+pub const JS_PLUGINS_TIMING: &str = "js-plugins";
+/// Plugin name under which time spent running type-aware rules is recorded.
+pub const TSGOLINT_TIMING: &str = "tsgolint";
+
+/// Total duration and number of invocations, keyed by plugin and rule name.
+type TimingsMap = FxHashMap<(&'static str, &'static str), (Duration, u64)>;
+
+/// Time spent in each rule, aggregated over all linted files.
+///
+/// Shared between the threads linting files. Each file's timings are recorded with a single
+/// [`RuleTimings::record_all`] call, so the lock is taken once per file rather than once per rule.
+#[derive(Debug, Default)]
+pub struct RuleTimings {
+    timings: Mutex<TimingsMap>,
+}
+
+/// Total time spent in a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// Name of the plugin, [`JS_PLUGINS_TIMING`] or [`TSGOLINT_TIMING`].
+    pub plugin_name: &'static str,
+    /// Name of the rule. Empty for JS plugins and tsgolint, which run all their rules at once.
+    pub rule_name: &'static str,
+    pub duration: Duration,
+    /// Number of times the rule ran, once per file (or per `<script>` block).
+    pub invocations: u64,
+}
+
+impl RuleTiming {
+    /// `plugin/rule`, or just the plugin name for JS plugins and tsgolint.
+    pub fn name(&self) -> Cow<'static, str> {
+        if self.rule_name.is_empty() {
+            Cow::Borrowed(self.plugin_name)
+        } else {
+            Cow::Owned(format!("{}/{}", self.plugin_name, self.rule_name))
+        }
+    }
+}
+
+impl RuleTimings {
+    /// Record a single run of a rule.
+    pub fn record(&self, plugin_name: &'static str, rule_name: &'static str, duration: Duration) {
+        self.record_all([(plugin_name, rule_name, duration)]);
+    }
+
+    /// Record a batch of rule runs, e.g. all rules which ran on a file.
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    pub fn record_all(
+        &self,
+        runs: impl IntoIterator<Item = (&'static str, &'static str, Duration)>,
+    ) {
+        let mut timings = self.timings.lock().unwrap();
+        for (plugin_name, rule_name, duration) in runs {
+            let (total, invocations) = timings.entry((plugin_name, rule_name)).or_default();
+            *total += duration;
+            *invocations += 1;
+        }
+    }
+
+    /// Total time spent in all rules.
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    pub fn total(&self) -> Duration {
+        self.timings.lock().unwrap().values().map(|(duration, _)| *duration).sum()
+    }
+
+    /// Timings of all rules which ran, slowest first.
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    pub fn slowest(&self) -> Vec<RuleTiming> {
+        let mut timings = self
+            .timings
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(&(plugin_name, rule_name), &(duration, invocations))| RuleTiming {
+                plugin_name,
+                rule_name,
+                duration,
+                invocations,
+            })
+            .collect::<Vec<_>>();
+        timings.sort_unstable_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| (a.plugin_name, a.rule_name).cmp(&(b.plugin_name, b.rule_name)))
+        });
+        timings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread, time::Duration};
+
+    use super::{RuleTiming, RuleTimings, TSGOLINT_TIMING};
+
+    #[test]
+    fn aggregates_across_threads() {
+        let timings = Arc::new(RuleTimings::default());
+        let handles = std::iter::repeat_with(|| {
+            let timings = Arc::clone(&timings);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    timings.record_all([
+                        ("eslint", "no-debugger", Duration::from_micros(1)),
+                        ("unicorn", "no-null", Duration::from_micros(3)),
+                    ]);
+                }
+            })
+        })
+        .take(4)
+        .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        timings.record(TSGOLINT_TIMING, "", Duration::from_micros(500));
+
+        assert_eq!(timings.total(), Duration::from_micros(2100));
+        assert_eq!(
+            timings.slowest(),
+            [
+                RuleTiming {
+                    plugin_name: "unicorn",
+                    rule_name: "no-null",
+                    duration: Duration::from_micros(1200),
+                    invocations: 400,
+                },
+                RuleTiming {
+                    plugin_name: "tsgolint",
+                    rule_name: "",
+                    duration: Duration::from_micros(500),
+                    invocations: 1,
+                },
+                RuleTiming {
+                    plugin_name: "eslint",
+                    rule_name: "no-debugger",
+                    duration: Duration::from_micros(400),
+                    invocations: 400,
+                },
+            ]
+        );
+        assert_eq!(timings.slowest()[0].name(), "unicorn/no-null");
+        assert_eq!(timings.slowest()[1].name(), "tsgolint");
+    }
+}
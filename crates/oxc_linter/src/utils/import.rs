@@ -0,0 +1,53 @@
+use oxc_resolver::NODEJS_BUILTINS;
+
+/// The kind of module an import specifier refers to, judging by the specifier alone.
+///
+/// Shared by `import/order` and the language server's organize imports, in the order they sort
+/// imports in by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportGroup {
+    /// `fs`, `node:path`, `fs/promises`
+    Builtin,
+    /// `react`, `lodash/get`, `@scope/package`
+    External,
+    /// `..`, `../module`
+    Parent,
+    /// `./module`
+    Sibling,
+    /// `.`, `./`, `./index`, `./index.js`
+    Index,
+    /// Everything else, e.g. aliases like `~/module`
+    Unknown,
+}
+
+impl ImportGroup {
+    pub fn new(specifier: &str) -> Self {
+        if is_builtin(specifier) {
+            Self::Builtin
+        } else if matches!(specifier, ".." | "../") || specifier.starts_with("../") {
+            Self::Parent
+        } else if matches!(specifier, "." | "./" | "./index") || specifier.starts_with("./index.") {
+            Self::Index
+        } else if specifier.starts_with("./") {
+            Self::Sibling
+        } else if is_external_looking_name(specifier) {
+            Self::External
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+fn is_builtin(specifier: &str) -> bool {
+    if specifier.starts_with("node:") {
+        return true;
+    }
+    let base = specifier.split_once('/').map_or(specifier, |(base, _)| base);
+    NODEJS_BUILTINS.binary_search(&base).is_ok()
+}
+
+/// `foo`, `foo/bar` or `@scope/foo`
+fn is_external_looking_name(specifier: &str) -> bool {
+    let specifier = specifier.strip_prefix('@').unwrap_or(specifier);
+    specifier.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
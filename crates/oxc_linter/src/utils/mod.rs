@@ -12,6 +12,7 @@ use oxc_allocator::Allocator;
 mod comment;
 mod config;
 mod express;
+mod import;
 mod jest;
 mod jsdoc;
 mod nextjs;
@@ -25,8 +26,8 @@ mod vitest;
 mod vue;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
-    react_perf::*, regex::*, unicorn::*, url::*, vitest::*, vue::*,
+    comment::*, config::*, express::*, import::*, jest::*, jsdoc::*, nextjs::*, promise::*,
+    react::*, react_perf::*, regex::*, unicorn::*, url::*, vitest::*, vue::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -1,10 +1,14 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+};
 
 use ignore::{DirEntry, overrides::Override};
-use oxc_linter::LINTABLE_EXTENSIONS;
 
-use crate::cli::IgnoreOptions;
+use crate::LINTABLE_EXTENSIONS;
 
+/// The file extensions to include when walking.
 #[derive(Debug, Clone)]
 pub struct Extensions(pub Vec<&'static str>);
 
@@ -14,15 +18,21 @@ impl Default for Extensions {
     }
 }
 
+type PathFilter = Arc<dyn Fn(&Path) -> bool + Send + Sync>;
+
+/// Finds the files to lint, the same way for `oxlint` and the language server.
 pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// Only include files for which this returns `true`, e.g. changed files.
+    filter: Option<PathFilter>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    filter: Option<PathFilter>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +41,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            filter: self.filter.clone(),
         })
     }
 }
@@ -39,6 +50,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    filter: Option<PathFilter>,
 }
 
 impl Drop for WalkCollector {
@@ -52,7 +64,9 @@ impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if Walk::is_wanted_entry(&entry, &self.extensions) {
+                if Walk::is_wanted_entry(&entry, &self.extensions)
+                    && self.filter.as_ref().is_none_or(|filter| filter(entry.path()))
+                {
                     self.paths.push(entry.path().as_os_str().into());
                 }
                 ignore::WalkState::Continue
@@ -61,12 +75,19 @@ impl ignore::ParallelVisitor for WalkCollector {
         }
     }
 }
+
 impl Walk {
+    /// Walk `paths`, skipping files ignored by `ignore_path` files and `override_builder`.
+    /// Pass `None` for both to skip no files, as with `--no-ignore`.
+    ///
     /// Will not canonicalize paths.
+    ///
     /// # Panics
+    ///
+    /// Panics if `paths` is empty.
     pub fn new(
         paths: &[PathBuf],
-        options: &IgnoreOptions,
+        ignore_path: Option<&OsStr>,
         override_builder: Option<Override>,
     ) -> Self {
         assert!(!paths.is_empty(), "At least one path must be provided to Walk::new");
@@ -84,33 +105,39 @@ impl Walk {
             }
         }
 
-        if !options.no_ignore {
-            inner.add_custom_ignore_filename(&options.ignore_path);
-
-            if let Some(override_builder) = override_builder {
-                inner.overrides(override_builder);
-            }
+        if let Some(ignore_path) = ignore_path {
+            inner.add_custom_ignore_filename(ignore_path);
+        }
+        if let Some(override_builder) = override_builder {
+            inner.overrides(override_builder);
         }
 
         let inner =
             inner.ignore(false).git_global(false).follow_links(true).hidden(false).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), filter: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder { sender, extensions: self.extensions, filter: self.filter };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
-    #[cfg_attr(not(test), expect(dead_code))]
+    #[must_use]
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
         self
     }
 
+    /// Only include files for which `filter` returns `true`.
+    #[must_use]
+    pub fn with_filter(mut self, filter: impl Fn(&Path) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
@@ -128,35 +155,41 @@ impl Walk {
 
 #[cfg(test)]
 mod test {
-    use std::{env, ffi::OsString, path::Path};
+    use std::{env, ffi::OsStr, path::Path};
 
     use ignore::overrides::OverrideBuilder;
 
     use super::{Extensions, Walk};
-    use crate::cli::IgnoreOptions;
 
     #[test]
     fn test_walk_with_extensions() {
         let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
         let fixtures = vec![fixture.clone()];
-        let ignore_options = IgnoreOptions {
-            no_ignore: false,
-            ignore_path: OsString::from(".gitignore"),
-            ignore_pattern: vec![],
-        };
-
         let override_builder = OverrideBuilder::new("/").build().unwrap();
 
-        let mut paths = Walk::new(&fixtures, &ignore_options, Some(override_builder))
-            .with_extensions(Extensions(["js", "vue"].to_vec()))
-            .paths()
-            .into_iter()
-            .map(|path| {
-                Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string()
-            })
-            .collect::<Vec<_>>();
+        let mut paths =
+            Walk::new(&fixtures, Some(OsStr::new(".gitignore")), Some(override_builder))
+                .with_extensions(Extensions(["js", "vue"].to_vec()))
+                .paths()
+                .into_iter()
+                .map(|path| {
+                    Path::new(&path).strip_prefix(&fixture).unwrap().to_string_lossy().to_string()
+                })
+                .collect::<Vec<_>>();
         paths.sort();
 
         assert_eq!(paths, vec!["bar.vue", "foo.js"]);
     }
+
+    #[test]
+    fn test_walk_with_filter() {
+        let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
+        let paths = Walk::new(&[fixture], None, None)
+            .with_extensions(Extensions(["js", "vue"].to_vec()))
+            .with_filter(|path| path.extension().is_some_and(|extension| extension == "js"))
+            .paths();
+
+        assert_eq!(paths.len(), 1);
+        assert!(Path::new(&paths[0]).ends_with("foo.js"));
+    }
 }
@@ -6,7 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
 
 use crate::{
-    Context, ParserImpl, diagnostics,
+    Context, ParserImpl, Token as PublicToken, TokenKind, diagnostics,
     error_handler::FatalError,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
 };
@@ -17,6 +17,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_len: usize,
     fatal_error: Option<FatalError>,
 }
 
@@ -101,6 +102,7 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
@@ -108,10 +110,29 @@ impl<'a> ParserImpl<'a> {
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the current token before it is consumed, if [`ParseOptions::tokens`] is enabled.
+    ///
+    /// [`ParseOptions::tokens`]: crate::ParseOptions::tokens
+    #[inline]
+    fn collect_token(&mut self) {
+        if self.options.tokens && !self.at(Kind::Eof) {
+            let token = PublicToken::new(self.cur_kind(), self.token.span(), self.source_text);
+            self.tokens.push(token);
+        }
+    }
+
+    /// Mark the most recently consumed token as a JSX identifier.
+    pub(crate) fn mark_jsx_identifier_token(&mut self) {
+        if let Some(token) = self.tokens.last_mut() {
+            token.kind = TokenKind::JSXIdentifier;
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     #[must_use = "Use `bump` instead of `eat` if you are ignoring the return value"]
@@ -277,6 +298,7 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
         }
     }
@@ -287,28 +309,54 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            tokens_len,
+            fatal_error,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.tokens.truncate(tokens_len);
         self.fatal_error = fatal_error;
     }
 
+    /// Rewind to `checkpoint` after a fatal error, in order to recover from it.
+    ///
+    /// Unlike [`Self::rewind`], the errors reported between `checkpoint` and the fatal error are kept.
+    /// Returns the fatal error, so it can be reported as a normal error.
+    pub(crate) fn rewind_after_fatal_error(
+        &mut self,
+        checkpoint: ParserCheckpoint<'a>,
+    ) -> Option<OxcDiagnostic> {
+        let FatalError { error, errors_len } = self.fatal_error.take()?;
+        self.errors.truncate(errors_len);
+        let errors = self.errors.split_off(checkpoint.errors_pos.min(errors_len));
+        self.rewind(checkpoint);
+        self.errors.extend(errors);
+        Some(error)
+    }
+
     pub(crate) fn try_parse<T>(
         &mut self,
         func: impl FnOnce(&mut ParserImpl<'a>) -> T,
     ) -> Option<T> {
         let checkpoint = self.checkpoint_with_error_recovery();
         let ctx = self.ctx;
+        self.state.speculation_depth += 1;
         let node = func(self);
+        self.state.speculation_depth -= 1;
         if self.fatal_error.is_none() {
             Some(node)
         } else {
@@ -320,7 +368,9 @@ impl<'a> ParserImpl<'a> {
 
     pub(crate) fn lookahead<U>(&mut self, predicate: impl Fn(&mut ParserImpl<'a>) -> U) -> U {
         let checkpoint = self.checkpoint();
+        self.state.speculation_depth += 1;
         let answer = predicate(self);
+        self.state.speculation_depth -= 1;
         self.rewind(checkpoint);
         answer
     }
@@ -90,6 +90,11 @@ pub fn unexpected_end(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected end of file").with_label(span)
 }
 
+#[cold]
+pub fn expression_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expression expected").with_label(span.label("expression expected"))
+}
+
 #[cold]
 pub fn unexpected_jsx_end(span: Span, a: char, b: &str) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Unexpected token. Did you mean `{{'{a}'}}` or `&{b};`?"))
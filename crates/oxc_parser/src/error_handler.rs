@@ -5,6 +5,15 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use crate::{ParserImpl, diagnostics, lexer::Kind};
 
+/// Where error recovery stops skipping invalid code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryBoundary {
+    /// End of a statement.
+    Statement,
+    /// End of a class element.
+    ClassElement,
+}
+
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
 pub struct FatalError {
@@ -70,4 +79,133 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn has_fatal_error(&self) -> bool {
         matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) || self.fatal_error.is_some()
     }
+
+    /// Parse with `parse`, and if it hits a fatal error, rewind and parse with `skip` instead.
+    ///
+    /// `skip` must skip the invalid code, and return a placeholder for it.
+    /// The fatal error is reported as a normal error, so parsing continues after the skipped code.
+    ///
+    /// Only recovers when [`ParseOptions::error_recovery`] is enabled and not within a speculative parse.
+    ///
+    /// [`ParseOptions::error_recovery`]: crate::ParseOptions::error_recovery
+    pub(crate) fn parse_with_recovery<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> T,
+        skip: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        if !self.options.error_recovery || self.state.speculation_depth > 0 {
+            return parse(self);
+        }
+
+        let checkpoint = self.checkpoint_with_error_recovery();
+        let ctx = self.ctx;
+        let node = parse(self);
+        if self.fatal_error.is_none() {
+            return node;
+        }
+
+        self.ctx = ctx;
+        let Some(error) = self.rewind_after_fatal_error(checkpoint) else { return node };
+        let lexer_errors_len = self.lexer.errors.len();
+        let node = skip(self);
+        // Lexer errors in the skipped code are reported again when skipping it,
+        // and the fatal error is likely caused by them, e.g. by an invalid character.
+        if self.lexer.errors.len() == lexer_errors_len {
+            self.error(error);
+        }
+        node
+    }
+
+    /// Error recovery: skip tokens up to `boundary`.
+    ///
+    /// Brackets are skipped as a whole. Skipping stops after a `;`, or before a `}` closing the enclosing block,
+    /// or at the end of file. A statement also ends before a keyword on a new line which starts a statement,
+    /// and a class element ends after its body.
+    ///
+    /// At least one token is skipped, so that the caller makes progress.
+    pub(crate) fn skip_to_recovery_boundary(&mut self, boundary: RecoveryBoundary) {
+        // Kinds of the open brackets, `TemplateHead` for a template literal substitution.
+        let mut brackets = vec![];
+        let mut first = true;
+        loop {
+            let token = self.cur_token();
+            let kind = token.kind();
+            match kind {
+                Kind::Eof => return,
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => {
+                    brackets.push(kind);
+                }
+                Kind::RCurly => {
+                    if brackets.last() == Some(&Kind::TemplateHead) {
+                        self.re_lex_template_substitution_tail();
+                        if self.at(Kind::TemplateTail) {
+                            brackets.pop();
+                        }
+                    } else if let Some(index) = brackets.iter().rposition(|&k| k == Kind::LCurly) {
+                        // Also closes brackets left open inside the block, e.g. `{ foo( }`
+                        brackets.truncate(index);
+                        if brackets.is_empty() && boundary == RecoveryBoundary::ClassElement {
+                            self.bump_any();
+                            return;
+                        }
+                    } else if !first {
+                        return;
+                    }
+                }
+                Kind::RParen | Kind::RBrack => {
+                    let open = if kind == Kind::RParen { Kind::LParen } else { Kind::LBrack };
+                    if brackets.last() == Some(&open) {
+                        brackets.pop();
+                    }
+                }
+                Kind::Semicolon if brackets.is_empty() => {
+                    self.bump_any();
+                    return;
+                }
+                _ if !first
+                    && boundary == RecoveryBoundary::Statement
+                    && token.is_on_new_line()
+                    && Self::is_recovery_statement_start(kind, brackets.last().copied()) =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+            self.bump_any();
+            first = false;
+        }
+    }
+
+    /// Whether a keyword of `kind` on a new line starts a new statement,
+    /// given the innermost bracket which is still open.
+    ///
+    /// Within unclosed `(` or `[`, only keywords which cannot appear in an expression are considered,
+    /// e.g. `foo(` followed by `const x = 1;` on the next line.
+    fn is_recovery_statement_start(kind: Kind, open_bracket: Option<Kind>) -> bool {
+        let is_statement_keyword = matches!(
+            kind,
+            Kind::Var
+                | Kind::Const
+                | Kind::If
+                | Kind::For
+                | Kind::While
+                | Kind::Do
+                | Kind::Return
+                | Kind::Throw
+                | Kind::Try
+                | Kind::Switch
+                | Kind::Export
+                | Kind::Break
+                | Kind::Continue
+                | Kind::Debugger
+        );
+        match open_bracket {
+            None => {
+                is_statement_keyword
+                    || matches!(kind, Kind::Let | Kind::Function | Kind::Class | Kind::Import)
+            }
+            Some(Kind::LParen | Kind::LBrack) => is_statement_keyword,
+            Some(_) => false,
+        }
+    }
 }
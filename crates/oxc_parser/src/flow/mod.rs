@@ -0,0 +1,207 @@
+//! [Flow](https://flow.org) syntax, enabled by [`SourceType::flow`](oxc_span::SourceType::flow).
+//!
+//! Flow is parsed on top of the TypeScript parser, so only the syntax which TypeScript does not
+//! share lives here. Every Flow construct is mapped to the closest TypeScript AST node,
+//! e.g. `(x: T)` casts become [`TSAsExpression`] and `opaque type` becomes [`TSTypeAliasDeclaration`].
+//! The program keeps its Flow source type, so TypeScript-only checks are not applied to it.
+
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ParserImpl, StatementContext, diagnostics,
+    lexer::Kind,
+    modifiers::{Modifier, ModifierFlags, ModifierKind, Modifiers},
+};
+
+impl<'a> ParserImpl<'a> {
+    /// Check if the parser is at `opaque type`.
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.source_type.is_flow()
+            && self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.lexer.peek_token().kind() == Kind::Type
+    }
+
+    /// `opaque type T: Super = Type;`
+    ///
+    /// The supertype is dropped, unless the alias is declared without a body:
+    /// `declare opaque type T: Super;`
+    pub(crate) fn parse_flow_opaque_type_alias_declaration(
+        &mut self,
+        span: u32,
+        modifiers: &Modifiers<'a>,
+    ) -> Declaration<'a> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type);
+
+        let id = self.parse_binding_identifier();
+        let params = self.parse_ts_type_parameters();
+        let supertype = self.eat(Kind::Colon).then(|| self.parse_ts_type());
+        let ty = if modifiers.contains_declare() && !self.at(Kind::Eq) {
+            supertype.unwrap_or_else(|| {
+                self.ast.ts_type_unknown_keyword(Span::empty(self.prev_token_end))
+            })
+        } else {
+            self.expect(Kind::Eq);
+            self.parse_ts_type()
+        };
+
+        self.asi();
+        let span = self.end_span(span);
+
+        self.verify_modifiers(
+            modifiers,
+            ModifierFlags::DECLARE,
+            diagnostics::modifier_cannot_be_used_here,
+        );
+
+        self.ast.declaration_ts_type_alias(span, id, params, ty, modifiers.contains_declare())
+    }
+
+    /// Check if the parser is at `declare export`.
+    pub(crate) fn at_flow_declare_export(&mut self) -> bool {
+        if !self.source_type.is_flow() || !self.at(Kind::Declare) {
+            return false;
+        }
+        let token = self.lexer.peek_token();
+        token.kind() == Kind::Export && !token.is_on_new_line()
+    }
+
+    /// `declare export function f(): void;`, the same as `export declare function f(): void;`
+    pub(crate) fn parse_flow_declare_export_declaration(
+        &mut self,
+        span: u32,
+        stmt_ctx: StatementContext,
+    ) -> Statement<'a> {
+        let declare = Modifier::new(self.cur_token().span(), ModifierKind::Declare);
+        self.bump_any(); // bump `declare`
+        self.expect(Kind::Export);
+
+        let modifiers = Modifiers::new(Some(self.ast.vec1(declare)), ModifierFlags::DECLARE);
+        let reserved_ctx = self.ctx;
+        self.ctx = self.ctx.and_ambient(true);
+        let declaration = self.parse_declaration(self.start_span(), &modifiers, self.ast.vec());
+        self.ctx = reserved_ctx;
+
+        let export_named_decl = self.ast.alloc_export_named_declaration(
+            self.end_span(span),
+            Some(declaration),
+            self.ast.vec(),
+            None,
+            ImportOrExportKind::Type,
+            NONE,
+        );
+        if stmt_ctx.is_top_level() {
+            self.module_record_builder.visit_export_named_declaration(&export_named_decl);
+        }
+        Statement::ExportNamedDeclaration(export_named_decl)
+    }
+
+    /// Object type with Flow extensions:
+    ///
+    /// * Exact objects `{| a: T |}`
+    /// * Inexact objects `{ a: T, ... }`
+    /// * Variance sigils `{ +a: T, -b: U }`, `+` is mapped to `readonly`
+    /// * Spreads `{ ...A, b: T }`, mapped to `A & { b: T }`
+    pub(crate) fn parse_flow_object_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly);
+
+        // `{||}`
+        if self.eat(Kind::Pipe2) {
+            self.expect(Kind::RCurly);
+            return self.ast.ts_type_type_literal(self.end_span(span), self.ast.vec());
+        }
+
+        let exact = self.eat(Kind::Pipe);
+        let mut members = self.ast.vec();
+        let mut spreads = self.ast.vec();
+        loop {
+            let at_end = if exact { self.at(Kind::Pipe) } else { self.at(Kind::RCurly) };
+            if at_end || self.has_fatal_error() {
+                break;
+            }
+            if self.eat(Kind::Dot3) {
+                // `...` on its own marks an inexact object
+                if !matches!(
+                    self.cur_kind(),
+                    Kind::RCurly | Kind::Pipe | Kind::Comma | Kind::Semicolon
+                ) {
+                    spreads.push(self.parse_ts_type());
+                }
+                self.parse_type_member_semicolon();
+                continue;
+            }
+
+            let covariant = self.eat_flow_variance();
+            let mut member = self.parse_ts_type_signature();
+            if covariant && let TSSignature::TSPropertySignature(signature) = &mut member {
+                signature.readonly = true;
+            }
+            members.push(member);
+        }
+        if exact {
+            self.expect(Kind::Pipe);
+        }
+        self.expect(Kind::RCurly);
+
+        let span = self.end_span(span);
+        let ty = self.ast.ts_type_type_literal(span, members);
+        if spreads.is_empty() {
+            ty
+        } else {
+            spreads.push(ty);
+            self.ast.ts_type_intersection_type(span, spreads)
+        }
+    }
+
+    /// Eat a variance sigil, `+` for covariant or `-` for contravariant.
+    /// Returns `true` if the sigil is covariant, which is mapped to `readonly`.
+    pub(crate) fn eat_flow_variance(&mut self) -> bool {
+        if !self.source_type.is_flow() {
+            return false;
+        }
+        let covariant = self.at(Kind::Plus);
+        if covariant || self.at(Kind::Minus) {
+            self.bump_any();
+        }
+        covariant
+    }
+
+    /// Predicate function annotation `%checks`, or `%checks(expression)` in declared functions,
+    /// which is dropped.
+    pub(crate) fn eat_flow_predicate(&mut self) {
+        if !self.source_type.is_flow() || !self.at(Kind::Percent) {
+            return;
+        }
+        let token = self.lexer.peek_token();
+        if token.kind() != Kind::Ident || self.token_source(&token) != "checks" {
+            return;
+        }
+        self.bump_any(); // bump `%`
+        self.bump_any(); // bump `checks`
+        if self.eat(Kind::LParen) {
+            self.parse_expr();
+            self.expect(Kind::RParen);
+        }
+    }
+
+    /// Check if the parser is at the `|}` closing an exact object type.
+    pub(crate) fn at_flow_exact_object_end(&mut self) -> bool {
+        self.source_type.is_flow()
+            && self.at(Kind::Pipe)
+            && self.lexer.peek_token().kind() == Kind::RCurly
+    }
+
+    /// Type cast `(expression: Type)`, represented as a [`TSAsExpression`].
+    pub(crate) fn parse_flow_type_cast(&mut self, expression: Expression<'a>) -> Expression<'a> {
+        if !self.source_type.is_flow() || !self.at(Kind::Colon) {
+            return expression;
+        }
+        let span = expression.span().start;
+        self.bump_any(); // bump `:`
+        let type_annotation = self.parse_ts_type();
+        self.ast.expression_ts_as(self.end_span(span), expression, type_annotation)
+    }
+}
@@ -0,0 +1,559 @@
+//! Incremental reparsing, see [`Parser::reparse`](crate::Parser::reparse).
+//!
+//! The statement list enclosing an edit is the only part of the AST which is parsed again.
+//! The statements touching the edit, plus one statement on either side, are reparsed from the end
+//! of the preceding statement. All other nodes are moved over from the previous [`Program`],
+//! and the spans of those after the edit are shifted by the change in length.
+//!
+//! The result is only used if it is guaranteed to be identical to a full parse:
+//! reparsing must stop exactly at the start of the first reused statement, and produce no errors.
+//! Otherwise the file is parsed from scratch.
+//!
+//! Only the [`Program`] and the [`ModuleRecord`] are updated incrementally.
+//! Scopes and symbols are not, they belong to `oxc_semantic` which rebuilds them from the whole program.
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{DynamicImport, ExportExportName, ModuleRecord};
+
+use crate::{
+    MAX_LEN, ParserImpl, ParserReturn,
+    context::{Context, StatementContext},
+};
+
+/// Edit from the old source text to the new one.
+#[derive(Debug, Clone, Copy)]
+struct Edit {
+    start: u32,
+    /// End of the replaced text in the old source text
+    old_end: u32,
+    /// End of the inserted text in the new source text
+    new_end: u32,
+}
+
+impl Edit {
+    /// Map a position after the edit in the old source text to the new source text.
+    fn shift(self, position: u32) -> u32 {
+        if position >= self.old_end { position - self.old_end + self.new_end } else { position }
+    }
+
+    fn shift_span(self, span: Span) -> Span {
+        Span::new(self.shift(span.start), self.shift(span.end))
+    }
+}
+
+/// Part of the previous [`Program`] to parse again.
+struct Region {
+    /// Indices of the function declarations enclosing the edit, starting from the top level.
+    /// Empty if top-level statements are reparsed.
+    path: Vec<usize>,
+    /// Statements to replace in the enclosing statement list.
+    /// `None` if the whole function body, including directives, is reparsed.
+    statements: Option<(usize, usize)>,
+    /// Context of the enclosing statement list
+    ctx: Context,
+    /// Lexing restarts here, at the end of the token before the region.
+    lex_start: u32,
+    /// End of the last token in the region, in the old source text.
+    old_last_token_end: u32,
+    /// Start of the first reused token after the region, in the old source text.
+    old_end: u32,
+    /// Start of the first reused token after the region, in the new source text.
+    new_end: u32,
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Reparse the part of `previous` affected by replacing `edit` with the corresponding text in
+    /// the new source text.
+    ///
+    /// Returns `None` if the result could differ from a full parse.
+    pub fn reparse(mut self, previous: ParserReturn<'a>, edit: Span) -> Option<ParserReturn<'a>> {
+        let ParserReturn {
+            mut program,
+            module_record,
+            errors,
+            irregular_whitespaces,
+            panicked,
+            ..
+        } = previous;
+        if panicked
+            || !errors.is_empty()
+            || self.options.tokens
+            || self.source_type.is_unambiguous()
+            || program.source_type != self.source_type
+        {
+            return None;
+        }
+        #[cfg(feature = "regular_expression")]
+        if self.options.parse_regular_expression {
+            return None;
+        }
+        let edit = self.check_edit(program.source_text, edit)?;
+        let region = self.find_region(&program, edit)?;
+
+        // Module record entries are recorded in source order, so reused statements before the
+        // region are replayed before parsing it.
+        let top_level_start = region.path.first().copied().or(region.statements.map(|(s, _)| s));
+        let top_level_start = top_level_start.unwrap_or_default();
+        let default_keywords = default_keyword_spans(&module_record, edit);
+        for stmt in &program.body[..top_level_start] {
+            self.replay_module_record(stmt, &default_keywords)?;
+        }
+        for dynamic_import in &module_record.dynamic_imports {
+            if dynamic_import.span.start < region.lex_start {
+                self.module_record_builder.add_dynamic_import(*dynamic_import);
+            }
+        }
+        for import_meta in &module_record.import_metas {
+            if import_meta.start < region.lex_start {
+                self.module_record_builder.visit_import_meta(*import_meta);
+            }
+        }
+
+        let (directives, statements) = self.parse_region(&region)?;
+
+        // `/* #__PURE__ */` and `/* #__NO_SIDE_EFFECTS__ */` comments before the first reused
+        // statement are re-lexed, but the statement was parsed with the old ones
+        if annotations(&program.comments, region.old_last_token_end, region.old_end)
+            != annotations(&self.lexer.trivia_builder.comments, self.prev_token_end, region.new_end)
+        {
+            return None;
+        }
+
+        // Shift spans of everything after the region, then swap in the reparsed statements.
+        let mut shift = ShiftSpans(edit);
+        let shift_start = match region.statements {
+            Some((_, end)) if region.path.is_empty() => end,
+            _ => top_level_start,
+        };
+        for stmt in program.body.iter_mut().skip(shift_start) {
+            shift.visit_statement(stmt);
+        }
+        let parsed_top_level_statements = region.path.is_empty().then_some(statements.len());
+        let list = match region.path.split_first() {
+            None => &mut program.body,
+            Some((&first, rest)) => {
+                let mut body = function_body_mut(&mut program.body[first]);
+                for &i in rest {
+                    body = function_body_mut(&mut body.statements[i]);
+                }
+                if let Some(directives) = directives {
+                    body.directives = directives;
+                }
+                &mut body.statements
+            }
+        };
+        let (start, end) = region.statements.unwrap_or((0, list.len()));
+        list.splice(start..end, statements);
+
+        let replay_start = top_level_start + parsed_top_level_statements.unwrap_or_default();
+        for stmt in &program.body[replay_start..] {
+            self.replay_module_record(stmt, &default_keywords)?;
+        }
+        for dynamic_import in &module_record.dynamic_imports {
+            if dynamic_import.span.start >= region.old_end {
+                self.module_record_builder.add_dynamic_import(DynamicImport {
+                    span: edit.shift_span(dynamic_import.span),
+                    module_request: edit.shift_span(dynamic_import.module_request),
+                });
+            }
+        }
+        for import_meta in &module_record.import_metas {
+            if import_meta.start >= region.old_end {
+                self.module_record_builder.visit_import_meta(edit.shift_span(*import_meta));
+            }
+        }
+
+        let mut comments = self.ast.vec();
+        comments.extend(program.comments.iter().filter(|c| c.span.start < region.lex_start));
+        comments.extend(
+            self.lexer.trivia_builder.comments.iter().filter(|c| c.span.start < region.new_end),
+        );
+        comments.extend(program.comments.iter().filter(|c| c.span.start >= region.old_end).map(
+            |comment| {
+                let mut comment = *comment;
+                comment.span = edit.shift_span(comment.span);
+                comment.attached_to = edit.shift(comment.attached_to);
+                comment
+            },
+        ));
+        let irregular_whitespaces = irregular_whitespaces
+            .iter()
+            .filter(|span| span.start < region.lex_start)
+            .copied()
+            .chain(
+                self.lexer
+                    .trivia_builder
+                    .irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start < region.new_end)
+                    .copied(),
+            )
+            .chain(
+                irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start >= region.old_end)
+                    .map(|span| edit.shift_span(*span)),
+            )
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        let (module_record, module_record_errors) = self.module_record_builder.build();
+        if !self.source_type.is_typescript()
+            && !self.source_type.is_flow()
+            && !module_record_errors.is_empty()
+        {
+            return None;
+        }
+
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(0, self.source_text.len() as u32);
+        program.span = span;
+        program.source_text = self.source_text;
+        program.comments = comments;
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors: vec![],
+            irregular_whitespaces,
+            panicked: false,
+            is_flow_language: false,
+            tokens: self.tokens,
+        })
+    }
+
+    /// Check `edit` is consistent with the old and new source text, and convert it to an [`Edit`].
+    fn check_edit(&self, old_source_text: &str, edit: Span) -> Option<Edit> {
+        let new_source_text = self.source_text;
+        if new_source_text.len() > MAX_LEN {
+            return None;
+        }
+        let (start, old_end) = (edit.start as usize, edit.end as usize);
+        let inserted_len = (new_source_text.len() + (old_end.checked_sub(start)?))
+            .checked_sub(old_source_text.len())?;
+        let new_end = start + inserted_len;
+        // Text outside of the edit must be unchanged
+        let (old_after, new_after) =
+            (old_source_text.get(old_end..)?, new_source_text.get(new_end..)?);
+        if old_source_text.get(..start)? != new_source_text.get(..start)? || old_after != new_after
+        {
+            return None;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let new_end = new_end as u32;
+        Some(Edit { start: edit.start, old_end: edit.end, new_end })
+    }
+
+    /// Find the statements to reparse.
+    ///
+    /// Descends into function declarations whose body contains the edit, so only the innermost
+    /// statement list is reparsed.
+    fn find_region(&self, program: &Program<'a>, edit: Edit) -> Option<Region> {
+        let mut path = vec![];
+        let mut ctx = self.ctx;
+        let mut statements = &program.body;
+        let mut body = None;
+        loop {
+            let i = statements.partition_point(|stmt| stmt.span().end <= edit.start);
+            let Some(function) = statements.get(i).and_then(statement_function) else { break };
+            let Some(function_body) = &function.body else { break };
+            if function_body.span.start >= edit.start || edit.old_end >= function_body.span.end {
+                break;
+            }
+            path.push(i);
+            ctx = self
+                .ctx
+                .and_in(true)
+                .and_return(true)
+                .and_await(function.r#async)
+                .and_yield(function.generator);
+            statements = &function_body.statements;
+            body = Some(function_body);
+        }
+
+        // List end is the closing `}` of the function body, or the end of the file
+        let list_end = body.map_or(program.span.end, |body| body.span.end - 1);
+        // First statement touching the edit, and first statement after it
+        let first = statements.partition_point(|stmt| stmt.span().end < edit.start);
+        let last = statements.partition_point(|stmt| stmt.span().start <= edit.old_end);
+
+        // Include one more statement on either side, in case the edit makes the statement
+        // boundaries move. Reparsing starts at the end of the statement before that.
+        // If that's the start of the list, a string literal could turn into a directive,
+        // so reparse the whole function body, or give up on the program.
+        let (statements, lex_start, old_last_token_end, old_end) = if first >= 2 {
+            let (start, end) = (first - 1, (last + 1).min(statements.len()));
+            let lex_start = statements[start - 1].span().end;
+            let old_last_token_end = statements[end - 1].span().end;
+            let old_end = statements.get(end).map_or(list_end, |stmt| stmt.span().start);
+            (Some((start, end)), lex_start, old_last_token_end, old_end)
+        } else {
+            let body = body?;
+            (None, body.span.start + 1, list_end, list_end)
+        };
+
+        Some(Region {
+            path,
+            statements,
+            ctx,
+            lex_start,
+            old_last_token_end,
+            old_end,
+            new_end: edit.shift(old_end),
+        })
+    }
+
+    /// Parse the statements in `region`.
+    ///
+    /// Returns `None` if the statements do not end exactly at the end of the region,
+    /// or contain errors.
+    fn parse_region(
+        &mut self,
+        region: &Region,
+    ) -> Option<(Option<ArenaVec<'a, Directive<'a>>>, ArenaVec<'a, Statement<'a>>)> {
+        self.token = self.lexer.seek_token(region.lex_start);
+        self.prev_token_end = region.lex_start;
+        self.ctx = region.ctx;
+
+        let (directives, statements) = if region.statements.is_some() {
+            let stmt_ctx = if region.path.is_empty() {
+                StatementContext::TopLevelStatementList
+            } else {
+                StatementContext::StatementList
+            };
+            let mut statements = self.ast.vec();
+            while self.cur_token().start() < region.new_end && !self.has_fatal_error() {
+                statements.push(self.parse_statement_list_item(stmt_ctx));
+            }
+            (None, statements)
+        } else {
+            let (directives, statements) =
+                self.parse_directives_and_statements(/* is_top_level */ false);
+            (Some(directives), statements)
+        };
+
+        self.check_unfinished_errors();
+        if self.fatal_error.is_some()
+            || !self.errors.is_empty()
+            || !self.lexer.errors.is_empty()
+            || self.cur_token().start() != region.new_end
+        {
+            return None;
+        }
+        Some((directives, statements))
+    }
+
+    /// Record import and export declarations of a reused top-level statement.
+    fn replay_module_record(
+        &mut self,
+        stmt: &Statement<'a>,
+        default_keywords: &[(u32, Span)],
+    ) -> Option<()> {
+        match stmt {
+            Statement::ImportDeclaration(decl) => {
+                self.module_record_builder.visit_import_declaration(decl);
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                self.module_record_builder.visit_export_all_declaration(decl);
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                self.module_record_builder.visit_export_named_declaration(decl);
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                // Position of `default` keyword is not stored in the AST
+                let (_, default_keyword_span) =
+                    default_keywords.iter().find(|(start, _)| *start == decl.span.start)?;
+                self.module_record_builder
+                    .visit_export_default_declaration(decl, *default_keyword_span);
+            }
+            Statement::TSExportAssignment(_) | Statement::TSNamespaceExportDeclaration(_) => {
+                self.module_record_builder.found_ts_export();
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+/// Spans of `default` keywords in `export default` declarations, keyed by statement start,
+/// mapped to the new source text.
+fn default_keyword_spans(module_record: &ModuleRecord<'_>, edit: Edit) -> Vec<(u32, Span)> {
+    module_record
+        .local_export_entries
+        .iter()
+        .chain(&module_record.indirect_export_entries)
+        .filter_map(|entry| match entry.export_name {
+            ExportExportName::Default(span) => {
+                Some((edit.shift(entry.statement_span.start), edit.shift_span(span)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether comments starting in `start..end` contain `#__PURE__` and `#__NO_SIDE_EFFECTS__`.
+fn annotations(comments: &[Comment], start: u32, end: u32) -> (bool, bool) {
+    comments.iter().filter(|comment| (start..end).contains(&comment.span.start)).fold(
+        (false, false),
+        |(pure, no_side_effects), comment| {
+            (pure || comment.is_pure(), no_side_effects || comment.is_no_side_effects())
+        },
+    )
+}
+
+/// Function declared by a statement, if any.
+fn statement_function<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b Function<'a>> {
+    match stmt {
+        Statement::FunctionDeclaration(function) => Some(function),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::FunctionDeclaration(function)) => Some(function),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(function) => Some(function),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Body of the function declared by a statement found by [`ParserImpl::find_region`].
+fn function_body_mut<'b, 'a>(stmt: &'b mut Statement<'a>) -> &'b mut FunctionBody<'a> {
+    let function = match stmt {
+        Statement::FunctionDeclaration(function) => Some(function),
+        Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+            Some(Declaration::FunctionDeclaration(function)) => Some(function),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(function) => Some(function),
+            _ => None,
+        },
+        _ => None,
+    };
+    function.and_then(|function| function.body.as_deref_mut()).unwrap()
+}
+
+/// Move spans after an edit to their position in the new source text.
+struct ShiftSpans(Edit);
+
+impl VisitMut<'_> for ShiftSpans {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.0.shift_span(*span);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{ParseOptions, Parser, ParserImpl, UniquePromise};
+
+    /// Replace `start..end` of `source_text` with `replacement`, and check that reparsing gives
+    /// the same result as a full parse. Returns whether the reparse was incremental.
+    #[expect(clippy::cast_possible_truncation)]
+    fn reparse(source_text: &str, start: usize, end: usize, replacement: &str) -> bool {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts().with_module(true);
+        let new_source_text =
+            format!("{}{replacement}{}", &source_text[..start], &source_text[end..]);
+        let previous = Parser::new(&allocator, source_text, source_type).parse();
+        let expected = Parser::new(&allocator, &new_source_text, source_type).parse();
+
+        let unique = UniquePromise::new_for_tests_and_benchmarks();
+        let parser = ParserImpl::new(
+            &allocator,
+            &new_source_text,
+            source_type,
+            ParseOptions::default(),
+            unique,
+        );
+        let ret = parser.reparse(previous, Span::new(start as u32, end as u32));
+        let incremental = ret.is_some();
+        let ret =
+            ret.unwrap_or_else(|| Parser::new(&allocator, &new_source_text, source_type).parse());
+
+        assert_eq!(format!("{:#?}", ret.program), format!("{:#?}", expected.program));
+        assert_eq!(format!("{:#?}", ret.module_record), format!("{:#?}", expected.module_record));
+        assert_eq!(ret.irregular_whitespaces, expected.irregular_whitespaces);
+        assert_eq!(ret.errors.len(), expected.errors.len());
+        incremental
+    }
+
+    fn replace(source_text: &str, old: &str, new: &str) -> bool {
+        let start = source_text.find(old).unwrap();
+        reparse(source_text, start, start + old.len(), new)
+    }
+
+    const SOURCE: &str = "#!/usr/bin/env node
+'use strict';
+import a, { b as c } from 'a';
+foo();
+/* leading */ let x = 1; // trailing
+export function f(a, b) {
+  'use asm';
+  const y = a + b;
+  if (y) {
+    return import('b');
+  }
+  function* g() { yield y; }
+  return `${y}`;
+}
+/* #__NO_SIDE_EFFECTS__ */ async function h() { await x; }
+bar
+(x)
+export default class {}
+export { x as y };
+const z = /re/g.test(import.meta.url) / 2;
+";
+
+    #[test]
+    fn top_level() {
+        assert!(replace(SOURCE, "1", "2 + 3"));
+        assert!(replace(SOURCE, "bar", "baz();\nqux"));
+        assert!(replace(SOURCE, "export { x as y };", "export * as ns from 'ns';"));
+        assert!(replace(SOURCE, "// trailing", "/* trailing */"));
+        // Edits at the start of the file are reparsed in full
+        assert!(!replace(SOURCE, "foo();", "'use asm';"));
+        assert!(!replace(SOURCE, "'use strict'", "'use asm'"));
+    }
+
+    #[test]
+    fn function_body() {
+        assert!(replace(SOURCE, "const y = a + b;", "const y = a - b;"));
+        assert!(replace(SOURCE, "yield y;", "yield* y;"));
+        assert!(replace(SOURCE, "await x;", "await import(x);"));
+        assert!(replace(SOURCE, "'use asm';", "'use strict';"));
+        assert!(replace(SOURCE, "return `${y}`;", "return;"));
+    }
+
+    #[test]
+    fn statement_boundaries() {
+        assert!(replace(SOURCE, "(x)", "x"));
+        assert!(!replace(SOURCE, "foo();", "foo()"));
+        assert!(replace(SOURCE, "bar\n", "bar;\n"));
+        replace(SOURCE, "const z", "/* const z");
+        replace(SOURCE, "let x = 1;", "let x = `");
+    }
+
+    #[test]
+    fn fallback_on_errors() {
+        assert!(!replace(SOURCE, "let x = 1;", "let x = ;"));
+        assert!(!replace(SOURCE, "export { x as y };", "export { x as };"));
+    }
+
+    /// Every single character deletion, and insertion of some characters at every position.
+    #[test]
+    fn every_position() {
+        for i in 0..SOURCE.len() {
+            reparse(SOURCE, i, i + 1, "");
+            for replacement in [";", "\n", "(", "}", "/*", " "] {
+                reparse(SOURCE, i, i, replacement);
+            }
+        }
+    }
+}
@@ -61,7 +61,8 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn is_parenthesized_arrow_function_expression_worker(&mut self) -> Tristate {
-        if self.eat(Kind::Async) {
+        let r#async = self.eat(Kind::Async);
+        if r#async {
             if self.cur_token().is_on_new_line() {
                 return Tristate::False;
             }
@@ -137,6 +138,8 @@ impl<'a> ParserImpl<'a> {
                         match third {
                             // If we have something like "(a:", then we must have a
                             // type-annotated parameter in an arrow function expression.
+                            // Unless this is a Flow type cast "(a: T)".
+                            Kind::Colon if self.source_type.is_flow() => Tristate::Maybe,
                             Kind::Colon => Tristate::True,
                             // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
                             Kind::Question => {
@@ -166,7 +169,8 @@ impl<'a> ParserImpl<'a> {
                 }
 
                 // JSX overrides
-                if self.source_type.is_jsx() {
+                // Flow parses `async <T>(x: T) => x` as an arrow function, since JSX cannot follow `async`.
+                if self.source_type.is_jsx() && !(r#async && self.source_type.is_flow()) {
                     // <const Ident extends Ident>
                     //  ^^^^^ Optional
                     self.bump(Kind::Const);
@@ -335,7 +339,9 @@ impl<'a> ParserImpl<'a> {
 
         let checkpoint = self.checkpoint_with_error_recovery();
 
+        self.state.speculation_depth += 1;
         let head = self.parse_parenthesized_arrow_function_head();
+        self.state.speculation_depth -= 1;
         if self.has_fatal_error() {
             self.state.not_parenthesized_arrow.insert(pos);
             self.rewind(checkpoint);
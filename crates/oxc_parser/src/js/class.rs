@@ -5,6 +5,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     Context, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryBoundary,
     lexer::Kind,
     modifiers::{ModifierFlags, ModifierKind, Modifiers},
 };
@@ -188,14 +189,26 @@ impl<'a> ParserImpl<'a> {
     fn parse_class_body(&mut self) -> Box<'a, ClassBody<'a>> {
         let span = self.start_span();
         let class_elements = self.parse_normal_list_breakable(Kind::LCurly, Kind::RCurly, |p| {
-            // Skip empty class element `;`
-            if p.eat(Kind::Semicolon) {
-                while p.eat(Kind::Semicolon) {}
-                if p.at(Kind::RCurly) {
-                    return None;
+            loop {
+                // Skip empty class element `;`
+                if p.eat(Kind::Semicolon) {
+                    while p.eat(Kind::Semicolon) {}
+                    if p.at(Kind::RCurly) {
+                        return None;
+                    }
+                }
+                let element = p.parse_with_recovery(
+                    |p| Some(p.parse_class_element()),
+                    |p| {
+                        p.skip_to_recovery_boundary(RecoveryBoundary::ClassElement);
+                        None
+                    },
+                );
+                if element.is_some() || p.at(Kind::RCurly) || p.has_fatal_error() {
+                    return element;
                 }
+                // An invalid class element was skipped by error recovery, continue with the next one.
             }
-            Some(Self::parse_class_element(p))
         });
         self.ast.alloc_class_body(self.end_span(span), class_elements)
     }
@@ -299,16 +312,23 @@ impl<'a> ParserImpl<'a> {
             );
         }
 
+        // Flow variance sigil: `+x: T`, `-x: T`
+        let covariant = self.eat_flow_variance();
+
         let kind = self.cur_kind();
         if kind.is_identifier_or_keyword() || kind == Kind::Star || kind == Kind::LBrack {
             let is_ambient = modifiers.contains(ModifierKind::Declare);
-            return if is_ambient {
+            let mut element = if is_ambient {
                 self.context(Context::Ambient, Context::empty(), |p| {
                     p.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
                 })
             } else {
                 self.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
             };
+            if covariant && let ClassElement::PropertyDefinition(property) = &mut element {
+                property.readonly = true;
+            }
+            return element;
         }
 
         self.unexpected()
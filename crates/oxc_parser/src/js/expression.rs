@@ -196,20 +196,45 @@ impl<'a> ParserImpl<'a> {
             Kind::At => self.parse_decorated_expression(),
             // Literal, RegularExpressionLiteral
             kind if kind.is_literal() => self.parse_literal_expression(),
+            kind if self.can_recover_missing_expression(kind) => self.parse_error_expression(),
             _ => self.parse_identifier_expression(),
         }
     }
 
+    /// Whether a missing expression before a token of `kind` can be replaced by an [`ErrorExpression`],
+    /// e.g. `let x = ;` or `foo(a, , b)`.
+    fn can_recover_missing_expression(&self, kind: Kind) -> bool {
+        self.options.error_recovery
+            && self.state.speculation_depth == 0
+            && matches!(
+                kind,
+                Kind::Semicolon
+                    | Kind::Comma
+                    | Kind::Colon
+                    | Kind::RParen
+                    | Kind::RBrack
+                    | Kind::RCurly
+                    | Kind::Eof
+            )
+    }
+
+    /// Error recovery: an empty [`ErrorExpression`] in place of a missing expression.
+    /// Does not consume the current token.
+    fn parse_error_expression(&mut self) -> Expression<'a> {
+        let span = Span::empty(self.cur_token().start());
+        self.error(diagnostics::expression_expected(span));
+        self.ast.expression_error(span)
+    }
+
     fn parse_parenthesized_expression(&mut self) -> Expression<'a> {
         let span = self.start_span();
         self.bump_any(); // `bump` `(`
         let expr_span = self.start_span();
         let (mut expressions, comma_span) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                Self::parse_assignment_expression_or_higher,
-            )
+            p.parse_delimited_list(Kind::RParen, Kind::Comma, |p| {
+                let expression = p.parse_assignment_expression_or_higher();
+                p.parse_flow_type_cast(expression)
+            })
         });
 
         if let Some(comma_span) = comma_span {
@@ -131,6 +131,15 @@ impl<'a> ParserImpl<'a> {
                     }
                 }
             }
+        } else if self.source_type.is_flow() && self.at(Kind::Typeof) {
+            // Flow `import typeof ...`, which imports the types of values
+            self.bump_any();
+            import_kind = ImportOrExportKind::Type;
+            if self.cur_kind().is_binding_identifier() {
+                // `import typeof something ...`
+                identifier_after_import = Some(self.parse_binding_identifier());
+                has_default_specifier = true;
+            }
         } else if token_after_import.kind() == Kind::Defer && self.at(Kind::Star) {
             // `import defer * ...`
             phase = Some(ImportPhase::Defer);
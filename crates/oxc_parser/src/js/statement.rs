@@ -5,6 +5,7 @@ use oxc_span::{Atom, GetSpan, Span};
 use super::{VariableDeclarationParent, grammar::CoverGrammar};
 use crate::{
     Context, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryBoundary,
     lexer::Kind,
     modifiers::{Modifier, ModifierFlags, ModifierKind, Modifiers},
 };
@@ -47,7 +48,7 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(stmt_ctx);
+            let stmt = self.parse_statement_list_item_with_recovery(stmt_ctx);
 
             // Section 11.2.1 Directive Prologue
             // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -74,6 +75,31 @@ impl<'a> ParserImpl<'a> {
         (directives, statements)
     }
 
+    /// Parse a `StatementListItem`, or an [`ErrorStatement`] covering the statement
+    /// if it has a fatal syntax error and [`ParseOptions::error_recovery`] is enabled.
+    ///
+    /// [`ParseOptions::error_recovery`]: crate::ParseOptions::error_recovery
+    pub(crate) fn parse_statement_list_item_with_recovery(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Statement<'a> {
+        self.parse_with_recovery(
+            |p| {
+                // A `}` without a matching `{` at the top level. It must not be parsed as a statement with
+                // a missing expression, which would not consume the `}`.
+                if p.at(Kind::RCurly) {
+                    return p.unexpected();
+                }
+                p.parse_statement_list_item(stmt_ctx)
+            },
+            |p| {
+                let span = p.start_span();
+                p.skip_to_recovery_boundary(RecoveryBoundary::Statement);
+                p.ast.statement_error(p.end_span(span))
+            },
+        )
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -125,6 +151,9 @@ impl<'a> ParserImpl<'a> {
             Kind::Const => self.parse_const_statement(stmt_ctx),
             Kind::Using if self.is_using_declaration() => self.parse_using_statement(),
             Kind::Await if self.is_using_statement() => self.parse_using_statement(),
+            Kind::Declare if self.at_flow_declare_export() => {
+                self.parse_flow_declare_export_declaration(self.start_span(), stmt_ctx)
+            }
             Kind::Interface
             | Kind::Type
             | Kind::Module
@@ -143,6 +172,9 @@ impl<'a> ParserImpl<'a> {
             {
                 self.parse_ts_declaration_statement(self.start_span())
             }
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_ts_declaration_statement(self.start_span())
+            }
             _ => self.parse_expression_or_labeled_statement(),
         };
 
@@ -208,7 +240,7 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_block(&mut self) -> Box<'a, BlockStatement<'a>> {
         let span = self.start_span();
         let body = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
-            p.parse_statement_list_item(StatementContext::StatementList)
+            p.parse_statement_list_item_with_recovery(StatementContext::StatementList)
         });
         self.ast.alloc_block_statement(self.end_span(span), body)
     }
@@ -623,7 +655,8 @@ impl<'a> ParserImpl<'a> {
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly)
             && !self.has_fatal_error()
         {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let stmt =
+                self.parse_statement_list_item_with_recovery(StatementContext::StatementList);
             consequent.push(stmt);
         }
         self.ast.switch_case(self.end_span(span), test, consequent)
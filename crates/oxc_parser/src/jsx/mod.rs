@@ -411,6 +411,9 @@ impl<'a> ParserImpl<'a> {
         // Currently at a valid normal Ident or Keyword, keep on lexing for `-` in `<component-name />`
         self.continue_lex_jsx_identifier();
         self.bump_any();
+        if self.options.tokens {
+            self.mark_jsx_identifier_token();
+        }
         let span = self.end_span(span);
         let name = span.source_text(self.source_text);
         self.ast.jsx_identifier(span, name)
@@ -207,6 +207,16 @@ impl<'a> Lexer<'a> {
         self.finish_next(kind)
     }
 
+    /// Restart lexing at `offset`, which must be the end of a token, and return the next token.
+    ///
+    /// Used for reparsing part of a file. Only trivia lexed after `offset` is collected.
+    pub fn seek_token(&mut self, offset: u32) -> Token {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.trivia_builder.restart_after_token();
+        self.next_token()
+    }
+
     /// Read next token in file.
     /// Use `first_token` for first token, and this method for all further tokens.
     pub fn next_token(&mut self) -> Token {
@@ -51,6 +51,13 @@ impl TriviaBuilder {
         self.has_no_side_effects_comment
     }
 
+    /// Reset state for lexing from the end of a token, when the preceding trivia has not been seen.
+    pub fn restart_after_token(&mut self) {
+        self.processed = self.comments.len();
+        self.saw_newline = false;
+        self.previous_kind = Kind::Undetermined;
+    }
+
     pub fn add_irregular_whitespace(&mut self, start: u32, end: u32) {
         self.irregular_whitespaces.push(Span::new(start, end));
     }
@@ -69,10 +69,13 @@
 mod context;
 mod cursor;
 mod error_handler;
+mod incremental;
 mod modifiers;
 mod module_record;
 mod state;
+mod tokens;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -86,7 +89,7 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
+use oxc_allocator::{Allocator, Box as ArenaBox, Dummy, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
     ast::{Expression, Program},
@@ -98,11 +101,13 @@ use oxc_syntax::module_record::ModuleRecord;
 use crate::{
     context::{Context, StatementContext},
     error_handler::FatalError,
-    lexer::{Lexer, Token},
+    lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
 
+pub use crate::tokens::{Token, TokenKind};
+
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
 // Length is constrained by 2 factors:
@@ -180,6 +185,12 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// Tokens of the source text, excluding comments.
+    ///
+    /// Only collected if [`ParseOptions::tokens`] is enabled.
+    /// Will be empty if the parser panicked.
+    pub tokens: ArenaVec<'a, Token<'a>>,
 }
 
 /// Parse options
@@ -221,6 +232,27 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Collect [`Token`]s into [`ParserReturn::tokens`].
+    ///
+    /// Default: `false`
+    pub tokens: bool,
+
+    /// Recover from syntax errors by inserting placeholder nodes, instead of returning an empty
+    /// [`Program`] with [`ParserReturn::panicked`] set.
+    ///
+    /// Missing expressions become [`ErrorExpression`]s, and statements and class members
+    /// which cannot be parsed are skipped up to the next statement or member boundary.
+    /// Skipped statements are represented by [`ErrorStatement`]s; skipped class members are dropped.
+    /// The syntax errors are still reported in [`ParserReturn::errors`].
+    ///
+    /// Intended for editor tooling, which needs an AST for the rest of the file while the user is typing.
+    ///
+    /// Default: `false`
+    ///
+    /// [`ErrorExpression`]: oxc_ast::ast::ErrorExpression
+    /// [`ErrorStatement`]: oxc_ast::ast::ErrorStatement
+    pub error_recovery: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +263,8 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            tokens: false,
+            error_recovery: false,
         }
     }
 }
@@ -318,6 +352,41 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse the source text after an edit, reusing the unaffected parts of a previous parse.
+        ///
+        /// `previous` must be the result of parsing the source text before the edit, with the same
+        /// [`SourceType`] and [`ParseOptions`]. `edit` is the span of the replaced text in the old
+        /// source text, and the source text of this `Parser` is the text after the edit.
+        ///
+        /// Top-level statements outside of the edit are reused. When the edit is inside the body
+        /// of a function declaration, only statements in that body are reparsed.
+        ///
+        /// The returned [`ParserReturn`] is identical to the result of [`Parser::parse`].
+        /// Where that cannot be guaranteed, this falls back to a full parse, including when:
+        ///
+        /// * `previous` or the new source text has errors.
+        /// * The source type is unambiguous, or [`ParseOptions::tokens`] or
+        ///   [`ParseOptions::parse_regular_expression`] is enabled.
+        /// * The edit is in the first two top-level statements, or in the hashbang or directives.
+        ///
+        /// Only parsing is incremental. Semantic analysis is not, so `oxc_semantic::SemanticBuilder`
+        /// has to be run over the whole returned [`Program`] again, and spans cached from the
+        /// previous semantic model are invalid after the edit.
+        pub fn reparse(self, previous: ParserReturn<'a>, edit: Span) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            if let Some(ret) = parser.reparse(previous, edit) {
+                return ret;
+            }
+            self.parse()
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -372,7 +441,10 @@ struct ParserImpl<'a> {
     fatal_error: Option<FatalError>,
 
     /// The current parsing token
-    token: Token,
+    token: lexer::Token,
+
+    /// Consumed tokens, only collected if [`ParseOptions::tokens`] is enabled
+    tokens: ArenaVec<'a, Token<'a>>,
 
     /// The end range of the previous token
     prev_token_end: u32,
@@ -413,13 +485,15 @@ impl<'a> ParserImpl<'a> {
             source_text,
             errors: vec![],
             fatal_error: None,
-            token: Token::default(),
+            token: lexer::Token::default(),
+            tokens: ArenaVec::new_in(allocator),
             prev_token_end: 0,
             state: ParserState::new(),
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
-            is_ts: source_type.is_typescript(),
+            // Flow is parsed on top of TypeScript
+            is_ts: source_type.is_typescript() || source_type.is_flow(),
         }
     }
 
@@ -442,6 +516,7 @@ impl<'a> ParserImpl<'a> {
             }
 
             program = Program::dummy(self.ast.allocator);
+            self.tokens.clear();
             program.source_type = self.source_type;
             program.source_text = self.source_text;
         }
@@ -470,7 +545,7 @@ impl<'a> ParserImpl<'a> {
             errors.extend(self.lexer.errors);
             errors.extend(self.errors);
             // Skip checking for exports in TypeScript {
-            if !self.source_type.is_typescript() {
+            if !self.is_ts {
                 errors.extend(module_record_errors);
             }
         }
@@ -493,6 +568,7 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            tokens: self.tokens,
         }
     }
 
@@ -590,7 +666,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{CommentKind, Declaration, Expression, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -637,6 +713,216 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::flow();
+        let sources = [
+            "// @flow\nfunction f(x: number, y?: ?string): void {}",
+            "import type { A } from 'a'; export type B = A;",
+            "type A = {| a: number, b: string | null |};",
+            "type A = {||};",
+            "type A = { a: number, ... };",
+            "type A = { ...B, +c: number, -d: string };",
+            "type A = { [string]: number };",
+            "function f<T: Object, +U = string>(x: T): T { return x; }",
+            "const x = (y: any);",
+            "const x = ((y: any): string), z = (a, b: T);",
+            "const f = (x: number): number => x;",
+            "opaque type T = string;",
+            "export opaque type T: string = string;",
+            "declare opaque type T: string;",
+            "const opaque = 1; opaque;",
+            "const x = <div />;",
+            "import typeof X from 'x';",
+            "import typeof X, { Y } from 'x'; import typeof * as Z from 'z';",
+            "declare export function f(): void;",
+            "declare export class A { m(): void }",
+            "declare export var x: number;",
+            "class A { static +x: T; -y: U = 1; +z: V }",
+            "function f(x: mixed): boolean %checks { return typeof x === 'string'; }",
+            "declare function f(x: mixed): boolean %checks(typeof x === 'string');",
+            "const f = (x: mixed): boolean %checks => typeof x === 'string';",
+            "const f = async <T>(x: T): Promise<T> => x;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+            assert!(!ret.is_flow_language);
+            assert!(ret.program.source_type.is_flow());
+            assert!(!ret.program.source_type.is_typescript());
+        }
+
+        let source = "(x: number);";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("Expected ExpressionStatement");
+        };
+        let Expression::ParenthesizedExpression(expr) = &stmt.expression else {
+            panic!("Expected ParenthesizedExpression");
+        };
+        assert!(matches!(expr.expression, Expression::TSAsExpression(_)));
+
+        let source = "opaque type T = string;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(matches!(ret.program.body.first(), Some(Statement::TSTypeAliasDeclaration(_))));
+
+        let source = "import typeof X from 'x';";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Some(Statement::ImportDeclaration(decl)) = ret.program.body.first() else {
+            panic!("Expected ImportDeclaration");
+        };
+        assert!(decl.import_kind.is_type());
+
+        let source = "declare export function f(): void;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Some(Statement::ExportNamedDeclaration(decl)) = ret.program.body.first() else {
+            panic!("Expected ExportNamedDeclaration");
+        };
+        assert!(decl.declaration.as_ref().is_some_and(Declaration::declare));
+
+        let ret = Parser::new(&allocator, "const x = (y: any);", SourceType::jsx()).parse();
+        assert!(!ret.errors.is_empty());
+    }
+
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let options = ParseOptions { tokens: true, ..ParseOptions::default() };
+        let source =
+            "// comment\nlet x: T = a < b ? /re/g : `t${#p in this}` ?? <A.b c='d'>e</A.b>;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        let tokens = ret.tokens.iter().map(|t| (t.kind, t.value)).collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Keyword, "let"),
+                (TokenKind::Identifier, "x"),
+                (TokenKind::Punctuator, ":"),
+                (TokenKind::Identifier, "T"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::Identifier, "a"),
+                (TokenKind::Punctuator, "<"),
+                (TokenKind::Identifier, "b"),
+                (TokenKind::Punctuator, "?"),
+                (TokenKind::RegularExpression, "/re/g"),
+                (TokenKind::Punctuator, ":"),
+                (TokenKind::Template, "`t${"),
+                (TokenKind::PrivateIdentifier, "p"),
+                (TokenKind::Keyword, "in"),
+                (TokenKind::Keyword, "this"),
+                (TokenKind::Template, "}`"),
+                (TokenKind::Punctuator, "??"),
+                (TokenKind::Punctuator, "<"),
+                (TokenKind::JSXIdentifier, "A"),
+                (TokenKind::Punctuator, "."),
+                (TokenKind::JSXIdentifier, "b"),
+                (TokenKind::JSXIdentifier, "c"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::String, "'d'"),
+                (TokenKind::Punctuator, ">"),
+                (TokenKind::JSXText, "e"),
+                (TokenKind::Punctuator, "<"),
+                (TokenKind::Punctuator, "/"),
+                (TokenKind::JSXIdentifier, "A"),
+                (TokenKind::Punctuator, "."),
+                (TokenKind::JSXIdentifier, "b"),
+                (TokenKind::Punctuator, ">"),
+                (TokenKind::Punctuator, ";"),
+            ]
+        );
+        assert_eq!(ret.tokens[9].regex(), Some(("re", "g")));
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn tokens_estree() {
+        use oxc_estree::{CompactJSSerializer, ESTree};
+
+        let allocator = Allocator::default();
+        let options = ParseOptions { tokens: true, ..ParseOptions::default() };
+        let source = "x = /a/u;";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(options).parse();
+        let mut serializer = CompactJSSerializer::new(false);
+        ret.tokens.serialize(&mut serializer);
+        assert_eq!(
+            serializer.into_string(),
+            r#"[{"type":"Identifier","value":"x","start":0,"end":1},{"type":"Punctuator","value":"=","start":2,"end":3},{"type":"RegularExpression","value":"/a/u","regex":{"pattern":"a","flags":"u"},"start":4,"end":8},{"type":"Punctuator","value":";","start":8,"end":9}]"#
+        );
+    }
+
+    #[test]
+    fn error_recovery() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let options = ParseOptions { error_recovery: true, ..ParseOptions::default() };
+        let parse = |source| {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(!ret.errors.is_empty(), "{source}");
+            ret.program
+        };
+
+        // Missing expression
+        let program = parse("let x = ;\nfoo();");
+        assert_eq!(program.body.len(), 2);
+        let Statement::VariableDeclaration(decl) = &program.body[0] else {
+            panic!("Expected VariableDeclaration");
+        };
+        assert!(matches!(decl.declarations[0].init, Some(Expression::ErrorExpression(_))));
+        assert!(matches!(program.body[1], Statement::ExpressionStatement(_)));
+
+        // Invalid statements are skipped up to the next statement
+        let source = "foo(\nconst y = 1;\na.;\nbar();";
+        let program = parse(source);
+        let texts =
+            program.body.iter().map(|stmt| stmt.span().source_text(source)).collect::<Vec<_>>();
+        assert_eq!(texts, ["foo(", "const y = 1;", "a.;", "bar();"]);
+        assert!(matches!(program.body[0], Statement::ErrorStatement(_)));
+        assert!(matches!(program.body[1], Statement::VariableDeclaration(_)));
+        assert!(matches!(program.body[2], Statement::ErrorStatement(_)));
+        assert!(matches!(program.body[3], Statement::ExpressionStatement(_)));
+
+        // Recovery within a function body keeps the function
+        let program = parse("function f() {\n  if (x) { a. }\n  return 1;\n}\ng();");
+        assert_eq!(program.body.len(), 2);
+        let Statement::FunctionDeclaration(func) = &program.body[0] else {
+            panic!("Expected FunctionDeclaration");
+        };
+        let body = &func.body.as_ref().unwrap().statements;
+        assert_eq!(body.len(), 2);
+        assert!(matches!(body[1], Statement::ReturnStatement(_)));
+
+        // Invalid class elements are skipped up to the next element
+        let program = parse("class A {\n  foo() {}\n  bar baz;\n  qux() {}\n}\nx;");
+        assert_eq!(program.body.len(), 2);
+        let Statement::ClassDeclaration(class) = &program.body[0] else {
+            panic!("Expected ClassDeclaration");
+        };
+        assert_eq!(class.body.body.len(), 2);
+
+        // An unmatched `}` at the top level is skipped
+        let program = parse("let x = }\nconst y = 1;");
+        assert_eq!(program.body.len(), 3);
+        assert!(matches!(program.body[1], Statement::ErrorStatement(_)));
+
+        // A lexer error is not reported twice
+        let source = "let s = 1 § 2;\nconst t = 1;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert_eq!(ret.errors.len(), 1, "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 2);
+
+        // Without error recovery, the program is empty
+        let ret = Parser::new(&allocator, "let x = ;\nfoo();", source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.body.is_empty());
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
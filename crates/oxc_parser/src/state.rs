@@ -15,6 +15,10 @@ pub struct ParserState<'a> {
     /// Keyed by start span of `ArrayExpression`.
     /// Valued by position of the trailing_comma.
     pub trailing_commas: FxHashMap<u32, Span>,
+
+    /// Number of enclosing speculative parses (`try_parse`, `lookahead`),
+    /// which rely on fatal errors to backtrack, so error recovery must be disabled within them.
+    pub speculation_depth: u32,
 }
 
 impl ParserState<'_> {
@@ -23,6 +27,7 @@ impl ParserState<'_> {
             not_parenthesized_arrow: FxHashSet::default(),
             cover_initialized_name: FxHashMap::default(),
             trailing_commas: FxHashMap::default(),
+            speculation_depth: 0,
         }
     }
 }
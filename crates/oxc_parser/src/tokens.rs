@@ -0,0 +1,151 @@
+//! Tokens collected by the parser when [`ParseOptions::tokens`](crate::ParseOptions::tokens) is enabled.
+
+#[cfg(feature = "serialize")]
+use oxc_estree::{ESTree, Serializer, StructSerializer};
+use oxc_span::Span;
+
+use crate::lexer::Kind;
+
+/// Token type, as in [espree](https://github.com/eslint/js/tree/main/packages/espree).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// `true` or `false`
+    Boolean,
+    /// Identifier, including contextual keywords such as `async` or `of`
+    Identifier,
+    /// Reserved word
+    Keyword,
+    /// `null`
+    Null,
+    /// Number or BigInt literal
+    Numeric,
+    /// Operator or punctuation
+    Punctuator,
+    /// String literal
+    String,
+    /// Regular expression literal
+    RegularExpression,
+    /// Template literal part, e.g. `` `a${ `` or `` }b` ``
+    Template,
+    /// Name of a JSX element or attribute
+    JSXIdentifier,
+    /// JSX text
+    JSXText,
+    /// Private identifier, e.g. `#x`
+    PrivateIdentifier,
+}
+
+impl TokenKind {
+    pub(crate) fn from_kind(kind: Kind) -> Self {
+        match kind {
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Null => Self::Null,
+            // espree treats `let` and `static` as keywords, although they are not reserved
+            Kind::Let | Kind::Static => Self::Keyword,
+            kind if kind.is_reserved_keyword() => Self::Keyword,
+            kind if kind.is_identifier_name() => Self::Identifier,
+            kind if kind.is_number() => Self::Numeric,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegularExpression,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::JSXText => Self::JSXText,
+            _ => Self::Punctuator,
+        }
+    }
+
+    /// Type name, as in espree.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Boolean => "Boolean",
+            Self::Identifier => "Identifier",
+            Self::Keyword => "Keyword",
+            Self::Null => "Null",
+            Self::Numeric => "Numeric",
+            Self::Punctuator => "Punctuator",
+            Self::String => "String",
+            Self::RegularExpression => "RegularExpression",
+            Self::Template => "Template",
+            Self::JSXIdentifier => "JSXIdentifier",
+            Self::JSXText => "JSXText",
+            Self::PrivateIdentifier => "PrivateIdentifier",
+        }
+    }
+}
+
+/// A token in source code.
+///
+/// Comments and whitespace are not tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// Token type
+    pub kind: TokenKind,
+    /// Location in source text
+    pub span: Span,
+    /// Source text of the token, without the leading `#` of private identifiers
+    pub value: &'a str,
+}
+
+impl<'a> Token<'a> {
+    pub(crate) fn new(kind: Kind, span: Span, source_text: &'a str) -> Self {
+        let kind = TokenKind::from_kind(kind);
+        let mut value = span.source_text(source_text);
+        if kind == TokenKind::PrivateIdentifier {
+            value = &value[1..];
+        }
+        Self { kind, span, value }
+    }
+
+    /// Pattern and flags of a regular expression token.
+    pub fn regex(&self) -> Option<(&'a str, &'a str)> {
+        if self.kind != TokenKind::RegularExpression {
+            return None;
+        }
+        let end = self.value.rfind('/')?;
+        Some((&self.value[1..end], &self.value[end + 1..]))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl ESTree for TokenKind {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        self.as_str().serialize(serializer);
+    }
+}
+
+/// Serializes as an espree token:
+/// `{ "type": "Punctuator", "value": "=", "start": 6, "end": 7 }`.
+///
+/// Regular expression tokens also have a `regex` field with `pattern` and `flags`.
+#[cfg(feature = "serialize")]
+impl ESTree for Token<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &self.kind);
+        state.serialize_field("value", self.value);
+        if let Some((pattern, flags)) = self.regex() {
+            state.serialize_field("regex", &RegexValue { pattern, flags });
+        }
+        state.serialize_span(self.span);
+        state.end();
+    }
+}
+
+#[cfg(feature = "serialize")]
+struct RegexValue<'a> {
+    pattern: &'a str,
+    flags: &'a str,
+}
+
+#[cfg(feature = "serialize")]
+impl ESTree for RegexValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("pattern", self.pattern);
+        state.serialize_field("flags", self.flags);
+        state.end();
+    }
+}
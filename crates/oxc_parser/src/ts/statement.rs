@@ -469,6 +469,9 @@ impl<'a> ParserImpl<'a> {
                 Declaration::TSModuleDeclaration(decl)
             }
             Kind::Type if self.is_ts => self.parse_ts_type_alias_declaration(start_span, modifiers),
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_alias_declaration(start_span, modifiers)
+            }
             Kind::Enum if self.is_ts => self.parse_ts_enum_declaration(start_span, modifiers),
             Kind::Interface if self.is_ts => {
                 self.bump_any();
@@ -631,6 +634,10 @@ impl<'a> ParserImpl<'a> {
                 Kind::Static => {
                     self.bump_any();
                 }
+                Kind::Ident if self.source_type.is_flow() && self.cur_src() == "opaque" => {
+                    self.bump_any();
+                    return self.at(Kind::Type) && !self.cur_token().is_on_new_line();
+                }
                 _ => {
                     return false;
                 }
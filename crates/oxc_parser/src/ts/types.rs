@@ -188,6 +188,9 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type_parameter(&mut self) -> TSTypeParameter<'a> {
         let span = self.start_span();
 
+        // Flow variance sigil: `<+T, -U>`
+        self.eat_flow_variance();
+
         let modifiers = self.parse_modifiers(true, false);
         self.verify_modifiers(
             &modifiers,
@@ -196,7 +199,12 @@ impl<'a> ParserImpl<'a> {
         );
 
         let name = self.parse_binding_identifier();
-        let constraint = self.parse_ts_type_constraint();
+        // Flow bound: `<T: Bound>`
+        let constraint = if self.source_type.is_flow() && self.eat(Kind::Colon) {
+            Some(self.parse_ts_type())
+        } else {
+            self.parse_ts_type_constraint()
+        };
         let default = self.parse_ts_default_type();
 
         self.ast.ts_type_parameter(
@@ -233,9 +241,9 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        if (self.at(kind) && !self.at_flow_exact_object_end()) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while !self.at_flow_exact_object_end() && self.eat(kind) {
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -652,6 +660,9 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_type_literal(&mut self) -> TSType<'a> {
+        if self.source_type.is_flow() {
+            return self.parse_flow_object_type();
+        }
         let span = self.start_span();
         let member_list =
             self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_ts_type_signature);
@@ -1059,7 +1070,15 @@ impl<'a> ParserImpl<'a> {
             return None;
         }
         let span = self.start_span();
-        let return_type = self.parse_return_type();
+        let return_type =
+            if self.source_type.is_flow() && self.lexer.peek_token().kind() == Kind::Percent {
+                // Flow `: %checks` without a return type
+                self.bump_any();
+                self.ast.ts_type_boolean_keyword(Span::empty(self.prev_token_end))
+            } else {
+                self.parse_return_type()
+            };
+        self.eat_flow_predicate();
         Some(self.ast.alloc_ts_type_annotation(self.end_span(span), return_type))
     }
 
@@ -1256,7 +1275,7 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
-    fn parse_type_member_semicolon(&mut self) {
+    pub(crate) fn parse_type_member_semicolon(&mut self) {
         // We allow type members to be separated by commas or (possibly ASI) semicolons.
         // First check if it was a comma.  If so, we're done with the member.
         if self.eat(Kind::Comma) {
@@ -1,6 +1,6 @@
 use oxc_span::SourceType;
 
-use crate::frameworks::FrameworkOptions;
+use crate::FrameworkOptions;
 
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
@@ -36,6 +36,10 @@ fn jsdoc_type_in_annotation(
 }
 
 pub fn check_ts_type_annotation(annotation: &TSTypeAnnotation<'_>, ctx: &SemanticBuilder<'_>) {
+    // `?T` is a maybe type in Flow
+    if ctx.source_type.is_flow() {
+        return;
+    }
     let (modifier, is_start, span_with_illegal_modifier) = match &annotation.type_annotation {
         TSType::JSDocNonNullableType(ty) => ('!', !ty.postfix, ty.span()),
         TSType::JSDocNullableType(ty) => ('?', !ty.postfix, ty.span()),
@@ -42,6 +42,8 @@ pub enum Language {
     TypeScript = 1,
     /// Indicates a TypeScript definition file (`*.d.ts`)
     TypeScriptDefinition = 2,
+    /// Indicates a JavaScript or JSX file with [Flow](https://flow.org) type annotations
+    Flow = 3,
 }
 
 /// Script or Module
@@ -282,6 +284,25 @@ impl SourceType {
         Self::ts().with_jsx(true)
     }
 
+    /// Creates a [`SourceType`] representing a [`Flow`] file with [`JSX`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let flow = SourceType::flow();
+    /// assert!(flow.is_flow());
+    /// assert!(!flow.is_javascript());
+    /// assert!(!flow.is_typescript());
+    /// assert!(flow.is_jsx());
+    /// ```
+    ///
+    /// [`Flow`]: Language::Flow
+    /// [`JSX`]: LanguageVariant::Jsx
+    pub const fn flow() -> Self {
+        Self::jsx().with_flow(true)
+    }
+
     /// Creates a [`SourceType`] representing a [`TypeScript definition`] file.
     ///
     /// ## Example
@@ -346,6 +367,11 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JavaScript file with Flow type annotations.
+    pub fn is_flow(self) -> bool {
+        self.language == Language::Flow
+    }
+
     /// Returns `true` if this source type is using JSX.
     ///
     /// Note that TSX is considered JSX in this context.
@@ -431,6 +457,18 @@ impl SourceType {
         self
     }
 
+    /// Mark this [`SourceType`] as using [Flow] if `yes` is `true`. No change
+    /// will occur if `yes` is `false`.
+    ///
+    /// [Flow]: Language::Flow
+    #[must_use]
+    pub const fn with_flow(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Flow;
+        }
+        self
+    }
+
     /// Mark this [`SourceType`] as using [JSX] if `yes` is `true`. No change
     /// will occur if `yes` is `false`.
     ///
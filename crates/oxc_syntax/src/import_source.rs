@@ -0,0 +1,62 @@
+//! Sorting and matching of import sources, shared by the formatter's import sorting, the
+//! `import/order` lint rule and the language server's organize imports.
+
+use std::cmp::Ordering;
+
+/// Compares import sources or specifier names.
+pub fn compare_names(a: &str, b: &str, ignore_case: bool) -> Ordering {
+    if ignore_case {
+        let lowercase = |s: &str| s.bytes().map(|b| b.to_ascii_lowercase()).collect::<Vec<_>>();
+        lowercase(a).cmp(&lowercase(b)).then_with(|| a.cmp(b))
+    } else {
+        a.cmp(b)
+    }
+}
+
+/// Matches `source` against `pattern`, where `*` matches any sequence of characters.
+pub fn matches_pattern(pattern: &str, source: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always a first part, which is empty if the pattern starts with `*`
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = source.strip_prefix(first) else {
+        return false;
+    };
+    let Some(last) = parts.next_back() else {
+        // No `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use super::{compare_names, matches_pattern};
+
+    #[test]
+    fn patterns() {
+        assert!(matches_pattern("react", "react"));
+        assert!(!matches_pattern("react", "react-dom"));
+        assert!(matches_pattern("react*", "react-dom"));
+        assert!(matches_pattern("@/*", "@/components/button"));
+        assert!(!matches_pattern("@/*", "@scope/pkg"));
+        assert!(matches_pattern("*.css", "./styles.css"));
+        assert!(matches_pattern("./*/*.css", "./a/b.css"));
+        assert!(!matches_pattern("./*/*.css", "./b.css"));
+        assert!(matches_pattern("*", ""));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(compare_names("B", "a", false), Ordering::Less);
+        assert_eq!(compare_names("B", "a", true), Ordering::Greater);
+        assert_eq!(compare_names("A", "a", true), Ordering::Less);
+    }
+}
@@ -9,6 +9,7 @@ pub mod class;
 pub mod comment_node;
 pub mod es_target;
 pub mod identifier;
+pub mod import_source;
 pub mod keyword;
 pub mod module_record;
 pub mod node;
@@ -143,9 +143,8 @@ impl<'a> Transformer<'a> {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
             plugins: Plugins::new(self.plugins, &self.ctx),
-            x0_typescript: program
-                .source_type
-                .is_typescript()
+            // Flow type annotations are parsed into TypeScript AST nodes, so they are stripped the same way
+            x0_typescript: (program.source_type.is_typescript() || program.source_type.is_flow())
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
             x2_es2026: ES2026::new(self.env.es2026, &self.ctx),
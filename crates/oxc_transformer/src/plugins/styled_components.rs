@@ -705,46 +705,10 @@ impl<'a> StyledComponents<'a, '_> {
     /// Returns true if the given callee is a styled-components binding.
     /// Handles various forms: styled.div, styled.default, styled(...), etc.
     fn is_styled(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
-        match callee.without_parentheses() {
-            Expression::StaticMemberExpression(member) => {
-                if let Expression::Identifier(ident) = &member.object {
-                    StyledComponentsHelper::from_str(&member.property.name).is_none()
-                        && Self::is_reference_of_styled(self.styled_bindings.styled, ident, ctx)
-                } else if let Expression::StaticMemberExpression(static_member) = &member.object {
-                    // Handle `styled.default`
-                    static_member.property.name == "default"
-                        && matches!(&static_member.object, Expression::Identifier(ident)
-                        if Self::is_reference_of_styled(self.styled_bindings.namespace, ident, ctx))
-                } else {
-                    false
-                }
-            }
-            Expression::CallExpression(call) => match &call.callee {
-                Expression::Identifier(ident) => {
-                    Self::is_reference_of_styled(self.styled_bindings.styled, ident, ctx)
-                }
-                Expression::StaticMemberExpression(member) => self.is_styled(&member.object, ctx),
-                Expression::SequenceExpression(sequence) => {
-                    if let Some(last) = sequence.expressions.last() {
-                        match last {
-                            Expression::Identifier(ident) => Self::is_reference_of_styled(
-                                self.styled_bindings.styled,
-                                ident,
-                                ctx,
-                            ),
-                            Expression::StaticMemberExpression(member) => {
-                                self.is_styled(&member.object, ctx)
-                            }
-                            _ => false,
-                        }
-                    } else {
-                        false
-                    }
-                }
-                _ => false,
-            },
-            _ => false,
-        }
+        callee.is_styled_components_tag(
+            &|ident| Self::is_reference_of_styled(self.styled_bindings.styled, ident, ctx),
+            &|ident| Self::is_reference_of_styled(self.styled_bindings.namespace, ident, ctx),
+        )
     }
 
     /// Checks if the identifier is a helper function of styled-components
@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, TransformOptions, Transformer, TypeScriptOptions};
+
+fn strip(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::flow()).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    // Flow only removes `import type`, unlike TypeScript which also elides unused imports.
+    let options = TransformOptions {
+        typescript: TypeScriptOptions { only_remove_type_imports: true, ..Default::default() },
+        jsx: JsxOptions { jsx_plugin: false, ..JsxOptions::default() },
+        ..TransformOptions::default()
+    };
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new().build(&program).code
+}
+
+#[test]
+fn flow() {
+    let source = "
+// @flow
+import type { Node } from 'react';
+import { type Props, useState } from './props';
+import Unused from 'unused';
+
+export type State = {| count: number, label: ?string |};
+opaque type Id: string = string;
+export opaque type Token = {...};
+
+export function Counter<T: Props>(props: T): Node {
+  const [count, setCount] = useState<number>(0);
+  const id = ((props.id: any): Id);
+  return <div id={id}>{(count: number)}</div>;
+}
+
+class Store<+T> {
+  items: Array<T> = [];
+  add = (item: T): void => { this.items.push(item); };
+}
+";
+    insta::assert_snapshot!(strip(source));
+}
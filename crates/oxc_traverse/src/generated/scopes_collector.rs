@@ -89,6 +89,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -118,6 +119,11 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         // Struct does not contain a scope. Halt traversal.
     }
 
+    #[inline(always)]
+    fn visit_error_expression(&mut self, it: &ErrorExpression) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
     #[inline]
     fn visit_array_expression(&mut self, it: &ArrayExpression<'a>) {
         self.visit_array_expression_elements(&it.elements);
@@ -199,6 +205,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -273,6 +280,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -391,6 +399,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -624,6 +633,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `ContinueStatement`
                 // `DebuggerStatement`
                 // `EmptyStatement`
+                // `ErrorStatement`
                 // `TSImportEqualsDeclaration`
                 // `ImportDeclaration`
                 // `ExportAllDeclaration`
@@ -684,6 +694,11 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         // Struct does not contain a scope. Halt traversal.
     }
 
+    #[inline(always)]
+    fn visit_error_statement(&mut self, it: &ErrorStatement) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
     #[inline]
     fn visit_expression_statement(&mut self, it: &ExpressionStatement<'a>) {
         self.visit_expression(&it.expression);
@@ -776,6 +791,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -1213,6 +1229,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -1373,6 +1390,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `ErrorExpression`
             }
         }
     }
@@ -94,6 +94,21 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_error_expression(
+        &mut self,
+        node: &mut ErrorExpression,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_error_expression(
+        &mut self,
+        node: &mut ErrorExpression,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
     #[inline]
     fn enter_array_expression(
         &mut self,
@@ -780,6 +795,21 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_error_statement(
+        &mut self,
+        node: &mut ErrorStatement,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_error_statement(
+        &mut self,
+        node: &mut ErrorStatement,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
     #[inline]
     fn enter_expression_statement(
         &mut self,
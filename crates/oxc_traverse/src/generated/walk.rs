@@ -197,6 +197,9 @@ unsafe fn walk_expression<'a, State, Tr: Traverse<'a, State>>(
         Expression::V8IntrinsicExpression(node) => {
             walk_v8_intrinsic_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::ErrorExpression(node) => {
+            walk_error_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -251,6 +254,15 @@ unsafe fn walk_this_expression<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_this_expression(&mut *node, ctx);
 }
 
+unsafe fn walk_error_expression<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut ErrorExpression,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_error_expression(&mut *node, ctx);
+    traverser.exit_error_expression(&mut *node, ctx);
+}
+
 unsafe fn walk_array_expression<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     node: *mut ArrayExpression<'a>,
@@ -320,6 +332,7 @@ unsafe fn walk_array_expression_element<'a, State, Tr: Traverse<'a, State>>(
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
         | ArrayExpressionElement::V8IntrinsicExpression(_)
+        | ArrayExpressionElement::ErrorExpression(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -450,6 +463,7 @@ unsafe fn walk_property_key<'a, State, Tr: Traverse<'a, State>>(
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
         | PropertyKey::V8IntrinsicExpression(_)
+        | PropertyKey::ErrorExpression(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -773,6 +787,7 @@ unsafe fn walk_argument<'a, State, Tr: Traverse<'a, State>>(
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
         | Argument::V8IntrinsicExpression(_)
+        | Argument::ErrorExpression(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -1372,6 +1387,9 @@ unsafe fn walk_statement<'a, State, Tr: Traverse<'a, State>>(
         Statement::WithStatement(node) => {
             walk_with_statement(traverser, (&mut **node) as *mut _, ctx)
         }
+        Statement::ErrorStatement(node) => {
+            walk_error_statement(traverser, (&mut **node) as *mut _, ctx)
+        }
         Statement::VariableDeclaration(_)
         | Statement::FunctionDeclaration(_)
         | Statement::ClassDeclaration(_)
@@ -1533,6 +1551,15 @@ unsafe fn walk_empty_statement<'a, State, Tr: Traverse<'a, State>>(
     traverser.exit_empty_statement(&mut *node, ctx);
 }
 
+unsafe fn walk_error_statement<'a, State, Tr: Traverse<'a, State>>(
+    traverser: &mut Tr,
+    node: *mut ErrorStatement,
+    ctx: &mut TraverseCtx<'a, State>,
+) {
+    traverser.enter_error_statement(&mut *node, ctx);
+    traverser.exit_error_statement(&mut *node, ctx);
+}
+
 unsafe fn walk_expression_statement<'a, State, Tr: Traverse<'a, State>>(
     traverser: &mut Tr,
     node: *mut ExpressionStatement<'a>,
@@ -1722,6 +1749,7 @@ unsafe fn walk_for_statement_init<'a, State, Tr: Traverse<'a, State>>(
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
         | ForStatementInit::V8IntrinsicExpression(_)
+        | ForStatementInit::ErrorExpression(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -3112,6 +3140,7 @@ unsafe fn walk_export_default_declaration_kind<'a, State, Tr: Traverse<'a, State
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
         | ExportDefaultDeclarationKind::V8IntrinsicExpression(_)
+        | ExportDefaultDeclarationKind::ErrorExpression(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3460,6 +3489,7 @@ unsafe fn walk_jsx_expression<'a, State, Tr: Traverse<'a, State>>(
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
         | JSXExpression::V8IntrinsicExpression(_)
+        | JSXExpression::ErrorExpression(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
@@ -0,0 +1,129 @@
+//! Conversion of YAML config files to JSON, shared by `oxlint` and `oxfmt` so YAML configs are
+//! deserialized the same way as JSON configs.
+
+use saphyr::{LoadableYamlNode, Scalar, Yaml};
+use serde_json::{Map, Number, Value};
+
+/// Parse a YAML document into a JSON value.
+///
+/// # Errors
+/// Returns an error message if `source` is not valid YAML, contains more than one document, or
+/// uses values which can not be represented in JSON.
+pub fn parse(source: &str) -> Result<Value, String> {
+    let mut documents = Yaml::load_from_str(source).map_err(|err| err.to_string())?;
+    match documents.len() {
+        0 => Ok(Value::Null),
+        1 => to_json(&documents.remove(0)),
+        _ => Err("Multiple YAML documents are not supported".to_string()),
+    }
+}
+
+fn to_json(yaml: &Yaml) -> Result<Value, String> {
+    Ok(match yaml {
+        Yaml::Value(scalar) => match scalar {
+            Scalar::Null => Value::Null,
+            Scalar::Boolean(value) => Value::Bool(*value),
+            Scalar::Integer(value) => Value::from(*value),
+            Scalar::FloatingPoint(value) => Number::from_f64(value.into_inner())
+                .map(Value::Number)
+                .ok_or_else(|| format!("`{value}` can not be represented in JSON"))?,
+            Scalar::String(value) => Value::String(value.to_string()),
+        },
+        Yaml::Sequence(items) => Value::Array(items.iter().map(to_json).collect::<Result<_, _>>()?),
+        Yaml::Mapping(entries) => {
+            let mut map = Map::new();
+            for (key, value) in entries {
+                let key = match to_json(key)? {
+                    Value::String(key) => key,
+                    key @ (Value::Null | Value::Bool(_) | Value::Number(_)) => key.to_string(),
+                    _ => return Err("Mapping keys must be scalars".to_string()),
+                };
+                map.insert(key, to_json(value)?);
+            }
+            Value::Object(map)
+        }
+        Yaml::Tagged(_, value) => to_json(value)?,
+        Yaml::Representation(..) | Yaml::Alias(_) | Yaml::BadValue => {
+            return Err("Invalid YAML value".to_string());
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::parse;
+
+    #[test]
+    fn mappings_and_sequences() {
+        let source = r#"
+# ESLint configuration
+root: true
+env:
+  browser: true
+  node: false
+extends:
+  - eslint:recommended
+  - "plugin:react/recommended"
+rules:
+  eqeqeq: [error, always]   # trailing comment
+  "no-console": 'off'
+  quotes:
+    - warn
+    - single
+    - avoidEscape: true
+      allowTemplateLiterals: false
+  max-len: [error, {
+    code: 100 }]
+overrides:
+- files: ["*.test.js"]
+  rules:
+    no-unused-expressions: 0
+"#;
+        assert_eq!(
+            parse(source).unwrap(),
+            json!({
+                "root": true,
+                "env": { "browser": true, "node": false },
+                "extends": ["eslint:recommended", "plugin:react/recommended"],
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "no-console": "off",
+                    "quotes": ["warn", "single", { "avoidEscape": true, "allowTemplateLiterals": false }],
+                    "max-len": ["error", { "code": 100 }]
+                },
+                "overrides": [{ "files": ["*.test.js"], "rules": { "no-unused-expressions": 0 } }]
+            })
+        );
+    }
+
+    #[test]
+    fn scalars() {
+        let source = "a: 1\nb: -2.5\nc: ~\nd: 'it''s'\ne: \"tab\\there\"\nf: foo bar\ng: '#not a comment'\nh:";
+        assert_eq!(
+            parse(source).unwrap(),
+            json!({
+                "a": 1, "b": -2.5, "c": null, "d": "it's", "e": "tab\there",
+                "f": "foo bar", "g": "#not a comment", "h": null
+            })
+        );
+        assert_eq!(parse("").unwrap(), json!(null));
+        assert_eq!(parse("a: &x 1\nb: *x").unwrap(), json!({ "a": 1, "b": 1 }));
+        assert_eq!(parse("- - a\n  - b\n- c").unwrap(), json!([["a", "b"], "c"]));
+        assert_eq!(
+            parse("1: a\ntrue: b\n~: c").unwrap(),
+            json!({ "1": "a", "true": "b", "null": "c" })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("a: 1\n  b: 2").is_err());
+        assert!(parse("a: [1, 2").is_err());
+        assert!(parse("a: 1\n---\nb: 2").is_err());
+        assert!(parse("a: .nan").is_err());
+        assert!(parse("a: .inf").is_err());
+        assert!(parse("[a]: 1").is_err());
+    }
+}
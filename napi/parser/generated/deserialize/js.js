@@ -128,6 +128,8 @@ function deserializeExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -188,6 +190,15 @@ function deserializeThisExpression(pos) {
   };
 }
 
+function deserializeErrorExpression(pos) {
+  let start = deserializeU32(pos), end = deserializeU32(pos + 4);
+  return {
+    type: 'ErrorExpression',
+    start,
+    end,
+  };
+}
+
 function deserializeArrayExpression(pos) {
   let start = deserializeU32(pos),
     end = deserializeU32(pos + 4),
@@ -283,6 +294,8 @@ function deserializeArrayExpressionElement(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -427,6 +440,8 @@ function deserializePropertyKey(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -703,6 +718,8 @@ function deserializeArgument(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -1149,6 +1166,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorStatement(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -1297,6 +1316,15 @@ function deserializeEmptyStatement(pos) {
   };
 }
 
+function deserializeErrorStatement(pos) {
+  let start = deserializeU32(pos), end = deserializeU32(pos + 4);
+  return {
+    type: 'ErrorStatement',
+    start,
+    end,
+  };
+}
+
 function deserializeExpressionStatement(pos) {
   let start = deserializeU32(pos),
     end = deserializeU32(pos + 4),
@@ -1458,6 +1486,8 @@ function deserializeForStatementInit(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2373,6 +2403,8 @@ function deserializeExportDefaultDeclarationKind(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -2805,6 +2837,8 @@ function deserializeJSXExpression(pos) {
       return deserializeBoxTSInstantiationExpression(pos + 8);
     case 39:
       return deserializeBoxV8IntrinsicExpression(pos + 8);
+    case 40:
+      return deserializeBoxErrorExpression(pos + 8);
     case 48:
       return deserializeBoxComputedMemberExpression(pos + 8);
     case 49:
@@ -5027,6 +5061,10 @@ function deserializeBoxV8IntrinsicExpression(pos) {
   return deserializeV8IntrinsicExpression(uint32[pos >> 2]);
 }
 
+function deserializeBoxErrorExpression(pos) {
+  return deserializeErrorExpression(uint32[pos >> 2]);
+}
+
 function deserializeVecArrayExpressionElement(pos) {
   let arr = [], pos32 = pos >> 2;
   pos = uint32[pos32];
@@ -5261,6 +5299,10 @@ function deserializeBoxWithStatement(pos) {
   return deserializeWithStatement(uint32[pos >> 2]);
 }
 
+function deserializeBoxErrorStatement(pos) {
+  return deserializeErrorStatement(uint32[pos >> 2]);
+}
+
 function deserializeBoxVariableDeclaration(pos) {
   return deserializeVariableDeclaration(uint32[pos >> 2]);
 }
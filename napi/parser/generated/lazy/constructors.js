@@ -151,6 +151,8 @@ function constructExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -394,6 +396,46 @@ export class ThisExpression {
 
 const DebugThisExpression = class ThisExpression {};
 
+export class ErrorExpression {
+  type = 'ErrorExpression';
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: 'ErrorExpression',
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugErrorExpression.prototype);
+  }
+}
+
+const DebugErrorExpression = class ErrorExpression {};
+
 export class ArrayExpression {
   type = 'ArrayExpression';
   #internal;
@@ -524,6 +566,8 @@ function constructArrayExpressionElement(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -796,6 +840,8 @@ function constructPropertyKey(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -1525,6 +1571,8 @@ function constructArgument(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -2618,6 +2666,8 @@ function constructStatement(pos, ast) {
       return constructBoxWhileStatement(pos + 8, ast);
     case 17:
       return constructBoxWithStatement(pos + 8, ast);
+    case 18:
+      return constructBoxErrorStatement(pos + 8, ast);
     case 32:
       return constructBoxVariableDeclaration(pos + 8, ast);
     case 33:
@@ -2999,6 +3049,46 @@ export class EmptyStatement {
 
 const DebugEmptyStatement = class EmptyStatement {};
 
+export class ErrorStatement {
+  type = 'ErrorStatement';
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructU32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructU32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: 'ErrorStatement',
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugErrorStatement.prototype);
+  }
+}
+
+const DebugErrorStatement = class ErrorStatement {};
+
 export class ExpressionStatement {
   type = 'ExpressionStatement';
   #internal;
@@ -3353,6 +3443,8 @@ function constructForStatementInit(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6186,6 +6278,8 @@ function constructExportDefaultDeclarationKind(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -7242,6 +7336,8 @@ function constructJSXExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxErrorExpression(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -12781,6 +12877,10 @@ function constructBoxV8IntrinsicExpression(pos, ast) {
   return new V8IntrinsicExpression(ast.buffer.uint32[pos >> 2], ast);
 }
 
+function constructBoxErrorExpression(pos, ast) {
+  return new ErrorExpression(ast.buffer.uint32[pos >> 2], ast);
+}
+
 function constructVecArrayExpressionElement(pos, ast) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
@@ -13026,6 +13126,10 @@ function constructBoxWithStatement(pos, ast) {
   return new WithStatement(ast.buffer.uint32[pos >> 2], ast);
 }
 
+function constructBoxErrorStatement(pos, ast) {
+  return new ErrorStatement(ast.buffer.uint32[pos >> 2], ast);
+}
+
 function constructBoxVariableDeclaration(pos, ast) {
   return new VariableDeclaration(ast.buffer.uint32[pos >> 2], ast);
 }
@@ -9,182 +9,184 @@ export const NODE_TYPE_IDS_MAP = new Map([
   ['BindingIdentifier', 2],
   ['LabelIdentifier', 3],
   ['ThisExpression', 4],
-  ['Elision', 5],
-  ['TemplateElement', 6],
-  ['Super', 7],
-  ['Hashbang', 8],
-  ['EmptyStatement', 9],
-  ['DebuggerStatement', 10],
-  ['PrivateIdentifier', 11],
-  ['BooleanLiteral', 12],
-  ['NullLiteral', 13],
-  ['NumericLiteral', 14],
-  ['StringLiteral', 15],
-  ['BigIntLiteral', 16],
-  ['RegExpLiteral', 17],
-  ['JSXOpeningFragment', 18],
-  ['JSXClosingFragment', 19],
-  ['JSXEmptyExpression', 20],
-  ['JSXIdentifier', 21],
-  ['JSXText', 22],
-  ['TSAnyKeyword', 23],
-  ['TSStringKeyword', 24],
-  ['TSBooleanKeyword', 25],
-  ['TSNumberKeyword', 26],
-  ['TSNeverKeyword', 27],
-  ['TSIntrinsicKeyword', 28],
-  ['TSUnknownKeyword', 29],
-  ['TSNullKeyword', 30],
-  ['TSUndefinedKeyword', 31],
-  ['TSVoidKeyword', 32],
-  ['TSSymbolKeyword', 33],
-  ['TSThisType', 34],
-  ['TSObjectKeyword', 35],
-  ['TSBigIntKeyword', 36],
-  ['JSDocUnknownType', 37],
+  ['ErrorExpression', 5],
+  ['Elision', 6],
+  ['TemplateElement', 7],
+  ['Super', 8],
+  ['Hashbang', 9],
+  ['EmptyStatement', 10],
+  ['ErrorStatement', 11],
+  ['DebuggerStatement', 12],
+  ['PrivateIdentifier', 13],
+  ['BooleanLiteral', 14],
+  ['NullLiteral', 15],
+  ['NumericLiteral', 16],
+  ['StringLiteral', 17],
+  ['BigIntLiteral', 18],
+  ['RegExpLiteral', 19],
+  ['JSXOpeningFragment', 20],
+  ['JSXClosingFragment', 21],
+  ['JSXEmptyExpression', 22],
+  ['JSXIdentifier', 23],
+  ['JSXText', 24],
+  ['TSAnyKeyword', 25],
+  ['TSStringKeyword', 26],
+  ['TSBooleanKeyword', 27],
+  ['TSNumberKeyword', 28],
+  ['TSNeverKeyword', 29],
+  ['TSIntrinsicKeyword', 30],
+  ['TSUnknownKeyword', 31],
+  ['TSNullKeyword', 32],
+  ['TSUndefinedKeyword', 33],
+  ['TSVoidKeyword', 34],
+  ['TSSymbolKeyword', 35],
+  ['TSThisType', 36],
+  ['TSObjectKeyword', 37],
+  ['TSBigIntKeyword', 38],
+  ['JSDocUnknownType', 39],
   // Non-leaf nodes
-  ['Program', 38],
-  ['ArrayExpression', 39],
-  ['ObjectExpression', 40],
-  ['ObjectProperty', 41],
-  ['TemplateLiteral', 42],
-  ['TaggedTemplateExpression', 43],
-  ['ComputedMemberExpression', 44],
-  ['StaticMemberExpression', 45],
-  ['PrivateFieldExpression', 46],
-  ['CallExpression', 47],
-  ['NewExpression', 48],
-  ['MetaProperty', 49],
-  ['SpreadElement', 50],
-  ['UpdateExpression', 51],
-  ['UnaryExpression', 52],
-  ['BinaryExpression', 53],
-  ['PrivateInExpression', 54],
-  ['LogicalExpression', 55],
-  ['ConditionalExpression', 56],
-  ['AssignmentExpression', 57],
-  ['ArrayAssignmentTarget', 58],
-  ['ObjectAssignmentTarget', 59],
-  ['AssignmentTargetWithDefault', 60],
-  ['AssignmentTargetPropertyIdentifier', 61],
-  ['AssignmentTargetPropertyProperty', 62],
-  ['SequenceExpression', 63],
-  ['AwaitExpression', 64],
-  ['ChainExpression', 65],
-  ['ParenthesizedExpression', 66],
-  ['BlockStatement', 67],
-  ['VariableDeclaration', 68],
-  ['VariableDeclarator', 69],
-  ['ExpressionStatement', 70],
-  ['IfStatement', 71],
-  ['DoWhileStatement', 72],
-  ['WhileStatement', 73],
-  ['ForStatement', 74],
-  ['ForInStatement', 75],
-  ['ForOfStatement', 76],
-  ['ContinueStatement', 77],
-  ['BreakStatement', 78],
-  ['ReturnStatement', 79],
-  ['WithStatement', 80],
-  ['SwitchStatement', 81],
-  ['SwitchCase', 82],
-  ['LabeledStatement', 83],
-  ['ThrowStatement', 84],
-  ['TryStatement', 85],
-  ['CatchClause', 86],
-  ['AssignmentPattern', 87],
-  ['ObjectPattern', 88],
-  ['BindingProperty', 89],
-  ['ArrayPattern', 90],
-  ['Function', 91],
-  ['FormalParameters', 92],
-  ['FunctionBody', 93],
-  ['ArrowFunctionExpression', 94],
-  ['YieldExpression', 95],
-  ['Class', 96],
-  ['ClassBody', 97],
-  ['MethodDefinition', 98],
-  ['PropertyDefinition', 99],
-  ['StaticBlock', 100],
-  ['AccessorProperty', 101],
-  ['ImportExpression', 102],
-  ['ImportDeclaration', 103],
-  ['ImportSpecifier', 104],
-  ['ImportDefaultSpecifier', 105],
-  ['ImportNamespaceSpecifier', 106],
-  ['ImportAttribute', 107],
-  ['ExportNamedDeclaration', 108],
-  ['ExportDefaultDeclaration', 109],
-  ['ExportAllDeclaration', 110],
-  ['ExportSpecifier', 111],
-  ['V8IntrinsicExpression', 112],
-  ['JSXElement', 113],
-  ['JSXOpeningElement', 114],
-  ['JSXClosingElement', 115],
-  ['JSXFragment', 116],
-  ['JSXNamespacedName', 117],
-  ['JSXMemberExpression', 118],
-  ['JSXExpressionContainer', 119],
-  ['JSXAttribute', 120],
-  ['JSXSpreadAttribute', 121],
-  ['JSXSpreadChild', 122],
-  ['TSEnumDeclaration', 123],
-  ['TSEnumBody', 124],
-  ['TSEnumMember', 125],
-  ['TSTypeAnnotation', 126],
-  ['TSLiteralType', 127],
-  ['TSConditionalType', 128],
-  ['TSUnionType', 129],
-  ['TSIntersectionType', 130],
-  ['TSParenthesizedType', 131],
-  ['TSTypeOperator', 132],
-  ['TSArrayType', 133],
-  ['TSIndexedAccessType', 134],
-  ['TSTupleType', 135],
-  ['TSNamedTupleMember', 136],
-  ['TSOptionalType', 137],
-  ['TSRestType', 138],
-  ['TSTypeReference', 139],
-  ['TSQualifiedName', 140],
-  ['TSTypeParameterInstantiation', 141],
-  ['TSTypeParameter', 142],
-  ['TSTypeParameterDeclaration', 143],
-  ['TSTypeAliasDeclaration', 144],
-  ['TSClassImplements', 145],
-  ['TSInterfaceDeclaration', 146],
-  ['TSInterfaceBody', 147],
-  ['TSPropertySignature', 148],
-  ['TSIndexSignature', 149],
-  ['TSCallSignatureDeclaration', 150],
-  ['TSMethodSignature', 151],
-  ['TSConstructSignatureDeclaration', 152],
-  ['TSIndexSignatureName', 153],
-  ['TSInterfaceHeritage', 154],
-  ['TSTypePredicate', 155],
-  ['TSModuleDeclaration', 156],
-  ['TSModuleBlock', 157],
-  ['TSTypeLiteral', 158],
-  ['TSInferType', 159],
-  ['TSTypeQuery', 160],
-  ['TSImportType', 161],
-  ['TSImportTypeQualifiedName', 162],
-  ['TSFunctionType', 163],
-  ['TSConstructorType', 164],
-  ['TSMappedType', 165],
-  ['TSTemplateLiteralType', 166],
-  ['TSAsExpression', 167],
-  ['TSSatisfiesExpression', 168],
-  ['TSTypeAssertion', 169],
-  ['TSImportEqualsDeclaration', 170],
-  ['TSExternalModuleReference', 171],
-  ['TSNonNullExpression', 172],
-  ['Decorator', 173],
-  ['TSExportAssignment', 174],
-  ['TSNamespaceExportDeclaration', 175],
-  ['TSInstantiationExpression', 176],
-  ['JSDocNullableType', 177],
-  ['JSDocNonNullableType', 178],
+  ['Program', 40],
+  ['ArrayExpression', 41],
+  ['ObjectExpression', 42],
+  ['ObjectProperty', 43],
+  ['TemplateLiteral', 44],
+  ['TaggedTemplateExpression', 45],
+  ['ComputedMemberExpression', 46],
+  ['StaticMemberExpression', 47],
+  ['PrivateFieldExpression', 48],
+  ['CallExpression', 49],
+  ['NewExpression', 50],
+  ['MetaProperty', 51],
+  ['SpreadElement', 52],
+  ['UpdateExpression', 53],
+  ['UnaryExpression', 54],
+  ['BinaryExpression', 55],
+  ['PrivateInExpression', 56],
+  ['LogicalExpression', 57],
+  ['ConditionalExpression', 58],
+  ['AssignmentExpression', 59],
+  ['ArrayAssignmentTarget', 60],
+  ['ObjectAssignmentTarget', 61],
+  ['AssignmentTargetWithDefault', 62],
+  ['AssignmentTargetPropertyIdentifier', 63],
+  ['AssignmentTargetPropertyProperty', 64],
+  ['SequenceExpression', 65],
+  ['AwaitExpression', 66],
+  ['ChainExpression', 67],
+  ['ParenthesizedExpression', 68],
+  ['BlockStatement', 69],
+  ['VariableDeclaration', 70],
+  ['VariableDeclarator', 71],
+  ['ExpressionStatement', 72],
+  ['IfStatement', 73],
+  ['DoWhileStatement', 74],
+  ['WhileStatement', 75],
+  ['ForStatement', 76],
+  ['ForInStatement', 77],
+  ['ForOfStatement', 78],
+  ['ContinueStatement', 79],
+  ['BreakStatement', 80],
+  ['ReturnStatement', 81],
+  ['WithStatement', 82],
+  ['SwitchStatement', 83],
+  ['SwitchCase', 84],
+  ['LabeledStatement', 85],
+  ['ThrowStatement', 86],
+  ['TryStatement', 87],
+  ['CatchClause', 88],
+  ['AssignmentPattern', 89],
+  ['ObjectPattern', 90],
+  ['BindingProperty', 91],
+  ['ArrayPattern', 92],
+  ['Function', 93],
+  ['FormalParameters', 94],
+  ['FunctionBody', 95],
+  ['ArrowFunctionExpression', 96],
+  ['YieldExpression', 97],
+  ['Class', 98],
+  ['ClassBody', 99],
+  ['MethodDefinition', 100],
+  ['PropertyDefinition', 101],
+  ['StaticBlock', 102],
+  ['AccessorProperty', 103],
+  ['ImportExpression', 104],
+  ['ImportDeclaration', 105],
+  ['ImportSpecifier', 106],
+  ['ImportDefaultSpecifier', 107],
+  ['ImportNamespaceSpecifier', 108],
+  ['ImportAttribute', 109],
+  ['ExportNamedDeclaration', 110],
+  ['ExportDefaultDeclaration', 111],
+  ['ExportAllDeclaration', 112],
+  ['ExportSpecifier', 113],
+  ['V8IntrinsicExpression', 114],
+  ['JSXElement', 115],
+  ['JSXOpeningElement', 116],
+  ['JSXClosingElement', 117],
+  ['JSXFragment', 118],
+  ['JSXNamespacedName', 119],
+  ['JSXMemberExpression', 120],
+  ['JSXExpressionContainer', 121],
+  ['JSXAttribute', 122],
+  ['JSXSpreadAttribute', 123],
+  ['JSXSpreadChild', 124],
+  ['TSEnumDeclaration', 125],
+  ['TSEnumBody', 126],
+  ['TSEnumMember', 127],
+  ['TSTypeAnnotation', 128],
+  ['TSLiteralType', 129],
+  ['TSConditionalType', 130],
+  ['TSUnionType', 131],
+  ['TSIntersectionType', 132],
+  ['TSParenthesizedType', 133],
+  ['TSTypeOperator', 134],
+  ['TSArrayType', 135],
+  ['TSIndexedAccessType', 136],
+  ['TSTupleType', 137],
+  ['TSNamedTupleMember', 138],
+  ['TSOptionalType', 139],
+  ['TSRestType', 140],
+  ['TSTypeReference', 141],
+  ['TSQualifiedName', 142],
+  ['TSTypeParameterInstantiation', 143],
+  ['TSTypeParameter', 144],
+  ['TSTypeParameterDeclaration', 145],
+  ['TSTypeAliasDeclaration', 146],
+  ['TSClassImplements', 147],
+  ['TSInterfaceDeclaration', 148],
+  ['TSInterfaceBody', 149],
+  ['TSPropertySignature', 150],
+  ['TSIndexSignature', 151],
+  ['TSCallSignatureDeclaration', 152],
+  ['TSMethodSignature', 153],
+  ['TSConstructSignatureDeclaration', 154],
+  ['TSIndexSignatureName', 155],
+  ['TSInterfaceHeritage', 156],
+  ['TSTypePredicate', 157],
+  ['TSModuleDeclaration', 158],
+  ['TSModuleBlock', 159],
+  ['TSTypeLiteral', 160],
+  ['TSInferType', 161],
+  ['TSTypeQuery', 162],
+  ['TSImportType', 163],
+  ['TSImportTypeQualifiedName', 164],
+  ['TSFunctionType', 165],
+  ['TSConstructorType', 166],
+  ['TSMappedType', 167],
+  ['TSTemplateLiteralType', 168],
+  ['TSAsExpression', 169],
+  ['TSSatisfiesExpression', 170],
+  ['TSTypeAssertion', 171],
+  ['TSImportEqualsDeclaration', 172],
+  ['TSExternalModuleReference', 173],
+  ['TSNonNullExpression', 174],
+  ['Decorator', 175],
+  ['TSExportAssignment', 176],
+  ['TSNamespaceExportDeclaration', 177],
+  ['TSInstantiationExpression', 178],
+  ['JSDocNullableType', 179],
+  ['JSDocNonNullableType', 180],
 ]);
 
-export const NODE_TYPES_COUNT = 179;
-export const LEAF_NODE_TYPES_COUNT = 38;
+export const NODE_TYPES_COUNT = 181;
+export const LEAF_NODE_TYPES_COUNT = 40;
@@ -33,6 +33,8 @@ import {
   DoWhileStatement,
   Elision,
   EmptyStatement,
+  ErrorExpression,
+  ErrorStatement,
   ExportAllDeclaration,
   ExportDefaultDeclaration,
   ExportNamedDeclaration,
@@ -186,7 +188,7 @@ import {
 export { walkProgram };
 
 function walkProgram(pos, ast, visitors) {
-  const enterExit = visitors[38];
+  const enterExit = visitors[40];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -322,6 +324,9 @@ function walkExpression(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -361,8 +366,13 @@ function walkThisExpression(pos, ast, visitors) {
   if (visit !== null) visit(new ThisExpression(pos, ast));
 }
 
+function walkErrorExpression(pos, ast, visitors) {
+  const visit = visitors[5];
+  if (visit !== null) visit(new ErrorExpression(pos, ast));
+}
+
 function walkArrayExpression(pos, ast, visitors) {
-  const enterExit = visitors[39];
+  const enterExit = visitors[41];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -497,6 +507,9 @@ function walkArrayExpressionElement(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -518,12 +531,12 @@ function walkArrayExpressionElement(pos, ast, visitors) {
 }
 
 function walkElision(pos, ast, visitors) {
-  const visit = visitors[5];
+  const visit = visitors[6];
   if (visit !== null) visit(new Elision(pos, ast));
 }
 
 function walkObjectExpression(pos, ast, visitors) {
-  const enterExit = visitors[40];
+  const enterExit = visitors[42];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -550,7 +563,7 @@ function walkObjectPropertyKind(pos, ast, visitors) {
 }
 
 function walkObjectProperty(pos, ast, visitors) {
-  const enterExit = visitors[41];
+  const enterExit = visitors[43];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -686,6 +699,9 @@ function walkPropertyKey(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -707,7 +723,7 @@ function walkPropertyKey(pos, ast, visitors) {
 }
 
 function walkTemplateLiteral(pos, ast, visitors) {
-  const enterExit = visitors[42];
+  const enterExit = visitors[44];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -722,7 +738,7 @@ function walkTemplateLiteral(pos, ast, visitors) {
 }
 
 function walkTaggedTemplateExpression(pos, ast, visitors) {
-  const enterExit = visitors[43];
+  const enterExit = visitors[45];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -738,12 +754,12 @@ function walkTaggedTemplateExpression(pos, ast, visitors) {
 }
 
 function walkTemplateElement(pos, ast, visitors) {
-  const visit = visitors[6];
+  const visit = visitors[7];
   if (visit !== null) visit(new TemplateElement(pos, ast));
 }
 
 function walkComputedMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[44];
+  const enterExit = visitors[46];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -758,7 +774,7 @@ function walkComputedMemberExpression(pos, ast, visitors) {
 }
 
 function walkStaticMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[45];
+  const enterExit = visitors[47];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -773,7 +789,7 @@ function walkStaticMemberExpression(pos, ast, visitors) {
 }
 
 function walkPrivateFieldExpression(pos, ast, visitors) {
-  const enterExit = visitors[46];
+  const enterExit = visitors[48];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -788,7 +804,7 @@ function walkPrivateFieldExpression(pos, ast, visitors) {
 }
 
 function walkCallExpression(pos, ast, visitors) {
-  const enterExit = visitors[47];
+  const enterExit = visitors[49];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -804,7 +820,7 @@ function walkCallExpression(pos, ast, visitors) {
 }
 
 function walkNewExpression(pos, ast, visitors) {
-  const enterExit = visitors[48];
+  const enterExit = visitors[50];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -820,7 +836,7 @@ function walkNewExpression(pos, ast, visitors) {
 }
 
 function walkMetaProperty(pos, ast, visitors) {
-  const enterExit = visitors[49];
+  const enterExit = visitors[51];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -835,7 +851,7 @@ function walkMetaProperty(pos, ast, visitors) {
 }
 
 function walkSpreadElement(pos, ast, visitors) {
-  const enterExit = visitors[50];
+  const enterExit = visitors[52];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -970,6 +986,9 @@ function walkArgument(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -988,7 +1007,7 @@ function walkArgument(pos, ast, visitors) {
 }
 
 function walkUpdateExpression(pos, ast, visitors) {
-  const enterExit = visitors[51];
+  const enterExit = visitors[53];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1002,7 +1021,7 @@ function walkUpdateExpression(pos, ast, visitors) {
 }
 
 function walkUnaryExpression(pos, ast, visitors) {
-  const enterExit = visitors[52];
+  const enterExit = visitors[54];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1016,7 +1035,7 @@ function walkUnaryExpression(pos, ast, visitors) {
 }
 
 function walkBinaryExpression(pos, ast, visitors) {
-  const enterExit = visitors[53];
+  const enterExit = visitors[55];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1031,7 +1050,7 @@ function walkBinaryExpression(pos, ast, visitors) {
 }
 
 function walkPrivateInExpression(pos, ast, visitors) {
-  const enterExit = visitors[54];
+  const enterExit = visitors[56];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1046,7 +1065,7 @@ function walkPrivateInExpression(pos, ast, visitors) {
 }
 
 function walkLogicalExpression(pos, ast, visitors) {
-  const enterExit = visitors[55];
+  const enterExit = visitors[57];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1061,7 +1080,7 @@ function walkLogicalExpression(pos, ast, visitors) {
 }
 
 function walkConditionalExpression(pos, ast, visitors) {
-  const enterExit = visitors[56];
+  const enterExit = visitors[58];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1077,7 +1096,7 @@ function walkConditionalExpression(pos, ast, visitors) {
 }
 
 function walkAssignmentExpression(pos, ast, visitors) {
-  const enterExit = visitors[57];
+  const enterExit = visitors[59];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1160,7 +1179,7 @@ function walkSimpleAssignmentTarget(pos, ast, visitors) {
 }
 
 function walkArrayAssignmentTarget(pos, ast, visitors) {
-  const enterExit = visitors[58];
+  const enterExit = visitors[60];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1174,7 +1193,7 @@ function walkArrayAssignmentTarget(pos, ast, visitors) {
 }
 
 function walkObjectAssignmentTarget(pos, ast, visitors) {
-  const enterExit = visitors[59];
+  const enterExit = visitors[61];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1228,7 +1247,7 @@ function walkAssignmentTargetMaybeDefault(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetWithDefault(pos, ast, visitors) {
-  const enterExit = visitors[60];
+  const enterExit = visitors[62];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1256,7 +1275,7 @@ function walkAssignmentTargetProperty(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetPropertyIdentifier(pos, ast, visitors) {
-  const enterExit = visitors[61];
+  const enterExit = visitors[63];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1271,7 +1290,7 @@ function walkAssignmentTargetPropertyIdentifier(pos, ast, visitors) {
 }
 
 function walkAssignmentTargetPropertyProperty(pos, ast, visitors) {
-  const enterExit = visitors[62];
+  const enterExit = visitors[64];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1286,7 +1305,7 @@ function walkAssignmentTargetPropertyProperty(pos, ast, visitors) {
 }
 
 function walkSequenceExpression(pos, ast, visitors) {
-  const enterExit = visitors[63];
+  const enterExit = visitors[65];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1300,12 +1319,12 @@ function walkSequenceExpression(pos, ast, visitors) {
 }
 
 function walkSuper(pos, ast, visitors) {
-  const visit = visitors[7];
+  const visit = visitors[8];
   if (visit !== null) visit(new Super(pos, ast));
 }
 
 function walkAwaitExpression(pos, ast, visitors) {
-  const enterExit = visitors[64];
+  const enterExit = visitors[66];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1319,7 +1338,7 @@ function walkAwaitExpression(pos, ast, visitors) {
 }
 
 function walkChainExpression(pos, ast, visitors) {
-  const enterExit = visitors[65];
+  const enterExit = visitors[67];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1355,7 +1374,7 @@ function walkChainElement(pos, ast, visitors) {
 }
 
 function walkParenthesizedExpression(pos, ast, visitors) {
-  const enterExit = visitors[66];
+  const enterExit = visitors[68];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1424,6 +1443,9 @@ function walkStatement(pos, ast, visitors) {
     case 17:
       walkBoxWithStatement(pos + 8, ast, visitors);
       return;
+    case 18:
+      walkBoxErrorStatement(pos + 8, ast, visitors);
+      return;
     case 32:
       walkBoxVariableDeclaration(pos + 8, ast, visitors);
       return;
@@ -1472,12 +1494,12 @@ function walkStatement(pos, ast, visitors) {
 }
 
 function walkHashbang(pos, ast, visitors) {
-  const visit = visitors[8];
+  const visit = visitors[9];
   if (visit !== null) visit(new Hashbang(pos, ast));
 }
 
 function walkBlockStatement(pos, ast, visitors) {
-  const enterExit = visitors[67];
+  const enterExit = visitors[69];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1522,7 +1544,7 @@ function walkDeclaration(pos, ast, visitors) {
 }
 
 function walkVariableDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[68];
+  const enterExit = visitors[70];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1536,7 +1558,7 @@ function walkVariableDeclaration(pos, ast, visitors) {
 }
 
 function walkVariableDeclarator(pos, ast, visitors) {
-  const enterExit = visitors[69];
+  const enterExit = visitors[71];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1551,12 +1573,17 @@ function walkVariableDeclarator(pos, ast, visitors) {
 }
 
 function walkEmptyStatement(pos, ast, visitors) {
-  const visit = visitors[9];
+  const visit = visitors[10];
   if (visit !== null) visit(new EmptyStatement(pos, ast));
 }
 
+function walkErrorStatement(pos, ast, visitors) {
+  const visit = visitors[11];
+  if (visit !== null) visit(new ErrorStatement(pos, ast));
+}
+
 function walkExpressionStatement(pos, ast, visitors) {
-  const enterExit = visitors[70];
+  const enterExit = visitors[72];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1570,7 +1597,7 @@ function walkExpressionStatement(pos, ast, visitors) {
 }
 
 function walkIfStatement(pos, ast, visitors) {
-  const enterExit = visitors[71];
+  const enterExit = visitors[73];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1586,7 +1613,7 @@ function walkIfStatement(pos, ast, visitors) {
 }
 
 function walkDoWhileStatement(pos, ast, visitors) {
-  const enterExit = visitors[72];
+  const enterExit = visitors[74];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1601,7 +1628,7 @@ function walkDoWhileStatement(pos, ast, visitors) {
 }
 
 function walkWhileStatement(pos, ast, visitors) {
-  const enterExit = visitors[73];
+  const enterExit = visitors[75];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1616,7 +1643,7 @@ function walkWhileStatement(pos, ast, visitors) {
 }
 
 function walkForStatement(pos, ast, visitors) {
-  const enterExit = visitors[74];
+  const enterExit = visitors[76];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1754,6 +1781,9 @@ function walkForStatementInit(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -1772,7 +1802,7 @@ function walkForStatementInit(pos, ast, visitors) {
 }
 
 function walkForInStatement(pos, ast, visitors) {
-  const enterExit = visitors[75];
+  const enterExit = visitors[77];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1828,7 +1858,7 @@ function walkForStatementLeft(pos, ast, visitors) {
 }
 
 function walkForOfStatement(pos, ast, visitors) {
-  const enterExit = visitors[76];
+  const enterExit = visitors[78];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1844,7 +1874,7 @@ function walkForOfStatement(pos, ast, visitors) {
 }
 
 function walkContinueStatement(pos, ast, visitors) {
-  const enterExit = visitors[77];
+  const enterExit = visitors[79];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1858,7 +1888,7 @@ function walkContinueStatement(pos, ast, visitors) {
 }
 
 function walkBreakStatement(pos, ast, visitors) {
-  const enterExit = visitors[78];
+  const enterExit = visitors[80];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1872,7 +1902,7 @@ function walkBreakStatement(pos, ast, visitors) {
 }
 
 function walkReturnStatement(pos, ast, visitors) {
-  const enterExit = visitors[79];
+  const enterExit = visitors[81];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1886,7 +1916,7 @@ function walkReturnStatement(pos, ast, visitors) {
 }
 
 function walkWithStatement(pos, ast, visitors) {
-  const enterExit = visitors[80];
+  const enterExit = visitors[82];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1901,7 +1931,7 @@ function walkWithStatement(pos, ast, visitors) {
 }
 
 function walkSwitchStatement(pos, ast, visitors) {
-  const enterExit = visitors[81];
+  const enterExit = visitors[83];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1916,7 +1946,7 @@ function walkSwitchStatement(pos, ast, visitors) {
 }
 
 function walkSwitchCase(pos, ast, visitors) {
-  const enterExit = visitors[82];
+  const enterExit = visitors[84];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1931,7 +1961,7 @@ function walkSwitchCase(pos, ast, visitors) {
 }
 
 function walkLabeledStatement(pos, ast, visitors) {
-  const enterExit = visitors[83];
+  const enterExit = visitors[85];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1946,7 +1976,7 @@ function walkLabeledStatement(pos, ast, visitors) {
 }
 
 function walkThrowStatement(pos, ast, visitors) {
-  const enterExit = visitors[84];
+  const enterExit = visitors[86];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1960,7 +1990,7 @@ function walkThrowStatement(pos, ast, visitors) {
 }
 
 function walkTryStatement(pos, ast, visitors) {
-  const enterExit = visitors[85];
+  const enterExit = visitors[87];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1976,7 +2006,7 @@ function walkTryStatement(pos, ast, visitors) {
 }
 
 function walkCatchClause(pos, ast, visitors) {
-  const enterExit = visitors[86];
+  const enterExit = visitors[88];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -1995,7 +2025,7 @@ function walkCatchParameter(pos, ast, visitors) {
 }
 
 function walkDebuggerStatement(pos, ast, visitors) {
-  const visit = visitors[10];
+  const visit = visitors[12];
   if (visit !== null) visit(new DebuggerStatement(pos, ast));
 }
 
@@ -2024,7 +2054,7 @@ function walkBindingPatternKind(pos, ast, visitors) {
 }
 
 function walkAssignmentPattern(pos, ast, visitors) {
-  const enterExit = visitors[87];
+  const enterExit = visitors[89];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2039,7 +2069,7 @@ function walkAssignmentPattern(pos, ast, visitors) {
 }
 
 function walkObjectPattern(pos, ast, visitors) {
-  const enterExit = visitors[88];
+  const enterExit = visitors[90];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2053,7 +2083,7 @@ function walkObjectPattern(pos, ast, visitors) {
 }
 
 function walkBindingProperty(pos, ast, visitors) {
-  const enterExit = visitors[89];
+  const enterExit = visitors[91];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2068,7 +2098,7 @@ function walkBindingProperty(pos, ast, visitors) {
 }
 
 function walkArrayPattern(pos, ast, visitors) {
-  const enterExit = visitors[90];
+  const enterExit = visitors[92];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2082,7 +2112,7 @@ function walkArrayPattern(pos, ast, visitors) {
 }
 
 function walkFunction(pos, ast, visitors) {
-  const enterExit = visitors[91];
+  const enterExit = visitors[93];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2100,7 +2130,7 @@ function walkFunction(pos, ast, visitors) {
 }
 
 function walkFormalParameters(pos, ast, visitors) {
-  const enterExit = visitors[92];
+  const enterExit = visitors[94];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2119,7 +2149,7 @@ function walkFormalParameter(pos, ast, visitors) {
 }
 
 function walkFunctionBody(pos, ast, visitors) {
-  const enterExit = visitors[93];
+  const enterExit = visitors[95];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2133,7 +2163,7 @@ function walkFunctionBody(pos, ast, visitors) {
 }
 
 function walkArrowFunctionExpression(pos, ast, visitors) {
-  const enterExit = visitors[94];
+  const enterExit = visitors[96];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2150,7 +2180,7 @@ function walkArrowFunctionExpression(pos, ast, visitors) {
 }
 
 function walkYieldExpression(pos, ast, visitors) {
-  const enterExit = visitors[95];
+  const enterExit = visitors[97];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2164,7 +2194,7 @@ function walkYieldExpression(pos, ast, visitors) {
 }
 
 function walkClass(pos, ast, visitors) {
-  const enterExit = visitors[96];
+  const enterExit = visitors[98];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2184,7 +2214,7 @@ function walkClass(pos, ast, visitors) {
 }
 
 function walkClassBody(pos, ast, visitors) {
-  const enterExit = visitors[97];
+  const enterExit = visitors[99];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2220,7 +2250,7 @@ function walkClassElement(pos, ast, visitors) {
 }
 
 function walkMethodDefinition(pos, ast, visitors) {
-  const enterExit = visitors[98];
+  const enterExit = visitors[100];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2236,7 +2266,7 @@ function walkMethodDefinition(pos, ast, visitors) {
 }
 
 function walkPropertyDefinition(pos, ast, visitors) {
-  const enterExit = visitors[99];
+  const enterExit = visitors[101];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2253,12 +2283,12 @@ function walkPropertyDefinition(pos, ast, visitors) {
 }
 
 function walkPrivateIdentifier(pos, ast, visitors) {
-  const visit = visitors[11];
+  const visit = visitors[13];
   if (visit !== null) visit(new PrivateIdentifier(pos, ast));
 }
 
 function walkStaticBlock(pos, ast, visitors) {
-  const enterExit = visitors[100];
+  const enterExit = visitors[102];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2272,7 +2302,7 @@ function walkStaticBlock(pos, ast, visitors) {
 }
 
 function walkAccessorProperty(pos, ast, visitors) {
-  const enterExit = visitors[101];
+  const enterExit = visitors[103];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2289,7 +2319,7 @@ function walkAccessorProperty(pos, ast, visitors) {
 }
 
 function walkImportExpression(pos, ast, visitors) {
-  const enterExit = visitors[102];
+  const enterExit = visitors[104];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2304,7 +2334,7 @@ function walkImportExpression(pos, ast, visitors) {
 }
 
 function walkImportDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[103];
+  const enterExit = visitors[105];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2336,7 +2366,7 @@ function walkImportDeclarationSpecifier(pos, ast, visitors) {
 }
 
 function walkImportSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[104];
+  const enterExit = visitors[106];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2351,7 +2381,7 @@ function walkImportSpecifier(pos, ast, visitors) {
 }
 
 function walkImportDefaultSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[105];
+  const enterExit = visitors[107];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2365,7 +2395,7 @@ function walkImportDefaultSpecifier(pos, ast, visitors) {
 }
 
 function walkImportNamespaceSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[106];
+  const enterExit = visitors[108];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2383,7 +2413,7 @@ function walkWithClause(pos, ast, visitors) {
 }
 
 function walkImportAttribute(pos, ast, visitors) {
-  const enterExit = visitors[107];
+  const enterExit = visitors[109];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2411,7 +2441,7 @@ function walkImportAttributeKey(pos, ast, visitors) {
 }
 
 function walkExportNamedDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[108];
+  const enterExit = visitors[110];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2428,7 +2458,7 @@ function walkExportNamedDeclaration(pos, ast, visitors) {
 }
 
 function walkExportDefaultDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[109];
+  const enterExit = visitors[111];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2442,7 +2472,7 @@ function walkExportDefaultDeclaration(pos, ast, visitors) {
 }
 
 function walkExportAllDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[110];
+  const enterExit = visitors[112];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2458,7 +2488,7 @@ function walkExportAllDeclaration(pos, ast, visitors) {
 }
 
 function walkExportSpecifier(pos, ast, visitors) {
-  const enterExit = visitors[111];
+  const enterExit = visitors[113];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2594,6 +2624,9 @@ function walkExportDefaultDeclarationKind(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -2634,7 +2667,7 @@ function walkModuleExportName(pos, ast, visitors) {
 }
 
 function walkV8IntrinsicExpression(pos, ast, visitors) {
-  const enterExit = visitors[112];
+  const enterExit = visitors[114];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2649,37 +2682,37 @@ function walkV8IntrinsicExpression(pos, ast, visitors) {
 }
 
 function walkBooleanLiteral(pos, ast, visitors) {
-  const visit = visitors[12];
+  const visit = visitors[14];
   if (visit !== null) visit(new BooleanLiteral(pos, ast));
 }
 
 function walkNullLiteral(pos, ast, visitors) {
-  const visit = visitors[13];
+  const visit = visitors[15];
   if (visit !== null) visit(new NullLiteral(pos, ast));
 }
 
 function walkNumericLiteral(pos, ast, visitors) {
-  const visit = visitors[14];
+  const visit = visitors[16];
   if (visit !== null) visit(new NumericLiteral(pos, ast));
 }
 
 function walkStringLiteral(pos, ast, visitors) {
-  const visit = visitors[15];
+  const visit = visitors[17];
   if (visit !== null) visit(new StringLiteral(pos, ast));
 }
 
 function walkBigIntLiteral(pos, ast, visitors) {
-  const visit = visitors[16];
+  const visit = visitors[18];
   if (visit !== null) visit(new BigIntLiteral(pos, ast));
 }
 
 function walkRegExpLiteral(pos, ast, visitors) {
-  const visit = visitors[17];
+  const visit = visitors[19];
   if (visit !== null) visit(new RegExpLiteral(pos, ast));
 }
 
 function walkJSXElement(pos, ast, visitors) {
-  const enterExit = visitors[113];
+  const enterExit = visitors[115];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2695,7 +2728,7 @@ function walkJSXElement(pos, ast, visitors) {
 }
 
 function walkJSXOpeningElement(pos, ast, visitors) {
-  const enterExit = visitors[114];
+  const enterExit = visitors[116];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2711,7 +2744,7 @@ function walkJSXOpeningElement(pos, ast, visitors) {
 }
 
 function walkJSXClosingElement(pos, ast, visitors) {
-  const enterExit = visitors[115];
+  const enterExit = visitors[117];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2725,7 +2758,7 @@ function walkJSXClosingElement(pos, ast, visitors) {
 }
 
 function walkJSXFragment(pos, ast, visitors) {
-  const enterExit = visitors[116];
+  const enterExit = visitors[118];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2741,12 +2774,12 @@ function walkJSXFragment(pos, ast, visitors) {
 }
 
 function walkJSXOpeningFragment(pos, ast, visitors) {
-  const visit = visitors[18];
+  const visit = visitors[20];
   if (visit !== null) visit(new JSXOpeningFragment(pos, ast));
 }
 
 function walkJSXClosingFragment(pos, ast, visitors) {
-  const visit = visitors[19];
+  const visit = visitors[21];
   if (visit !== null) visit(new JSXClosingFragment(pos, ast));
 }
 
@@ -2773,7 +2806,7 @@ function walkJSXElementName(pos, ast, visitors) {
 }
 
 function walkJSXNamespacedName(pos, ast, visitors) {
-  const enterExit = visitors[117];
+  const enterExit = visitors[119];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2788,7 +2821,7 @@ function walkJSXNamespacedName(pos, ast, visitors) {
 }
 
 function walkJSXMemberExpression(pos, ast, visitors) {
-  const enterExit = visitors[118];
+  const enterExit = visitors[120];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2819,7 +2852,7 @@ function walkJSXMemberExpressionObject(pos, ast, visitors) {
 }
 
 function walkJSXExpressionContainer(pos, ast, visitors) {
-  const enterExit = visitors[119];
+  const enterExit = visitors[121];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -2954,6 +2987,9 @@ function walkJSXExpression(pos, ast, visitors) {
     case 39:
       walkBoxV8IntrinsicExpression(pos + 8, ast, visitors);
       return;
+    case 40:
+      walkBoxErrorExpression(pos + 8, ast, visitors);
+      return;
     case 48:
       walkBoxComputedMemberExpression(pos + 8, ast, visitors);
       return;
@@ -2972,7 +3008,7 @@ function walkJSXExpression(pos, ast, visitors) {
 }
 
 function walkJSXEmptyExpression(pos, ast, visitors) {
-  const visit = visitors[20];
+  const visit = visitors[22];
   if (visit !== null) visit(new JSXEmptyExpression(pos, ast));
 }
 
@@ -2990,7 +3026,7 @@ function walkJSXAttributeItem(pos, ast, visitors) {
 }
 
 function walkJSXAttribute(pos, ast, visitors) {
-  const enterExit = visitors[120];
+  const enterExit = visitors[122];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3005,7 +3041,7 @@ function walkJSXAttribute(pos, ast, visitors) {
 }
 
 function walkJSXSpreadAttribute(pos, ast, visitors) {
-  const enterExit = visitors[121];
+  const enterExit = visitors[123];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3051,7 +3087,7 @@ function walkJSXAttributeValue(pos, ast, visitors) {
 }
 
 function walkJSXIdentifier(pos, ast, visitors) {
-  const visit = visitors[21];
+  const visit = visitors[23];
   if (visit !== null) visit(new JSXIdentifier(pos, ast));
 }
 
@@ -3078,7 +3114,7 @@ function walkJSXChild(pos, ast, visitors) {
 }
 
 function walkJSXSpreadChild(pos, ast, visitors) {
-  const enterExit = visitors[122];
+  const enterExit = visitors[124];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3092,12 +3128,12 @@ function walkJSXSpreadChild(pos, ast, visitors) {
 }
 
 function walkJSXText(pos, ast, visitors) {
-  const visit = visitors[22];
+  const visit = visitors[24];
   if (visit !== null) visit(new JSXText(pos, ast));
 }
 
 function walkTSEnumDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[123];
+  const enterExit = visitors[125];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3112,7 +3148,7 @@ function walkTSEnumDeclaration(pos, ast, visitors) {
 }
 
 function walkTSEnumBody(pos, ast, visitors) {
-  const enterExit = visitors[124];
+  const enterExit = visitors[126];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3126,7 +3162,7 @@ function walkTSEnumBody(pos, ast, visitors) {
 }
 
 function walkTSEnumMember(pos, ast, visitors) {
-  const enterExit = visitors[125];
+  const enterExit = visitors[127];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3160,7 +3196,7 @@ function walkTSEnumMemberName(pos, ast, visitors) {
 }
 
 function walkTSTypeAnnotation(pos, ast, visitors) {
-  const enterExit = visitors[126];
+  const enterExit = visitors[128];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3174,7 +3210,7 @@ function walkTSTypeAnnotation(pos, ast, visitors) {
 }
 
 function walkTSLiteralType(pos, ast, visitors) {
-  const enterExit = visitors[127];
+  const enterExit = visitors[129];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3331,7 +3367,7 @@ function walkTSType(pos, ast, visitors) {
 }
 
 function walkTSConditionalType(pos, ast, visitors) {
-  const enterExit = visitors[128];
+  const enterExit = visitors[130];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3348,7 +3384,7 @@ function walkTSConditionalType(pos, ast, visitors) {
 }
 
 function walkTSUnionType(pos, ast, visitors) {
-  const enterExit = visitors[129];
+  const enterExit = visitors[131];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3362,7 +3398,7 @@ function walkTSUnionType(pos, ast, visitors) {
 }
 
 function walkTSIntersectionType(pos, ast, visitors) {
-  const enterExit = visitors[130];
+  const enterExit = visitors[132];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3376,7 +3412,7 @@ function walkTSIntersectionType(pos, ast, visitors) {
 }
 
 function walkTSParenthesizedType(pos, ast, visitors) {
-  const enterExit = visitors[131];
+  const enterExit = visitors[133];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3390,7 +3426,7 @@ function walkTSParenthesizedType(pos, ast, visitors) {
 }
 
 function walkTSTypeOperator(pos, ast, visitors) {
-  const enterExit = visitors[132];
+  const enterExit = visitors[134];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3404,7 +3440,7 @@ function walkTSTypeOperator(pos, ast, visitors) {
 }
 
 function walkTSArrayType(pos, ast, visitors) {
-  const enterExit = visitors[133];
+  const enterExit = visitors[135];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3418,7 +3454,7 @@ function walkTSArrayType(pos, ast, visitors) {
 }
 
 function walkTSIndexedAccessType(pos, ast, visitors) {
-  const enterExit = visitors[134];
+  const enterExit = visitors[136];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3433,7 +3469,7 @@ function walkTSIndexedAccessType(pos, ast, visitors) {
 }
 
 function walkTSTupleType(pos, ast, visitors) {
-  const enterExit = visitors[135];
+  const enterExit = visitors[137];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3447,7 +3483,7 @@ function walkTSTupleType(pos, ast, visitors) {
 }
 
 function walkTSNamedTupleMember(pos, ast, visitors) {
-  const enterExit = visitors[136];
+  const enterExit = visitors[138];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3462,7 +3498,7 @@ function walkTSNamedTupleMember(pos, ast, visitors) {
 }
 
 function walkTSOptionalType(pos, ast, visitors) {
-  const enterExit = visitors[137];
+  const enterExit = visitors[139];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3476,7 +3512,7 @@ function walkTSOptionalType(pos, ast, visitors) {
 }
 
 function walkTSRestType(pos, ast, visitors) {
-  const enterExit = visitors[138];
+  const enterExit = visitors[140];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3614,77 +3650,77 @@ function walkTSTupleElement(pos, ast, visitors) {
 }
 
 function walkTSAnyKeyword(pos, ast, visitors) {
-  const visit = visitors[23];
+  const visit = visitors[25];
   if (visit !== null) visit(new TSAnyKeyword(pos, ast));
 }
 
 function walkTSStringKeyword(pos, ast, visitors) {
-  const visit = visitors[24];
+  const visit = visitors[26];
   if (visit !== null) visit(new TSStringKeyword(pos, ast));
 }
 
 function walkTSBooleanKeyword(pos, ast, visitors) {
-  const visit = visitors[25];
+  const visit = visitors[27];
   if (visit !== null) visit(new TSBooleanKeyword(pos, ast));
 }
 
 function walkTSNumberKeyword(pos, ast, visitors) {
-  const visit = visitors[26];
+  const visit = visitors[28];
   if (visit !== null) visit(new TSNumberKeyword(pos, ast));
 }
 
 function walkTSNeverKeyword(pos, ast, visitors) {
-  const visit = visitors[27];
+  const visit = visitors[29];
   if (visit !== null) visit(new TSNeverKeyword(pos, ast));
 }
 
 function walkTSIntrinsicKeyword(pos, ast, visitors) {
-  const visit = visitors[28];
+  const visit = visitors[30];
   if (visit !== null) visit(new TSIntrinsicKeyword(pos, ast));
 }
 
 function walkTSUnknownKeyword(pos, ast, visitors) {
-  const visit = visitors[29];
+  const visit = visitors[31];
   if (visit !== null) visit(new TSUnknownKeyword(pos, ast));
 }
 
 function walkTSNullKeyword(pos, ast, visitors) {
-  const visit = visitors[30];
+  const visit = visitors[32];
   if (visit !== null) visit(new TSNullKeyword(pos, ast));
 }
 
 function walkTSUndefinedKeyword(pos, ast, visitors) {
-  const visit = visitors[31];
+  const visit = visitors[33];
   if (visit !== null) visit(new TSUndefinedKeyword(pos, ast));
 }
 
 function walkTSVoidKeyword(pos, ast, visitors) {
-  const visit = visitors[32];
+  const visit = visitors[34];
   if (visit !== null) visit(new TSVoidKeyword(pos, ast));
 }
 
 function walkTSSymbolKeyword(pos, ast, visitors) {
-  const visit = visitors[33];
+  const visit = visitors[35];
   if (visit !== null) visit(new TSSymbolKeyword(pos, ast));
 }
 
 function walkTSThisType(pos, ast, visitors) {
-  const visit = visitors[34];
+  const visit = visitors[36];
   if (visit !== null) visit(new TSThisType(pos, ast));
 }
 
 function walkTSObjectKeyword(pos, ast, visitors) {
-  const visit = visitors[35];
+  const visit = visitors[37];
   if (visit !== null) visit(new TSObjectKeyword(pos, ast));
 }
 
 function walkTSBigIntKeyword(pos, ast, visitors) {
-  const visit = visitors[36];
+  const visit = visitors[38];
   if (visit !== null) visit(new TSBigIntKeyword(pos, ast));
 }
 
 function walkTSTypeReference(pos, ast, visitors) {
-  const enterExit = visitors[139];
+  const enterExit = visitors[141];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3715,7 +3751,7 @@ function walkTSTypeName(pos, ast, visitors) {
 }
 
 function walkTSQualifiedName(pos, ast, visitors) {
-  const enterExit = visitors[140];
+  const enterExit = visitors[142];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3730,7 +3766,7 @@ function walkTSQualifiedName(pos, ast, visitors) {
 }
 
 function walkTSTypeParameterInstantiation(pos, ast, visitors) {
-  const enterExit = visitors[141];
+  const enterExit = visitors[143];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3744,7 +3780,7 @@ function walkTSTypeParameterInstantiation(pos, ast, visitors) {
 }
 
 function walkTSTypeParameter(pos, ast, visitors) {
-  const enterExit = visitors[142];
+  const enterExit = visitors[144];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3760,7 +3796,7 @@ function walkTSTypeParameter(pos, ast, visitors) {
 }
 
 function walkTSTypeParameterDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[143];
+  const enterExit = visitors[145];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3774,7 +3810,7 @@ function walkTSTypeParameterDeclaration(pos, ast, visitors) {
 }
 
 function walkTSTypeAliasDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[144];
+  const enterExit = visitors[146];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3790,7 +3826,7 @@ function walkTSTypeAliasDeclaration(pos, ast, visitors) {
 }
 
 function walkTSClassImplements(pos, ast, visitors) {
-  const enterExit = visitors[145];
+  const enterExit = visitors[147];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3805,7 +3841,7 @@ function walkTSClassImplements(pos, ast, visitors) {
 }
 
 function walkTSInterfaceDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[146];
+  const enterExit = visitors[148];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3822,7 +3858,7 @@ function walkTSInterfaceDeclaration(pos, ast, visitors) {
 }
 
 function walkTSInterfaceBody(pos, ast, visitors) {
-  const enterExit = visitors[147];
+  const enterExit = visitors[149];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3836,7 +3872,7 @@ function walkTSInterfaceBody(pos, ast, visitors) {
 }
 
 function walkTSPropertySignature(pos, ast, visitors) {
-  const enterExit = visitors[148];
+  const enterExit = visitors[150];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3873,7 +3909,7 @@ function walkTSSignature(pos, ast, visitors) {
 }
 
 function walkTSIndexSignature(pos, ast, visitors) {
-  const enterExit = visitors[149];
+  const enterExit = visitors[151];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3888,7 +3924,7 @@ function walkTSIndexSignature(pos, ast, visitors) {
 }
 
 function walkTSCallSignatureDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[150];
+  const enterExit = visitors[152];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3904,7 +3940,7 @@ function walkTSCallSignatureDeclaration(pos, ast, visitors) {
 }
 
 function walkTSMethodSignature(pos, ast, visitors) {
-  const enterExit = visitors[151];
+  const enterExit = visitors[153];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3921,7 +3957,7 @@ function walkTSMethodSignature(pos, ast, visitors) {
 }
 
 function walkTSConstructSignatureDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[152];
+  const enterExit = visitors[154];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3937,7 +3973,7 @@ function walkTSConstructSignatureDeclaration(pos, ast, visitors) {
 }
 
 function walkTSIndexSignatureName(pos, ast, visitors) {
-  const enterExit = visitors[153];
+  const enterExit = visitors[155];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3951,7 +3987,7 @@ function walkTSIndexSignatureName(pos, ast, visitors) {
 }
 
 function walkTSInterfaceHeritage(pos, ast, visitors) {
-  const enterExit = visitors[154];
+  const enterExit = visitors[156];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3966,7 +4002,7 @@ function walkTSInterfaceHeritage(pos, ast, visitors) {
 }
 
 function walkTSTypePredicate(pos, ast, visitors) {
-  const enterExit = visitors[155];
+  const enterExit = visitors[157];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -3994,7 +4030,7 @@ function walkTSTypePredicateName(pos, ast, visitors) {
 }
 
 function walkTSModuleDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[156];
+  const enterExit = visitors[158];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4035,7 +4071,7 @@ function walkTSModuleDeclarationBody(pos, ast, visitors) {
 }
 
 function walkTSModuleBlock(pos, ast, visitors) {
-  const enterExit = visitors[157];
+  const enterExit = visitors[159];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4049,7 +4085,7 @@ function walkTSModuleBlock(pos, ast, visitors) {
 }
 
 function walkTSTypeLiteral(pos, ast, visitors) {
-  const enterExit = visitors[158];
+  const enterExit = visitors[160];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4063,7 +4099,7 @@ function walkTSTypeLiteral(pos, ast, visitors) {
 }
 
 function walkTSInferType(pos, ast, visitors) {
-  const enterExit = visitors[159];
+  const enterExit = visitors[161];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4077,7 +4113,7 @@ function walkTSInferType(pos, ast, visitors) {
 }
 
 function walkTSTypeQuery(pos, ast, visitors) {
-  const enterExit = visitors[160];
+  const enterExit = visitors[162];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4111,7 +4147,7 @@ function walkTSTypeQueryExprName(pos, ast, visitors) {
 }
 
 function walkTSImportType(pos, ast, visitors) {
-  const enterExit = visitors[161];
+  const enterExit = visitors[163];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4141,7 +4177,7 @@ function walkTSImportTypeQualifier(pos, ast, visitors) {
 }
 
 function walkTSImportTypeQualifiedName(pos, ast, visitors) {
-  const enterExit = visitors[162];
+  const enterExit = visitors[164];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4156,7 +4192,7 @@ function walkTSImportTypeQualifiedName(pos, ast, visitors) {
 }
 
 function walkTSFunctionType(pos, ast, visitors) {
-  const enterExit = visitors[163];
+  const enterExit = visitors[165];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4172,7 +4208,7 @@ function walkTSFunctionType(pos, ast, visitors) {
 }
 
 function walkTSConstructorType(pos, ast, visitors) {
-  const enterExit = visitors[164];
+  const enterExit = visitors[166];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4188,7 +4224,7 @@ function walkTSConstructorType(pos, ast, visitors) {
 }
 
 function walkTSMappedType(pos, ast, visitors) {
-  const enterExit = visitors[165];
+  const enterExit = visitors[167];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4203,7 +4239,7 @@ function walkTSMappedType(pos, ast, visitors) {
 }
 
 function walkTSTemplateLiteralType(pos, ast, visitors) {
-  const enterExit = visitors[166];
+  const enterExit = visitors[168];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4218,7 +4254,7 @@ function walkTSTemplateLiteralType(pos, ast, visitors) {
 }
 
 function walkTSAsExpression(pos, ast, visitors) {
-  const enterExit = visitors[167];
+  const enterExit = visitors[169];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4233,7 +4269,7 @@ function walkTSAsExpression(pos, ast, visitors) {
 }
 
 function walkTSSatisfiesExpression(pos, ast, visitors) {
-  const enterExit = visitors[168];
+  const enterExit = visitors[170];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4248,7 +4284,7 @@ function walkTSSatisfiesExpression(pos, ast, visitors) {
 }
 
 function walkTSTypeAssertion(pos, ast, visitors) {
-  const enterExit = visitors[169];
+  const enterExit = visitors[171];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4263,7 +4299,7 @@ function walkTSTypeAssertion(pos, ast, visitors) {
 }
 
 function walkTSImportEqualsDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[170];
+  const enterExit = visitors[172];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4297,7 +4333,7 @@ function walkTSModuleReference(pos, ast, visitors) {
 }
 
 function walkTSExternalModuleReference(pos, ast, visitors) {
-  const enterExit = visitors[171];
+  const enterExit = visitors[173];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4311,7 +4347,7 @@ function walkTSExternalModuleReference(pos, ast, visitors) {
 }
 
 function walkTSNonNullExpression(pos, ast, visitors) {
-  const enterExit = visitors[172];
+  const enterExit = visitors[174];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4325,7 +4361,7 @@ function walkTSNonNullExpression(pos, ast, visitors) {
 }
 
 function walkDecorator(pos, ast, visitors) {
-  const enterExit = visitors[173];
+  const enterExit = visitors[175];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4339,7 +4375,7 @@ function walkDecorator(pos, ast, visitors) {
 }
 
 function walkTSExportAssignment(pos, ast, visitors) {
-  const enterExit = visitors[174];
+  const enterExit = visitors[176];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4353,7 +4389,7 @@ function walkTSExportAssignment(pos, ast, visitors) {
 }
 
 function walkTSNamespaceExportDeclaration(pos, ast, visitors) {
-  const enterExit = visitors[175];
+  const enterExit = visitors[177];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4367,7 +4403,7 @@ function walkTSNamespaceExportDeclaration(pos, ast, visitors) {
 }
 
 function walkTSInstantiationExpression(pos, ast, visitors) {
-  const enterExit = visitors[176];
+  const enterExit = visitors[178];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4382,7 +4418,7 @@ function walkTSInstantiationExpression(pos, ast, visitors) {
 }
 
 function walkJSDocNullableType(pos, ast, visitors) {
-  const enterExit = visitors[177];
+  const enterExit = visitors[179];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4396,7 +4432,7 @@ function walkJSDocNullableType(pos, ast, visitors) {
 }
 
 function walkJSDocNonNullableType(pos, ast, visitors) {
-  const enterExit = visitors[178];
+  const enterExit = visitors[180];
   let node, enter, exit = null;
   if (enterExit !== null) {
     ({ enter, exit } = enterExit);
@@ -4410,7 +4446,7 @@ function walkJSDocNonNullableType(pos, ast, visitors) {
 }
 
 function walkJSDocUnknownType(pos, ast, visitors) {
-  const visit = visitors[37];
+  const visit = visitors[39];
   if (visit !== null) visit(new JSDocUnknownType(pos, ast));
 }
 
@@ -4591,6 +4627,10 @@ function walkBoxV8IntrinsicExpression(pos, ast, visitors) {
   return walkV8IntrinsicExpression(ast.buffer.uint32[pos >> 2], ast, visitors);
 }
 
+function walkBoxErrorExpression(pos, ast, visitors) {
+  return walkErrorExpression(ast.buffer.uint32[pos >> 2], ast, visitors);
+}
+
 function walkVecArrayExpressionElement(pos, ast, visitors) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
@@ -4806,6 +4846,10 @@ function walkBoxWithStatement(pos, ast, visitors) {
   return walkWithStatement(ast.buffer.uint32[pos >> 2], ast, visitors);
 }
 
+function walkBoxErrorStatement(pos, ast, visitors) {
+  return walkErrorStatement(ast.buffer.uint32[pos >> 2], ast, visitors);
+}
+
 function walkBoxVariableDeclaration(pos, ast, visitors) {
   return walkVariableDeclaration(ast.buffer.uint32[pos >> 2], ast, visitors);
 }
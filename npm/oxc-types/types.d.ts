@@ -50,6 +50,7 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | ErrorExpression
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -93,6 +94,11 @@ export interface ThisExpression extends Span {
   parent?: Node;
 }
 
+export interface ErrorExpression extends Span {
+  type: 'ErrorExpression';
+  parent?: Node;
+}
+
 export interface ArrayExpression extends Span {
   type: 'ArrayExpression';
   elements: Array<ArrayExpressionElement>;
@@ -397,6 +403,7 @@ export type Statement =
   | TryStatement
   | WhileStatement
   | WithStatement
+  | ErrorStatement
   | Declaration
   | ModuleDeclaration;
 
@@ -452,6 +459,11 @@ export interface EmptyStatement extends Span {
   parent?: Node;
 }
 
+export interface ErrorStatement extends Span {
+  type: 'ErrorStatement';
+  parent?: Node;
+}
+
 export interface ExpressionStatement extends Span {
   type: 'ExpressionStatement';
   expression: Expression;
@@ -1716,6 +1728,7 @@ export type Node =
   | BindingIdentifier
   | LabelIdentifier
   | ThisExpression
+  | ErrorExpression
   | ArrayExpression
   | ObjectExpression
   | ObjectProperty
@@ -1753,6 +1766,7 @@ export type Node =
   | VariableDeclaration
   | VariableDeclarator
   | EmptyStatement
+  | ErrorStatement
   | ExpressionStatement
   | IfStatement
   | DoWhileStatement
@@ -22,6 +22,8 @@ fn bench_formatter(criterion: &mut Criterion) {
                     allow_return_outside_function: true,
                     // `oxc_formatter` expects this to be false
                     preserve_parens: false,
+                    tokens: false,
+                    error_recovery: false,
                 };
                 let program = Parser::new(&allocator, source_text, source_type)
                     .with_options(parse_options)
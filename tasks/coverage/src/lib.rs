@@ -32,6 +32,10 @@ use crate::{
         formatter::{
             FormatterBabelCase, FormatterMiscCase, FormatterTest262Case, FormatterTypeScriptCase,
         },
+        incremental::{
+            IncrementalBabelCase, IncrementalMiscCase, IncrementalTest262Case,
+            IncrementalTypeScriptCase,
+        },
         minifier::{MinifierBabelCase, MinifierNodeCompatCase, MinifierTest262Case},
         semantic::{
             SemanticBabelCase, SemanticMiscCase, SemanticTest262Case, SemanticTypeScriptCase,
@@ -105,6 +109,15 @@ impl AppArgs {
         MiscSuite::<FormatterMiscCase>::new().run("formatter_misc", self);
     }
 
+    /// Not part of the default run. Checks [`Parser::reparse`](oxc::parser::Parser::reparse)
+    /// against a full parse.
+    pub fn run_incremental(&self) {
+        Test262Suite::<IncrementalTest262Case>::new().run("incremental_test262", self);
+        BabelSuite::<IncrementalBabelCase>::new().run("incremental_babel", self);
+        TypeScriptSuite::<IncrementalTypeScriptCase>::new().run("incremental_typescript", self);
+        MiscSuite::<IncrementalMiscCase>::new().run("incremental_misc", self);
+    }
+
     pub fn run_transformer(&self) {
         Test262Suite::<TransformerTest262Case>::new().run("transformer_test262", self);
         BabelSuite::<TransformerBabelCase>::new().run("transformer_babel", self);
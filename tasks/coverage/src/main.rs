@@ -30,6 +30,7 @@ fn main() {
         "semantic" => args.run_semantic(),
         "codegen" => args.run_codegen(),
         "formatter" => args.run_formatter(),
+        "incremental" => args.run_incremental(),
         "transformer" => args.run_transformer(),
         "transpiler" => args.run_transpiler(),
         "minifier" => args.run_minifier(),
@@ -27,6 +27,8 @@ fn get_result(source_text: &str, source_type: SourceType) -> TestResult {
         allow_return_outside_function: true,
         // `oxc_formatter` expects this to be false
         preserve_parens: false,
+        tokens: false,
+        error_recovery: false,
     };
     let ParserReturn { program, .. } =
         Parser::new(&allocator, source_text, source_type).with_options(parse_options).parse();
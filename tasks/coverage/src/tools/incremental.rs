@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use oxc::{
+    allocator::Allocator,
+    parser::{Parser, ParserReturn},
+    span::{SourceType, Span},
+};
+
+use crate::{
+    babel::BabelCase,
+    misc::MiscCase,
+    suite::{Case, TestResult},
+    test262::Test262Case,
+    typescript::TypeScriptCase,
+};
+
+/// Edits applied at a few positions in each file: deleting a character, and inserting text.
+const EDITS: [(bool, &str); 3] = [(true, ""), (false, ";"), (false, "\n")];
+
+/// Incremental reparse after an edit must match a full parse of the edited text
+fn get_result(source_text: &str, source_type: SourceType) -> TestResult {
+    let allocator = Allocator::default();
+    if !Parser::new(&allocator, source_text, source_type).parse().errors.is_empty() {
+        return TestResult::Passed;
+    }
+
+    for fraction in [4, 2] {
+        let mut start = source_text.len() / fraction;
+        while !source_text.is_char_boundary(start) {
+            start += 1;
+        }
+        for (delete, inserted) in EDITS {
+            let deleted = source_text[start..].chars().next().filter(|_| delete);
+            let end = start + deleted.map_or(0, char::len_utf8);
+            let new_source_text =
+                format!("{}{inserted}{}", &source_text[..start], &source_text[end..]);
+            let allocator = Allocator::default();
+            let previous = Parser::new(&allocator, source_text, source_type).parse();
+            #[expect(clippy::cast_possible_truncation)]
+            let edit = Span::new(start as u32, end as u32);
+            let ParserReturn { program, module_record, errors, .. } =
+                Parser::new(&allocator, &new_source_text, source_type).reparse(previous, edit);
+            let actual = format!("{program:#?}\n{module_record:#?}\n{}", errors.len());
+            let ParserReturn { program, module_record, errors, .. } =
+                Parser::new(&allocator, &new_source_text, source_type).parse();
+            let expected = format!("{program:#?}\n{module_record:#?}\n{}", errors.len());
+            if actual != expected {
+                return TestResult::Mismatch("Mismatch", actual, expected);
+            }
+        }
+    }
+    TestResult::Passed
+}
+
+pub struct IncrementalTest262Case {
+    base: Test262Case,
+}
+
+impl Case for IncrementalTest262Case {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: Test262Case::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.should_fail() || self.base.skip_test_case()
+    }
+
+    fn run(&mut self) {
+        let source_text = self.base.code();
+        let is_module = self.base.is_module();
+        let source_type = SourceType::default().with_module(is_module);
+        let result = get_result(source_text, source_type);
+        self.base.set_result(result);
+    }
+}
+
+pub struct IncrementalBabelCase {
+    base: BabelCase,
+}
+
+impl Case for IncrementalBabelCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: BabelCase::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case() || self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        let source_text = self.base.code();
+        let source_type = self.base.source_type();
+        let result = get_result(source_text, source_type);
+        self.base.set_result(result);
+    }
+}
+
+pub struct IncrementalTypeScriptCase {
+    base: TypeScriptCase,
+}
+
+impl Case for IncrementalTypeScriptCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: TypeScriptCase::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case() || self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        let units = self.base.units.clone();
+        for unit in units {
+            self.base.code = unit.content.to_string();
+            let result = get_result(&unit.content, unit.source_type);
+            if result != TestResult::Passed {
+                self.base.result = result;
+                return;
+            }
+        }
+        self.base.result = TestResult::Passed;
+    }
+}
+
+pub struct IncrementalMiscCase {
+    base: MiscCase,
+}
+
+impl Case for IncrementalMiscCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: MiscCase::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case() || self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        let source_text = self.base.code();
+        let source_type = self.base.source_type();
+        let result = get_result(source_text, source_type);
+        self.base.set_result(result);
+    }
+}
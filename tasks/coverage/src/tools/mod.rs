@@ -1,6 +1,7 @@
 pub mod codegen;
 pub mod estree;
 pub mod formatter;
+pub mod incremental;
 pub mod minifier;
 pub mod semantic;
 pub mod transformer;
@@ -428,6 +428,8 @@ impl TestRunner {
                 allow_return_outside_function: true,
                 // `oxc_formatter` expects this to be false
                 preserve_parens: false,
+                tokens: false,
+                error_recovery: false,
             })
             .parse();
         Formatter::new(&allocator, formatter_options).build(&ret.program)